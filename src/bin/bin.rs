@@ -1,4 +1,4 @@
-use ::num2words::{Currency, Lang, Num2Words};
+use ::num2words::{Currency, Lang, Num2Words, Unit};
 use std::env;
 use std::str::FromStr;
 
@@ -14,7 +14,7 @@ VERSION:
 
 COMMANDS:
 GLOBAL OPTIONS:
-    -l, --lang [value]          set language (default: "en")
+    -l, --lang [value]          set language, e.g. "nl" or "nl-NL" (default: "en")
     -t, --to [output]           set output (default: "cardinal")
     -p, --prefer [preference]   add a language preference (default: none)
     -h, --help                  show help
@@ -26,17 +26,52 @@ AVAILABLE LANGUAGES:
     fr_BE:   French (Belgium and the Democratic Republic of the Congo)
     fr_CH:   French (Swiss Confederation and Aosta Valley)
     uk:      Ukrainian
+    nl:      Dutch
+    fy:      Frisian
+    ps:      Pashto
+    be:      Belarusian
+    it:      Italian
+    pt:      Portuguese (Portugal)
+    pt_BR:   Portuguese (Brazil)
+    pl:      Polish
+    ar:      Arabic
+    ja:      Japanese
+    zh:      Chinese
+    ko:      Korean
+    hi:      Hindi
+    sv:      Swedish
+    no:      Norwegian (Bokmål)
+    da:      Danish
+    ro:      Romanian
+    cs:      Czech
+    sk:      Slovak
+    el:      Greek
+    fi:      Finnish
+    eo:      Esperanto
 
 AVAILABLE OUTPUTS:
-    cardinal:      forty-two (42)
-    ordinal:       forty-second (42)
-    ordinal_num:   42nd (42)
-    year:          nineteen oh-one (1901)
-    currency:      forty-two dollars and one cent (42.01)
+    cardinal:       forty-two (42)
+    ordinal:        forty-second (42)
+    ordinal_num:    42nd (42)
+    year:           nineteen oh-one (1901)
+    multiplicative: twice (2)
+    scientific:     ten to the third power (1e3)
+    approximate:    about a hundred (98)
+    compact:        two and a half million (2.5e6)
+    expanded:       two hundreds and four ones (204)
+    percent:        five percent (5)
+    permille:       twelve per mille (12)
+    decade:         the nineteen-nineties (1990)
+    century:        the twentieth century (1900)
+    currency:       forty-two dollars and one cent (42.01)
+    unit:           twenty-one kilometers (21 km)
 
 AVAILABLE CURRENCIES:
     ISO 4217 code      - USD, EUR, GBP, etc.
-    generic currencies - DINAR, DOLLAR, PESO, RIYAL"#;
+    generic currencies - DINAR, DOLLAR, MILL, PESO, RIYAL
+
+AVAILABLE UNITS:
+    mm, cm, m, km, mi, g, kg, l, s, min, h, d"#;
 
 fn get_version() -> String {
     let version = env!("CARGO_PKG_VERSION");
@@ -58,7 +93,13 @@ fn help() {
 }
 
 fn handle_cmd(n: String, mut args: std::env::Args) {
-    if let Some(mut num) = Num2Words::parse(&n) {
+    // whole numbers are read exactly, however many digits they have
+    let num = if !n.is_empty() && n.bytes().all(|d| d.is_ascii_digit()) {
+        Some(Num2Words::from_digits(&n))
+    } else {
+        Num2Words::parse(&n)
+    };
+    if let Some(mut num) = num {
         while let Some(arg) = args.next() {
             match arg.as_str() {
                 "--lang" | "-l" => match args.next() {
@@ -86,6 +127,8 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                     Some(t) => {
                         if let Ok(v) = Currency::from_str(t.as_str()) {
                             num = num.currency(v);
+                        } else if let Ok(v) = Unit::from_str(t.as_str()) {
+                            num = num.unit(v);
                         } else {
                             match t.as_str() {
                                 "cardinal" => {
@@ -100,6 +143,33 @@ fn handle_cmd(n: String, mut args: std::env::Args) {
                                 "year" => {
                                     num = num.year();
                                 }
+                                "multiplicative" => {
+                                    num = num.multiplicative();
+                                }
+                                "scientific" => {
+                                    num = num.scientific();
+                                }
+                                "approximate" => {
+                                    num = num.approximate();
+                                }
+                                "compact" => {
+                                    num = num.compact();
+                                }
+                                "expanded" => {
+                                    num = num.expanded();
+                                }
+                                "percent" => {
+                                    num = num.percent();
+                                }
+                                "permille" => {
+                                    num = num.permille();
+                                }
+                                "decade" => {
+                                    num = num.decade();
+                                }
+                                "century" => {
+                                    num = num.century();
+                                }
                                 _ => {
                                     eprintln!("Error: invalid to tag");
                                     return;
@@ -2,22 +2,66 @@ use std::str::FromStr;
 
 /// Defines currencies
 ///
-/// Every three-letter variant is a valid ISO 4217 currency code. The only
-/// exceptions are `DINAR`, `DOLLAR`, `PESO` and `RIYAL`, which are generic
-/// terminology for the respective currencies.
-#[derive(Clone, Copy)]
+/// Every currency in circulation has a variant named after its ISO 4217
+/// code, see [`Currency::from_code`]. The only exceptions are `DINAR`,
+/// `DOLLAR`, `MILL`, `PESO` and `RIYAL`, which are generic terminology for
+/// the respective currencies.
+///
+/// Languages read the currencies they have names for in their own words, and
+/// the others with the English names of [`Currency::default_string`].
+#[derive(Clone, Copy, Debug)]
 #[non_exhaustive]
 pub enum Currency {
     /// Dirham
     AED,
+    /// Afghan afghani
+    AFN,
+    /// Albanian lek
+    ALL,
+    /// Armenian dram
+    AMD,
+    /// Angolan kwanza
+    AOA,
     /// Argentine peso
     ARS,
     /// Australian dollar
     AUD,
+    /// Aruban florin
+    AWG,
+    /// Azerbaijani manat
+    AZN,
+    /// Bosnia and Herzegovina convertible mark
+    BAM,
+    /// Barbadian dollar
+    BBD,
+    /// Bangladeshi taka
+    BDT,
+    /// Bahraini dinar
+    BHD,
+    /// Burundian franc
+    BIF,
+    /// Bermudian dollar
+    BMD,
+    /// Brunei dollar
+    BND,
+    /// Bolivian boliviano
+    BOB,
     /// Brazilian real
     BRL,
+    /// Bahamian dollar
+    BSD,
+    /// Bhutanese ngultrum
+    BTN,
+    /// Botswana pula
+    BWP,
+    /// Belarusian ruble
+    BYN,
+    /// Belize dollar
+    BZD,
     /// Canadian dollar
     CAD,
+    /// Congolese franc
+    CDF,
     /// Swiss franc
     CHF,
     /// Chilean peso
@@ -28,86 +72,399 @@ pub enum Currency {
     COP,
     /// Costa Rican colón
     CRC,
+    /// Cuban peso
+    CUP,
+    /// Cape Verdean escudo
+    CVE,
+    /// Czech koruna
+    CZK,
     /// Dinar
     DINAR,
+    /// Djiboutian franc
+    DJF,
+    /// Danish krone
+    DKK,
     /// Dollar
     DOLLAR,
+    /// Dominican peso
+    DOP,
     /// Algerian dinar
     DZD,
+    /// Egyptian pound
+    EGP,
+    /// Eritrean nakfa
+    ERN,
+    /// Ethiopian birr
+    ETB,
     /// Euro
     EUR,
+    /// Fijian dollar
+    FJD,
+    /// Falkland Islands pound
+    FKP,
     /// British pound
     GBP,
+    /// Georgian lari
+    GEL,
+    /// Ghanaian cedi
+    GHS,
+    /// Gibraltar pound
+    GIP,
+    /// Gambian dalasi
+    GMD,
+    /// Guinean franc
+    GNF,
+    /// Guatemalan quetzal
+    GTQ,
+    /// Guyanese dollar
+    GYD,
     /// Hong Kong dollar
     HKD,
+    /// Honduran lempira
+    HNL,
+    /// Haitian gourde
+    HTG,
+    /// Hungarian forint
+    HUF,
     /// Indonesian rupiah
     IDR,
     /// Israeli new shekel
     ILS,
     /// Indian rupee
     INR,
+    /// Iraqi dinar
+    IQD,
+    /// Iranian rial
+    IRR,
+    /// Icelandic króna
+    ISK,
+    /// Jamaican dollar
+    JMD,
+    /// Jordanian dinar
+    JOD,
     /// Japanese yen
     JPY,
+    /// Kenyan shilling
+    KES,
+    /// Kyrgyzstani som
+    KGS,
+    /// Cambodian riel
+    KHR,
+    /// Comorian franc
+    KMF,
+    /// North Korean won
+    KPW,
     /// South Korean won
     KRW,
     /// Kuwaiti dinar
     KWD,
+    /// Cayman Islands dollar
+    KYD,
     /// Kazakhstani tenge
     KZT,
+    /// Lao kip
+    LAK,
+    /// Lebanese pound
+    LBP,
+    /// Sri Lankan rupee
+    LKR,
+    /// Liberian dollar
+    LRD,
+    /// Lesotho loti
+    LSL,
+    /// Libyan dinar
+    LYD,
+    /// Moroccan dirham
+    MAD,
+    /// Moldovan leu
+    MDL,
+    /// Malagasy ariary
+    MGA,
+    /// Dollar counted in mills, a thousandth of a dollar, e.g. for tax rates
+    MILL,
+    /// Macedonian denar
+    MKD,
+    /// Myanmar kyat
+    MMK,
+    /// Mongolian tögrög
+    MNT,
+    /// Macanese pataca
+    MOP,
+    /// Mauritanian ouguiya
+    MRU,
+    /// Mauritian rupee
+    MUR,
+    /// Maldivian rufiyaa
+    MVR,
+    /// Malawian kwacha
+    MWK,
     /// Mexican peso
     MXN,
     /// Malaysian ringgit
     MYR,
+    /// Mozambican metical
+    MZN,
+    /// Namibian dollar
+    NAD,
+    /// Nigerian naira
+    NGN,
+    /// Nicaraguan córdoba
+    NIO,
     /// Norwegian krone
     NOK,
+    /// Nepalese rupee
+    NPR,
     /// New Zealand dollar
     NZD,
+    /// Omani rial
+    OMR,
+    /// Panamanian balboa
+    PAB,
     /// Peruvian sol
     PEN,
     /// Peso
     PESO,
+    /// Papua New Guinean kina
+    PGK,
     /// Philippine peso
     PHP,
+    /// Pakistani rupee
+    PKR,
     /// Polish zloty
     PLN,
+    /// Paraguayan guaraní
+    PYG,
     /// Qatari riyal
     QAR,
     /// Riyal
     RIYAL,
+    /// Romanian leu
+    RON,
+    /// Serbian dinar
+    RSD,
     /// Russian ruble
     RUB,
+    /// Rwandan franc
+    RWF,
     /// Saudi riyal
     SAR,
+    /// Solomon Islands dollar
+    SBD,
+    /// Seychellois rupee
+    SCR,
+    /// Sudanese pound
+    SDG,
+    /// Swedish krona
+    SEK,
     /// Singapore dollar
     SGD,
+    /// Saint Helena pound
+    SHP,
+    /// Sierra Leonean leone
+    SLE,
+    /// Somali shilling
+    SOS,
+    /// Surinamese dollar
+    SRD,
+    /// South Sudanese pound
+    SSP,
+    /// São Tomé and Príncipe dobra
+    STN,
+    /// Salvadoran colón
+    SVC,
+    /// Syrian pound
+    SYP,
+    /// Eswatini lilangeni
+    SZL,
     /// Thai baht
     THB,
+    /// Tajikistani somoni
+    TJS,
+    /// Turkmenistani manat
+    TMT,
+    /// Tunisian dinar
+    TND,
+    /// Tongan paʻanga
+    TOP,
     /// Turkish lira
     TRY,
+    /// Trinidad and Tobago dollar
+    TTD,
     /// Taiwan dollar
     TWD,
+    /// Tanzanian shilling
+    TZS,
     /// Ukrainian hryvnia
     UAH,
+    /// Ugandan shilling
+    UGX,
     /// US dollar
     USD,
     /// Uruguayan peso
     UYU,
+    /// Uzbekistani som
+    UZS,
+    /// Venezuelan bolívar
+    VES,
     /// Vietnamese dong
     VND,
+    /// Vanuatu vatu
+    VUV,
+    /// Samoan tālā
+    WST,
+    /// Central African CFA franc
+    XAF,
+    /// East Caribbean dollar
+    XCD,
+    /// Caribbean guilder
+    XCG,
+    /// West African CFA franc
+    XOF,
+    /// CFP franc
+    XPF,
+    /// Yemeni rial
+    YER,
     /// South African rand
     ZAR,
+    /// Zambian kwacha
+    ZMW,
+    /// Zimbabwe gold
+    ZWG,
+    /// Currency given by its forms in the language it is read in, see
+    /// [`Currency::custom`]
+    Custom {
+        singular: &'static str,
+        plural: &'static str,
+        subunit_singular: &'static str,
+        subunit_plural: &'static str,
+        exponent: u32,
+        feminine: bool,
+        feminine_subunit: bool,
+    },
 }
 
 impl Currency {
+    /// Defines a currency by its forms, e.g. for cryptocurrencies, game
+    /// currencies or historical money
+    ///
+    /// The subunit is a tenth to the power of `exponent` of the unit, e.g. 8
+    /// for the satoshis of a bitcoin. Languages read the forms as they read
+    /// the name of any other currency, the singular after one and the plural
+    /// otherwise, and count them as masculine nouns unless
+    /// [`Currency::feminine`] says otherwise.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Words};
+    /// let bitcoin = Currency::custom("bitcoin", "bitcoins", "satoshi", "satoshis", 8);
+    /// assert_eq!(
+    ///     Num2Words::new(1.00000002).currency(bitcoin).to_words(),
+    ///     Ok(String::from("one bitcoin and two satoshis"))
+    /// );
+    /// ```
+    pub const fn custom(
+        singular: &'static str,
+        plural: &'static str,
+        subunit_singular: &'static str,
+        subunit_plural: &'static str,
+        exponent: u32,
+    ) -> Self {
+        Currency::Custom {
+            singular,
+            plural,
+            subunit_singular,
+            subunit_plural,
+            exponent,
+            feminine: false,
+            feminine_subunit: false,
+        }
+    }
+
+    /// Sets whether the names of a [`Currency::custom`] and of its subunit
+    /// are feminine, for the languages whose numbers agree with the gender
+    /// of what they count, e.g. "une livre" in French
+    ///
+    /// Other currencies have the gender of their name in each language.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Lang, Num2Words};
+    /// let crown = Currency::custom("couronne", "couronnes", "denier", "deniers", 2)
+    ///     .feminine(true, false);
+    /// assert_eq!(
+    ///     Num2Words::new(21.01)
+    ///         .lang(Lang::French)
+    ///         .currency(crown)
+    ///         .to_words(),
+    ///     Ok(String::from("vingt et une couronnes et un denier"))
+    /// );
+    /// ```
+    pub const fn feminine(self, unit: bool, subunit: bool) -> Self {
+        match self {
+            Currency::Custom {
+                singular,
+                plural,
+                subunit_singular,
+                subunit_plural,
+                exponent,
+                ..
+            } => Currency::Custom {
+                singular,
+                plural,
+                subunit_singular,
+                subunit_plural,
+                exponent,
+                feminine: unit,
+                feminine_subunit: subunit,
+            },
+            currency => currency,
+        }
+    }
+
+    /// Returns whether the name of a [`Currency::custom`] is feminine, see
+    /// [`Currency::feminine`]
+    pub fn is_feminine(&self) -> bool {
+        matches!(self, Currency::Custom { feminine: true, .. })
+    }
+
+    /// Returns whether the subunit name of a [`Currency::custom`] is
+    /// feminine, see [`Currency::feminine`]
+    pub fn is_subunit_feminine(&self) -> bool {
+        matches!(
+            self,
+            Currency::Custom {
+                feminine_subunit: true,
+                ..
+            }
+        )
+    }
+
     /// Returns a default string representation for the currency
     ///
     /// Since many languages share the same work for a specific currency (like
     /// euro), it is easier and wiser for modularity to have a default value.
     pub fn default_string(&self, plural_form: bool) -> String {
-        match self {
+        match *self {
+            Currency::Custom {
+                singular, plural, ..
+            } => return String::from(if plural_form { plural } else { singular }),
             Currency::AED => "dirham{}",
+            Currency::AFN => "afghani{}",
+            Currency::ALL => "albanian lek{}",
+            Currency::AMD => "armenian dram{}",
+            Currency::AOA => "kwanza{}",
             Currency::ARS => "argentine peso{}",
             Currency::AUD => "australian dollar{}",
+            Currency::AWG => "aruban florin{}",
+            Currency::AZN => "azerbaijani manat{}",
+            Currency::BAM => "convertible mark{}",
+            Currency::BBD => "barbadian dollar{}",
+            Currency::BDT => "taka{}",
+            Currency::BHD => "bahraini dinar{}",
+            Currency::BIF => "burundian franc{}",
+            Currency::BMD => "bermudian dollar{}",
+            Currency::BND => "brunei dollar{}",
+            Currency::BOB => "boliviano{}",
             Currency::BRL => {
                 if plural_form {
                     "reais"
@@ -115,7 +472,13 @@ impl Currency {
                     "real"
                 }
             }
+            Currency::BSD => "bahamian dollar{}",
+            Currency::BTN => "ngultrum{}",
+            Currency::BWP => "pula{}",
+            Currency::BYN => "belarusian ruble{}",
+            Currency::BZD => "belize dollar{}",
             Currency::CAD => "canadian dollar{}",
+            Currency::CDF => "congolese franc{}",
             Currency::CHF => "franc{}",
             Currency::CLP => "chilean peso{}",
             Currency::CNY => "yuan{}",
@@ -127,23 +490,92 @@ impl Currency {
                     "colón"
                 }
             }
+            Currency::CUP => "cuban peso{}",
+            Currency::CVE => "cape verdean escudo{}",
+            Currency::CZK => {
+                if plural_form {
+                    "czech korunas"
+                } else {
+                    "czech koruna"
+                }
+            }
             Currency::DINAR => "dinar{}",
+            Currency::DJF => "djiboutian franc{}",
+            Currency::DKK => "danish krone{}",
             Currency::DOLLAR => "dollar{}",
+            Currency::DOP => "dominican peso{}",
             Currency::DZD => "algerian dinar{}",
+            Currency::EGP => "egyptian pound{}",
+            Currency::ERN => "nakfa{}",
+            Currency::ETB => "birr{}",
             Currency::EUR => "euro{}",
+            Currency::FJD => "fijian dollar{}",
+            Currency::FKP => "falkland islands pound{}",
             Currency::GBP => "pound{}",
+            Currency::GEL => "lari{}",
+            Currency::GHS => "cedi{}",
+            Currency::GIP => "gibraltar pound{}",
+            Currency::GMD => "dalasi{}",
+            Currency::GNF => "guinean franc{}",
+            Currency::GTQ => "quetzal{}",
+            Currency::GYD => "guyanese dollar{}",
             Currency::HKD => "hong kong dollar{}",
+            Currency::HNL => "lempira{}",
+            Currency::HTG => "gourde{}",
+            Currency::HUF => "forint{}",
             Currency::IDR => "indonesian rupiah{}",
             Currency::ILS => "new shekel{}",
             Currency::INR => "rupee{}",
+            Currency::IQD => "iraqi dinar{}",
+            Currency::IRR => "iranian rial{}",
+            Currency::ISK => "icelandic króna{}",
+            Currency::JMD => "jamaican dollar{}",
+            Currency::JOD => "jordanian dinar{}",
             Currency::JPY => "yen{}",
+            Currency::KES => "kenyan shilling{}",
+            Currency::KGS => "kyrgyzstani som{}",
+            Currency::KHR => "riel{}",
+            Currency::KMF => "comorian franc{}",
+            Currency::KPW => "north korean won{}",
             Currency::KRW => "won{}",
             Currency::KWD => "kuwaiti dinar{}",
+            Currency::KYD => "cayman islands dollar{}",
             Currency::KZT => "tenge{}",
+            Currency::LAK => "kip{}",
+            Currency::LBP => "lebanese pound{}",
+            Currency::LKR => "sri lankan rupee{}",
+            Currency::LRD => "liberian dollar{}",
+            Currency::LSL => "loti{}",
+            Currency::LYD => "libyan dinar{}",
+            Currency::MAD => "moroccan dirham{}",
+            Currency::MDL => {
+                if plural_form {
+                    "moldovan lei"
+                } else {
+                    "moldovan leu"
+                }
+            }
+            Currency::MGA => "ariary{}",
+            Currency::MILL => "dollar{}",
+            Currency::MKD => "macedonian denar{}",
+            Currency::MMK => "kyat{}",
+            Currency::MNT => "tögrög{}",
+            Currency::MOP => "pataca{}",
+            Currency::MRU => "ouguiya{}",
+            Currency::MUR => "mauritian rupee{}",
+            Currency::MVR => "rufiyaa{}",
+            Currency::MWK => "malawian kwacha{}",
             Currency::MXN => "mexican peso{}",
             Currency::MYR => "ringgit{}",
+            Currency::MZN => "metical{}",
+            Currency::NAD => "namibian dollar{}",
+            Currency::NGN => "naira{}",
+            Currency::NIO => "córdoba{}",
             Currency::NOK => "norwegian krone{}",
+            Currency::NPR => "nepalese rupee{}",
             Currency::NZD => "new zealand dollar{}",
+            Currency::OMR => "omani rial{}",
+            Currency::PAB => "balboa{}",
             Currency::PEN => {
                 if plural_form {
                     "soles"
@@ -152,98 +584,469 @@ impl Currency {
                 }
             }
             Currency::PESO => "peso{}",
+            Currency::PGK => "kina{}",
             Currency::PHP => "philippine peso{}",
+            Currency::PKR => "pakistani rupee{}",
             Currency::PLN => "zloty{}",
+            Currency::PYG => "guaraní{}",
             Currency::QAR => "qatari riyal{}",
             Currency::RIYAL => "riyal{}",
+            Currency::RON => {
+                if plural_form {
+                    "romanian lei"
+                } else {
+                    "romanian leu"
+                }
+            }
+            Currency::RSD => "serbian dinar{}",
             Currency::RUB => "ruble{}",
+            Currency::RWF => "rwandan franc{}",
             Currency::SAR => "saudi riyal{}",
+            Currency::SBD => "solomon islands dollar{}",
+            Currency::SCR => "seychellois rupee{}",
+            Currency::SDG => "sudanese pound{}",
+            Currency::SEK => "swedish krona{}",
             Currency::SGD => "singapore dollar{}",
+            Currency::SHP => "saint helena pound{}",
+            Currency::SLE => "leone{}",
+            Currency::SOS => "somali shilling{}",
+            Currency::SRD => "surinamese dollar{}",
+            Currency::SSP => "south sudanese pound{}",
+            Currency::STN => "dobra{}",
+            Currency::SVC => "salvadoran colón{}",
+            Currency::SYP => "syrian pound{}",
+            Currency::SZL => "lilangeni{}",
             Currency::THB => "baht{}",
+            Currency::TJS => "somoni{}",
+            Currency::TMT => "turkmenistani manat{}",
+            Currency::TND => "tunisian dinar{}",
+            Currency::TOP => "paʻanga{}",
             Currency::TRY => "lira{}",
+            Currency::TTD => "trinidad and tobago dollar{}",
             Currency::TWD => "taiwan dollar{}",
+            Currency::TZS => "tanzanian shilling{}",
             Currency::UAH => "hryvnia{}",
+            Currency::UGX => "ugandan shilling{}",
             Currency::USD => "US dollar{}",
             Currency::UYU => "uruguayan peso{}",
+            Currency::UZS => "uzbekistani som{}",
+            Currency::VES => "venezuelan bolívar{}",
             Currency::VND => "dong{}",
+            Currency::VUV => "vatu{}",
+            Currency::WST => "tālā{}",
+            Currency::XAF => "central african CFA franc{}",
+            Currency::XCD => "east caribbean dollar{}",
+            Currency::XCG => "caribbean guilder{}",
+            Currency::XOF => "west african CFA franc{}",
+            Currency::XPF => "CFP franc{}",
+            Currency::YER => "yemeni rial{}",
             Currency::ZAR => "rand{}",
+            Currency::ZMW => "zambian kwacha{}",
+            Currency::ZWG => "zimbabwe gold",
         }
         .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    /// Returns the short native name of the currency, e.g. "dollar" rather
+    /// than "US dollar"
+    pub fn native_string(&self, plural_form: bool) -> String {
+        let full = self.default_string(plural_form);
+        match full.rsplit_once(' ') {
+            Some((_, short)) if !matches!(self, Currency::Custom { .. }) => String::from(short),
+            _ => full,
+        }
+    }
+
+    /// Returns the ISO 4217 code of the currency, e.g. "USD"
+    ///
+    /// Generic currencies such as `DOLLAR` have no code.
+    pub fn iso_code(&self) -> Option<String> {
+        match self {
+            Currency::DINAR
+            | Currency::DOLLAR
+            | Currency::MILL
+            | Currency::PESO
+            | Currency::RIYAL
+            | Currency::Custom { .. } => None,
+            _ => Some(format!("{:?}", self)),
+        }
+    }
+
+    /// Returns the symbol of the currency, e.g. "€"
+    ///
+    /// Only the currencies with a symbol of their own have one, e.g. the
+    /// swiss franc has none.
+    pub fn symbol(&self) -> Option<&'static str> {
+        match self {
+            Currency::DOLLAR | Currency::PESO | Currency::USD => Some("$"),
+            Currency::AUD => Some("A$"),
+            Currency::AZN => Some("₼"),
+            Currency::BDT => Some("৳"),
+            Currency::BRL => Some("R$"),
+            Currency::CAD => Some("CA$"),
+            Currency::CNY => Some("CN¥"),
+            Currency::CRC => Some("₡"),
+            Currency::EUR => Some("€"),
+            Currency::GBP => Some("£"),
+            Currency::GEL => Some("₾"),
+            Currency::GHS => Some("GH₵"),
+            Currency::HKD => Some("HK$"),
+            Currency::ILS => Some("₪"),
+            Currency::INR => Some("₹"),
+            Currency::JPY => Some("¥"),
+            Currency::KHR => Some("៛"),
+            Currency::KRW => Some("₩"),
+            Currency::KZT => Some("₸"),
+            Currency::LAK => Some("₭"),
+            Currency::MNT => Some("₮"),
+            Currency::MXN => Some("MX$"),
+            Currency::NGN => Some("₦"),
+            Currency::NZD => Some("NZ$"),
+            Currency::PHP => Some("₱"),
+            Currency::PLN => Some("zł"),
+            Currency::PYG => Some("₲"),
+            Currency::RUB => Some("₽"),
+            Currency::SGD => Some("S$"),
+            Currency::THB => Some("฿"),
+            Currency::TRY => Some("₺"),
+            Currency::TWD => Some("NT$"),
+            Currency::UAH => Some("₴"),
+            Currency::VND => Some("₫"),
+            _ => None,
+        }
+    }
+
+    /// Returns the currency of an ISO 4217 code, e.g. "CHF"
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Words};
+    /// let franc = Currency::from_code("CHF").unwrap();
+    /// assert_eq!(
+    ///     Num2Words::new(2).currency(franc).to_words(),
+    ///     Ok(String::from("two francs"))
+    /// );
+    /// assert!(Currency::from_code("DOLLAR").is_none());
+    /// ```
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "AED" => Some(Currency::AED),
+            "AFN" => Some(Currency::AFN),
+            "ALL" => Some(Currency::ALL),
+            "AMD" => Some(Currency::AMD),
+            "AOA" => Some(Currency::AOA),
+            "ARS" => Some(Currency::ARS),
+            "AUD" => Some(Currency::AUD),
+            "AWG" => Some(Currency::AWG),
+            "AZN" => Some(Currency::AZN),
+            "BAM" => Some(Currency::BAM),
+            "BBD" => Some(Currency::BBD),
+            "BDT" => Some(Currency::BDT),
+            "BHD" => Some(Currency::BHD),
+            "BIF" => Some(Currency::BIF),
+            "BMD" => Some(Currency::BMD),
+            "BND" => Some(Currency::BND),
+            "BOB" => Some(Currency::BOB),
+            "BRL" => Some(Currency::BRL),
+            "BSD" => Some(Currency::BSD),
+            "BTN" => Some(Currency::BTN),
+            "BWP" => Some(Currency::BWP),
+            "BYN" => Some(Currency::BYN),
+            "BZD" => Some(Currency::BZD),
+            "CAD" => Some(Currency::CAD),
+            "CDF" => Some(Currency::CDF),
+            "CHF" => Some(Currency::CHF),
+            "CLP" => Some(Currency::CLP),
+            "CNY" => Some(Currency::CNY),
+            "COP" => Some(Currency::COP),
+            "CRC" => Some(Currency::CRC),
+            "CUP" => Some(Currency::CUP),
+            "CVE" => Some(Currency::CVE),
+            "CZK" => Some(Currency::CZK),
+            "DJF" => Some(Currency::DJF),
+            "DKK" => Some(Currency::DKK),
+            "DOP" => Some(Currency::DOP),
+            "DZD" => Some(Currency::DZD),
+            "EGP" => Some(Currency::EGP),
+            "ERN" => Some(Currency::ERN),
+            "ETB" => Some(Currency::ETB),
+            "EUR" => Some(Currency::EUR),
+            "FJD" => Some(Currency::FJD),
+            "FKP" => Some(Currency::FKP),
+            "GBP" => Some(Currency::GBP),
+            "GEL" => Some(Currency::GEL),
+            "GHS" => Some(Currency::GHS),
+            "GIP" => Some(Currency::GIP),
+            "GMD" => Some(Currency::GMD),
+            "GNF" => Some(Currency::GNF),
+            "GTQ" => Some(Currency::GTQ),
+            "GYD" => Some(Currency::GYD),
+            "HKD" => Some(Currency::HKD),
+            "HNL" => Some(Currency::HNL),
+            "HTG" => Some(Currency::HTG),
+            "HUF" => Some(Currency::HUF),
+            "IDR" => Some(Currency::IDR),
+            "ILS" => Some(Currency::ILS),
+            "INR" => Some(Currency::INR),
+            "IQD" => Some(Currency::IQD),
+            "IRR" => Some(Currency::IRR),
+            "ISK" => Some(Currency::ISK),
+            "JMD" => Some(Currency::JMD),
+            "JOD" => Some(Currency::JOD),
+            "JPY" => Some(Currency::JPY),
+            "KES" => Some(Currency::KES),
+            "KGS" => Some(Currency::KGS),
+            "KHR" => Some(Currency::KHR),
+            "KMF" => Some(Currency::KMF),
+            "KPW" => Some(Currency::KPW),
+            "KRW" => Some(Currency::KRW),
+            "KWD" => Some(Currency::KWD),
+            "KYD" => Some(Currency::KYD),
+            "KZT" => Some(Currency::KZT),
+            "LAK" => Some(Currency::LAK),
+            "LBP" => Some(Currency::LBP),
+            "LKR" => Some(Currency::LKR),
+            "LRD" => Some(Currency::LRD),
+            "LSL" => Some(Currency::LSL),
+            "LYD" => Some(Currency::LYD),
+            "MAD" => Some(Currency::MAD),
+            "MDL" => Some(Currency::MDL),
+            "MGA" => Some(Currency::MGA),
+            "MKD" => Some(Currency::MKD),
+            "MMK" => Some(Currency::MMK),
+            "MNT" => Some(Currency::MNT),
+            "MOP" => Some(Currency::MOP),
+            "MRU" => Some(Currency::MRU),
+            "MUR" => Some(Currency::MUR),
+            "MVR" => Some(Currency::MVR),
+            "MWK" => Some(Currency::MWK),
+            "MXN" => Some(Currency::MXN),
+            "MYR" => Some(Currency::MYR),
+            "MZN" => Some(Currency::MZN),
+            "NAD" => Some(Currency::NAD),
+            "NGN" => Some(Currency::NGN),
+            "NIO" => Some(Currency::NIO),
+            "NOK" => Some(Currency::NOK),
+            "NPR" => Some(Currency::NPR),
+            "NZD" => Some(Currency::NZD),
+            "OMR" => Some(Currency::OMR),
+            "PAB" => Some(Currency::PAB),
+            "PEN" => Some(Currency::PEN),
+            "PGK" => Some(Currency::PGK),
+            "PHP" => Some(Currency::PHP),
+            "PKR" => Some(Currency::PKR),
+            "PLN" => Some(Currency::PLN),
+            "PYG" => Some(Currency::PYG),
+            "QAR" => Some(Currency::QAR),
+            "RON" => Some(Currency::RON),
+            "RSD" => Some(Currency::RSD),
+            "RUB" => Some(Currency::RUB),
+            "RWF" => Some(Currency::RWF),
+            "SAR" => Some(Currency::SAR),
+            "SBD" => Some(Currency::SBD),
+            "SCR" => Some(Currency::SCR),
+            "SDG" => Some(Currency::SDG),
+            "SEK" => Some(Currency::SEK),
+            "SGD" => Some(Currency::SGD),
+            "SHP" => Some(Currency::SHP),
+            "SLE" => Some(Currency::SLE),
+            "SOS" => Some(Currency::SOS),
+            "SRD" => Some(Currency::SRD),
+            "SSP" => Some(Currency::SSP),
+            "STN" => Some(Currency::STN),
+            "SVC" => Some(Currency::SVC),
+            "SYP" => Some(Currency::SYP),
+            "SZL" => Some(Currency::SZL),
+            "THB" => Some(Currency::THB),
+            "TJS" => Some(Currency::TJS),
+            "TMT" => Some(Currency::TMT),
+            "TND" => Some(Currency::TND),
+            "TOP" => Some(Currency::TOP),
+            "TRY" => Some(Currency::TRY),
+            "TTD" => Some(Currency::TTD),
+            "TWD" => Some(Currency::TWD),
+            "TZS" => Some(Currency::TZS),
+            "UAH" => Some(Currency::UAH),
+            "UGX" => Some(Currency::UGX),
+            "USD" => Some(Currency::USD),
+            "UYU" => Some(Currency::UYU),
+            "UZS" => Some(Currency::UZS),
+            "VES" => Some(Currency::VES),
+            "VND" => Some(Currency::VND),
+            "VUV" => Some(Currency::VUV),
+            "WST" => Some(Currency::WST),
+            "XAF" => Some(Currency::XAF),
+            "XCD" => Some(Currency::XCD),
+            "XCG" => Some(Currency::XCG),
+            "XOF" => Some(Currency::XOF),
+            "XPF" => Some(Currency::XPF),
+            "YER" => Some(Currency::YER),
+            "ZAR" => Some(Currency::ZAR),
+            "ZMW" => Some(Currency::ZMW),
+            "ZWG" => Some(Currency::ZWG),
+            _ => None,
+        }
+    }
+
+    /// Returns the number of decimals of the currency's subunit, as given by
+    /// ISO 4217, e.g. 2 for the cents of a dollar, 3 for the fils of a Kuwaiti
+    /// dinar and 0 for the yen, which has no subunit in use
+    pub fn subunit_exponent(&self) -> u32 {
+        match *self {
+            Currency::Custom { exponent, .. } => exponent,
+            Currency::BIF
+            | Currency::CLP
+            | Currency::DJF
+            | Currency::GNF
+            | Currency::ISK
+            | Currency::JPY
+            | Currency::KMF
+            | Currency::KRW
+            | Currency::PYG
+            | Currency::RWF
+            | Currency::UGX
+            | Currency::VND
+            | Currency::VUV
+            | Currency::XAF
+            | Currency::XOF
+            | Currency::XPF => 0,
+            Currency::BHD
+            | Currency::IQD
+            | Currency::JOD
+            | Currency::KWD
+            | Currency::LYD
+            | Currency::MILL
+            | Currency::OMR
+            | Currency::TND => 3,
+            _ => 2,
+        }
+    }
+
+    /// Returns how many subunits make up one unit of the currency, e.g. 100
+    /// cents in a dollar, or 1 for a currency without subunits
+    pub fn subunit_ratio(&self) -> u64 {
+        10u64.pow(self.subunit_exponent())
+    }
+
     /// Returns a default string representation for the cents of the currency
     pub fn default_subunit_string(&self, cent: &str, plural_form: bool) -> String {
-        String::from(
-            match self {
-                Currency::AED | Currency::KWD => "fils",
-                Currency::ARS | Currency::BRL | Currency::CLP | Currency::COP | Currency::MXN => {
-                    "centavo{}"
+        match *self {
+            Currency::Custom {
+                subunit_singular,
+                subunit_plural,
+                ..
+            } => {
+                return String::from(if plural_form {
+                    subunit_plural
+                } else {
+                    subunit_singular
+                })
+            }
+            Currency::AED
+            | Currency::BHD
+            | Currency::IQD
+            | Currency::JOD
+            | Currency::KWD
+            | Currency::YER => "fils",
+            Currency::AFN => "pul{}",
+            Currency::ALL => "qindarka",
+            Currency::AMD => "luma",
+            Currency::AOA | Currency::STN => "cêntimo{}",
+            Currency::ARS
+            | Currency::BOB
+            | Currency::BRL
+            | Currency::CLP
+            | Currency::COP
+            | Currency::CUP
+            | Currency::CVE
+            | Currency::DOP
+            | Currency::GTQ
+            | Currency::HNL
+            | Currency::MXN
+            | Currency::MZN
+            | Currency::NIO
+            | Currency::SVC => "centavo{}",
+            Currency::AZN => "qəpik{}",
+            Currency::BAM => "fening{}",
+            Currency::BDT => "poisha",
+            Currency::BTN => "chhertum{}",
+            Currency::BWP => "thebe",
+            Currency::BYN => "kopeck{}",
+            Currency::CDF | Currency::HTG | Currency::MAD => "centime{}",
+            Currency::CRC | Currency::VES => "céntimo{}",
+            Currency::CZK => "haler{}",
+            Currency::BND | Currency::IDR | Currency::KHR | Currency::MYR => "sen{}",
+            Currency::DKK => "øre",
+            Currency::EGP | Currency::LBP | Currency::SDG | Currency::SSP | Currency::SYP => {
+                "piastre{}"
+            }
+            Currency::ETB => "santim{}",
+            Currency::FKP | Currency::GIP | Currency::SHP => "penny",
+            Currency::GEL => "tetri",
+            Currency::GHS => "pesewa{}",
+            Currency::GMD => "butut{}",
+            Currency::HUF => "fillér{}",
+            Currency::IRR => "dinar{}",
+            Currency::KGS => "tyiyn{}",
+            Currency::KPW => "chon{}",
+            Currency::KRW => "jeon{}",
+            Currency::LAK => "att{}",
+            Currency::LSL => "sente{}",
+            Currency::LYD => "dirham{}",
+            Currency::MGA => "iraimbilanja{}",
+            Currency::MILL => "mill{}",
+            Currency::MKD => "deni",
+            Currency::MMK => "pya{}",
+            Currency::MNT => "möngö{}",
+            Currency::MOP => "avo{}",
+            Currency::MRU => "khoums",
+            Currency::MVR => "laari{}",
+            Currency::MWK => "tambala{}",
+            Currency::NGN => "kobo{}",
+            Currency::NPR | Currency::PKR => "paisa{}",
+            Currency::OMR => "baisa{}",
+            Currency::PAB => "centésimo{}",
+            Currency::PGK => "toea{}",
+            Currency::MDL | Currency::RON => {
+                if plural_form {
+                    "bani"
+                } else {
+                    "ban"
                 }
-                Currency::CRC => "céntimo{}",
-                Currency::IDR | Currency::MYR => "sen{}",
-                Currency::KRW => "jeon{}",
-                Currency::SAR => "halalat{}",
-                Currency::THB => "satang{}",
-                Currency::UAH => "kopiyok{}",
-                Currency::UYU => "centesimo{}",
-                Currency::VND => "xu{}",
-                _ => cent,
             }
-            .replace("{}", if plural_form { "s" } else { "" }),
-        )
+            Currency::RSD => "para{}",
+            Currency::SAR => "halalat{}",
+            Currency::SEK => "öre",
+            Currency::THB => "satang{}",
+            Currency::TJS => "diram{}",
+            Currency::TMT => "tenge{}",
+            Currency::TND => "millime{}",
+            Currency::TOP => "seniti",
+            Currency::UAH => "kopiyok{}",
+            Currency::UYU => "centesimo{}",
+            Currency::UZS => "tiyin{}",
+            Currency::VND => "xu{}",
+            Currency::WST => "sene",
+            Currency::ZMW => "ngwee",
+            _ => cent,
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
     }
 }
 
 impl FromStr for Currency {
     type Err = ();
 
+    /// Parses an ISO 4217 code, see [`Currency::from_code`], or the name of a
+    /// generic currency, e.g. "DOLLAR"
     fn from_str(currency: &str) -> Result<Self, Self::Err> {
         match currency {
-            "AED" => Ok(Currency::AED),
-            "ARS" => Ok(Currency::ARS),
-            "AUD" => Ok(Currency::AUD),
-            "BRL" => Ok(Currency::BRL),
-            "CAD" => Ok(Currency::CAD),
-            "CHF" => Ok(Currency::CHF),
-            "CLP" => Ok(Currency::CLP),
-            "CNY" => Ok(Currency::CNY),
-            "COP" => Ok(Currency::COP),
-            "CRC" => Ok(Currency::CRC),
             "DINAR" => Ok(Currency::DINAR),
             "DOLLAR" => Ok(Currency::DOLLAR),
-            "DZD" => Ok(Currency::DZD),
-            "EUR" => Ok(Currency::EUR),
-            "GBP" => Ok(Currency::GBP),
-            "HKD" => Ok(Currency::HKD),
-            "IDR" => Ok(Currency::IDR),
-            "ILS" => Ok(Currency::ILS),
-            "INR" => Ok(Currency::INR),
-            "JPY" => Ok(Currency::JPY),
-            "KRW" => Ok(Currency::KRW),
-            "KWD" => Ok(Currency::KWD),
-            "KZT" => Ok(Currency::KZT),
-            "MXN" => Ok(Currency::MXN),
-            "MYR" => Ok(Currency::MYR),
-            "NOK" => Ok(Currency::NOK),
-            "NZD" => Ok(Currency::NZD),
-            "PEN" => Ok(Currency::PEN),
+            "MILL" => Ok(Currency::MILL),
             "PESO" => Ok(Currency::PESO),
-            "PHP" => Ok(Currency::PHP),
-            "PLN" => Ok(Currency::PLN),
-            "QAR" => Ok(Currency::QAR),
             "RIYAL" => Ok(Currency::RIYAL),
-            "RUB" => Ok(Currency::RUB),
-            "SAR" => Ok(Currency::SAR),
-            "SGD" => Ok(Currency::SGD),
-            "THB" => Ok(Currency::THB),
-            "TRY" => Ok(Currency::TRY),
-            "TWD" => Ok(Currency::TWD),
-            "UAH" => Ok(Currency::UAH),
-            "USD" => Ok(Currency::USD),
-            "UYU" => Ok(Currency::UYU),
-            "VND" => Ok(Currency::VND),
-            "ZAR" => Ok(Currency::ZAR),
-            _ => Err(()),
+            _ => Currency::from_code(currency).ok_or(()),
         }
     }
 }
@@ -0,0 +1,711 @@
+use crate::{
+    lang::{whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Gender, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Arabic {
+    feminine: bool,
+}
+
+// Forms counting masculine nouns, e.g. "ثلاثة آلاف"
+const UNITS: [&str; 9] = [
+    "واحد",
+    "اثنان",
+    "ثلاثة",
+    "أربعة",
+    "خمسة",
+    "ستة",
+    "سبعة",
+    "ثمانية",
+    "تسعة",
+];
+
+// Forms counting feminine nouns, e.g. "ثلاث هللات"
+const UNITS_FEMININE: [&str; 9] = [
+    "واحدة",
+    "اثنتان",
+    "ثلاث",
+    "أربع",
+    "خمس",
+    "ست",
+    "سبع",
+    "ثماني",
+    "تسع",
+];
+
+const TEENS: [&str; 10] = [
+    "عشرة",
+    "أحد عشر",
+    "اثنا عشر",
+    "ثلاثة عشر",
+    "أربعة عشر",
+    "خمسة عشر",
+    "ستة عشر",
+    "سبعة عشر",
+    "ثمانية عشر",
+    "تسعة عشر",
+];
+
+const TEENS_FEMININE: [&str; 10] = [
+    "عشر",
+    "إحدى عشرة",
+    "اثنتا عشرة",
+    "ثلاث عشرة",
+    "أربع عشرة",
+    "خمس عشرة",
+    "ست عشرة",
+    "سبع عشرة",
+    "ثماني عشرة",
+    "تسع عشرة",
+];
+
+const TENS: [&str; 9] = [
+    "عشرة",
+    "عشرون",
+    "ثلاثون",
+    "أربعون",
+    "خمسون",
+    "ستون",
+    "سبعون",
+    "ثمانون",
+    "تسعون",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "مئة",
+    "مئتان",
+    "ثلاثمئة",
+    "أربعمئة",
+    "خمسمئة",
+    "ستمئة",
+    "سبعمئة",
+    "ثمانمئة",
+    "تسعمئة",
+];
+
+// Singular, dual, plural (3 to 10) and accusative (11 to 99) forms, e.g.
+// "ألف", "ألفان", "ثلاثة آلاف", "أحد عشر ألفًا"
+const MEGAS: [[&str; 4]; 6] = [
+    ["ألف", "ألفان", "آلاف", "ألفًا"],
+    ["مليون", "مليونان", "ملايين", "مليونًا"],
+    ["مليار", "ملياران", "مليارات", "مليارًا"],
+    ["تريليون", "تريليونان", "تريليونات", "تريليونًا"],
+    ["كوادريليون", "كوادريليونان", "كوادريليونات", "كوادريليونًا"],
+    ["كوينتليون", "كوينتليونان", "كوينتليونات", "كوينتليونًا"],
+];
+
+const UNITS_ORDINAL: [&str; 10] = [
+    "الأول",
+    "الثاني",
+    "الثالث",
+    "الرابع",
+    "الخامس",
+    "السادس",
+    "السابع",
+    "الثامن",
+    "التاسع",
+    "العاشر",
+];
+
+const UNITS_ORDINAL_FEMININE: [&str; 10] = [
+    "الأولى",
+    "الثانية",
+    "الثالثة",
+    "الرابعة",
+    "الخامسة",
+    "السادسة",
+    "السابعة",
+    "الثامنة",
+    "التاسعة",
+    "العاشرة",
+];
+
+impl Arabic {
+    pub fn new(feminine: bool) -> Self {
+        Self { feminine }
+    }
+
+    fn currencies(&self, currency: Currency) -> Option<[&'static str; 4]> {
+        match currency {
+            Currency::BHD | Currency::DINAR | Currency::DZD | Currency::KWD => {
+                Some(["دينار", "ديناران", "دنانير", "دينارًا"])
+            }
+            Currency::AED => Some(["درهم", "درهمان", "دراهم", "درهمًا"]),
+            Currency::RIYAL | Currency::SAR | Currency::QAR => {
+                Some(["ريال", "ريالان", "ريالات", "ريالًا"])
+            }
+            Currency::DOLLAR | Currency::USD => Some(["دولار", "دولاران", "دولارات", "دولارًا"]),
+            // "يورو" is invariable but for its dual, e.g. "ثلاثة يورو"
+            Currency::EUR => Some(["يورو", "يوروان", "يورو", "يورو"]),
+            _ => None,
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> Option<[&'static str; 4]> {
+        match currency {
+            Currency::BHD | Currency::DINAR | Currency::KWD | Currency::AED => {
+                Some(["فلس", "فلسان", "فلوس", "فلسًا"])
+            }
+            Currency::DZD => Some(["سنتيم", "سنتيمان", "سنتيمات", "سنتيمًا"]),
+            Currency::RIYAL | Currency::SAR => Some(["هللة", "هللتان", "هللات", "هللةً"]),
+            Currency::QAR => Some(["درهم", "درهمان", "دراهم", "درهمًا"]),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => {
+                Some(["سنت", "سنتان", "سنتات", "سنتًا"])
+            }
+            _ => None,
+        }
+    }
+
+    // halalas are feminine, e.g. "ثلاث هللات"
+    fn cents_feminine(&self, currency: Currency) -> bool {
+        matches!(currency, Currency::RIYAL | Currency::SAR) || currency.is_subunit_feminine()
+    }
+
+    // index of the noun form agreeing with the count, see `MEGAS`
+    fn noun_form(&self, num: BigFloat) -> usize {
+        if num == BigFloat::from(1) {
+            return 0;
+        }
+        if num == BigFloat::from(2) {
+            return 1;
+        }
+        match (num % BigFloat::from(100)).to_u64().unwrap() {
+            3..=10 => 2,
+            11..=99 => 3,
+            _ => 0,
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 325 => "ثلاثمئة وخمسة وعشرون"
+    fn triplet_to_words(&self, triplet: u64, feminine: bool) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(HUNDREDS[hundreds - 1]);
+        }
+
+        let units_words = if feminine { UNITS_FEMININE } else { UNITS };
+        match (tens, units) {
+            (0, 0) => (),
+            (0, _) => words.push(units_words[units - 1]),
+            (1, _) => words.push(if feminine { TEENS_FEMININE } else { TEENS }[units]),
+            (_, 0) => words.push(TENS[tens - 1]),
+            _ => {
+                // the unit comes first, e.g. "واحد وعشرون", "إحدى وعشرون"
+                words.push(if feminine && units == 1 {
+                    "إحدى"
+                } else {
+                    units_words[units - 1]
+                });
+                words.push(TENS[tens - 1]);
+            }
+        }
+
+        words.join(" و")
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat, feminine: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("صفر"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("سالب"));
+            num = -num;
+        }
+
+        // iterate over thousands, joined by "و"
+        let mut groups = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            if i == 0 {
+                groups.push(self.triplet_to_words(*triplet, feminine));
+                continue;
+            }
+
+            let mega = match MEGAS.get(i - 1) {
+                Some(mega) => mega,
+                None => return Err(Num2Err::CannotConvert),
+            };
+            // one and two are read by the noun alone, e.g. "ألف", "ألفان"
+            let form = self.noun_form(BigFloat::from(*triplet));
+            groups.push(match triplet {
+                1 | 2 => String::from(mega[form]),
+                _ => format!("{} {}", self.triplet_to_words(*triplet, false), mega[form]),
+            });
+        }
+        words.push(groups.join(" و"));
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if negative {
+            words.push(String::from("سالب"));
+        }
+
+        let integral_word = self.int_to_cardinal(integral_part, self.feminine)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("فاصلة"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("صفر"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    // e.g. 21 => "الحادي والعشرون", 13 => "الثالث عشر"
+    fn below_hundred_ordinal(&self, num: u64) -> String {
+        let tens = (num / 10) as usize;
+        let units = (num % 10) as usize;
+        let ordinals = if self.feminine {
+            UNITS_ORDINAL_FEMININE
+        } else {
+            UNITS_ORDINAL
+        };
+        // "الحادي" rather than "الأول" in compounds
+        let unit = match (units, self.feminine) {
+            (1, false) if tens > 0 => "الحادي",
+            (1, true) if tens > 0 => "الحادية",
+            _ => ordinals[units.max(1) - 1],
+        };
+
+        match (tens, units) {
+            (0, _) | (1, 0) => String::from(ordinals[num as usize - 1]),
+            (1, _) => format!("{} {}", unit, if self.feminine { "عشرة" } else { "عشر" }),
+            (_, 0) => format!("ال{}", TENS[tens - 1]),
+            _ => format!("{} وال{}", unit, TENS[tens - 1]),
+        }
+    }
+
+    // digits in Arabic script keep the reading direction of the text
+    fn arabic_digits(&self, num: BigFloat) -> String {
+        whole_digits(num)
+            .chars()
+            .map(|c| char::from_u32('٠' as u32 + c.to_digit(10).unwrap()).unwrap())
+            .collect()
+    }
+
+    // e.g. "دينار واحد", "ديناران", "ثلاثة دنانير"
+    fn counted(&self, num: BigFloat, forms: [&str; 4], feminine: bool) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            let one = if feminine {
+                UNITS_FEMININE[0]
+            } else {
+                UNITS[0]
+            };
+            Ok(format!("{} {}", forms[0], one))
+        } else if num == BigFloat::from(2) {
+            Ok(String::from(forms[1]))
+        } else {
+            Ok(format!(
+                "{} {}",
+                self.int_to_cardinal(num, feminine)?,
+                forms[self.noun_form(num)]
+            ))
+        }
+    }
+
+    // names without an Arabic form are isolated so that their left-to-right
+    // script doesn't reorder the words around them; the plural followed by
+    // "اثنان" stands in for the dual
+    fn isolated(&self, singular: String, plural: String) -> [String; 4] {
+        let singular = format!("\u{2068}{}\u{2069}", singular);
+        let plural = format!("\u{2068}{}\u{2069}", plural);
+        [
+            singular.clone(),
+            format!("{} {}", plural, UNITS[1]),
+            plural,
+            singular,
+        ]
+    }
+}
+
+impl Language for Arabic {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("ما لا نهاية"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("سالب ما لا نهاية"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.feminine)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn gendered(&self, gender: Gender) -> Option<Box<dyn Language>> {
+        Some(Box::new(Arabic::new(gender == Gender::Feminine)))
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            return Err(Num2Err::CannotConvert);
+        }
+        if num.is_zero() {
+            return Ok(String::from("الصفر"));
+        }
+
+        let below_hundred = (num % BigFloat::from(100)).to_u64().unwrap();
+        let higher = num - BigFloat::from(below_hundred);
+        if higher.is_zero() {
+            return Ok(self.below_hundred_ordinal(below_hundred));
+        }
+
+        // the higher part takes the article, e.g. "المئة والثالث والعشرون"
+        let mut words = format!("ال{}", self.int_to_cardinal(higher, false)?);
+        if below_hundred != 0 {
+            words.push_str(" و");
+            words.push_str(&self.below_hundred_ordinal(below_hundred));
+        }
+        Ok(words)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.arabic_digits(num))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} قبل الميلاد", self.int_to_cardinal(-num, false)?))
+        } else {
+            self.int_to_cardinal(num, false)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let forms = match self.currencies(currency) {
+            Some(forms) => forms.map(String::from),
+            None => self.isolated(
+                currency.default_string(false),
+                currency.default_string(true),
+            ),
+        };
+        let forms = [
+            forms[0].as_str(),
+            forms[1].as_str(),
+            forms[2].as_str(),
+            forms[3].as_str(),
+        ];
+
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[2]))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "سالب خمسة دنانير وخمسون فلسًا"
+            Ok(format!("سالب {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            self.counted(num, forms, false)
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents = match self.cents(currency) {
+                Some(cents) => cents.map(String::from),
+                None => self.isolated(
+                    currency.default_subunit_string("cent{}", false),
+                    currency.default_subunit_string("cent{}", true),
+                ),
+            };
+            let cents_words = self.counted(
+                cents_nb,
+                [
+                    cents[0].as_str(),
+                    cents[1].as_str(),
+                    cents[2].as_str(),
+                    cents[3].as_str(),
+                ],
+                self.cents_feminine(currency),
+            )?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(cents_words)
+            } else {
+                Ok(format!(
+                    "{}{}{}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " و"
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('٬', '٫')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDREDS[0]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0][0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("صفر"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("أحد عشر"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("واحد وعشرون"))
+        );
+        assert_eq!(
+            Num2Words::new(325).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("ثلاثمئة وخمسة وعشرون"))
+        );
+        assert_eq!(
+            Num2Words::new(-7).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("سالب سبعة"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5).lang(Lang::Arabic).cardinal().to_words(),
+            Ok(String::from("واحد فاصلة خمسة"))
+        );
+    }
+
+    #[test]
+    fn test_dual() {
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Arabic)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ألف"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Arabic)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ألفان"))
+        );
+        assert_eq!(
+            Num2Words::new(3000)
+                .lang(Lang::Arabic)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ثلاثة آلاف"))
+        );
+        assert_eq!(
+            Num2Words::new(11000)
+                .lang(Lang::Arabic)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("أحد عشر ألفًا"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_300)
+                .lang(Lang::Arabic)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("مليونان وثلاثمئة"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Arabic)
+                .prefer("feminine")
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ثلاث"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Arabic)
+                .prefer("feminine")
+                .cardinal()
+                .to_words(),
+            Ok(String::from("إحدى وعشرون"))
+        );
+        assert_eq!(
+            Num2Words::new(3000)
+                .lang(Lang::Arabic)
+                .prefer("feminine")
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ثلاثة آلاف"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Arabic).ordinal().to_words(),
+            Ok(String::from("الأول"))
+        );
+        assert_eq!(
+            Num2Words::new(13).lang(Lang::Arabic).ordinal().to_words(),
+            Ok(String::from("الثالث عشر"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Arabic).ordinal().to_words(),
+            Ok(String::from("الحادي والعشرون"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Arabic)
+                .prefer("feminine")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("الثانية"))
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Arabic)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("٤٢"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::Arabic)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("١{}", "٠".repeat(40)))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Arabic)
+                .currency(Currency::KWD)
+                .to_words(),
+            Ok(String::from("دينار واحد"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Arabic)
+                .currency(Currency::AED)
+                .to_words(),
+            Ok(String::from("درهمان"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Arabic)
+                .currency(Currency::DINAR)
+                .to_words(),
+            Ok(String::from("خمسة دنانير وخمسون فلسًا"))
+        );
+        assert_eq!(
+            Num2Words::new(0.03)
+                .lang(Lang::Arabic)
+                .currency(Currency::SAR)
+                .to_words(),
+            Ok(String::from("ثلاث هللات"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Arabic)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("ثلاثة \u{2068}pounds\u{2069}"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Arabic)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("يوروان"))
+        );
+        assert_eq!(
+            Num2Words::new(2.02)
+                .lang(Lang::Arabic)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("يوروان وسنتان"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Arabic)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("ثلاثة يورو"))
+        );
+    }
+
+    #[test]
+    fn test_currency_custom() {
+        let bitcoin = Currency::custom("bitcoin", "bitcoins", "satoshi", "satoshis", 8);
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Arabic)
+                .currency(bitcoin)
+                .to_words(),
+            Ok(String::from("\u{2068}bitcoins\u{2069} اثنان"))
+        );
+        assert_eq!(
+            Num2Words::new(15)
+                .lang(Lang::Arabic)
+                .currency(bitcoin)
+                .to_words(),
+            Ok(String::from("خمسة عشر \u{2068}bitcoin\u{2069}"))
+        );
+    }
+}
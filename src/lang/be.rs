@@ -0,0 +1,759 @@
+use crate::{
+    lang::{slavic_plural, whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Belarusian {}
+
+const UNITS: [&str; 9] = [
+    "адзін",
+    "два",
+    "тры",
+    "чатыры",
+    "пяць",
+    "шэсць",
+    "сем",
+    "восем",
+    "дзевяць",
+];
+
+// "адна тысяча", "дзве капейкі"
+const UNITS_FEMININE: [&str; 2] = ["адна", "дзве"];
+
+const TENS: [&str; 9] = [
+    "дзесяць",
+    "дваццаць",
+    "трыццаць",
+    "сорак",
+    "пяцьдзясят",
+    "шэсцьдзясят",
+    "семдзесят",
+    "восемдзесят",
+    "дзевяноста",
+];
+
+const TEENS: [&str; 10] = [
+    "дзесяць",
+    "адзінаццаць",
+    "дванаццаць",
+    "трынаццаць",
+    "чатырнаццаць",
+    "пятнаццаць",
+    "шаснаццаць",
+    "сямнаццаць",
+    "васямнаццаць",
+    "дзевятнаццаць",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "сто",
+    "дзвесце",
+    "трыста",
+    "чатырыста",
+    "пяцьсот",
+    "шэсцьсот",
+    "семсот",
+    "восемсот",
+    "дзевяцьсот",
+];
+
+// One, few and many forms, e.g. "адна тысяча", "дзве тысячы", "пяць тысяч"
+const MEGAS: [[&str; 3]; 7] = [
+    ["тысяча", "тысячы", "тысяч"],
+    ["мільён", "мільёны", "мільёнаў"],
+    ["мільярд", "мільярды", "мільярдаў"],
+    ["трыльён", "трыльёны", "трыльёнаў"],
+    ["квадрыльён", "квадрыльёны", "квадрыльёнаў"],
+    ["квінтыльён", "квінтыльёны", "квінтыльёнаў"],
+    ["секстыльён", "секстыльёны", "секстыльёнаў"],
+];
+
+const UNITS_ORDINAL: [&str; 9] = [
+    "першы",
+    "другі",
+    "трэці",
+    "чацвёрты",
+    "пяты",
+    "шосты",
+    "сёмы",
+    "восьмы",
+    "дзявяты",
+];
+
+const TENS_ORDINAL: [&str; 9] = [
+    "дзясяты",
+    "дваццаты",
+    "трыццаты",
+    "саракавы",
+    "пяцідзясяты",
+    "шасцідзясяты",
+    "сямідзясяты",
+    "васьмідзясяты",
+    "дзевяносты",
+];
+
+const TEENS_ORDINAL: [&str; 10] = [
+    "дзясяты",
+    "адзінаццаты",
+    "дванаццаты",
+    "трынаццаты",
+    "чатырнаццаты",
+    "пятнаццаты",
+    "шаснаццаты",
+    "сямнаццаты",
+    "васямнаццаты",
+    "дзевятнаццаты",
+];
+
+const HUNDREDS_ORDINAL: [&str; 9] = [
+    "соты",
+    "двухсоты",
+    "трохсоты",
+    "чатырохсоты",
+    "пяцісоты",
+    "шасцісоты",
+    "сямісоты",
+    "васьмісоты",
+    "дзевяцісоты",
+];
+
+const MEGAS_ORDINAL: [&str; 7] = [
+    "тысячны",
+    "мільённы",
+    "мільярдны",
+    "трыльённы",
+    "квадрыльённы",
+    "квінтыльённы",
+    "секстыльённы",
+];
+
+// Genitive stems joined to a round scale ordinal, e.g. "двух" in "двухтысячны"
+const UNITS_GENITIVE: [&str; 9] = [
+    "адна",
+    "двух",
+    "трох",
+    "чатырох",
+    "пяці",
+    "шасці",
+    "сямі",
+    "васьмі",
+    "дзевяці",
+];
+
+const TENS_GENITIVE: [&str; 9] = [
+    "дзесяці",
+    "дваццаці",
+    "трыццаці",
+    "сарака",
+    "пяцідзесяці",
+    "шасцідзесяці",
+    "сямідзесяці",
+    "васьмідзесяці",
+    "дзевяноста",
+];
+
+const TEENS_GENITIVE: [&str; 10] = [
+    "дзесяці",
+    "адзінаццаці",
+    "дванаццаці",
+    "трынаццаці",
+    "чатырнаццаці",
+    "пятнаццаці",
+    "шаснаццаці",
+    "сямнаццаці",
+    "васямнаццаці",
+    "дзевятнаццаці",
+];
+
+const HUNDREDS_GENITIVE: [&str; 9] = [
+    "ста",
+    "двухсот",
+    "трохсот",
+    "чатырохсот",
+    "пяцісот",
+    "шасцісот",
+    "сямісот",
+    "васьмісот",
+    "дзевяцісот",
+];
+
+impl Belarusian {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, form: usize) -> String {
+        match currency {
+            Currency::BYN | Currency::RUB => String::from(["рубель", "рублі", "рублёў"][form]),
+            Currency::DOLLAR | Currency::USD => String::from(["долар", "долары", "долараў"][form]),
+            Currency::EUR => String::from("еўра"),
+            _ => currency.default_string(form != 0),
+        }
+    }
+
+    fn cents(&self, currency: Currency, form: usize) -> String {
+        match currency {
+            Currency::BYN | Currency::RUB => String::from(["капейка", "капейкі", "капеек"][form]),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => {
+                String::from(["цэнт", "цэнты", "цэнтаў"][form])
+            }
+            _ => currency.default_subunit_string("cent{}", form != 0),
+        }
+    }
+
+    // kopecks are feminine, e.g. "дзве капейкі"
+    fn cents_feminine(&self, currency: Currency) -> bool {
+        matches!(currency, Currency::BYN | Currency::RUB) || currency.is_subunit_feminine()
+    }
+
+    // plural form agreeing with the last two digits of the number
+    fn plural_form(&self, num: BigFloat) -> usize {
+        slavic_plural((num.abs() % BigFloat::from(100)).to_u64().unwrap())
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_words(&self, triplet: u64, feminine: bool) -> Vec<String> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if hundreds > 0 {
+            words.push(String::from(HUNDREDS[hundreds - 1]));
+        }
+
+        match tens {
+            0 => (),
+            1 => words.push(String::from(TEENS[units])),
+            _ => words.push(String::from(TENS[tens - 1])),
+        }
+
+        if tens != 1 && units > 0 {
+            words.push(String::from(if feminine && units <= 2 {
+                UNITS_FEMININE[units - 1]
+            } else {
+                UNITS[units - 1]
+            }));
+        }
+
+        words
+    }
+
+    // e.g. 2 => "двух", 25 => "дваццаціпяці", 1 => "" as in "тысячны"
+    fn triplet_to_genitive(&self, triplet: u64) -> String {
+        if triplet == 1 {
+            return String::new();
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut word = String::new();
+
+        if hundreds > 0 {
+            word.push_str(HUNDREDS_GENITIVE[hundreds - 1]);
+        }
+
+        match tens {
+            0 => (),
+            1 => word.push_str(TEENS_GENITIVE[units]),
+            _ => word.push_str(TENS_GENITIVE[tens - 1]),
+        }
+
+        if tens != 1 && units > 0 {
+            word.push_str(UNITS_GENITIVE[units - 1]);
+        }
+
+        word
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat, feminine: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("нуль"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("мінус"));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            // thousands are feminine, e.g. "дзве тысячы"
+            words.extend(self.triplet_to_words(*triplet, if i == 0 { feminine } else { i == 1 }));
+
+            if i != 0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(String::from(MEGAS[i - 1][slavic_plural(triplet % 100)]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if negative {
+            words.push(String::from("мінус"));
+        }
+
+        let integral_word = self.int_to_cardinal(integral_part, false)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("коска"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("нуль"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Belarusian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("бясконцасць"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("мінус бясконцасць"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, false)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from("нулявы"));
+        }
+
+        let thousands = self.split_thousands(num);
+        // only the lowest non-zero group is read as an ordinal
+        let (i, triplet) = thousands
+            .iter()
+            .enumerate()
+            .find(|(_, triplet)| **triplet != 0)
+            .unwrap();
+
+        let mut words: Vec<String> = vec![];
+        let mut lowest = BigFloat::from(*triplet);
+        for _ in 0..i {
+            lowest *= BigFloat::from(1000);
+        }
+        let higher = num - lowest;
+        if !higher.is_zero() {
+            words.push(self.int_to_cardinal(higher, false)?);
+        }
+
+        if i != 0 {
+            // e.g. "двухтысячны", "мільённы"
+            if i > MEGAS_ORDINAL.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            words.push(format!(
+                "{}{}",
+                self.triplet_to_genitive(*triplet),
+                MEGAS_ORDINAL[i - 1]
+            ));
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if tens == 0 && units == 0 {
+                words.push(String::from(HUNDREDS_ORDINAL[hundreds - 1]));
+            } else {
+                if hundreds > 0 {
+                    words.push(String::from(HUNDREDS[hundreds - 1]));
+                }
+                match (tens, units) {
+                    (1, _) => words.push(String::from(TEENS_ORDINAL[units])),
+                    (_, 0) => words.push(String::from(TENS_ORDINAL[tens - 1])),
+                    (0, _) => words.push(String::from(UNITS_ORDINAL[units - 1])),
+                    _ => {
+                        words.push(String::from(TENS[tens - 1]));
+                        words.push(String::from(UNITS_ORDINAL[units - 1]));
+                    }
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let ordinal = self.to_ordinal(num)?;
+        // e.g. "2-гі", "3-ці", "7-мы" but "5-ы"
+        let suffix = ["гі", "ці", "мы"]
+            .iter()
+            .find(|suffix| ordinal.ends_with(**suffix))
+            .copied()
+            .unwrap_or("ы");
+        Ok(format!("{}-{}", whole_digits(num), suffix))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} год да н.э.", self.to_ordinal(-num)?))
+        } else {
+            Ok(format!("{} год", self.to_ordinal(num)?))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, 2)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "мінус пяць рублёў пяцьдзясят капеек"
+            Ok(format!("мінус {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num, false)?;
+            Ok(format!(
+                "{} {}",
+                words,
+                self.currencies(currency, self.plural_form(num))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb, self.cents_feminine(currency))?;
+            let cents_suffix = self.cents(currency, self.plural_form(cents_nb));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDREDS[0]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0][0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("нуль"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("адзін"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("два"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("пяць"))
+        );
+        assert_eq!(
+            Num2Words::new(13)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("трынаццаць"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("дваццаць адзін"))
+        );
+        assert_eq!(
+            Num2Words::new(-42)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("мінус сорак два"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("адна тысяча"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("дзве тысячы"))
+        );
+        assert_eq!(
+            Num2Words::new(5000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("пяць тысяч"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("адзін мільён"))
+        );
+        assert_eq!(
+            Num2Words::new(3000000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("тры мільёны"))
+        );
+        assert_eq!(
+            Num2Words::new(11000000)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("адзінаццаць мільёнаў"))
+        );
+        assert_eq!(
+            Num2Words::new(21234)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("дваццаць адна тысяча дзвесце трыццаць чатыры"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("першы"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("дваццаць першы"))
+        );
+        assert_eq!(
+            Num2Words::new(40)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("саракавы"))
+        );
+        assert_eq!(
+            Num2Words::new(300)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("трохсоты"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("тысячны"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("двухтысячны"))
+        );
+        assert_eq!(
+            Num2Words::new(1002000)
+                .lang(Lang::Belarusian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("адзін мільён двухтысячны"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Belarusian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("2-гі"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Belarusian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("5-ы"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(2023)
+                .lang(Lang::Belarusian)
+                .year()
+                .to_words(),
+            Ok(String::from("дзве тысячы дваццаць трэці год"))
+        );
+    }
+
+    #[test]
+    fn test_cardinal_float() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("дванаццаць коска пяць"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Belarusian)
+                .currency(Currency::BYN)
+                .to_words(),
+            Ok(String::from("адзін рубель"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Belarusian)
+                .currency(Currency::BYN)
+                .to_words(),
+            Ok(String::from("два рублі"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Belarusian)
+                .currency(Currency::BYN)
+                .to_words(),
+            Ok(String::from("пяць рублёў"))
+        );
+        assert_eq!(
+            Num2Words::new(21.02)
+                .lang(Lang::Belarusian)
+                .currency(Currency::BYN)
+                .to_words(),
+            Ok(String::from("дваццаць адзін рубель дзве капейкі"))
+        );
+        assert_eq!(
+            Num2Words::new(0.21)
+                .lang(Lang::Belarusian)
+                .currency(Currency::RUB)
+                .to_words(),
+            Ok(String::from("дваццаць адна капейка"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Belarusian)
+                .currency(Currency::BYN)
+                .to_words(),
+            Ok(String::from("адзін мільён рублёў"))
+        );
+    }
+
+    #[test]
+    fn test_big_num() {
+        assert_eq!(
+            Num2Words::new(1e21)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("адзін секстыльён"))
+        );
+        assert_eq!(
+            Num2Words::new(1e24)
+                .lang(Lang::Belarusian)
+                .cardinal()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+}
@@ -0,0 +1,684 @@
+use crate::{
+    lang::{czech_plural, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Czech {}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+const UNITS: [&str; 20] = [
+    "nula",
+    "jedna",
+    "dva",
+    "tři",
+    "čtyři",
+    "pět",
+    "šest",
+    "sedm",
+    "osm",
+    "devět",
+    "deset",
+    "jedenáct",
+    "dvanáct",
+    "třináct",
+    "čtrnáct",
+    "patnáct",
+    "šestnáct",
+    "sedmnáct",
+    "osmnáct",
+    "devatenáct",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvacet",
+    "třicet",
+    "čtyřicet",
+    "padesát",
+    "šedesát",
+    "sedmdesát",
+    "osmdesát",
+    "devadesát",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "sto",
+    "dvě stě",
+    "tři sta",
+    "čtyři sta",
+    "pět set",
+    "šest set",
+    "sedm set",
+    "osm set",
+    "devět set",
+];
+
+// One, few and many forms and the gender they are counted in, e.g. "tisíc",
+// "dva tisíce", "pět tisíc" but "dvě miliardy"
+const MEGAS: [([&str; 3], Gender); 5] = [
+    (["tisíc", "tisíce", "tisíc"], Gender::Masculine),
+    (["milion", "miliony", "milionů"], Gender::Masculine),
+    (["miliarda", "miliardy", "miliard"], Gender::Feminine),
+    (["bilion", "biliony", "bilionů"], Gender::Masculine),
+    (["biliarda", "biliardy", "biliard"], Gender::Feminine),
+];
+
+const UNITS_ORDINAL: [&str; 20] = [
+    "nultý",
+    "první",
+    "druhý",
+    "třetí",
+    "čtvrtý",
+    "pátý",
+    "šestý",
+    "sedmý",
+    "osmý",
+    "devátý",
+    "desátý",
+    "jedenáctý",
+    "dvanáctý",
+    "třináctý",
+    "čtrnáctý",
+    "patnáctý",
+    "šestnáctý",
+    "sedmnáctý",
+    "osmnáctý",
+    "devatenáctý",
+];
+
+const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "",
+    "dvacátý",
+    "třicátý",
+    "čtyřicátý",
+    "padesátý",
+    "šedesátý",
+    "sedmdesátý",
+    "osmdesátý",
+    "devadesátý",
+];
+
+const HUNDREDS_ORDINAL: [&str; 10] = [
+    "",
+    "stý",
+    "dvoustý",
+    "třístý",
+    "čtyřstý",
+    "pětistý",
+    "šestistý",
+    "sedmistý",
+    "osmistý",
+    "devítistý",
+];
+
+const MEGAS_ORDINAL: [&str; 5] = ["tisící", "miliontý", "miliardtý", "biliontý", "biliardtý"];
+
+// prefixes of compound ordinals, e.g. "dvou" in "dvoutisící"
+const UNITS_PREFIX: [&str; 10] = [
+    "", "", "dvou", "tří", "čtyř", "pěti", "šesti", "sedmi", "osmi", "devíti",
+];
+
+impl Czech {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // one, few and many forms and gender of the currency
+    fn currencies(&self, currency: Currency) -> ([String; 3], Gender) {
+        let (forms, gender) = match currency {
+            Currency::CZK => (["koruna", "koruny", "korun"], Gender::Feminine),
+            Currency::DOLLAR | Currency::USD => (["dolar", "dolary", "dolarů"], Gender::Masculine),
+            Currency::EUR => (["euro", "eura", "eur"], Gender::Neuter),
+            _ => {
+                let (one, many) = (
+                    currency.default_string(false),
+                    currency.default_string(true),
+                );
+                let gender = match currency.is_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Masculine,
+                };
+                return ([one, many.clone(), many], gender);
+            }
+        };
+        (forms.map(String::from), gender)
+    }
+
+    fn cents(&self, currency: Currency) -> [String; 3] {
+        match currency {
+            Currency::CZK => ["haléř", "haléře", "haléřů"].map(String::from),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => {
+                ["cent", "centy", "centů"].map(String::from)
+            }
+            _ => {
+                let one = currency.default_subunit_string("cent{}", false);
+                let many = currency.default_subunit_string("cent{}", true);
+                [one, many.clone(), many]
+            }
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 122 => "sto dvacet dva", or "sto dvacet dvě" counting feminine nouns
+    fn triplet_to_words(&self, triplet: u64, gender: Option<Gender>) -> Vec<&'static str> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100 % 10) as usize;
+        let rest = (triplet % 100) as usize;
+
+        if hundreds > 0 {
+            words.push(HUNDREDS[hundreds]);
+        }
+        if rest >= 20 {
+            words.push(TENS[rest / 10]);
+        }
+
+        let units = if rest >= 20 { rest % 10 } else { rest };
+        match (units, gender) {
+            (0, _) => (),
+            (1, Some(Gender::Masculine)) => words.push("jeden"),
+            (1, Some(Gender::Neuter)) => words.push("jedno"),
+            (2, Some(Gender::Feminine | Gender::Neuter)) => words.push("dvě"),
+            _ => words.push(UNITS[units]),
+        }
+
+        words
+    }
+
+    fn counted(&self, num: BigFloat, gender: Gender) -> Result<String, Num2Err> {
+        // the neuter "jedno" only counts one, e.g. "dvacet jedna eur"
+        let gender = match gender {
+            Gender::Neuter if num != BigFloat::from(1) => Gender::Feminine,
+            gender => gender,
+        };
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet, Some(gender)));
+                continue;
+            }
+
+            let (forms, mega_gender) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // one is left out before a scale word, e.g. "tisíc", "milion"
+            if *triplet != 1 {
+                words.extend(self.triplet_to_words(*triplet, Some(*mega_gender)));
+            }
+            words.push(forms[czech_plural(BigFloat::from(*triplet))]);
+        }
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet, None));
+                continue;
+            }
+
+            let (forms, mega_gender) = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            if *triplet != 1 {
+                words.extend(self.triplet_to_words(*triplet, Some(*mega_gender)));
+            }
+            words.push(forms[czech_plural(BigFloat::from(*triplet))]);
+        }
+
+        if num.is_negative() {
+            Ok(format!("minus {}", words.join(" ")))
+        } else {
+            Ok(words.join(" "))
+        }
+    }
+
+    // the whole part counts "celá", e.g. "dvě celé pět"
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int().abs();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        } else {
+            words.push(self.counted(integral_part, Gender::Feminine)?);
+        }
+        words.push(String::from(
+            ["celá", "celé", "celých"][czech_plural(integral_part)],
+        ));
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // one, few, many and decimal forms of a noun read after the number,
+    // e.g. "dvě procenta" or "dvě celé pět procenta"
+    fn counted_noun(
+        &self,
+        num: BigFloat,
+        forms: [&str; 4],
+        gender: Gender,
+    ) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[2]))
+        } else if num.is_negative() {
+            Ok(format!(
+                "minus {}",
+                self.counted_noun(num.abs(), forms, gender)?
+            ))
+        } else if num.frac().is_zero() {
+            let words = match num.is_zero() {
+                true => String::from(UNITS[0]),
+                false => self.counted(num, gender)?,
+            };
+            Ok(format!("{} {}", words, forms[czech_plural(num)]))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[3]))
+        }
+    }
+}
+
+impl Language for Czech {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("nekonečno"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus nekonečno"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(UNITS_ORDINAL[0]));
+        }
+
+        let thousands = self.split_thousands(num);
+        // only the lowest non-zero group is read as an ordinal
+        let (i, triplet) = thousands
+            .iter()
+            .enumerate()
+            .find(|(_, triplet)| **triplet != 0)
+            .unwrap();
+
+        let mut words: Vec<String> = vec![];
+        let mut lowest = BigFloat::from(*triplet);
+        for _ in 0..i {
+            lowest *= BigFloat::from(1000);
+        }
+        let higher = num - lowest;
+        if !higher.is_zero() {
+            words.push(self.int_to_cardinal(higher)?);
+        }
+
+        if i != 0 {
+            let mega = MEGAS_ORDINAL.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // e.g. "tisící", "dvoutisící", but "dvacet tisící"
+            match *triplet {
+                1 => words.push(String::from(*mega)),
+                2..=9 => words.push(format!("{}{}", UNITS_PREFIX[*triplet as usize], mega)),
+                _ => {
+                    words.push(self.int_to_cardinal(BigFloat::from(*triplet))?);
+                    words.push(String::from(*mega));
+                }
+            }
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let rest = (triplet % 100) as usize;
+
+            // e.g. "sto dvacátý třetí"
+            match (hundreds, rest) {
+                (_, 0) => words.push(String::from(HUNDREDS_ORDINAL[hundreds])),
+                (0, _) => (),
+                _ => words.push(String::from(HUNDREDS[hundreds])),
+            }
+            if rest >= 20 {
+                words.push(String::from(TENS_ORDINAL[rest / 10]));
+            }
+            match if rest >= 20 { rest % 10 } else { rest } {
+                0 => (),
+                units => words.push(String::from(UNITS_ORDINAL[units])),
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} před naším letopočtem", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (forms, gender) = self.currencies(currency);
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[2]))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus pět korun padesát haléřů"
+            Ok(format!("minus {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = match num.is_zero() {
+                true => String::from(UNITS[0]),
+                false => self.counted(num, gender)?,
+            };
+            Ok(format!("{} {}", words, forms[czech_plural(num)]))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_gender = match currency.is_subunit_feminine() {
+                true => Gender::Feminine,
+                false => Gender::Masculine,
+            };
+            let cents_words = self.counted(cents_nb, cents_gender)?;
+            let cents_suffix = &self.cents(currency)[czech_plural(cents_nb)];
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m.0[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m.0[0] == word) {
+            Some(m) => String::from(m.0[2]),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "sto"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "tisíc"
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.counted_noun(
+            num,
+            ["procento", "procenta", "procent", "procenta"],
+            Gender::Neuter,
+        )
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.counted_noun(num, ["promile"; 4], Gender::Neuter)
+    }
+
+    // seconds, minutes, hours and miles are feminine, e.g. "dvě minuty"
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let words = self.unit_words(unit).ok_or(Num2Err::CannotConvert)?;
+        let gender = match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Mile => Gender::Feminine,
+            _ => Gender::Masculine,
+        };
+        self.counted_noun(num, words, gender)
+    }
+
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        match unit {
+            Unit::Millimeter => Some(["milimetr", "milimetry", "milimetrů", "milimetru"]),
+            Unit::Centimeter => Some(["centimetr", "centimetry", "centimetrů", "centimetru"]),
+            Unit::Meter => Some(["metr", "metry", "metrů", "metru"]),
+            Unit::Kilometer => Some(["kilometr", "kilometry", "kilometrů", "kilometru"]),
+            Unit::Mile => Some(["míle", "míle", "mil", "míle"]),
+            Unit::Gram => Some(["gram", "gramy", "gramů", "gramu"]),
+            Unit::Kilogram => Some(["kilogram", "kilogramy", "kilogramů", "kilogramu"]),
+            Unit::Liter => Some(["litr", "litry", "litrů", "litru"]),
+            Unit::Second => Some(["sekunda", "sekundy", "sekund", "sekundy"]),
+            Unit::Minute => Some(["minuta", "minuty", "minut", "minuty"]),
+            Unit::Hour => Some(["hodina", "hodiny", "hodin", "hodiny"]),
+            Unit::Day => Some(["den", "dny", "dní", "dne"]),
+            _ => unit.custom_words(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Czech).cardinal().to_words(),
+            Ok(String::from("nula"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Czech).cardinal().to_words(),
+            Ok(String::from("čtyřicet dva"))
+        );
+        assert_eq!(
+            Num2Words::new(1234).lang(Lang::Czech).cardinal().to_words(),
+            Ok(String::from("tisíc dvě stě třicet čtyři"))
+        );
+        assert_eq!(
+            Num2Words::new(3000).lang(Lang::Czech).cardinal().to_words(),
+            Ok(String::from("tři tisíce"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000_000)
+                .lang(Lang::Czech)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dvě miliardy"))
+        );
+        assert_eq!(
+            Num2Words::new(5_000_000)
+                .lang(Lang::Czech)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("pět milionů"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Czech).cardinal().to_words(),
+            Ok(String::from("dvě celé pět"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("první"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("sto dvacátý třetí"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Czech).ordinal().to_words(),
+            Ok(String::from("dvoutisící"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("jedna koruna"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("dvě koruny"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Czech)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("pět korun padesát haléřů"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Czech)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("jedno euro"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Czech)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("dvacet jedna eur"))
+        );
+        assert_eq!(
+            Num2Words::new(101)
+                .lang(Lang::Czech)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("sto jedna eur"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("jedno procento"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("dvě procenta"))
+        );
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("dvacet pět procent"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("dvě celé pět procenta"))
+        );
+        assert_eq!(
+            Num2Words::new(-5).lang(Lang::Czech).percent().to_words(),
+            Ok(String::from("minus pět procent"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Czech).permille().to_words(),
+            Ok(String::from("dvě promile"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Czech)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("dvě minuty"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Czech)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("pět kilometrů"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Czech)
+                .unit(Unit::Day)
+                .to_words(),
+            Ok(String::from("jeden den"))
+        );
+    }
+
+    #[test]
+    fn test_currency_gender() {
+        let tolar = Currency::custom("tolar", "tolary", "krejcar", "krejcary", 2);
+        assert_eq!(
+            Num2Words::new(2.02)
+                .lang(Lang::Czech)
+                .currency(tolar)
+                .to_words(),
+            Ok(String::from("dva tolary dva krejcary"))
+        );
+        let hrivna =
+            Currency::custom("hřivna", "hřivny", "kopějka", "kopějky", 2).feminine(true, true);
+        assert_eq!(
+            Num2Words::new(2.02)
+                .lang(Lang::Czech)
+                .currency(hrivna)
+                .to_words(),
+            Ok(String::from("dvě hřivny dvě kopějky"))
+        );
+    }
+}
@@ -0,0 +1,238 @@
+use crate::{lang::germanic::Germanic, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Danish {}
+
+// Tens from fifty on count in scores, e.g. "halvtreds" is half-third times
+// twenty, and come after the units, e.g. "toogfyrre"
+const WORDS: Germanic = Germanic {
+    units: [
+        "nul", "en", "to", "tre", "fire", "fem", "seks", "syv", "otte", "ni", "ti", "elleve",
+        "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten", "nitten",
+    ],
+    tens: [
+        "",
+        "ti",
+        "tyve",
+        "tredive",
+        "fyrre",
+        "halvtreds",
+        "tres",
+        "halvfjerds",
+        "firs",
+        "halvfems",
+    ],
+    hundred: "hundrede",
+    thousand: "tusind",
+    megas: [
+        ["million", "millioner"],
+        ["milliard", "milliarder"],
+        ["billion", "billioner"],
+        ["billiard", "billiarder"],
+    ],
+    ordinal_units: [
+        "nulte",
+        "første",
+        "anden",
+        "tredje",
+        "fjerde",
+        "femte",
+        "sjette",
+        "syvende",
+        "ottende",
+        "niende",
+        "tiende",
+        "ellevte",
+        "tolvte",
+        "trettende",
+        "fjortende",
+        "femtende",
+        "sekstende",
+        "syttende",
+        "attende",
+        "nittende",
+    ],
+    ordinal_tens: [
+        "",
+        "tiende",
+        "tyvende",
+        "tredivte",
+        "fyrretyvende",
+        "halvtredsindstyvende",
+        "tresindstyvende",
+        "halvfjerdsindstyvende",
+        "firsindstyvende",
+        "halvfemsindstyvende",
+    ],
+    ordinal_hundred: "hundrede",
+    ordinal_thousand: "tusinde",
+    neuter_one: "et",
+    common_one: "en",
+    and: Some("og"),
+    joiner: " ",
+    units_first: true,
+    connector: " og ",
+    minus: "minus",
+    point: "komma",
+    infinity: "uendelig",
+    before_christ: "før Kristus",
+};
+
+impl Danish {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::DKK if plural_form => String::from("kroner"),
+            Currency::DKK => String::from("krone"),
+            Currency::SEK if plural_form => String::from("svenske kroner"),
+            Currency::SEK => String::from("svensk krone"),
+            Currency::NOK if plural_form => String::from("norske kroner"),
+            Currency::NOK => String::from("norsk krone"),
+            Currency::DOLLAR | Currency::USD => String::from("dollar"),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from("pund"),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::DKK | Currency::SEK | Currency::NOK => String::from("øre"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("cent"),
+            Currency::GBP => String::from("pence"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+}
+
+impl Language for Danish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_cardinal(num)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_ordinal(num)
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_year(num)
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        WORDS.to_currency(
+            num,
+            currency,
+            |c, plural| self.currencies(c, plural),
+            |c, plural| self.cents(c, plural),
+        )
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        WORDS.connector
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        WORDS.scale_word(power)
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        WORDS.scale_plural(word)
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        WORDS.hundred
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        WORDS.thousand
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("procent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("promille")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Danish).cardinal().to_words(),
+            Ok(String::from("toogfyrre"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::Danish).cardinal().to_words(),
+            Ok(String::from("halvtreds"))
+        );
+        assert_eq!(
+            Num2Words::new(80).lang(Lang::Danish).cardinal().to_words(),
+            Ok(String::from("firs"))
+        );
+        assert_eq!(
+            Num2Words::new(175).lang(Lang::Danish).cardinal().to_words(),
+            Ok(String::from("et hundrede og femoghalvfjerds"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000)
+                .lang(Lang::Danish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("enogtyve tusind"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Danish).ordinal().to_words(),
+            Ok(String::from("anden"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Danish).ordinal().to_words(),
+            Ok(String::from("enogtyvende"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::Danish).ordinal().to_words(),
+            Ok(String::from("halvtredsindstyvende"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1984).lang(Lang::Danish).year().to_words(),
+            Ok(String::from("nitten hundrede og fireogfirs"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Danish)
+                .currency(Currency::DKK)
+                .to_words(),
+            Ok(String::from("to kroner og halvtreds øre"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Danish)
+                .currency(Currency::DKK)
+                .to_words(),
+            Ok(String::from("en krone"))
+        );
+    }
+}
@@ -0,0 +1,472 @@
+use crate::{
+    lang::{intern, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language,
+};
+use num_bigfloat::BigFloat;
+
+/// Word tables and joining rules of a [`DataDrivenLanguage`]
+///
+/// With the `serde` feature it can be read from any format serde supports,
+/// e.g. JSON with `DataDrivenLanguage::from_json` or TOML with the `toml`
+/// crate. Only the tables and the minus and point words are required:
+///
+/// ```json
+/// {
+///     "units": ["zero", "one", "two", "three", "four",
+///               "five", "six", "seven", "eight", "nine"],
+///     "teens": ["ten", "eleven", "twelve", "thirteen", "fourteen",
+///               "fifteen", "sixteen", "seventeen", "eighteen", "nineteen"],
+///     "tens": ["twenty", "thirty", "forty", "fifty",
+///              "sixty", "seventy", "eighty", "ninety"],
+///     "hundred": "hundred",
+///     "megas": ["thousand", "million", "billion"],
+///     "tens_joiner": "-",
+///     "minus": "minus",
+///     "point": "point",
+///     "ordinal_suffix": "th",
+///     "ordinal_num_suffix": "th"
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+pub struct LanguageDefinition {
+    /// Words for 0 to 9
+    pub units: Vec<String>,
+    /// Words for 10 to 19
+    pub teens: Vec<String>,
+    /// Words for 20, 30, up to 90
+    pub tens: Vec<String>,
+    /// Word for a hundred, counted by the units, e.g. `hundred` in
+    /// "two hundred"
+    pub hundred: String,
+    /// Scale words from a thousand up, e.g. `["thousand", "million"]`
+    pub megas: Vec<String>,
+    /// Text joining tens and units, e.g. `-` in "forty-two"
+    #[cfg_attr(feature = "serde", serde(default = "space"))]
+    pub tens_joiner: String,
+    /// Text joining hundreds and the rest, e.g. ` and ` in
+    /// "one hundred and five"
+    #[cfg_attr(feature = "serde", serde(default = "space"))]
+    pub hundred_joiner: String,
+    /// Word before negative numbers, e.g. `minus`
+    pub minus: String,
+    /// Word before the fraction digits, e.g. `point`
+    pub point: String,
+    /// Suffix added to the last word of an ordinal, e.g. `th`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordinal_suffix: String,
+    /// Suffix of an ordinal in digits, e.g. `.` in "42."
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub ordinal_num_suffix: String,
+    /// Text joining the units and the cents of a currency amount
+    #[cfg_attr(feature = "serde", serde(default = "space"))]
+    pub currency_connector: String,
+}
+
+#[cfg(feature = "serde")]
+fn space() -> String {
+    String::from(" ")
+}
+
+/// Language read from a [`LanguageDefinition`] instead of written in Rust,
+/// for languages that spell numbers by joining words from a few tables
+///
+/// The scale words, the hundred and the suffixes the [`Language`] trait
+/// hands out are kept for the rest of the program, once however often they
+/// are built. Build it once and reuse it, e.g. with
+/// [`Num2Words::lang_custom`](crate::Num2Words::lang_custom).
+///
+/// Example:
+/// ```
+/// use num2words::{DataDrivenLanguage, LanguageDefinition, Num2Words};
+/// let words = |w: &str| w.split(' ').map(String::from).collect();
+/// let language = DataDrivenLanguage::new(LanguageDefinition {
+///     units: words("nul en to tre fire fem seks syv otte ni"),
+///     teens: words("ti elleve tolv tretten fjorten femten seksten sytten atten nitten"),
+///     tens: words("tyve tredive fyrre halvtreds tres halvfjerds firs halvfems"),
+///     hundred: String::from("hundrede"),
+///     megas: words("tusind million"),
+///     tens_joiner: String::from(" "),
+///     hundred_joiner: String::from(" og "),
+///     minus: String::from("minus"),
+///     point: String::from("komma"),
+///     ordinal_suffix: String::from("e"),
+///     ordinal_num_suffix: String::from("."),
+///     currency_connector: String::from(" og "),
+/// })
+/// .unwrap();
+/// assert_eq!(
+///     Num2Words::new(140).lang_custom(language).to_words(),
+///     Ok(String::from("en hundrede og fyrre"))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct DataDrivenLanguage {
+    units: Vec<String>,
+    teens: Vec<String>,
+    tens: Vec<String>,
+    hundred: &'static str,
+    megas: Vec<&'static str>,
+    tens_joiner: String,
+    hundred_joiner: String,
+    minus: String,
+    point: String,
+    ordinal_suffix: String,
+    ordinal_num_suffix: &'static str,
+    currency_connector: &'static str,
+}
+
+impl DataDrivenLanguage {
+    /// Builds the language, returning [`Num2Err::CannotConvert`] unless
+    /// there are ten units, ten teens, eight tens and words for minus and
+    /// point
+    pub fn new(definition: LanguageDefinition) -> Result<Self, Num2Err> {
+        if definition.units.len() != 10
+            || definition.teens.len() != 10
+            || definition.tens.len() != 8
+            || definition.minus.is_empty()
+            || definition.point.is_empty()
+        {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        Ok(Self {
+            units: definition.units,
+            teens: definition.teens,
+            tens: definition.tens,
+            hundred: intern(definition.hundred),
+            megas: definition.megas.into_iter().map(intern).collect(),
+            tens_joiner: definition.tens_joiner,
+            hundred_joiner: definition.hundred_joiner,
+            minus: definition.minus,
+            point: definition.point,
+            ordinal_suffix: definition.ordinal_suffix,
+            ordinal_num_suffix: intern(definition.ordinal_num_suffix),
+            currency_connector: intern(definition.currency_connector),
+        })
+    }
+
+    /// Reads the language from a JSON [`LanguageDefinition`]
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{DataDrivenLanguage, Num2Words};
+    /// let language = DataDrivenLanguage::from_json(r#"{
+    ///     "units": ["nul", "een", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen"],
+    ///     "teens": ["tien", "elf", "twaalf", "dertien", "veertien",
+    ///               "vijftien", "zestien", "zeventien", "achttien", "negentien"],
+    ///     "tens": ["twintig", "dertig", "veertig", "vijftig",
+    ///              "zestig", "zeventig", "tachtig", "negentig"],
+    ///     "hundred": "honderd",
+    ///     "megas": ["duizend", "miljoen"],
+    ///     "minus": "min",
+    ///     "point": "komma"
+    /// }"#)
+    /// .unwrap();
+    /// assert_eq!(
+    ///     Num2Words::new(-1.25).lang_custom(language).to_words(),
+    ///     Ok(String::from("min een komma twee vijf"))
+    /// );
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        let definition: LanguageDefinition = serde_json::from_str(json)?;
+        Self::new(definition).map_err(|_| {
+            serde::de::Error::custom(
+                "expected ten units, ten teens, eight tens, a minus and a point",
+            )
+        })
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let rest = (triplet % 100) as usize;
+
+        let rest_words = match rest {
+            0 => String::new(),
+            1..=9 => self.units[rest].clone(),
+            10..=19 => self.teens[rest - 10].clone(),
+            _ => match rest % 10 {
+                0 => self.tens[rest / 10 - 2].clone(),
+                units => format!(
+                    "{}{}{}",
+                    self.tens[rest / 10 - 2],
+                    self.tens_joiner,
+                    self.units[units]
+                ),
+            },
+        };
+
+        match (hundreds, rest) {
+            (0, _) => rest_words,
+            (_, 0) => format!("{} {}", self.units[hundreds], self.hundred),
+            _ => format!(
+                "{} {}{}{}",
+                self.units[hundreds], self.hundred, self.hundred_joiner, rest_words
+            ),
+        }
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(self.units[0].clone());
+        }
+
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(self.minus.clone());
+        }
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            words.push(self.triplet_to_words(*triplet));
+            if i != 0 {
+                let mega = self.megas.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+                words.push(String::from(*mega));
+            }
+        }
+
+        Ok(Self::join(words))
+    }
+
+    // words left empty by the tables are skipped, e.g. a unit read as ""
+    fn join(words: Vec<String>) -> String {
+        let words: Vec<String> = words.into_iter().filter(|w| !w.is_empty()).collect();
+        words.join(" ")
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(self.minus.clone());
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+        words.push(self.point.clone());
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.units[digit.to_u64().unwrap() as usize].clone());
+        }
+        Ok(Self::join(words))
+    }
+}
+
+impl Language for DataDrivenLanguage {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Err(Num2Err::CannotConvert)
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn ordinal_transform(&self, word: &str) -> String {
+        format!("{}{}", word, self.ordinal_suffix)
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok(self.ordinal_num_suffix)
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.to_cardinal(num)
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            // the sign is read once, also when there are only cents
+            return Ok(format!(
+                "{} {}",
+                self.minus,
+                self.to_currency(num.abs(), currency)?
+            ));
+        }
+        let integral_part = num.int();
+        let ratio = BigFloat::from(currency.subunit_ratio());
+        let cents_nb = (num.abs() * ratio).int() % ratio;
+        let words = format!(
+            "{} {}",
+            self.to_cardinal(integral_part)?,
+            currency.default_string(integral_part.abs() != BigFloat::from(1))
+        );
+
+        if cents_nb.is_zero() {
+            Ok(words)
+        } else {
+            Ok(format!(
+                "{}{}{} {}",
+                words,
+                self.currency_connector,
+                self.to_cardinal(cents_nb)?,
+                currency.default_subunit_string("cent{}", cents_nb != BigFloat::from(1))
+            ))
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        self.currency_connector
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        self.megas.get(power.checked_sub(1)?).copied()
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        self.hundred
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        self.megas.first().copied().unwrap_or(self.hundred)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn english() -> LanguageDefinition {
+        let words = |w: &str| w.split(' ').map(String::from).collect();
+        LanguageDefinition {
+            units: words("zero one two three four five six seven eight nine"),
+            teens: words(
+                "ten eleven twelve thirteen fourteen fifteen sixteen seventeen eighteen nineteen",
+            ),
+            tens: words("twenty thirty forty fifty sixty seventy eighty ninety"),
+            hundred: String::from("hundred"),
+            megas: words("thousand million"),
+            tens_joiner: String::from("-"),
+            hundred_joiner: String::from(" and "),
+            minus: String::from("minus"),
+            point: String::from("point"),
+            ordinal_suffix: String::from("th"),
+            ordinal_num_suffix: String::from("th"),
+            currency_connector: String::from(" and "),
+        }
+    }
+
+    #[test]
+    fn test_data_driven() {
+        let language = DataDrivenLanguage::new(english()).unwrap();
+        let spell = |num: f64| Num2Words::new(num).lang_custom(language.clone()).to_words();
+
+        assert_eq!(spell(0.0), Ok(String::from("zero")));
+        assert_eq!(spell(15.0), Ok(String::from("fifteen")));
+        assert_eq!(
+            spell(1_234_567.0),
+            Ok(String::from(
+                "one million two hundred and thirty-four thousand five hundred and sixty-seven"
+            ))
+        );
+        assert_eq!(spell(-2.5), Ok(String::from("minus two point five")));
+        assert_eq!(spell(1e9), Err(Num2Err::CannotConvert));
+        assert_eq!(
+            Num2Words::new(40)
+                .lang_custom(language.clone())
+                .ordinal()
+                .to_words(),
+            Ok(String::from("fortyth"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang_custom(language)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("one euro and fifty cents"))
+        );
+    }
+
+    #[test]
+    fn test_data_driven_negative() {
+        let language = DataDrivenLanguage::new(english()).unwrap();
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang_custom(language.clone())
+                .to_words(),
+            Ok(String::from("minus zero point five"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang_custom(language)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("minus zero euros and fifty cents"))
+        );
+    }
+
+    #[test]
+    fn test_data_driven_tables() {
+        let mut definition = english();
+        definition.tens.pop();
+        assert!(DataDrivenLanguage::new(definition).is_err());
+
+        let mut definition = english();
+        definition.minus = String::new();
+        assert!(DataDrivenLanguage::new(definition).is_err());
+    }
+
+    #[test]
+    fn test_data_driven_rebuilt() {
+        // words handed out by the trait are kept once, however often the
+        // language is built
+        let first = DataDrivenLanguage::new(english()).unwrap();
+        let second = DataDrivenLanguage::new(english()).unwrap();
+        assert!(std::ptr::eq(first.hundred_word(), second.hundred_word()));
+        assert!(std::ptr::eq(first.thousand_word(), second.thousand_word()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_json() {
+        let language = DataDrivenLanguage::from_json(
+            r#"{
+                "units": ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+                "teens": ["10", "11", "12", "13", "14", "15", "16", "17", "18", "19"],
+                "tens": ["20", "30", "40", "50", "60", "70", "80", "90"],
+                "hundred": "x100",
+                "megas": ["x1000"],
+                "minus": "-",
+                "point": "."
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(
+            Num2Words::new(2345).lang_custom(language).to_words(),
+            Ok(String::from("2 x1000 3 x100 40 5"))
+        );
+        assert!(DataDrivenLanguage::from_json(r#"{"units": []}"#).is_err());
+        assert!(DataDrivenLanguage::from_json(
+            r#"{
+                "units": ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"],
+                "teens": ["10", "11", "12", "13", "14", "15", "16", "17", "18", "19"],
+                "tens": ["20", "30", "40", "50", "60", "70", "80", "90"],
+                "hundred": "x100",
+                "megas": ["x1000"]
+            }"#,
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,524 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Greek {}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Gender {
+    Neuter,
+    Feminine,
+}
+
+const UNITS: [&str; 20] = [
+    "μηδέν",
+    "ένα",
+    "δύο",
+    "τρία",
+    "τέσσερα",
+    "πέντε",
+    "έξι",
+    "επτά",
+    "οκτώ",
+    "εννέα",
+    "δέκα",
+    "έντεκα",
+    "δώδεκα",
+    "δεκατρία",
+    "δεκατέσσερα",
+    "δεκαπέντε",
+    "δεκαέξι",
+    "δεκαεπτά",
+    "δεκαοκτώ",
+    "δεκαεννέα",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "δέκα",
+    "είκοσι",
+    "τριάντα",
+    "σαράντα",
+    "πενήντα",
+    "εξήντα",
+    "εβδομήντα",
+    "ογδόντα",
+    "ενενήντα",
+];
+
+// neuter and feminine forms, e.g. "διακόσια ευρώ" but "διακόσιες χιλιάδες"
+const HUNDREDS: [[&str; 2]; 10] = [
+    ["", ""],
+    ["εκατό", "εκατό"],
+    ["διακόσια", "διακόσιες"],
+    ["τριακόσια", "τριακόσιες"],
+    ["τετρακόσια", "τετρακόσιες"],
+    ["πεντακόσια", "πεντακόσιες"],
+    ["εξακόσια", "εξακόσιες"],
+    ["επτακόσια", "επτακόσιες"],
+    ["οκτακόσια", "οκτακόσιες"],
+    ["εννιακόσια", "εννιακόσιες"],
+];
+
+// a thousand is "χίλια" while its multiples count the feminine "χιλιάδες"
+const MEGAS: [[&str; 2]; 4] = [
+    ["χίλια", "χιλιάδες"],
+    ["εκατομμύριο", "εκατομμύρια"],
+    ["δισεκατομμύριο", "δισεκατομμύρια"],
+    ["τρισεκατομμύριο", "τρισεκατομμύρια"],
+];
+
+const UNITS_ORDINAL: [&str; 13] = [
+    "μηδενικός",
+    "πρώτος",
+    "δεύτερος",
+    "τρίτος",
+    "τέταρτος",
+    "πέμπτος",
+    "έκτος",
+    "έβδομος",
+    "όγδοος",
+    "ένατος",
+    "δέκατος",
+    "ενδέκατος",
+    "δωδέκατος",
+];
+
+const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "δέκατος",
+    "εικοστός",
+    "τριακοστός",
+    "τεσσαρακοστός",
+    "πεντηκοστός",
+    "εξηκοστός",
+    "εβδομηκοστός",
+    "ογδοηκοστός",
+    "ενενηκοστός",
+];
+
+const HUNDREDS_ORDINAL: [&str; 10] = [
+    "",
+    "εκατοστός",
+    "διακοσιοστός",
+    "τριακοσιοστός",
+    "τετρακοσιοστός",
+    "πεντακοσιοστός",
+    "εξακοσιοστός",
+    "επτακοσιοστός",
+    "οκτακοσιοστός",
+    "εννιακοσιοστός",
+];
+
+const MEGAS_ORDINAL: [&str; 4] = [
+    "χιλιοστός",
+    "εκατομμυριοστός",
+    "δισεκατομμυριοστός",
+    "τρισεκατομμυριοστός",
+];
+
+// multiplicative prefixes of thousands, e.g. "δισ" in "δισχιλιοστός"
+const TIMES_PREFIX: [&str; 10] = [
+    "",
+    "",
+    "δισ",
+    "τρισ",
+    "τετρακισ",
+    "πεντακισ",
+    "εξακισ",
+    "επτακισ",
+    "οκτακισ",
+    "εννεακισ",
+];
+
+impl Greek {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // singular, plural and the gender they are counted in
+    fn currencies(&self, currency: Currency) -> ([String; 2], Gender) {
+        let (forms, gender) = match currency {
+            Currency::EUR => (["ευρώ", "ευρώ"], Gender::Neuter),
+            Currency::DOLLAR | Currency::USD => (["δολάριο", "δολάρια"], Gender::Neuter),
+            Currency::GBP => (["λίρα", "λίρες"], Gender::Feminine),
+            _ => {
+                let forms = [
+                    currency.default_string(false),
+                    currency.default_string(true),
+                ];
+                let gender = match currency.is_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Neuter,
+                };
+                return (forms, gender);
+            }
+        };
+        (forms.map(String::from), gender)
+    }
+
+    fn cents(&self, currency: Currency) -> ([String; 2], Gender) {
+        let (forms, gender) = match currency {
+            Currency::EUR => (["λεπτό", "λεπτά"], Gender::Neuter),
+            Currency::DOLLAR | Currency::USD => (["σεντ", "σεντ"], Gender::Neuter),
+            Currency::GBP => (["πένα", "πένες"], Gender::Feminine),
+            _ => {
+                let forms = [
+                    currency.default_subunit_string("cent{}", false),
+                    currency.default_subunit_string("cent{}", true),
+                ];
+                let gender = match currency.is_subunit_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Neuter,
+                };
+                return (forms, gender);
+            }
+        };
+        (forms.map(String::from), gender)
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // units that agree with a feminine noun, e.g. "μία", "τρεις"
+    fn unit_to_word(&self, unit: usize, gender: Gender) -> &'static str {
+        match (unit, gender) {
+            (1, Gender::Feminine) => "μία",
+            (3, Gender::Feminine) => "τρεις",
+            (4, Gender::Feminine) => "τέσσερις",
+            (13, Gender::Feminine) => "δεκατρείς",
+            (14, Gender::Feminine) => "δεκατέσσερις",
+            _ => UNITS[unit],
+        }
+    }
+
+    // e.g. 101 => "εκατόν ένα", 200 => "διακόσια"
+    fn triplet_to_words(&self, triplet: u64, gender: Gender) -> Vec<&'static str> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100 % 10) as usize;
+        let rest = (triplet % 100) as usize;
+
+        match (hundreds, rest) {
+            (0, _) => (),
+            (1, 1..) => words.push("εκατόν"),
+            _ => words.push(HUNDREDS[hundreds][gender as usize]),
+        }
+        if rest >= 20 {
+            words.push(TENS[rest / 10]);
+            match rest % 10 {
+                0 => (),
+                units => words.push(self.unit_to_word(units, gender)),
+            }
+        } else if rest > 0 {
+            words.push(self.unit_to_word(rest, gender));
+        }
+
+        words
+    }
+
+    fn counted(&self, num: BigFloat, gender: Gender) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            match (i, *triplet) {
+                (_, 0) => (),
+                (0, _) => words.extend(self.triplet_to_words(*triplet, gender)),
+                (1, 1) => words.push(MEGAS[0][0]),
+                (1, _) => {
+                    words.extend(self.triplet_to_words(*triplet, Gender::Feminine));
+                    words.push(MEGAS[0][1]);
+                }
+                _ => {
+                    let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+                    words.extend(self.triplet_to_words(*triplet, Gender::Neuter));
+                    words.push(mega[(*triplet != 1) as usize]);
+                }
+            }
+        }
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let words = self.counted(num.abs(), Gender::Neuter)?;
+        if num.is_negative() {
+            Ok(format!("μείον {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from("μείον"));
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+        words.push(String::from("κόμμα"));
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Greek {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("άπειρο"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("μείον άπειρο"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(UNITS_ORDINAL[0]));
+        }
+
+        let thousands = self.split_thousands(num);
+        // only the lowest non-zero group is read as an ordinal
+        let (i, triplet) = thousands
+            .iter()
+            .enumerate()
+            .find(|(_, triplet)| **triplet != 0)
+            .unwrap();
+
+        let mut words: Vec<String> = vec![];
+        let mut lowest = BigFloat::from(*triplet);
+        for _ in 0..i {
+            lowest *= BigFloat::from(1000);
+        }
+        let higher = num - lowest;
+        if !higher.is_zero() {
+            words.push(self.int_to_cardinal(higher)?);
+        }
+
+        if i != 0 {
+            let mega = MEGAS_ORDINAL.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // e.g. "χιλιοστός", "δισχιλιοστός", but "είκοσι χιλιοστός"
+            match (i, *triplet) {
+                (_, 1) => words.push(String::from(*mega)),
+                (1, 2..=9) => words.push(format!("{}{}", TIMES_PREFIX[*triplet as usize], mega)),
+                _ => {
+                    words.push(self.int_to_cardinal(BigFloat::from(*triplet))?);
+                    words.push(String::from(*mega));
+                }
+            }
+        } else {
+            // every part of the group is an ordinal, e.g. "εκατοστός εικοστός τρίτος"
+            let hundreds = (triplet / 100 % 10) as usize;
+            let rest = (triplet % 100) as usize;
+
+            if hundreds > 0 {
+                words.push(String::from(HUNDREDS_ORDINAL[hundreds]));
+            }
+            match rest {
+                0 => (),
+                1..=12 => words.push(String::from(UNITS_ORDINAL[rest])),
+                _ => {
+                    words.push(String::from(TENS_ORDINAL[rest / 10]));
+                    match rest % 10 {
+                        0 => (),
+                        units => words.push(String::from(UNITS_ORDINAL[units])),
+                    }
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok("ος")
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} προ Χριστού", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (forms, gender) = self.currencies(currency);
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[1]))
+        } else if num.is_negative() {
+            Ok(format!("μείον {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = match num.is_zero() {
+                true => String::from(UNITS[0]),
+                false => self.counted(num, gender)?,
+            };
+            let plural = num != BigFloat::from(1);
+            Ok(format!("{} {}", words, forms[plural as usize]))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let (cents_forms, cents_gender) = self.cents(currency);
+            let cents_words = self.counted(cents_nb, cents_gender)?;
+            let cents_suffix = &cents_forms[(cents_nb != BigFloat::from(1)) as usize];
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " και "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m[0] == word) {
+            Some(m) => String::from(m[1]),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "εκατό"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "χίλια"
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("τοις εκατό")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("τοις χιλίοις")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Greek).cardinal().to_words(),
+            Ok(String::from("σαράντα δύο"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Greek).cardinal().to_words(),
+            Ok(String::from("εκατόν ένα"))
+        );
+        assert_eq!(
+            Num2Words::new(1200).lang(Lang::Greek).cardinal().to_words(),
+            Ok(String::from("χίλια διακόσια"))
+        );
+        assert_eq!(
+            Num2Words::new(203_000)
+                .lang(Lang::Greek)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("διακόσιες τρεις χιλιάδες"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Greek)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("δύο εκατομμύρια"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5).lang(Lang::Greek).cardinal().to_words(),
+            Ok(String::from("μείον δύο κόμμα πέντε"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Greek).ordinal().to_words(),
+            Ok(String::from("πρώτος"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Greek).ordinal().to_words(),
+            Ok(String::from("εκατοστός εικοστός τρίτος"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Greek).ordinal().to_words(),
+            Ok(String::from("δισχιλιοστός"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Greek).ordinal_num().to_words(),
+            Ok(String::from("5ος"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Greek)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("ένα ευρώ"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Greek)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("δύο ευρώ και πενήντα λεπτά"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Greek)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("τρεις λίρες"))
+        );
+    }
+}
@@ -1,9 +1,33 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::{
+    lang::{
+        compact_scale, illion_stem, intern, is_power_of_ten, is_really_big, join_list,
+        place_values, round_significant, scientific_parts, shares_century, ssml, SliceWriter,
+        MAX_FRACTION_DIGITS,
+    },
+    num2words::Num2Err,
+    Currency, Era, GroupingScheme, Language, Scale, Unit,
+};
 use num_bigfloat::BigFloat;
+use std::fmt::Write;
 
+#[derive(Clone)]
 pub struct English {
     prefer_oh: bool,
     prefer_nil: bool,
+    paren: bool,
+    currency_and: bool,
+    decimal_currency: bool,
+    abbrev: bool,
+    simple: bool,
+    fraction_group: usize,
+    native_names: bool,
+    iso_names: bool,
+    decimal_scale: bool,
+    phone_double: bool,
+    minus: &'static str,
+    decimal_word: &'static str,
+    scale: Scale,
+    milliard: bool,
 }
 
 const UNITS: [&str; 9] = [
@@ -27,6 +51,9 @@ const TEENS: [&str; 10] = [
     "nineteen",
 ];
 
+// names of the hexadecimal digits A to F, as read in color codes
+const HEX_LETTERS: [&str; 6] = ["ay", "bee", "cee", "dee", "ee", "eff"];
+
 // As defined by the AHD4, CED, RHD2, W3 and UM authorities
 // For more information, see
 // https://en.wikipedia.org/wiki/Names_of_large_numbers
@@ -54,22 +81,238 @@ const MEGAS: [&str; 21] = [
     "vigintillion",
 ];
 
+// a thousand of each -illion in the long scale, if not read as "thousand
+// million", e.g. "milliard" for 10^9
+const MILLIARDS: [&str; 10] = [
+    "milliard",
+    "billiard",
+    "trilliard",
+    "quadrilliard",
+    "quintilliard",
+    "sextilliard",
+    "septilliard",
+    "octilliard",
+    "nonilliard",
+    "decilliard",
+];
+
+// words read after years before and within the era of Anno Domini and the
+// common era
+const ERA_WORDS: [(&str, &str); 2] = [("BC", "AD"), ("BCE", "CE")];
+
 impl English {
     pub fn new(prefer_oh: bool, prefer_nil: bool) -> Self {
         Self {
             prefer_oh,
             prefer_nil,
+            paren: false,
+            currency_and: false,
+            decimal_currency: false,
+            abbrev: false,
+            simple: false,
+            fraction_group: 1,
+            native_names: false,
+            iso_names: false,
+            decimal_scale: false,
+            phone_double: false,
+            minus: "minus",
+            decimal_word: "point",
+            scale: Scale::Short,
+            milliard: false,
+        }
+    }
+
+    // the sign read before a negative number, e.g. "minus "
+    fn sign(&self, negative: bool) -> String {
+        match negative {
+            true => format!("{} ", self.minus),
+            false => String::new(),
+        }
+    }
+
+    pub fn with_paren(mut self, paren: bool) -> Self {
+        self.paren = paren;
+        self
+    }
+
+    pub fn with_currency_and(mut self, currency_and: bool) -> Self {
+        self.currency_and = currency_and;
+        self
+    }
+
+    pub fn with_decimal_currency(mut self, decimal_currency: bool) -> Self {
+        self.decimal_currency = decimal_currency;
+        self
+    }
+
+    pub fn with_abbrev(mut self, abbrev: bool) -> Self {
+        self.abbrev = abbrev;
+        self
+    }
+
+    pub fn with_simple(mut self, simple: bool) -> Self {
+        self.simple = simple;
+        self
+    }
+
+    pub fn with_fraction_group(mut self, fraction_group: usize) -> Self {
+        self.fraction_group = fraction_group.max(1);
+        self
+    }
+
+    pub fn with_currency_names(mut self, native_names: bool, iso_names: bool) -> Self {
+        self.native_names = native_names;
+        self.iso_names = iso_names;
+        self
+    }
+
+    pub fn with_decimal_scale(mut self, decimal_scale: bool) -> Self {
+        self.decimal_scale = decimal_scale;
+        self
+    }
+
+    pub fn with_phone_double(mut self, phone_double: bool) -> Self {
+        self.phone_double = phone_double;
+        self
+    }
+
+    pub fn with_negative(mut self, negative: bool) -> Self {
+        self.minus = if negative { "negative" } else { "minus" };
+        self
+    }
+
+    pub fn with_decimal_word(mut self, decimal_word: &'static str) -> Self {
+        self.decimal_word = decimal_word;
+        self
+    }
+
+    /// Names the scale words in the long scale with "milliard" rather than
+    /// "thousand million", e.g. "milliard" and "billiard"
+    pub fn with_milliard(mut self, milliard: bool) -> Self {
+        self.milliard = milliard;
+        self
+    }
+
+    // the scale word after the group at index `i`, e.g. "thousand million"
+    // in the long scale, or only "thousand" when the millions follow it
+    fn group_scale_word(&self, i: usize, millions: bool) -> Result<String, Num2Err> {
+        match self.scale_word(i) {
+            Some(word) => Ok(String::from(word)),
+            None if self.scale == Scale::Long && i % 2 == 1 => {
+                let mega = self.scale_word(i - 1).ok_or(Num2Err::CannotConvert)?;
+                match millions {
+                    true => Ok(String::from(self.thousand_word())),
+                    false => Ok(format!("{} {}", self.thousand_word(), mega)),
+                }
+            }
+            None => Err(Num2Err::CannotConvert),
+        }
+    }
+
+    // round thousands and millions read informally, e.g. "five kay" for 5K
+    // and "two mil" for 2M, anything else has no abbreviation
+    fn abbreviation(&self, num: BigFloat) -> Result<Option<String>, Num2Err> {
+        for (scale, word) in [(1e6, "mil"), (1e3, "kay")] {
+            let count = num / BigFloat::from(scale);
+            if count.frac().is_zero() && !count.is_zero() && count.abs() < BigFloat::from(1000) {
+                let words = self.int_to_cardinal(count, false)?;
+                return Ok(Some(format!("{} {}", words, word)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // millions and above with at most two decimals before the scale word,
+    // e.g. "two point seven five billion", anything else reads in full
+    fn decimal_scale_reading(&self, num: BigFloat) -> Result<Option<String>, Num2Err> {
+        if !num.frac().is_zero() || num.abs() < BigFloat::from(1e6) {
+            return Ok(None);
+        }
+
+        let mut power = 0;
+        let mut count = num;
+        while count.abs() >= BigFloat::from(1000) {
+            count /= BigFloat::from(1000);
+            power += 1;
+        }
+
+        let word = match self.scale_word(power) {
+            Some(word) => word,
+            None => return Ok(None),
+        };
+        if count.frac().is_zero() || !(count * BigFloat::from(100)).frac().is_zero() {
+            return Ok(None);
+        }
+        Ok(Some(format!("{} {}", self.float_to_cardinal(count)?, word)))
+    }
+
+    fn plain_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinity"))
+        } else if num.is_inf_neg() {
+            Ok(format!("{} infinity", self.minus))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, false)
+        } else {
+            self.float_to_cardinal(num)
         }
     }
 
+    // full names by default, e.g. "US dollars", else the short native name
+    // "dollars" or the code "USD" where the currency has one
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
-        currency.default_string(plural_form)
+        if self.iso_names {
+            if let Some(code) = currency.iso_code() {
+                return code;
+            }
+        }
+        if self.native_names {
+            currency.native_string(plural_form)
+        } else {
+            currency.default_string(plural_form)
+        }
     }
 
     fn cents(&self, currency: Currency, plural_form: bool) -> String {
         currency.default_subunit_string("cent{}", plural_form)
     }
 
+    // splits an amount into its whole units and its cents, e.g. 5.50 => 5, 50
+    fn currency_parts(&self, num: BigFloat, currency: Currency) -> (BigFloat, BigFloat) {
+        let ratio = BigFloat::from(currency.subunit_ratio());
+        (num.int(), (num * ratio).int() % ratio)
+    }
+
+    // e.g. 3 => "thousand", 4 => "ten thousand"
+    fn place_word(&self, power: usize, plural: bool) -> Result<String, Num2Err> {
+        let scale = match power / 3 {
+            0 => None,
+            i => Some(self.scale_word(i).ok_or(Num2Err::CannotConvert)?),
+        };
+        let word = match (power % 3, scale) {
+            (0, None) => String::from("one"),
+            (0, Some(scale)) => String::from(scale),
+            (1, scale) => format!("ten {}", scale.unwrap_or_default()),
+            (_, scale) => format!("{} {}", self.hundred_word(), scale.unwrap_or_default()),
+        };
+        let word = word.trim_end();
+
+        if plural {
+            Ok(format!("{}s", word))
+        } else {
+            Ok(String::from(word))
+        }
+    }
+
+    fn paren_wrap(&self, word: String, negative: bool) -> String {
+        if negative && self.paren {
+            format!("({})", word)
+        } else {
+            word
+        }
+    }
+
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
         let bf_1000 = BigFloat::from(1000);
@@ -82,7 +325,7 @@ impl English {
         thousands
     }
 
-    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+    fn int_to_cardinal(&self, mut num: BigFloat, currency: bool) -> Result<String, Num2Err> {
         // special case zero
         if num.is_zero() {
             return Ok(String::from(if self.prefer_oh {
@@ -96,26 +339,52 @@ impl English {
 
         // handling negative values
         let mut words = vec![];
-        if num.is_negative() {
-            words.push(String::from("minus"));
+        let negative = num.is_negative();
+        if negative {
+            if !self.paren {
+                words.push(String::from(self.minus));
+            }
             num = -num;
         }
 
+        self.push_triplets(&mut words, &self.split_thousands(num), currency)?;
+
+        Ok(self.paren_wrap(words.join(" "), negative))
+    }
+
+    // reads groups of a thousand, least significant first, e.g. [234, 1] as
+    // "one thousand two hundred and thirty-four"
+    fn push_triplets(
+        &self,
+        words: &mut Vec<String>,
+        triplets: &[u64],
+        currency: bool,
+    ) -> Result<(), Num2Err> {
+        // "and" is said after a scale word, unless it is kept for currencies
+        // where it is also said after "hundred"
+        let with_and = !self.currency_and || currency;
+        let and_after_hundred = self.currency_and && currency;
+
         // iterate over thousands
         let mut first_elem = true;
-        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+        for (i, triplet) in triplets.iter().enumerate().rev() {
             let hundreds = (triplet / 100 % 10) as usize;
             let tens = (triplet / 10 % 10) as usize;
             let units = (triplet % 10) as usize;
 
             if hundreds > 0 {
                 words.push(String::from(UNITS[hundreds - 1]));
-                words.push(String::from("hundred"));
+                words.push(String::from(self.hundred_word()));
+                if and_after_hundred {
+                    first_elem = false;
+                }
             }
 
             if tens != 0 || units != 0 {
                 if i == 0 && !first_elem {
-                    words.push(String::from("and"));
+                    if with_and {
+                        words.push(String::from("and"));
+                    }
                 } else {
                     first_elem = false;
                 }
@@ -141,124 +410,167 @@ impl English {
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
-                words.push(String::from(MEGAS[i - 1]));
+                words.push(if i == 1 {
+                    String::from(self.thousand_word())
+                } else {
+                    self.group_scale_word(i, triplets[i - 1] != 0)?
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    fn digit_to_word(&self, digit: u64) -> String {
+        match digit {
+            0 => String::from(if self.prefer_oh { "oh" } else { "zero" }),
+            i => String::from(UNITS[i as usize - 1]),
+        }
+    }
+
+    // e.g. "5500" => "five five oh oh", or "double five double oh" when
+    // repeated digits are collapsed
+    fn phone_group_to_words(&self, group: &str) -> String {
+        let digits: Vec<u64> = group.bytes().map(|d| (d - b'0') as u64).collect();
+        let mut words = vec![];
+        let mut i = 0;
+
+        while i < digits.len() {
+            let word = self.digit_to_word(digits[i]);
+            let run = match self.phone_double {
+                true => digits[i..].iter().take_while(|&&d| d == digits[i]).count(),
+                false => 1,
+            };
+            // runs of four read as two doubles, e.g. "double five double five"
+            let take = match run {
+                1 => 1,
+                2 | 4 => 2,
+                _ => 3,
+            };
+            match take {
+                1 => words.push(word),
+                2 => words.push(format!("double {}", word)),
+                _ => words.push(format!("triple {}", word)),
             }
+            i += take;
         }
 
+        words.join(" ")
+    }
+
+    // leading zeros of a group are read one by one so that no digit is lost,
+    // e.g. [1, 4] => "fourteen" but [0, 5] => "zero five"
+    fn fraction_group_to_words(&self, group: &[u64]) -> Result<String, Num2Err> {
+        let zeros = group.iter().take_while(|&&d| d == 0).count();
+        let mut words: Vec<String> = group[..zeros]
+            .iter()
+            .map(|&d| self.digit_to_word(d))
+            .collect();
+        if zeros < group.len() {
+            let value = group[zeros..].iter().fold(0, |acc, d| acc * 10 + d);
+            words.push(self.int_to_cardinal(BigFloat::from(value), false)?);
+        }
         Ok(words.join(" "))
     }
 
     fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
         let integral_part = num.int();
         let mut words: Vec<String> = vec![];
 
+        if negative && !self.paren {
+            words.push(String::from(self.minus));
+        }
+
         if !integral_part.is_zero() {
-            let integral_word = self.int_to_cardinal(integral_part)?;
+            let integral_word = self.int_to_cardinal(integral_part, false)?;
             words.push(integral_word);
         }
 
         let mut ordinal_part = num.frac();
         if !ordinal_part.is_zero() {
-            words.push(String::from("point"));
+            words.push(String::from(self.decimal_word));
         }
-        while !ordinal_part.is_zero() {
+        let mut digits = vec![];
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
-            words.push(match digit.to_u64().unwrap() {
-                0 => String::from(if self.prefer_oh { "oh" } else { "zero" }),
-                i => String::from(UNITS[i as usize - 1]),
-            });
+            digits.push(digit.to_u64().unwrap());
         }
-        Ok(words.join(" "))
+        for group in digits.chunks(self.fraction_group) {
+            words.push(self.fraction_group_to_words(group)?);
+        }
+        Ok(self.paren_wrap(words.join(" "), negative))
     }
 }
 
 impl Language for English {
     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
-        if num.is_inf_pos() {
-            Ok(String::from("infinity"))
-        } else if num.is_inf_neg() {
-            Ok(String::from("minus infinity"))
-        } else if num.frac().is_zero() {
-            self.int_to_cardinal(num)
-        } else {
-            self.float_to_cardinal(num)
+        if self.simple && is_really_big(num) {
+            return Ok(format!(
+                "{}a really big number",
+                self.sign(num.is_negative())
+            ));
+        }
+        if self.abbrev && !num.is_inf() {
+            if let Some(words) = self.abbreviation(num)? {
+                return Ok(words);
+            }
+        }
+        if self.decimal_scale && !num.is_inf() {
+            if let Some(words) = self.decimal_scale_reading(num)? {
+                return Ok(words);
+            }
         }
-    }
-
-    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let cardinal_word = self.to_cardinal(num)?;
-
-        let mut words: Vec<String> = vec![];
-        let mut split = cardinal_word.split_whitespace().peekable();
-
-        while let Some(w) = split.next() {
-            if split.peek().is_some() {
-                // not last word, no modification needed
-                words.push(String::from(w));
-            } else {
-                // last word, needs to be processed
-                let mut prefix = String::from("");
-                let mut suffix = String::from(w);
 
-                if w.contains('-') {
-                    // e.g. forty-two => forty-second
-                    let mut w_split = w.split('-');
+        self.plain_cardinal(num)
+    }
 
-                    if let Some(pre) = w_split.next() {
-                        prefix = format!("{}-", pre);
-                    }
+    fn ordinal_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.plain_cardinal(num)
+    }
 
-                    if let Some(suf) = w_split.next() {
-                        suffix = String::from(suf);
-                    }
+    fn ordinal_transform(&self, word: &str) -> String {
+        match word {
+            "one" => String::from("first"),
+            "two" => String::from("second"),
+            "three" => String::from("third"),
+            "four" => String::from("fourth"),
+            "five" => String::from("fifth"),
+            "six" => String::from("sixth"),
+            "seven" => String::from("seventh"),
+            "eight" => String::from("eighth"),
+            "nine" => String::from("ninth"),
+            "ten" => String::from("tenth"),
+            "eleven" => String::from("eleventh"),
+            "twelve" => String::from("twelfth"),
+            _ => {
+                if let Some(stem) = word.strip_suffix('y') {
+                    format!("{}ieth", stem)
+                } else {
+                    format!("{}th", word)
                 }
-
-                suffix = match suffix.as_str() {
-                    "one" => String::from("first"),
-                    "two" => String::from("second"),
-                    "three" => String::from("third"),
-                    "four" => String::from("fourth"),
-                    "five" => String::from("fifth"),
-                    "six" => String::from("sixth"),
-                    "seven" => String::from("seventh"),
-                    "eight" => String::from("eighth"),
-                    "nine" => String::from("ninth"),
-                    "ten" => String::from("tenth"),
-                    "eleven" => String::from("eleventh"),
-                    "twelve" => String::from("twelfth"),
-                    _ => {
-                        if suffix.ends_with('y') {
-                            format!("{}ieth", &suffix[..suffix.len() - 1])
-                        } else {
-                            format!("{}th", suffix)
-                        }
-                    }
-                };
-
-                words.push(format!("{}{}", prefix, suffix))
             }
         }
+    }
 
-        Ok(words.join(" "))
+    fn to_ordinal_from_end(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} from last", self.to_ordinal(num)?))
     }
 
-    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
         let tail = (num % BigFloat::from(100)).to_u64().unwrap();
         let last = tail % 10;
-        Ok(format!(
-            "{}{}",
-            num.to_u128().unwrap(),
-            match (tail / 10 != 1, last) {
-                (true, 1) => "st",
-                (true, 2) => "nd",
-                (true, 3) => "rd",
-                _ => "th",
-            }
-        ))
+        Ok(match (tail / 10 != 1, last) {
+            (true, 1) => "st",
+            (true, 2) => "nd",
+            (true, 3) => "rd",
+            _ => "th",
+        })
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -268,55 +580,107 @@ impl Language for English {
 
         let mut num = num;
 
-        let mut suffix = "";
+        let mut era = None;
         if num.is_negative() {
             num = num.inv_sign();
-            suffix = " BC";
+            era = Some(ERA_WORDS[0].0);
         }
 
-        let bf_100 = BigFloat::from(100);
-
-        let (high, low) = (
-            (num / bf_100).to_i64().unwrap(),
-            (num % bf_100).to_i64().unwrap(),
-        );
-        let year_word = if high == 0 || (high % 10 == 0 && low < 10) || high >= 100 {
-            // if year is 00XX, X00X, or beyond 9999, go cardinal
-            self.int_to_cardinal(num)?
-        } else {
-            let high_word = self.int_to_cardinal(BigFloat::from(high))?;
-            let low_word = if low == 0 {
-                String::from("hundred")
-            } else if low < 10 {
-                format!("oh-{}", self.int_to_cardinal(BigFloat::from(low))?)
-            } else {
-                self.int_to_cardinal(BigFloat::from(low))?
-            };
+        // if year is 00XX, X00X, or beyond 9999, go cardinal
+        let halves = num
+            .to_u64()
+            .map(|year| (year / 100, year % 100))
+            .filter(|&(high, low)| high > 0 && high < 100 && !(high % 10 == 0 && low < 10));
+        let year_word = match halves {
+            Some((high, low)) => {
+                let high_word = self.int_to_cardinal(BigFloat::from(high), false)?;
+                let low_word = self.year_low_half(low)?;
 
-            format!("{} {}", high_word, low_word)
+                format!("{} {}", high_word, low_word)
+            }
+            None => self.int_to_cardinal(num, false)?,
         };
 
-        Ok(format!("{}{}", year_word, suffix))
+        match era {
+            Some(era) => Ok(format!("{} {}", year_word, era)),
+            None => Ok(year_word),
+        }
+    }
+
+    fn era_words(&self, era: Era) -> Option<(&'static str, &'static str)> {
+        match era {
+            Era::AnnoDomini => Some(ERA_WORDS[0]),
+            Era::CommonEra => Some(ERA_WORDS[1]),
+            Era::Custom { .. } => None,
+        }
+    }
+
+    fn to_year_range(
+        &self,
+        start: BigFloat,
+        end: BigFloat,
+        shared_century: bool,
+    ) -> Result<String, Num2Err> {
+        // e.g. "from nineteen fourteen to eighteen"
+        if shared_century && shares_century(start, end) {
+            let low = (end % BigFloat::from(100)).to_u64().unwrap();
+            return Ok(format!(
+                "from {} to {}",
+                self.to_year(start)?,
+                self.year_low_half(low)?
+            ));
+        }
+        Ok(format!(
+            "{} to {}",
+            self.to_year(start)?,
+            self.to_year(end)?
+        ))
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
         if num.is_inf() {
             Ok(format!(
                 "{}an infinity of {}",
-                if num.is_negative() { "minus " } else { "" },
+                self.sign(num.is_negative()),
                 self.currencies(currency, true)
             ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus five dollars and fifty cents"
+            let words = self.to_currency(num.abs(), currency)?;
+            if self.paren {
+                Ok(self.paren_wrap(words, true))
+            } else {
+                Ok(format!("{} {}", self.minus, words))
+            }
         } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
+            let words = self.int_to_cardinal(num, true)?;
             Ok(format!(
                 "{} {}",
                 words,
                 self.currencies(currency, num != BigFloat::from(1))
             ))
+        } else if self.decimal_currency && currency.subunit_exponent() > 0 {
+            // e.g. 5.50 => five point five zero euros
+            let (integral_part, cents_nb) = self.currency_parts(num, currency);
+            let digits = format!(
+                "{:0width$}",
+                cents_nb.to_u64().unwrap(),
+                width = currency.subunit_exponent() as usize
+            );
+            let digit_words: Vec<String> = digits
+                .bytes()
+                .map(|d| self.digit_to_word((d - b'0') as u64))
+                .collect();
+            Ok(format!(
+                "{} {} {} {}",
+                self.int_to_cardinal(integral_part, true)?,
+                self.decimal_word,
+                digit_words.join(" "),
+                self.currencies(currency, true)
+            ))
         } else {
-            let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
-            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let (integral_part, cents_nb) = self.currency_parts(num, currency);
+            let cents_words = self.int_to_cardinal(cents_nb, true)?;
             let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
             let integral_word = self.to_currency(integral_part, currency)?;
 
@@ -326,361 +690,2219 @@ impl Language for English {
                 Ok(format!("{} {}", cents_words, cents_suffix))
             } else {
                 Ok(format!(
-                    "{} and {} {}",
-                    integral_word, cents_words, cents_suffix
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
                 ))
             }
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+    fn to_currency_parts(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+    ) -> Result<(String, String, String, String), Num2Err> {
+        if num.is_inf() {
+            return Err(Num2Err::CannotConvert);
+        }
 
-    #[test]
-    fn test_cardinal() {
-        assert_eq!(
-            Num2Words::new(0).lang(Lang::English).cardinal().to_words(),
-            Ok(String::from("zero"))
-        );
-        assert_eq!(
-            Num2Words::new(-10)
-                .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Ok(String::from("minus ten"))
-        );
-        assert_eq!(
-            Num2Words::new(38123147081932i64)
-                .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Ok(String::from(
-                "thirty-eight trillion one hundred twenty-three \
-                 billion one hundred forty-seven million eighty-one thousand \
-                 nine hundred and thirty-two"
-            ))
-        );
-        assert_eq!(
-            Num2Words::new(100000000000i64)
-                .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Ok(String::from("one hundred billion"))
-        );
+        let (integral_part, cents_nb) = self.currency_parts(num.abs(), currency);
+        let mut major_words = self.int_to_cardinal(integral_part, true)?;
+        if num.is_negative() {
+            major_words = if self.paren {
+                self.paren_wrap(major_words, true)
+            } else {
+                format!("{} {}", self.minus, major_words)
+            };
+        }
+
+        Ok((
+            major_words,
+            self.int_to_cardinal(cents_nb, true)?,
+            self.currencies(currency, integral_part != BigFloat::from(1)),
+            self.cents(currency, cents_nb != BigFloat::from(1)),
+        ))
     }
 
-    #[test]
-    fn test_ordinal() {
-        assert_eq!(
-            Num2Words::new(10).lang(Lang::English).ordinal().to_words(),
-            Ok(String::from("tenth"))
-        );
-        assert_eq!(
-            Num2Words::new(21).lang(Lang::English).ordinal().to_words(),
-            Ok(String::from("twenty-first"))
-        );
-        assert_eq!(
-            Num2Words::new(102).lang(Lang::English).ordinal().to_words(),
-            Ok(String::from("one hundred second"))
-        );
-        assert_eq!(
-            Num2Words::new(73).lang(Lang::English).ordinal().to_words(),
-            Ok(String::from("seventy-third"))
-        );
-        assert_eq!(
-            Num2Words::new(-1).lang(Lang::English).ordinal().to_words(),
-            Err(num2words::Num2Err::NegativeOrdinal)
-        );
-        assert_eq!(
-            Num2Words::new(1.2).lang(Lang::English).ordinal().to_words(),
-            Err(num2words::Num2Err::FloatingOrdinal)
-        );
+    fn to_multiplicative(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf() || num.is_negative() || num.is_zero() || !num.frac().is_zero() {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        match num.to_u64() {
+            Some(1) => Ok(String::from("once")),
+            Some(2) => Ok(String::from("twice")),
+            Some(3) => Ok(String::from("thrice")),
+            _ => {
+                let cardinal = self.int_to_cardinal(num, false)?;
+                // single words from ten onwards take -fold, e.g. "tenfold",
+                // anything else is read as "N times", e.g. "four times"
+                if num >= BigFloat::from(10) && !cardinal.contains([' ', '-']) {
+                    Ok(format!("{}fold", cardinal))
+                } else {
+                    Ok(format!("{} times", cardinal))
+                }
+            }
+        }
     }
 
-    #[test]
-    fn test_ordinal_num() {
-        assert_eq!(
-            Num2Words::new(10)
-                .lang(Lang::English)
-                .ordinal_num()
-                .to_words(),
-            Ok(String::from("10th"))
-        );
-        assert_eq!(
-            Num2Words::new(13)
-                .lang(Lang::English)
-                .ordinal_num()
-                .to_words(),
-            Ok(String::from("13th"))
+    fn to_scientific(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf() || num.is_zero() {
+            return self.to_cardinal(num);
+        }
+
+        let (mantissa, exponent) = scientific_parts(num);
+        if exponent == 0 {
+            return self.to_cardinal(mantissa);
+        }
+
+        // e.g. 1e-3 => ten to the minus third power
+        let power = format!(
+            "ten to the {}{} power",
+            self.sign(exponent < 0),
+            self.to_ordinal(BigFloat::from(exponent.unsigned_abs()))?
         );
-        assert_eq!(
-            Num2Words::new(21)
-                .lang(Lang::English)
-                .ordinal_num()
+
+        if mantissa == BigFloat::from(1) {
+            Ok(power)
+        } else if mantissa == BigFloat::from(-1) {
+            Ok(format!("{} {}", self.minus, power))
+        } else {
+            Ok(format!("{} times {}", self.to_cardinal(mantissa)?, power))
+        }
+    }
+
+    fn to_expanded(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let places = place_values(num);
+        if places.is_empty() {
+            return self.to_cardinal(num);
+        }
+
+        let mut words = vec![];
+        for (digit, power) in places {
+            words.push(format!(
+                "{} {}",
+                self.int_to_cardinal(BigFloat::from(digit), false)?,
+                self.place_word(power, digit != 1)?
+            ));
+        }
+
+        Ok(join_list(&words, "and"))
+    }
+
+    fn to_color(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let hex = format!("{:06X}", num.to_u64().unwrap());
+        let pairs: Vec<String> = hex
+            .as_bytes()
+            .chunks(2)
+            .map(|pair| {
+                pair.iter()
+                    .map(|&d| match d {
+                        b'0'..=b'9' => self.digit_to_word((d - b'0') as u64),
+                        _ => String::from(HEX_LETTERS[(d - b'A') as usize]),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("-")
+            })
+            .collect();
+        Ok(format!("hash {}", pairs.join(" ")))
+    }
+
+    // e.g. 3:15 => "quarter past three", or "fifteen fifteen" on a 24-hour
+    // clock
+    fn to_time(&self, hour: u32, minute: u32, clock_24: bool) -> Result<String, Num2Err> {
+        let cardinal = |n: u32| self.int_to_cardinal(BigFloat::from(n), false);
+
+        if clock_24 {
+            let hour_words = match hour {
+                0 => String::from("zero"),
+                1..=9 => format!("oh {}", cardinal(hour)?),
+                _ => cardinal(hour)?,
+            };
+            let minute_words = match minute {
+                0 => String::from("hundred"),
+                1..=9 => format!("oh {}", cardinal(minute)?),
+                _ => cardinal(minute)?,
+            };
+            return Ok(format!("{} {}", hour_words, minute_words));
+        }
+
+        let hour_word = |hour: u32| match hour % 12 {
+            0 => cardinal(12),
+            h => cardinal(h),
+        };
+        let minutes_word = |minutes: u32| match minutes {
+            15 => Ok(String::from("quarter")),
+            1 => Ok(String::from("one minute")),
+            5 | 10 | 20 | 25 => cardinal(minutes),
+            _ => Ok(format!("{} minutes", cardinal(minutes)?)),
+        };
+
+        match minute {
+            0 if hour == 0 => Ok(String::from("midnight")),
+            0 if hour == 12 => Ok(String::from("noon")),
+            0 => Ok(format!("{} o'clock", hour_word(hour)?)),
+            30 => Ok(format!("half past {}", hour_word(hour)?)),
+            1..=29 => Ok(format!(
+                "{} past {}",
+                minutes_word(minute)?,
+                hour_word(hour)?
+            )),
+            _ => Ok(format!(
+                "{} to {}",
+                minutes_word(60 - minute)?,
+                hour_word(hour + 1)?
+            )),
+        }
+    }
+
+    fn to_phone(&self, country_code: Option<&str>, groups: &[&str]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if let Some(code) = country_code {
+            let code = code.parse::<u64>().map_err(|_| Num2Err::CannotConvert)?;
+            words.push(format!(
+                "plus {}",
+                self.int_to_cardinal(BigFloat::from(code), false)?
+            ));
+        }
+        words.extend(groups.iter().map(|group| self.phone_group_to_words(group)));
+        Ok(words.join(", "))
+    }
+
+    // the year with its last word in the plural, e.g. "nineteen ninety" =>
+    // "the nineteen-nineties"
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let year = self.to_year(num)?;
+        let (head, last) = year.rsplit_once(' ').unwrap_or(("", year.as_str()));
+        let plural = match last.strip_suffix('y') {
+            Some(stem) => format!("{}ies", stem),
+            None => format!("{}s", last),
+        };
+        if head.is_empty() {
+            Ok(format!("the {}", plural))
+        } else {
+            Ok(format!("the {}-{}", head.replace(' ', "-"), plural))
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!("the {} century", self.to_ordinal(century)?))
+    }
+
+    fn to_approximate(&self, num: BigFloat, digits: usize) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let rounded = round_significant(num, digits);
+        let hedge = if rounded.abs() < BigFloat::from(1000) {
+            "about"
+        } else {
+            "roughly"
+        };
+
+        // the sign is read before the hedge, e.g. "minus about a hundred"
+        let sign = self.sign(rounded.is_negative());
+        let words = self.to_cardinal(rounded.abs())?;
+        // e.g. "one hundred" => "a hundred"
+        match words.strip_prefix("one ") {
+            Some(rest) if rounded.abs() >= BigFloat::from(100) => {
+                Ok(format!("{}{} a {}", sign, hedge, rest))
+            }
+            _ => Ok(format!("{}{} {}", sign, hedge, words)),
+        }
+    }
+
+    fn to_compact(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let (whole, quarters, scale) = match compact_scale(num.abs()) {
+            Some((whole, quarters, power)) => match self.scale_word(power) {
+                Some(scale) => (whole, quarters, scale),
+                None => return self.to_cardinal(num),
+            },
+            None => return self.to_cardinal(num),
+        };
+
+        let words = match (whole, quarters) {
+            (0, 1) => format!("a quarter of a {}", scale),
+            (0, 2) => format!("half a {}", scale),
+            (0, _) => format!("three quarters of a {}", scale),
+            (_, _) => format!(
+                "{} and {} {}",
+                self.to_cardinal(BigFloat::from(whole))?,
+                ["a quarter", "a half", "three-quarter"][quarters as usize - 1],
+                scale
+            ),
+        };
+        Ok(format!("{}{}", self.sign(num.is_negative()), words))
+    }
+
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let numerator_word = self.int_to_cardinal(numerator, false)?;
+        let plural = numerator.abs() != BigFloat::from(1);
+
+        match self.denominator_word(denominator, plural) {
+            Some(word) => Ok(format!("{} {}", numerator_word, word)),
+            None => Ok(format!(
+                "{} over {}",
+                numerator_word,
+                self.int_to_cardinal(denominator, false)?
+            )),
+        }
+    }
+
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        let suffix = if plural { "s" } else { "" };
+        match denominator.to_u64() {
+            Some(2) => Some(String::from(if plural { "halves" } else { "half" })),
+            Some(4) => Some(format!("quarter{}", suffix)),
+            Some(3..=12) => Some(format!("{}{}", self.to_ordinal(denominator).ok()?, suffix)),
+            _ if is_power_of_ten(denominator) => {
+                // e.g. "one hundredth" => "seven hundredths"
+                let ordinal = self.to_ordinal(denominator).ok()?;
+                Some(format!("{}{}", ordinal.trim_start_matches("one "), suffix))
+            }
+            _ => None,
+        }
+    }
+
+    fn to_mixed_fraction(
+        &self,
+        whole: BigFloat,
+        numerator: BigFloat,
+        denominator: BigFloat,
+    ) -> Result<String, Num2Err> {
+        let fraction = self.to_fraction(numerator, denominator)?;
+        // e.g. "two and a half" rather than "two and one half"
+        let fraction = match fraction.strip_prefix("one ") {
+            Some(rest) if !rest.starts_with("over ") => format!("a {}", rest),
+            _ => fraction,
+        };
+        Ok(format!(
+            "{} and {}",
+            self.int_to_cardinal(whole, false)?,
+            fraction
+        ))
+    }
+
+    fn to_ssml(&self, words: String, currency: Option<Currency>) -> String {
+        let unit = currency.map(|c| {
+            let plural = self.currencies(c, true);
+            if words.contains(&plural) {
+                plural
+            } else {
+                self.currencies(c, false)
+            }
+        });
+        ssml(&words, &MEGAS, unit.as_deref().zip(currency))
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " and "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("in debit")
+    }
+
+    fn scaled(&self, scale: Scale) -> Option<Box<dyn Language>> {
+        Some(Box::new(Self {
+            scale,
+            ..self.clone()
+        }))
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        if scheme != GroupingScheme::Thousands || groups.iter().any(|g| *g >= 1000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return self.int_to_cardinal(BigFloat::from(0), false);
+        }
+
+        let mut words = vec![];
+        let triplets: Vec<u64> = groups.iter().rev().copied().collect();
+        self.push_triplets(&mut words, &triplets, false)?;
+        Ok(words.join(" "))
+    }
+
+    // e.g. "million" for 2, and in the long scale "billion" for 4 and
+    // "milliard" or nothing for 3, past the tables after Conway and Wechsler,
+    // e.g. "unvigintillion"
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        let (table, n, ending) = match (self.scale, power) {
+            (_, 0) => return None,
+            (_, 1) => return Some(MEGAS[0]),
+            (Scale::Short, _) => (&MEGAS[1..], power - 1, "illion"),
+            (Scale::Long, _) if power % 2 == 1 && self.milliard => {
+                (&MILLIARDS[..], power / 2, "illiard")
+            }
+            (Scale::Long, _) if power % 2 == 1 => return None,
+            (Scale::Long, _) => (&MEGAS[1..], power / 2, "illion"),
+        };
+        match table.get(n - 1) {
+            Some(word) => Some(word),
+            None => Some(intern(format!("{}{}", illion_stem(n)?, ending))),
+        }
+    }
+
+    fn spell_small_into<'a>(&self, n: u16, buf: &'a mut [u8]) -> Result<&'a str, Num2Err> {
+        if n > 999 {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let hundreds = (n / 100) as usize;
+        let tens = (n / 10 % 10) as usize;
+        let units = (n % 10) as usize;
+
+        let mut writer = SliceWriter::new(buf);
+        let mut spell = || -> std::fmt::Result {
+            if n == 0 {
+                return write!(writer, "zero");
+            }
+            if hundreds > 0 {
+                write!(writer, "{} {}", UNITS[hundreds - 1], self.hundred_word())?;
+                if tens != 0 || units != 0 {
+                    write!(writer, " ")?;
+                }
+            }
+            match (tens, units) {
+                (0, 0) => Ok(()),
+                (0, _) => write!(writer, "{}", UNITS[units - 1]),
+                (1, _) => write!(writer, "{}", TEENS[units]),
+                (_, 0) => write!(writer, "{}", TENS[tens - 1]),
+                _ => write!(writer, "{}-{}", TENS[tens - 1], UNITS[units - 1]),
+            }
+        };
+        spell().map_err(|_| Num2Err::CannotConvert)?;
+
+        Ok(writer.into_str())
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "hundred"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0]
+    }
+
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        let (one, many) = match unit {
+            Unit::Millimeter => ("millimeter", "millimeters"),
+            Unit::Centimeter => ("centimeter", "centimeters"),
+            Unit::Meter => ("meter", "meters"),
+            Unit::Kilometer => ("kilometer", "kilometers"),
+            Unit::Mile => ("mile", "miles"),
+            Unit::Gram => ("gram", "grams"),
+            Unit::Kilogram => ("kilogram", "kilograms"),
+            Unit::Liter => ("liter", "liters"),
+            Unit::Second => ("second", "seconds"),
+            Unit::Minute => ("minute", "minutes"),
+            Unit::Hour => ("hour", "hours"),
+            Unit::Day => ("day", "days"),
+            _ => return unit.custom_words(),
+        };
+        Some([one, many, many, many])
+    }
+
+    fn vague_plural(&self, power: usize) -> Option<String> {
+        match power {
+            2 => Some(format!("{}s", self.hundred_word())),
+            _ => Some(format!("{}s", self.scale_word(power / 3)?)),
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("percent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("per mille")
+    }
+
+    fn year_oh(&self) -> Option<&'static str> {
+        Some("oh")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::English).cardinal().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(-10)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus ten"))
+        );
+        assert_eq!(
+            Num2Words::new(38123147081932i64)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from(
+                "thirty-eight trillion one hundred twenty-three \
+                 billion one hundred forty-seven million eighty-one thousand \
+                 nine hundred and thirty-two"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(100000000000i64)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("one hundred billion"))
+        );
+    }
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::English)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("one million"))
+        );
+        assert_eq!(
+            Num2Words::new(1e15)
+                .lang(Lang::English)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("a really big number"))
+        );
+        assert_eq!(
+            Num2Words::new(-1e15)
+                .lang(Lang::English)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("minus a really big number"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::English).ordinal().to_words(),
+            Ok(String::from("tenth"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::English).ordinal().to_words(),
+            Ok(String::from("twenty-first"))
+        );
+        assert_eq!(
+            Num2Words::new(102).lang(Lang::English).ordinal().to_words(),
+            Ok(String::from("one hundred second"))
+        );
+        assert_eq!(
+            Num2Words::new(73).lang(Lang::English).ordinal().to_words(),
+            Ok(String::from("seventy-third"))
+        );
+        assert_eq!(
+            Num2Words::new(-1).lang(Lang::English).ordinal().to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(1.2).lang(Lang::English).ordinal().to_words(),
+            Err(num2words::Num2Err::FloatingOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_transform() {
+        let english = crate::lang::English::new(false, false);
+        assert_eq!(english.ordinal_transform("two"), "second");
+        assert_eq!(english.ordinal_transform("forty"), "fortieth");
+        assert_eq!(english.ordinal_transform("million"), "millionth");
+        assert_eq!(
+            Num2Words::new(2_000_003)
+                .lang(Lang::English)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("two million and third"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::English)
+                .prefer("abbrev")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("one millionth"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_from_end() {
+        assert_eq!(
+            Num2Words::new(-1)
+                .lang(Lang::English)
+                .ordinal()
+                .prefer("from-end")
+                .to_words(),
+            Ok(String::from("first from last"))
+        );
+        assert_eq!(
+            Num2Words::new(-2)
+                .lang(Lang::English)
+                .ordinal()
+                .prefer("from-end")
+                .to_words(),
+            Ok(String::from("second from last"))
+        );
+        assert_eq!(
+            Num2Words::new(-2).lang(Lang::English).ordinal().to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("10th"))
+        );
+        assert_eq!(
+            Num2Words::new(13)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("13th"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("21st"))
+        );
+        assert_eq!(
+            Num2Words::new(102)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("102nd"))
+        );
+        assert_eq!(
+            Num2Words::new(73)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("73rd"))
+        );
+        assert_eq!(
+            Num2Words::new(-42)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(7.3)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::FloatingOrdinal)
+        );
+        // digits past a u128 are written out all the same
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("1{}th", "0".repeat(40)))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num_zero() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("0th"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("zero-numeral")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("0th"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("zero-spelled")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("zeroth"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("zero-error")
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::ZeroOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_cardinal_float() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twelve point five"))
+        );
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twelve point five one"))
+        );
+        assert_eq!(
+            Num2Words::new(12.53)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twelve point five three"))
+        );
+        assert_eq!(
+            Num2Words::new(12.59)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twelve point five nine"))
+        );
+    }
+
+    #[test]
+    fn test_repeating_fraction() {
+        use num_bigfloat::BigFloat;
+
+        let third = BigFloat::from(1) / BigFloat::from(3);
+        let words = Num2Words::new(third)
+            .lang(Lang::English)
+            .to_words()
+            .unwrap();
+        assert!(words.starts_with("point three three"));
+        assert!(words.split(' ').count() <= lang::MAX_FRACTION_DIGITS + 1);
+    }
+
+    #[test]
+    #[allow(clippy::approx_constant)]
+    fn test_mixed() {
+        assert_eq!(
+            Num2Words::new(3.14159)
+                .lang(Lang::English)
+                .prefer("mixed")
+                .to_words(),
+            Ok(String::from("three point one four one five nine"))
+        );
+        assert_eq!(
+            Num2Words::new(3.14159)
+                .lang(Lang::English)
+                .prefer("mixed-pairs")
+                .to_words(),
+            Ok(String::from("three point fourteen fifteen nine"))
+        );
+        assert_eq!(
+            Num2Words::new(3.14159)
+                .lang(Lang::English)
+                .prefer("mixed-triples")
+                .to_words(),
+            Ok(String::from("three point one hundred forty-one fifty-nine"))
+        );
+        assert_eq!(
+            Num2Words::new(2.0504)
+                .lang(Lang::English)
+                .prefer("mixed-pairs")
+                .to_words(),
+            Ok(String::from("two point zero five zero four"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("one dollar and one cent"))
+        );
+        assert_eq!(
+            Num2Words::new(4000)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("four thousand US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(1.)
+                .lang(Lang::English)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("one euro"))
+        );
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("twenty cents"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("zero dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::English)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("two euros"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("two US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-5)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus five US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.5)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus five US dollars and fifty cents"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus fifty cents"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.5)
+                .lang(Lang::English)
+                .prefer("paren")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("(five US dollars and fifty cents)"))
+        );
+    }
+
+    #[test]
+    fn test_currency_negative_zero() {
+        assert_eq!(
+            Num2Words::new(-0.00)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("zero US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.001)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("zero US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.01)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus one cent"))
+        );
+    }
+
+    #[test]
+    fn test_currency_below_unit() {
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("one cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.05)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("five cents"))
+        );
+        assert_eq!(
+            Num2Words::new(0.99)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("ninety-nine cents"))
+        );
+        assert_eq!(
+            Num2Words::new(0.00)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("zero US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(0.001)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("zero US dollars"))
+        );
+    }
+
+    #[test]
+    fn test_currency_connector() {
+        let english = crate::lang::English::new(false, false);
+        assert_eq!(english.currency_connector(), " and ");
+        assert_eq!(
+            Num2Words::new(1.50)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("one US dollar and fifty cents"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen ninety"))
+        );
+        assert_eq!(
+            Num2Words::new(5555).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five fifty-five"))
+        );
+        assert_eq!(
+            Num2Words::new(2022).lang(Lang::English).year().to_words(),
+            Ok(String::from("twenty twenty-two"))
+        );
+        assert_eq!(
+            Num2Words::new(2001).lang(Lang::English).year().to_words(),
+            Ok(String::from("two thousand and one"))
+        );
+        assert_eq!(
+            Num2Words::new(1901).lang(Lang::English).year().to_words(),
+            Ok(String::from("nineteen oh-one"))
+        );
+        assert_eq!(
+            Num2Words::new(5500).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty-five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(500).lang(Lang::English).year().to_words(),
+            Ok(String::from("five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::English).year().to_words(),
+            Ok(String::from("fifty"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::English).year().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::English).year().to_words(),
+            Ok(String::from("forty-four BC"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
+            Err(num2words::Num2Err::FloatingYear)
+        );
+        // years past a u64 are read as cardinals
+        for year in [1e21, 1e40] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::English).year().to_words(),
+                Num2Words::new(year).lang(Lang::English).to_words()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::English).decade().to_words(),
+            Ok(String::from("the nineteen-nineties"))
+        );
+        assert_eq!(
+            Num2Words::new(2010).lang(Lang::English).decade().to_words(),
+            Ok(String::from("the twenty-tens"))
+        );
+        assert_eq!(
+            Num2Words::new(1e21).lang(Lang::English).decade().to_words(),
+            Ok(String::from("the one-sextillions"))
+        );
+        assert_eq!(
+            Num2Words::new(1900)
+                .lang(Lang::English)
+                .century()
+                .to_words(),
+            Ok(String::from("the twentieth century"))
+        );
+        assert_eq!(
+            Num2Words::new(1995).lang(Lang::English).decade().to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::new(1950)
+                .lang(Lang::English)
+                .century()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_year_low_half() {
+        use crate::lang::Language;
+
+        let english = lang::English::new(false, false);
+        assert_eq!(english.year_low_half(0), Ok(String::from("hundred")));
+        assert_eq!(english.year_low_half(1), Ok(String::from("oh-one")));
+        assert_eq!(english.year_low_half(5), Ok(String::from("oh-five")));
+        assert_eq!(english.year_low_half(10), Ok(String::from("ten")));
+    }
+
+    #[test]
+    fn test_prefer() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("oh"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::English)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("nil"))
+        );
+        assert_eq!(
+            Num2Words::new(0.005)
+                .lang(Lang::English)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("point oh oh five"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::English)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("two point zero five"))
+        );
+        assert_eq!(
+            Num2Words::new(-42)
+                .lang(Lang::English)
+                .prefer("paren")
+                .to_words(),
+            Ok(String::from("(forty-two)"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5).lang(Lang::English).to_words(),
+            Ok(String::from("minus point five"))
+        );
+    }
+
+    #[test]
+    fn test_decimal_currency() {
+        assert_eq!(
+            Num2Words::new(5.50)
+                .lang(Lang::English)
+                .prefer("decimal-currency")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("five point five zero euros"))
+        );
+        assert_eq!(
+            Num2Words::new(5.05)
+                .lang(Lang::English)
+                .prefer("decimal-currency")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("five point zero five euros"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .prefer("decimal-currency")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("five euros"))
+        );
+    }
+
+    #[test]
+    fn test_currency_and() {
+        assert_eq!(
+            Num2Words::new(123)
+                .lang(Lang::English)
+                .prefer("currency-and")
+                .to_words(),
+            Ok(String::from("one hundred twenty-three"))
+        );
+        assert_eq!(
+            Num2Words::new(123)
+                .lang(Lang::English)
+                .prefer("currency-and")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("one hundred and twenty-three US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(1123)
+                .lang(Lang::English)
+                .prefer("currency-and")
+                .to_words(),
+            Ok(String::from("one thousand one hundred twenty-three"))
+        );
+    }
+
+    #[test]
+    fn test_currency_names() {
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("five US dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .prefer("native")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("five dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .prefer("iso")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("five USD"))
+        );
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::English)
+                .prefer("native")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("one dollar and one cent"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .prefer("iso")
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("five dollars"))
+        );
+    }
+
+    #[test]
+    fn test_currency_mills() {
+        assert_eq!(
+            Num2Words::new(5.005)
+                .lang(Lang::English)
+                .currency(Currency::MILL)
+                .to_words(),
+            Ok(String::from("five dollars and five mills"))
+        );
+        assert_eq!(
+            Num2Words::new(5.050)
+                .lang(Lang::English)
+                .currency(Currency::MILL)
+                .to_words(),
+            Ok(String::from("five dollars and fifty mills"))
+        );
+    }
+
+    #[test]
+    fn test_currency_parts() {
+        assert_eq!(
+            Num2Words::new(123.45)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_currency_parts(),
+            Ok((
+                String::from("one hundred twenty-three"),
+                String::from("forty-five"),
+                String::from("US dollars"),
+                String::from("cents"),
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(-1.01)
+                .lang(Lang::English)
+                .currency(Currency::USD)
+                .to_currency_parts(),
+            Ok((
+                String::from("minus one"),
+                String::from("one"),
+                String::from("US dollar"),
+                String::from("cent"),
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(123.45)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_currency_parts(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_multiplicative() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("once"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("twice"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("thrice"))
+        );
+        assert_eq!(
+            Num2Words::new(4)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("four times"))
+        );
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("tenfold"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Ok(String::from("twenty-one times"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::English)
+                .multiplicative()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_ssml() {
+        assert_eq!(
+            Num2Words::new(1234)
+                .lang(Lang::English)
+                .prefer("ssml")
+                .to_words(),
+            Ok(String::from(
+                "<speak>one thousand<break/> two hundred and thirty-four</speak>"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(5.50)
+                .lang(Lang::English)
+                .prefer("ssml")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from(
+                "<speak>five <sub alias=\"US dollars\">USD</sub> and fifty cents</speak>"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(1234)
+                .lang(Lang::English)
+                .prefer("ssml")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("one thousand two hundred and thirty-fourth"))
+        );
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(
+            Num2Words::new(1e3)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from("ten to the third power"))
+        );
+        assert_eq!(
+            Num2Words::new(1e6)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from("ten to the sixth power"))
+        );
+        assert_eq!(
+            Num2Words::new(1e21)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from("ten to the twenty-first power"))
+        );
+        assert_eq!(
+            Num2Words::new(-2500)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "minus two point five times ten to the third power"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(0.001)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from("ten to the minus third power"))
+        );
+        assert_eq!(
+            Num2Words::new(1e100)
+                .lang(Lang::English)
+                .scientific()
+                .to_words(),
+            Ok(String::from("ten to the one hundredth power"))
+        );
+    }
+
+    #[test]
+    fn test_abbrev() {
+        assert_eq!(
+            Num2Words::new(5000)
+                .lang(Lang::English)
+                .prefer("abbrev")
+                .to_words(),
+            Ok(String::from("five kay"))
+        );
+        assert_eq!(
+            Num2Words::new(2000000)
+                .lang(Lang::English)
+                .prefer("abbrev")
+                .to_words(),
+            Ok(String::from("two mil"))
+        );
+        assert_eq!(
+            Num2Words::new(1500)
+                .lang(Lang::English)
+                .prefer("abbrev")
+                .to_words(),
+            Ok(String::from("one thousand five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(5000)
+                .lang(Lang::English)
+                .prefer("abbrev")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("five thousandth"))
+        );
+    }
+
+    #[test]
+    fn test_decimal_scale() {
+        assert_eq!(
+            Num2Words::new(1_500_000)
+                .lang(Lang::English)
+                .prefer("decimal-scale")
+                .to_words(),
+            Ok(String::from("one point five million"))
+        );
+        assert_eq!(
+            Num2Words::new(2_750_000_000i64)
+                .lang(Lang::English)
+                .prefer("decimal-scale")
+                .to_words(),
+            Ok(String::from("two point seven five billion"))
+        );
+        assert_eq!(
+            Num2Words::new(1_234_567)
+                .lang(Lang::English)
+                .prefer("decimal-scale")
+                .to_words(),
+            Ok(String::from(
+                "one million two hundred thirty-four thousand five hundred and sixty-seven"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(-1_500_000)
+                .lang(Lang::English)
+                .prefer("decimal-scale")
+                .to_words(),
+            Ok(String::from("minus one point five million"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::English)
+                .prefer("decimal-scale")
+                .to_words(),
+            Ok(String::from("two million"))
+        );
+    }
+
+    #[test]
+    fn test_color() {
+        assert_eq!(
+            Num2Words::parse("#FFFFFF")
+                .unwrap()
+                .lang(Lang::English)
+                .prefer("color")
+                .to_words(),
+            Ok(String::from("hash eff-eff eff-eff eff-eff"))
+        );
+        assert_eq!(
+            Num2Words::parse("#1A2B3C")
+                .unwrap()
+                .lang(Lang::English)
+                .prefer("color")
+                .to_words(),
+            Ok(String::from("hash one-ay two-bee three-cee"))
+        );
+        assert_eq!(
+            Num2Words::parse("#00ff00")
+                .unwrap()
+                .lang(Lang::English)
+                .prefer("color")
+                .to_words(),
+            Ok(String::from("hash zero-zero eff-eff zero-zero"))
+        );
+        assert!(Num2Words::parse("#FFF").is_none());
+    }
+
+    #[test]
+    fn test_expanded() {
+        assert_eq!(
+            Num2Words::new(234)
+                .lang(Lang::English)
+                .expanded()
+                .to_words(),
+            Ok(String::from("two hundreds, three tens and four ones"))
+        );
+        assert_eq!(
+            Num2Words::new(1050)
+                .lang(Lang::English)
+                .expanded()
+                .to_words(),
+            Ok(String::from("one thousand and five tens"))
+        );
+        assert_eq!(
+            Num2Words::new(41_001)
+                .lang(Lang::English)
+                .expanded()
+                .to_words(),
+            Ok(String::from("four ten thousands, one thousand and one one"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::English).expanded().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::English)
+                .expanded()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(-5).lang(Lang::English).percent().to_words(),
+            Ok(String::from("minus five percent"))
+        );
+        assert_eq!(
+            Num2Words::new(12).lang(Lang::English).permille().to_words(),
+            Ok(String::from("twelve per mille"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::English).percent().to_words(),
+            Ok(String::from("point five percent"))
+        );
+    }
+
+    #[test]
+    fn test_vague_plural() {
+        assert_eq!(
+            Num2Words::new(3000)
+                .lang(Lang::English)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("thousands"))
+        );
+        assert_eq!(
+            Num2Words::new(500)
+                .lang(Lang::English)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("hundreds"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::English)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("millions"))
+        );
+        assert_eq!(
+            Num2Words::new(100)
+                .lang(Lang::English)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("one hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(3500)
+                .lang(Lang::English)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("three thousand five hundred"))
+        );
+    }
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(
+            Num2Words::new(98)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("about a hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(1950)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("roughly two thousand"))
+        );
+        assert_eq!(
+            Num2Words::new(512)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("about five hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(-1_040_000)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("minus roughly a million"))
+        );
+        assert_eq!(
+            Num2Words::new(-98)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("minus about a hundred"))
+        );
+        assert_eq!(
+            Num2Words::new(7.3)
+                .lang(Lang::English)
+                .approximate()
+                .to_words(),
+            Ok(String::from("about seven"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(7)
+                .lang(Lang::English)
+                .fraction(13)
+                .to_words(),
+            Ok(String::from("seven over thirteen"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::English)
+                .fraction(100)
+                .to_words(),
+            Ok(String::from("one hundredth"))
+        );
+        assert_eq!(
+            Num2Words::new(7)
+                .lang(Lang::English)
+                .fraction(100)
+                .to_words(),
+            Ok(String::from("seven hundredths"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::English).fraction(3).to_words(),
+            Ok(String::from("two thirds"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::English).fraction(2).to_words(),
+            Ok(String::from("one half"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::English).fraction(0).to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_time() {
+        let time = |hour, minute| Num2Words::time(hour, minute).lang(Lang::English).to_words();
+        assert_eq!(time(3, 0), Ok(String::from("three o'clock")));
+        assert_eq!(time(15, 15), Ok(String::from("quarter past three")));
+        assert_eq!(time(3, 30), Ok(String::from("half past three")));
+        assert_eq!(time(3, 45), Ok(String::from("quarter to four")));
+        assert_eq!(time(3, 20), Ok(String::from("twenty past three")));
+        assert_eq!(time(3, 7), Ok(String::from("seven minutes past three")));
+        assert_eq!(time(11, 59), Ok(String::from("one minute to twelve")));
+        assert_eq!(time(0, 0), Ok(String::from("midnight")));
+        assert_eq!(time(12, 0), Ok(String::from("noon")));
+        assert_eq!(time(24, 0), Err(Num2Err::CannotConvert));
+        assert_eq!(time(3, 60), Err(Num2Err::CannotConvert));
+        assert_eq!(
+            Num2Words::time(9, 5)
+                .lang(Lang::English)
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from("oh nine oh five"))
+        );
+        assert_eq!(
+            Num2Words::time(15, 0)
+                .lang(Lang::English)
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from("fifteen hundred"))
+        );
+    }
+
+    #[test]
+    fn test_phone() {
+        assert_eq!(
+            Num2Words::phone("020 7946 0018")
+                .lang(Lang::English)
+                .to_words(),
+            Ok(String::from(
+                "zero two zero, seven nine four six, zero zero one eight"
+            ))
+        );
+        assert_eq!(
+            Num2Words::phone("0800 555 5500")
+                .lang(Lang::English)
+                .prefer("oh")
+                .prefer("double")
+                .to_words(),
+            Ok(String::from(
+                "oh eight double oh, triple five, double five double oh"
+            ))
+        );
+        assert_eq!(
+            Num2Words::phone("+44 (0)20 7946")
+                .lang(Lang::English)
+                .to_words(),
+            Ok(String::from(
+                "plus forty-four, zero, two zero, seven nine four six"
+            ))
+        );
+        assert_eq!(
+            Num2Words::phone("call me").lang(Lang::English).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_mixed_fraction() {
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::English)
+                .fraction(2)
+                .prefer("mixed-number")
+                .to_words(),
+            Ok(String::from("two and a half"))
+        );
+        assert_eq!(
+            Num2Words::new(-11)
+                .lang(Lang::English)
+                .fraction(4)
+                .prefer("mixed-number")
+                .to_words(),
+            Ok(String::from("minus two and three quarters"))
+        );
+        assert_eq!(
+            Num2Words::new(8)
+                .lang(Lang::English)
+                .fraction(7)
+                .prefer("mixed-number")
+                .to_words(),
+            Ok(String::from("one and a seventh"))
+        );
+        assert_eq!(
+            Num2Words::new(14)
+                .lang(Lang::English)
+                .fraction(13)
+                .prefer("mixed-number")
+                .to_words(),
+            Ok(String::from("one and one over thirteen"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::English)
+                .fraction(4)
+                .prefer("mixed-number")
+                .to_words(),
+            Ok(String::from("three quarters"))
+        );
+    }
+
+    #[test]
+    fn test_improper_fraction() {
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::English).fraction(2).to_words(),
+            Ok(String::from("five halves"))
+        );
+        assert_eq!(
+            Num2Words::new(4).lang(Lang::English).fraction(2).to_words(),
+            Ok(String::from("two"))
+        );
+        assert_eq!(
+            Num2Words::new(6).lang(Lang::English).fraction(4).to_words(),
+            Ok(String::from("three halves"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::English)
+                .fraction(-6)
+                .to_words(),
+            Ok(String::from("minus one half"))
+        );
+    }
+
+    #[test]
+    fn test_big_num() {
+        use crate::lang::en::MEGAS;
+        use num_bigfloat::BigFloat;
+
+        let mut num = BigFloat::from(1);
+        for m in MEGAS {
+            num *= BigFloat::from(1000);
+            assert_eq!(
+                Num2Words::new(num)
+                    .lang(Lang::English)
+                    .cardinal()
+                    .to_words(),
+                Ok(format!("one {}", m))
+            );
+        }
+
+        assert_eq!(
+            Num2Words::parse("2.8e64")
+                .unwrap()
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twenty-eight vigintillion"))
+        );
+
+        assert_eq!(
+            Num2Words::new(1e100)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ten duotrigintillion"))
+        );
+        assert_eq!(
+            Num2Words::new(3e72)
+                .lang(Lang::English)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("three tresvigintillion"))
+        );
+        assert_eq!(
+            Num2Words::new(1e90)
+                .lang(Lang::English)
+                .cardinal()
                 .to_words(),
-            Ok(String::from("21st"))
+            Ok(String::from("one novemvigintillion"))
         );
+    }
+
+    #[test]
+    fn test_infinity() {
         assert_eq!(
-            Num2Words::new(102)
+            Num2Words::new(f64::INFINITY)
                 .lang(Lang::English)
-                .ordinal_num()
+                .cardinal()
                 .to_words(),
-            Ok(String::from("102nd"))
+            Ok(String::from("infinity"))
         );
         assert_eq!(
-            Num2Words::new(73)
+            Num2Words::new(f64::NEG_INFINITY)
                 .lang(Lang::English)
-                .ordinal_num()
+                .cardinal()
                 .to_words(),
-            Ok(String::from("73rd"))
+            Ok(String::from("minus infinity"))
         );
         assert_eq!(
-            Num2Words::new(-42)
+            Num2Words::new(f64::INFINITY)
                 .lang(Lang::English)
-                .ordinal_num()
+                .ordinal()
                 .to_words(),
-            Err(num2words::Num2Err::NegativeOrdinal)
+            Err(num2words::Num2Err::InfiniteOrdinal)
         );
         assert_eq!(
-            Num2Words::new(7.3)
+            Num2Words::new(f64::INFINITY)
                 .lang(Lang::English)
                 .ordinal_num()
                 .to_words(),
-            Err(num2words::Num2Err::FloatingOrdinal)
+            Err(num2words::Num2Err::InfiniteOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang(Lang::English)
+                .year()
+                .to_words(),
+            Err(num2words::Num2Err::InfiniteYear)
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang(Lang::English)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("an infinity of dollars"))
         );
     }
 
     #[test]
-    fn test_cardinal_float() {
+    fn test_unit() {
         assert_eq!(
-            Num2Words::new(12.5)
+            Num2Words::new(21)
                 .lang(Lang::English)
-                .cardinal()
+                .unit(Unit::Kilometer)
                 .to_words(),
-            Ok(String::from("twelve point five"))
+            Ok(String::from("twenty-one kilometers"))
         );
         assert_eq!(
-            Num2Words::new(12.51)
+            Num2Words::new(1)
                 .lang(Lang::English)
-                .cardinal()
+                .unit(Unit::Hour)
                 .to_words(),
-            Ok(String::from("twelve point five one"))
+            Ok(String::from("one hour"))
         );
         assert_eq!(
-            Num2Words::new(12.53)
+            Num2Words::new(2.5)
                 .lang(Lang::English)
-                .cardinal()
+                .unit(Unit::Liter)
                 .to_words(),
-            Ok(String::from("twelve point five three"))
+            Ok(String::from("two point five liters"))
         );
         assert_eq!(
-            Num2Words::new(12.59)
+            Num2Words::new(3)
                 .lang(Lang::English)
-                .cardinal()
+                .unit(Unit::Custom {
+                    singular: "parsec",
+                    paucal: "parsecs",
+                    plural: "parsecs",
+                })
                 .to_words(),
-            Ok(String::from("twelve point five nine"))
+            Ok(String::from("three parsecs"))
         );
     }
 
     #[test]
-    fn test_currency() {
+    fn test_negative_word() {
         assert_eq!(
-            Num2Words::new(1.01)
+            Num2Words::new(-10)
+                .lang(Lang::English)
+                .cardinal()
+                .prefer("negative")
+                .to_words(),
+            Ok(String::from("negative ten"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.5)
                 .lang(Lang::English)
                 .currency(Currency::DOLLAR)
+                .prefer("negative")
                 .to_words(),
-            Ok(String::from("one dollar and one cent"))
+            Ok(String::from("negative five dollars and fifty cents"))
         );
         assert_eq!(
-            Num2Words::new(4000)
+            Num2Words::new(-0.5)
                 .lang(Lang::English)
-                .currency(Currency::USD)
+                .cardinal()
+                .prefer("negative")
                 .to_words(),
-            Ok(String::from("four thousand US dollars"))
+            Ok(String::from("negative point five"))
         );
         assert_eq!(
-            Num2Words::new(1.)
+            Num2Words::new(1e-3)
                 .lang(Lang::English)
-                .currency(Currency::EUR)
+                .scientific()
+                .prefer("negative")
                 .to_words(),
-            Ok(String::from("one euro"))
+            Ok(String::from("ten to the negative third power"))
         );
+    }
+
+    #[test]
+    fn test_decimal_word() {
         assert_eq!(
-            Num2Words::new(0.20)
+            Num2Words::new(2.5)
                 .lang(Lang::English)
-                .currency(Currency::DOLLAR)
+                .cardinal()
+                .prefer("comma")
                 .to_words(),
-            Ok(String::from("twenty cents"))
+            Ok(String::from("two comma five"))
         );
         assert_eq!(
-            Num2Words::new(0)
+            Num2Words::new(5.5)
                 .lang(Lang::English)
-                .currency(Currency::DOLLAR)
+                .currency(Currency::EUR)
+                .prefer("comma")
+                .prefer("decimal-currency")
                 .to_words(),
-            Ok(String::from("zero dollars"))
+            Ok(String::from("five comma five zero euros"))
         );
     }
 
     #[test]
-    fn test_year() {
+    fn test_whole_fraction() {
         assert_eq!(
-            Num2Words::new(1990).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen ninety"))
+            Num2Words::new(12.51)
+                .lang(Lang::English)
+                .prefer("whole-fraction")
+                .to_words(),
+            Ok(String::from("twelve point fifty-one"))
         );
         assert_eq!(
-            Num2Words::new(5555).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five fifty-five"))
+            Num2Words::new(3.05)
+                .lang(Lang::English)
+                .prefer("whole-fraction")
+                .to_words(),
+            Ok(String::from("three point zero five"))
         );
+    }
+
+    #[test]
+    fn test_currency_subunit_exponent() {
         assert_eq!(
-            Num2Words::new(2022).lang(Lang::English).year().to_words(),
-            Ok(String::from("twenty twenty-two"))
+            Num2Words::new(42.5).currency(Currency::JPY).to_words(),
+            Ok(String::from("forty-two yens"))
         );
         assert_eq!(
-            Num2Words::new(2001).lang(Lang::English).year().to_words(),
-            Ok(String::from("two thousand and one"))
+            Num2Words::new(42.5)
+                .prefer("decimal-currency")
+                .currency(Currency::JPY)
+                .to_words(),
+            Ok(String::from("forty-two yens"))
         );
         assert_eq!(
-            Num2Words::new(1901).lang(Lang::English).year().to_words(),
-            Ok(String::from("nineteen oh-one"))
+            Num2Words::new(1.234).currency(Currency::KWD).to_words(),
+            Ok(String::from(
+                "one kuwaiti dinar and two hundred thirty-four fils"
+            ))
         );
         assert_eq!(
-            Num2Words::new(5500).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty-five hundred"))
+            Num2Words::new(0.005).currency(Currency::BHD).to_words(),
+            Ok(String::from("five fils"))
         );
+        assert_eq!(Currency::JPY.subunit_ratio(), 1);
+        assert_eq!(Currency::BHD.subunit_exponent(), 3);
+    }
+
+    #[test]
+    fn test_currency_catalog() {
+        let sek = Currency::from_code("SEK").unwrap();
         assert_eq!(
-            Num2Words::new(500).lang(Lang::English).year().to_words(),
-            Ok(String::from("five hundred"))
+            Num2Words::new(2.5).currency(sek).to_words(),
+            Ok(String::from("two swedish kronas and fifty öre"))
         );
+        let xof = Currency::from_code("XOF").unwrap();
         assert_eq!(
-            Num2Words::new(50).lang(Lang::English).year().to_words(),
-            Ok(String::from("fifty"))
+            Num2Words::new(1500.5).currency(xof).to_words(),
+            Ok(String::from(
+                "one thousand five hundred west african CFA francs"
+            ))
         );
         assert_eq!(
-            Num2Words::new(0).lang(Lang::English).year().to_words(),
-            Ok(String::from("zero"))
+            Num2Words::new(1.5)
+                .currency("MDL".parse().unwrap())
+                .to_words(),
+            Ok(String::from("one moldovan leu and fifty bani"))
         );
+        assert!(Currency::from_code("XYZ").is_none());
+        assert!("PESO".parse::<Currency>().is_ok());
+    }
+
+    #[test]
+    fn test_currency_custom() {
+        let gold = Currency::custom("gold piece", "gold pieces", "copper", "coppers", 2);
         assert_eq!(
-            Num2Words::new(-44).lang(Lang::English).year().to_words(),
-            Ok(String::from("forty-four BC"))
+            Num2Words::new(1.01).currency(gold).to_words(),
+            Ok(String::from("one gold piece and one copper"))
         );
         assert_eq!(
-            Num2Words::new(1.1).lang(Lang::English).year().to_words(),
-            Err(num2words::Num2Err::FloatingYear)
+            Num2Words::new(12.5).currency(gold).to_words(),
+            Ok(String::from("twelve gold pieces and fifty coppers"))
         );
+        assert_eq!(gold.iso_code(), None);
+        assert_eq!(gold.native_string(true), "gold pieces");
     }
 
     #[test]
-    fn test_prefer() {
+    fn test_currency_negative() {
         assert_eq!(
-            Num2Words::new(0)
-                .lang(Lang::English)
-                .prefer("oh")
-                .to_words(),
-            Ok(String::from("oh"))
+            Num2Words::new(-5.5).currency(Currency::DOLLAR).to_words(),
+            Ok(String::from("minus five dollars and fifty cents"))
         );
         assert_eq!(
-            Num2Words::new(0)
-                .lang(Lang::English)
-                .prefer("nil")
+            Num2Words::new(-5.5)
+                .currency(Currency::DOLLAR)
+                .prefer("debit")
                 .to_words(),
-            Ok(String::from("nil"))
+            Ok(String::from("five dollars and fifty cents in debit"))
         );
         assert_eq!(
-            Num2Words::new(0.005)
-                .lang(Lang::English)
-                .prefer("oh")
+            Num2Words::new(5)
+                .currency(Currency::DOLLAR)
+                .prefer("debit")
                 .to_words(),
-            Ok(String::from("point oh oh five"))
+            Ok(String::from("five dollars"))
+        );
+    }
+
+    #[test]
+    fn test_year_era() {
+        let year = |n: i64, era| Num2Words::new(n).year().era(era).to_words();
+        assert_eq!(
+            year(79, Era::AnnoDomini),
+            Ok(String::from("seventy-nine AD"))
         );
         assert_eq!(
-            Num2Words::new(2.05)
-                .lang(Lang::English)
-                .prefer("nil")
-                .to_words(),
-            Ok(String::from("two point zero five"))
+            year(-44, Era::AnnoDomini),
+            Ok(String::from("forty-four BC"))
+        );
+        assert_eq!(
+            year(1990, Era::CommonEra),
+            Ok(String::from("nineteen ninety CE"))
+        );
+        assert_eq!(
+            year(-44, Era::CommonEra),
+            Ok(String::from("forty-four BCE"))
+        );
+        assert_eq!(
+            year(
+                -44,
+                Era::Custom {
+                    before: "before Rome",
+                    after: "",
+                }
+            ),
+            Ok(String::from("forty-four before Rome"))
+        );
+        assert_eq!(
+            year(
+                79,
+                Era::Custom {
+                    before: "before Rome",
+                    after: "",
+                }
+            ),
+            Ok(String::from("seventy-nine"))
         );
     }
 
     #[test]
-    fn test_big_num() {
-        use crate::lang::en::MEGAS;
-        use num_bigfloat::BigFloat;
-
-        let mut num = BigFloat::from(1);
-        for m in MEGAS {
-            num *= BigFloat::from(1000);
-            assert_eq!(
-                Num2Words::new(num)
-                    .lang(Lang::English)
-                    .cardinal()
-                    .to_words(),
-                Ok(format!("one {}", m))
-            );
-        }
-
+    fn test_year_range() {
+        let shared = |start: i64, end: i64| {
+            Num2Words::year_range(start, end)
+                .prefer("shared-century")
+                .to_words()
+        };
         assert_eq!(
-            Num2Words::parse("2.8e64")
-                .unwrap()
-                .lang(Lang::English)
-                .cardinal()
+            Num2Words::year_range(1914, 1918).to_words(),
+            Ok(String::from("nineteen fourteen to nineteen eighteen"))
+        );
+        assert_eq!(
+            shared(1914, 1918),
+            Ok(String::from("from nineteen fourteen to eighteen"))
+        );
+        // other centuries and years not read in pairs are read in full
+        assert_eq!(
+            shared(1995, 2005),
+            Ok(String::from(
+                "nineteen ninety-five to two thousand and five"
+            ))
+        );
+        assert_eq!(
+            shared(1901, 1905),
+            Ok(String::from("nineteen oh-one to nineteen oh-five"))
+        );
+        assert_eq!(
+            Num2Words::year_range(1918, 1914).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::year_range(1914.5, 1918).to_words(),
+            Err(Num2Err::FloatingYear)
+        );
+        // years past a u64 are read as cardinals
+        assert_eq!(
+            Num2Words::year_range(1e21, 1e21)
+                .prefer("shared-century")
                 .to_words(),
-            Ok(String::from("twenty-eight vigintillion"))
+            Ok(String::from("one sextillion to one sextillion"))
         );
-
         assert_eq!(
-            Num2Words::new(1e100)
+            Num2Words::year_range(1990, 1e21).to_words(),
+            Ok(String::from("nineteen ninety to one sextillion"))
+        );
+    }
+
+    #[test]
+    fn test_approximate_significant_digits() {
+        let approximate = |n: i64, digits: usize| {
+            Num2Words::new(n)
                 .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Err(num2words::Num2Err::CannotConvert)
+                .approximate()
+                .significant_digits(digits)
+                .to_words()
+        };
+        assert_eq!(
+            approximate(1940, 2),
+            Ok(String::from("roughly a thousand nine hundred"))
+        );
+        assert_eq!(
+            approximate(987, 2),
+            Ok(String::from("about nine hundred ninety"))
+        );
+        assert_eq!(approximate(42, 5), Ok(String::from("about forty-two")));
+        assert_eq!(
+            approximate(1940, 0),
+            Ok(String::from("roughly two thousand"))
         );
     }
 
     #[test]
-    fn test_infinity() {
+    fn test_compact() {
+        let compact = |n: f64| Num2Words::new(n).lang(Lang::English).compact().to_words();
+        assert_eq!(compact(2.5e6), Ok(String::from("two and a half million")));
         assert_eq!(
-            Num2Words::new(f64::INFINITY)
-                .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Ok(String::from("infinity"))
+            compact(1.25e9),
+            Ok(String::from("one and a quarter billion"))
         );
         assert_eq!(
-            Num2Words::new(f64::NEG_INFINITY)
-                .lang(Lang::English)
-                .cardinal()
-                .to_words(),
-            Ok(String::from("minus infinity"))
+            compact(12.75e6),
+            Ok(String::from("twelve and three-quarter million"))
         );
+        assert_eq!(compact(5e5), Ok(String::from("half a million")));
         assert_eq!(
-            Num2Words::new(f64::INFINITY)
-                .lang(Lang::English)
-                .ordinal()
-                .to_words(),
-            Err(num2words::Num2Err::InfiniteOrdinal)
+            compact(7.5e5),
+            Ok(String::from("three quarters of a million"))
         );
         assert_eq!(
-            Num2Words::new(f64::INFINITY)
-                .lang(Lang::English)
-                .ordinal_num()
-                .to_words(),
-            Err(num2words::Num2Err::InfiniteOrdinal)
+            compact(-2.5e6),
+            Ok(String::from("minus two and a half million"))
         );
+        assert_eq!(compact(3e6), Ok(String::from("three million")));
         assert_eq!(
-            Num2Words::new(f64::INFINITY)
-                .lang(Lang::English)
-                .year()
-                .to_words(),
-            Err(num2words::Num2Err::InfiniteYear)
+            compact(2.4e6),
+            Ok(String::from("two million four hundred thousand"))
         );
         assert_eq!(
-            Num2Words::new(f64::INFINITY)
-                .lang(Lang::English)
-                .currency(Currency::DOLLAR)
+            compact(2500.0),
+            Ok(String::from("two thousand five hundred"))
+        );
+    }
+
+    #[test]
+    fn test_long_scale() {
+        let long = |n: f64| Num2Words::new(n).scale(Scale::Long).to_words();
+        assert_eq!(long(1e6), Ok(String::from("one million")));
+        assert_eq!(long(1e9), Ok(String::from("one thousand million")));
+        assert_eq!(
+            long(1.5e9),
+            Ok(String::from("one thousand five hundred million"))
+        );
+        assert_eq!(long(3e12), Ok(String::from("three billion")));
+        assert_eq!(
+            long(2_000_001_000_000.0),
+            Ok(String::from("two billion one million"))
+        );
+        assert_eq!(
+            long(4.2e15),
+            Ok(String::from("four thousand two hundred billion"))
+        );
+        assert_eq!(long(1e18), Ok(String::from("one trillion")));
+        assert_eq!(
+            Num2Words::new(2.5e9)
+                .scale(Scale::Long)
+                .prefer("milliard")
                 .to_words(),
-            Ok(String::from("an infinity of dollars"))
+            Ok(String::from("two milliard five hundred million"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5e9).scale(Scale::Short).to_words(),
+            Ok(String::from("two billion five hundred million"))
         );
     }
 }
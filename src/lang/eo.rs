@@ -0,0 +1,379 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+// Esperanto has no exceptions: tens and hundreds are the unit joined with
+// "dek" and "cent", ordinals take "-a" and plurals take "-j". It relies on the
+// `Language` defaults wherever it can, which makes it a small reference for
+// implementing the trait.
+pub struct Esperanto {}
+
+const UNITS: [&str; 10] = [
+    "nul", "unu", "du", "tri", "kvar", "kvin", "ses", "sep", "ok", "naŭ",
+];
+
+const MEGAS: [&str; 5] = ["mil", "miliono", "miliardo", "biliono", "biliardo"];
+
+impl Esperanto {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        let word = match currency {
+            Currency::DOLLAR | Currency::USD => "dolaro",
+            Currency::EUR => "eŭro",
+            Currency::GBP => "pundo",
+            _ => return currency.default_string(plural_form),
+        };
+        self.plural(word, plural_form)
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        let word = match currency {
+            Currency::DOLLAR | Currency::USD | Currency::EUR => "cendo",
+            Currency::GBP => "penco",
+            _ => return currency.default_subunit_string("cent{}", plural_form),
+        };
+        self.plural(word, plural_form)
+    }
+
+    fn plural(&self, word: &str, plural_form: bool) -> String {
+        match plural_form {
+            true => format!("{}j", word),
+            false => String::from(word),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 345 => "tricent kvardek kvin"
+    fn triplet_to_words(&self, triplet: u64) -> Vec<String> {
+        let mut words = vec![];
+        let digits = [triplet / 100 % 10, triplet / 10 % 10, triplet % 10];
+
+        for (digit, suffix) in digits.iter().zip(["cent", "dek", ""]) {
+            match (*digit, suffix) {
+                (0, _) => (),
+                (1, "") => words.push(String::from(UNITS[1])),
+                (1, _) => words.push(String::from(suffix)),
+                _ => words.push(format!("{}{}", UNITS[*digit as usize], suffix)),
+            }
+        }
+
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+        }
+        for (i, triplet) in self.split_thousands(num.abs()).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.extend(self.triplet_to_words(*triplet));
+                continue;
+            }
+
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // "mil" is counted as is, the larger scales are nouns
+            match (i, *triplet) {
+                (1, 1) => (),
+                _ => words.extend(self.triplet_to_words(*triplet)),
+            }
+            match (i, *triplet) {
+                (1, _) => words.push(String::from(*mega)),
+                _ => words.push(self.plural(mega, *triplet != 1)),
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+        words.push(String::from("komo"));
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Esperanto {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("senfineco"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus senfineco"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    // nouns drop their "-o" or plural "-oj", e.g. "miliona", the other words
+    // add "-a"
+    fn ordinal_transform(&self, word: &str) -> String {
+        match word.strip_suffix("oj").or_else(|| word.strip_suffix('o')) {
+            Some(stem) => format!("{}a", stem),
+            None => format!("{}a", word),
+        }
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok("-a")
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} antaŭ Kristo", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!(
+                "{} {}",
+                words,
+                self.currencies(currency, num.abs() != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num.abs() * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                let minus = if num.is_negative() { "minus " } else { "" };
+                Ok(format!("{}{} {}", minus, cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " kaj "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).copied()
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match word {
+            "mil" => String::from(word),
+            _ => self.plural(word, true),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "cent"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "mil"
+    }
+
+    // the number joined with "-ono", e.g. "triono" and "dekduono"
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        if denominator > BigFloat::from(1000) {
+            return None;
+        }
+        let cardinal = self.int_to_cardinal(denominator).ok()?.replace(' ', "");
+        let word = format!("{}ono", cardinal.trim_end_matches('o'));
+        Some(self.plural(&word, plural))
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = self.plural("procento", num.abs() != BigFloat::from(1));
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = self.plural("promilo", num.abs() != BigFloat::from(1));
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Esperanto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("kvardek du"))
+        );
+        assert_eq!(
+            Num2Words::new(1999)
+                .lang(Lang::Esperanto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("mil naŭcent naŭdek naŭ"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Esperanto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("du milionoj"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang(Lang::Esperanto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus nul komo kvin"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Esperanto)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("dudek unua"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Esperanto)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("unu miliona"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Esperanto)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("du miliona"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000_000u64)
+                .lang(Lang::Esperanto)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("tri miliarda"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Esperanto)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("3-a"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Esperanto)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("du eŭroj kaj kvindek cendoj"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Esperanto).percent().to_words(),
+            Ok(String::from("unu procento"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Esperanto).percent().to_words(),
+            Ok(String::from("kvin procentoj"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Esperanto)
+                .permille()
+                .to_words(),
+            Ok(String::from("dek du promiloj"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Esperanto)
+                .fraction(2)
+                .to_words(),
+            Ok(String::from("unu duono"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Esperanto)
+                .fraction(3)
+                .to_words(),
+            Ok(String::from("du trionoj"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Esperanto)
+                .fraction(12)
+                .to_words(),
+            Ok(String::from("kvin dekduonoj"))
+        );
+    }
+}
@@ -0,0 +1,480 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Finnish {}
+
+const UNITS: [&str; 11] = [
+    "nolla",
+    "yksi",
+    "kaksi",
+    "kolme",
+    "neljä",
+    "viisi",
+    "kuusi",
+    "seitsemän",
+    "kahdeksan",
+    "yhdeksän",
+    "kymmenen",
+];
+
+// ordinals ending a number, e.g. "ensimmäinen" in "kahdeskymmenesensimmäinen"
+const ORDINALS: [&str; 11] = [
+    "nollas",
+    "ensimmäinen",
+    "toinen",
+    "kolmas",
+    "neljäs",
+    "viides",
+    "kuudes",
+    "seitsemäs",
+    "kahdeksas",
+    "yhdeksäs",
+    "kymmenes",
+];
+
+// ordinals inside a compound, e.g. "kahdes" in "kahdeskymmenes"
+const COMPOUND_ORDINALS: [&str; 11] = [
+    "nollas",
+    "yhdes",
+    "kahdes",
+    "kolmas",
+    "neljäs",
+    "viides",
+    "kuudes",
+    "seitsemäs",
+    "kahdeksas",
+    "yhdeksäs",
+    "kymmenes",
+];
+
+// singular, partitive after other counts and ordinal, e.g. "tuhat",
+// "kaksituhatta" and "tuhannes"
+const MEGAS: [[&str; 3]; 5] = [
+    ["tuhat", "tuhatta", "tuhannes"],
+    ["miljoona", "miljoonaa", "miljoonas"],
+    ["miljardi", "miljardia", "miljardis"],
+    ["biljoona", "biljoonaa", "biljoonas"],
+    ["triljoona", "triljoonaa", "triljoonas"],
+];
+
+// Parts a number is compounded from, e.g. "kaksikymmentä" and "yksi" in
+// "kaksikymmentäyksi"
+#[derive(Clone, Copy)]
+enum Part {
+    Unit(usize),
+    Teen(usize),
+    Tens(usize),
+    Hundreds(usize),
+    // scale word of the given power and its count
+    Mega(usize, u64),
+}
+
+impl Finnish {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::EUR if plural_form => String::from("euroa"),
+            Currency::EUR => String::from("euro"),
+            Currency::DOLLAR | Currency::USD if plural_form => String::from("dollaria"),
+            Currency::DOLLAR | Currency::USD => String::from("dollari"),
+            Currency::GBP if plural_form => String::from("puntaa"),
+            Currency::GBP => String::from("punta"),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::DOLLAR | Currency::USD | Currency::EUR if plural_form => {
+                String::from("senttiä")
+            }
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("sentti"),
+            Currency::GBP if plural_form => String::from("pennyä"),
+            Currency::GBP => String::from("penny"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_parts(&self, triplet: u64) -> Vec<Part> {
+        let mut parts = vec![];
+        let hundreds = (triplet / 100 % 10) as usize;
+        let rest = (triplet % 100) as usize;
+
+        if hundreds > 0 {
+            parts.push(Part::Hundreds(hundreds));
+        }
+        match rest {
+            0 => (),
+            1..=10 => parts.push(Part::Unit(rest)),
+            11..=19 => parts.push(Part::Teen(rest - 10)),
+            _ => {
+                parts.push(Part::Tens(rest / 10));
+                match rest % 10 {
+                    0 => (),
+                    units => parts.push(Part::Unit(units)),
+                }
+            }
+        }
+
+        parts
+    }
+
+    // Words of a positive integer, each made of the parts written together,
+    // e.g. [["kaksituhatta"], ["viisisataa"]] for 2500
+    fn int_to_words(&self, num: BigFloat) -> Result<Vec<Vec<Part>>, Num2Err> {
+        let mut words = vec![];
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.push(self.triplet_to_parts(*triplet));
+                continue;
+            }
+            if MEGAS.get(i - 1).is_none() {
+                return Err(Num2Err::CannotConvert);
+            }
+
+            // a thousand is counted within the word, e.g. "kaksituhatta", but
+            // larger scales stand apart, e.g. "kaksi miljoonaa"
+            let mut count = match *triplet {
+                1 => vec![],
+                _ => self.triplet_to_parts(*triplet),
+            };
+            if i == 1 || count.is_empty() {
+                count.push(Part::Mega(i, *triplet));
+                words.push(count);
+            } else {
+                words.push(count);
+                words.push(vec![Part::Mega(i, *triplet)]);
+            }
+        }
+        Ok(words)
+    }
+
+    fn part_to_cardinal(&self, part: Part) -> String {
+        match part {
+            Part::Unit(n) => String::from(UNITS[n]),
+            Part::Teen(n) => format!("{}toista", UNITS[n]),
+            Part::Tens(n) => format!("{}kymmentä", UNITS[n]),
+            Part::Hundreds(1) => String::from("sata"),
+            Part::Hundreds(n) => format!("{}sataa", UNITS[n]),
+            Part::Mega(power, 1) => String::from(MEGAS[power - 1][0]),
+            Part::Mega(power, _) => String::from(MEGAS[power - 1][1]),
+        }
+    }
+
+    // every part is inflected, the last one in its standalone form, e.g.
+    // "kahdes" + "kymmenes" + "ensimmäinen"
+    fn part_to_ordinal(&self, part: Part, last: bool) -> String {
+        match part {
+            Part::Unit(n) if last => String::from(ORDINALS[n]),
+            Part::Unit(n) => String::from(COMPOUND_ORDINALS[n]),
+            Part::Teen(n) => format!("{}toista", COMPOUND_ORDINALS[n]),
+            Part::Tens(n) => format!("{}kymmenes", COMPOUND_ORDINALS[n]),
+            Part::Hundreds(1) => String::from("sadas"),
+            Part::Hundreds(n) => format!("{}sadas", COMPOUND_ORDINALS[n]),
+            Part::Mega(power, _) => String::from(MEGAS[power - 1][2]),
+        }
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let words: Vec<String> = self
+            .int_to_words(num.abs())?
+            .into_iter()
+            .map(|parts| {
+                parts
+                    .into_iter()
+                    .map(|p| self.part_to_cardinal(p))
+                    .collect()
+            })
+            .collect();
+
+        if num.is_negative() {
+            Ok(format!("miinus {}", words.join(" ")))
+        } else {
+            Ok(words.join(" "))
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from("miinus"));
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+        words.push(String::from("pilkku"));
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Finnish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("ääretön"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("miinus ääretön"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(ORDINALS[0]));
+        }
+
+        let words = self.int_to_words(num)?;
+        let last_word = words.len() - 1;
+        let ordinal: Vec<String> = words
+            .into_iter()
+            .enumerate()
+            .map(|(i, parts)| {
+                let last_part = parts.len() - 1;
+                parts
+                    .into_iter()
+                    .enumerate()
+                    .map(|(j, p)| self.part_to_ordinal(p, i == last_word && j == last_part))
+                    .collect()
+            })
+            .collect();
+
+        Ok(ordinal.join(" "))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!(
+                "{} ennen ajanlaskun alkua",
+                self.to_cardinal(-num)?
+            ))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            // counts other than one take the partitive, e.g. "kaksi euroa"
+            Ok(format!(
+                "{} {}",
+                words,
+                self.currencies(currency, num.abs() != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num.abs() * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                let minus = if num.is_negative() { "miinus " } else { "" };
+                Ok(format!("{}{} {}", minus, cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " ja "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m[0] == word) {
+            Some(m) => String::from(m[1]),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "sata"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "tuhat"
+    }
+
+    // counts other than one take the partitive, e.g. "kaksi prosenttia"
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = match num.abs() == BigFloat::from(1) {
+            true => "prosentti",
+            false => "prosenttia",
+        };
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = match num.abs() == BigFloat::from(1) {
+            true => "promille",
+            false => "promillea",
+        };
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Finnish).cardinal().to_words(),
+            Ok(String::from("neljäkymmentäkaksi"))
+        );
+        assert_eq!(
+            Num2Words::new(115)
+                .lang(Lang::Finnish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("sataviisitoista"))
+        );
+        assert_eq!(
+            Num2Words::new(2500)
+                .lang(Lang::Finnish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("kaksituhatta viisisataa"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_001)
+                .lang(Lang::Finnish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("kolme miljoonaa yksi"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.5)
+                .lang(Lang::Finnish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("miinus yksi pilkku viisi"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Finnish).ordinal().to_words(),
+            Ok(String::from("ensimmäinen"))
+        );
+        assert_eq!(
+            Num2Words::new(12).lang(Lang::Finnish).ordinal().to_words(),
+            Ok(String::from("kahdestoista"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Finnish).ordinal().to_words(),
+            Ok(String::from("kahdeskymmenesensimmäinen"))
+        );
+        assert_eq!(
+            Num2Words::new(2222)
+                .lang(Lang::Finnish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from(
+                "kahdestuhannes kahdessadaskahdeskymmenestoinen"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Finnish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("kahdes miljoonas"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Finnish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("yksi euro"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Finnish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("kaksi euroa ja viisikymmentä senttiä"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Finnish).percent().to_words(),
+            Ok(String::from("yksi prosentti"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Finnish).percent().to_words(),
+            Ok(String::from("kaksi prosenttia"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Finnish).permille().to_words(),
+            Ok(String::from("kolme promillea"))
+        );
+    }
+}
@@ -1,10 +1,16 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::{
+    lang::{illion_stem, intern, scientific_parts, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Era, Gender, Language, Scale, Unit,
+};
 use num_bigfloat::BigFloat;
 
 pub struct French {
     feminine: bool,
     reformed: bool,
     region: RegionFrench,
+    octante: bool,
+    scale: Scale,
 }
 
 #[derive(Default, Clone, Copy, PartialEq, Debug)]
@@ -15,11 +21,11 @@ pub enum RegionFrench {
     CH,
 }
 
-const UNITS: [&'static str; 9] = [
+const UNITS: [&str; 9] = [
     "un", "deux", "trois", "quatre", "cinq", "six", "sept", "huit", "neuf",
 ];
 
-const TENS: [&'static str; 9] = [
+const TENS: [&str; 9] = [
     "dix",
     "vingt",
     "trente",
@@ -31,12 +37,12 @@ const TENS: [&'static str; 9] = [
     "quatre-vingt-dix",
 ];
 
-const TEENS: [&'static str; 10] = [
+const TEENS: [&str; 10] = [
     "dix", "onze", "douze", "treize", "quatorze", "quinze", "seize", "dix-sept", "dix-huit",
     "dix-neuf",
 ];
 
-const MEGAS: [&'static str; 33] = [
+const MEGAS: [&str; 33] = [
     "mille",
     "million",
     "milliard",
@@ -72,18 +78,41 @@ const MEGAS: [&'static str; 33] = [
     "sexdécilliard",
 ];
 
+// words read after years before and within the era of Anno Domini and the
+// common era
+const ERA_WORDS: [(&str, &str); 2] = [
+    ("avant JC", "après JC"),
+    ("avant notre ère", "de notre ère"),
+];
+
 impl French {
     pub fn new(feminine: bool, reformed: bool, region: RegionFrench) -> Self {
         Self {
             feminine,
             reformed,
             region,
+            octante: false,
+            scale: Scale::Long,
         }
     }
 
+    /// Reads eighty as "octante" rather than "huitante" in Switzerland
+    pub fn with_octante(mut self, octante: bool) -> Self {
+        self.octante = octante;
+        self
+    }
+
+    /// Names the scale words in the short scale, e.g. "billion" rather than
+    /// "milliard" for 10^9
+    pub fn with_scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
     fn get_tens(&self, tens: usize) -> &str {
         match (tens, self.region) {
             (7, RegionFrench::BE) | (7, RegionFrench::CH) => "septante",
+            (8, RegionFrench::CH) if self.octante => "octante",
             (8, RegionFrench::CH) => "huitante",
             (9, RegionFrench::BE) | (9, RegionFrench::CH) => "nonante",
             _ => TENS[tens - 1],
@@ -92,39 +121,58 @@ impl French {
 
     fn currencies(&self, currency: Currency, plural_form: bool) -> String {
         match currency {
-            Currency::ARS => String::from("peso{} argentin"),
-            Currency::AUD => String::from("dollar{} australien"),
+            Currency::ARS => String::from("peso{} argentin{}"),
+            Currency::AUD => String::from("dollar{} australien{}"),
             Currency::BRL => String::from(if plural_form { "réaux" } else { "réal" }),
-            Currency::CAD => String::from("dollar{} canadien"),
-            Currency::CLP => String::from("peso{} chilien"),
-            Currency::COP => String::from("peso{} colombien"),
-            Currency::DZD => String::from("dinar{} algérien"),
+            Currency::CAD => String::from("dollar{} canadien{}"),
+            Currency::CLP => String::from("peso{} chilien{}"),
+            Currency::COP => String::from("peso{} colombien{}"),
+            Currency::CZK => String::from("couronne{} tchèque{}"),
+            Currency::DKK => String::from("couronne{} danoise{}"),
+            Currency::DZD => String::from("dinar{} algérien{}"),
             Currency::GBP => String::from("livre{}"),
             Currency::HKD => String::from("dollar{} de Hong Kong"),
-            Currency::IDR => String::from("roupie{} indonésienne"),
+            Currency::IDR => String::from("roupie{} indonésienne{}"),
             Currency::ILS => String::from("shekel{}"),
             Currency::INR => String::from("roupie{}"),
-            Currency::KWD => String::from("dinar{} koweïtien"),
-            Currency::MXN => String::from("peso{} mexicain"),
-            Currency::NOK => String::from("couronne{} norvégienne"),
+            Currency::KWD => String::from("dinar{} koweïtien{}"),
+            Currency::MXN => String::from("peso{} mexicain{}"),
+            Currency::NOK => String::from("couronne{} norvégienne{}"),
             Currency::NZD => String::from("dollar{} néo-zélandais"),
-            Currency::PHP => String::from("peso{} phillippin"),
+            Currency::PHP => String::from("peso{} phillippin{}"),
             Currency::PLN => String::from("złoty{}"),
-            Currency::QAR => String::from("riyal{} qatarien"),
+            Currency::QAR => String::from("riyal{} qatarien{}"),
             Currency::RUB => String::from("rouble{}"),
-            Currency::SAR => String::from("riyal{} saoudien"),
+            Currency::SAR => String::from("riyal{} saoudien{}"),
+            Currency::SEK => String::from("couronne{} suédoise{}"),
             Currency::SGD => String::from("dollar{} de Singapour"),
             Currency::THB => String::from("baht{}"),
             Currency::TRY => String::from("lire{}"),
             Currency::TWD => String::from("dollar{} de Taïwan"),
             Currency::UAH => String::from("hryvnia{}"),
-            Currency::USD => String::from("dollar{} américain"),
-            Currency::UYU => String::from("peso{} uruguayen"),
-            _ => currency.default_string(plural_form),
+            Currency::USD => String::from("dollar{} américain{}"),
+            Currency::UYU => String::from("peso{} uruguayen{}"),
+            _ => return currency.default_string(plural_form),
         }
         .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    // the number agrees with feminine names, e.g. "une livre"
+    fn currency_feminine(&self, currency: Currency) -> bool {
+        match currency {
+            Currency::CZK
+            | Currency::DKK
+            | Currency::GBP
+            | Currency::IDR
+            | Currency::INR
+            | Currency::NOK
+            | Currency::SEK
+            | Currency::TRY
+            | Currency::UAH => true,
+            _ => currency.is_feminine(),
+        }
+    }
+
     fn cents(&self, _currency: Currency, plural_form: bool) -> String {
         match _currency {
             Currency::UAH => String::from("kopeck{}"),
@@ -133,6 +181,13 @@ impl French {
         .replace("{}", if plural_form { "s" } else { "" })
     }
 
+    // the same reading, counting feminine or masculine nouns
+    fn with_gender(&self, feminine: bool) -> Self {
+        French::new(feminine, self.reformed, self.region)
+            .with_octante(self.octante)
+            .with_scale(self.scale)
+    }
+
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
         let mut thousands = Vec::new();
         let bf_1000 = BigFloat::from(1000);
@@ -168,13 +223,12 @@ impl French {
                 if hundreds != 1 {
                     words.push(String::from(UNITS[hundreds - 1]));
                 }
-                words.push(String::from(
-                    if i != 1 && tens == 0 && units == 0 && hundreds > 1 {
-                        "cents"
-                    } else {
-                        "cent"
-                    },
-                ));
+                // "deux cents" but "deux cent un" and "deux cent mille"
+                words.push(if i != 1 && tens == 0 && units == 0 && hundreds > 1 {
+                    self.scale_plural(self.hundred_word())
+                } else {
+                    String::from(self.hundred_word())
+                });
             }
 
             if tens != 0 || units != 0 {
@@ -244,15 +298,12 @@ impl French {
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
-                let plural_form = if hundreds == 0 && tens == 0 && units == 1 || i == 1 {
-                    ""
+                let mega = self.scale_word(i).ok_or(Num2Err::CannotConvert)?;
+                words.push(if hundreds == 0 && tens == 0 && units == 1 {
+                    String::from(mega)
                 } else {
-                    "s"
-                };
-                words.push(format!("{}{}", MEGAS[i - 1], plural_form));
+                    self.scale_plural(mega)
+                });
             }
         }
 
@@ -272,7 +323,10 @@ impl French {
         if !ordinal_part.is_zero() {
             words.push(String::from("point"));
         }
-        while !ordinal_part.is_zero() {
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
             words.push(match digit.to_u64().unwrap() {
@@ -297,6 +351,14 @@ impl Language for French {
         }
     }
 
+    fn gendered(&self, gender: Gender) -> Option<Box<dyn Language>> {
+        Some(Box::new(self.with_gender(gender == Gender::Feminine)))
+    }
+
+    fn scaled(&self, scale: Scale) -> Option<Box<dyn Language>> {
+        Some(Box::new(self.with_gender(self.feminine).with_scale(scale)))
+    }
+
     fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
         if num == BigFloat::from(1) {
             return Ok(String::from("premier"));
@@ -312,40 +374,177 @@ impl Language for French {
                 words.push(String::from(w));
             } else {
                 // last word, needs to be processed
-                words.push(format!(
-                    "{}ième",
-                    if w.ends_with('e') {
-                        &w[..w.len() - 1]
-                    } else {
-                        &w
-                    }
-                ));
+                words.push(format!("{}ième", w.strip_suffix('e').unwrap_or(w)));
             }
         }
 
         Ok(words.join(" "))
     }
 
-    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
-        Ok(format!(
-            "{}{}",
-            num.to_u128().unwrap(),
-            if num == BigFloat::from(1) {
-                "er"
-            } else {
-                "ème"
+    // e.g. 3:45 => "quatre heures moins le quart", or "quinze heures
+    // quarante-cinq" on a 24-hour clock
+    fn to_time(&self, hour: u32, minute: u32, clock_24: bool) -> Result<String, Num2Err> {
+        // hours and minutes are feminine, e.g. "vingt et une heures"
+        let feminine = self.with_gender(true);
+        let cardinal = |n: u32| feminine.int_to_cardinal(BigFloat::from(n));
+        let hours = |hour: u32| match hour {
+            0 if !clock_24 => Ok(String::from("minuit")),
+            12 if !clock_24 => Ok(String::from("midi")),
+            0 | 1 => Ok(format!("{} heure", cardinal(hour)?)),
+            _ => Ok(format!("{} heures", cardinal(hour)?)),
+        };
+
+        if clock_24 {
+            return match minute {
+                0 => hours(hour),
+                _ => Ok(format!("{} {}", hours(hour)?, cardinal(minute)?)),
+            };
+        }
+
+        let clock_hour = |hour: u32| match hour % 24 {
+            h @ (0 | 12) => hours(h),
+            h => hours(h % 12),
+        };
+        match minute {
+            0 => clock_hour(hour),
+            15 => Ok(format!("{} et quart", clock_hour(hour)?)),
+            // "demi" agrees with "midi" and "minuit", "demie" with "heure"
+            30 if matches!(hour, 0 | 12) => Ok(format!("{} et demi", clock_hour(hour)?)),
+            30 => Ok(format!("{} et demie", clock_hour(hour)?)),
+            45 => Ok(format!("{} moins le quart", clock_hour(hour + 1)?)),
+            1..=30 => Ok(format!("{} {}", clock_hour(hour)?, cardinal(minute)?)),
+            _ => Ok(format!(
+                "{} moins {}",
+                clock_hour(hour + 1)?,
+                cardinal(60 - minute)?
+            )),
+        }
+    }
+
+    // the digits in pairs, e.g. "zéro six, douze, trente-quatre", after a
+    // single digit for groups of odd length
+    fn to_phone(&self, country_code: Option<&str>, groups: &[&str]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if let Some(code) = country_code {
+            let code = code.parse::<u64>().map_err(|_| Num2Err::CannotConvert)?;
+            words.push(format!(
+                "plus {}",
+                self.int_to_cardinal(BigFloat::from(code))?
+            ));
+        }
+        for group in groups {
+            let (head, pairs) = group.split_at(group.len() % 2);
+            let mut parts: Vec<&str> = vec![];
+            if !head.is_empty() {
+                parts.push(head);
             }
-        ))
+            parts.extend(
+                pairs
+                    .as_bytes()
+                    .chunks(2)
+                    .map(|p| std::str::from_utf8(p).unwrap()),
+            );
+
+            let mut group_words = vec![];
+            for part in parts {
+                let value = BigFloat::from(part.parse::<u64>().unwrap());
+                // a leading zero is read too, e.g. "zéro six"
+                match part.strip_prefix('0') {
+                    Some(rest) if !rest.is_empty() => {
+                        group_words.push(format!("zéro {}", self.int_to_cardinal(value)?))
+                    }
+                    _ => group_words.push(self.int_to_cardinal(value)?),
+                }
+            }
+            words.push(group_words.join(" "));
+        }
+        Ok(words.join(", "))
+    }
+
+    fn to_scientific(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf() || num.is_zero() {
+            return self.to_cardinal(num);
+        }
+
+        let (mantissa, exponent) = scientific_parts(num);
+        if exponent == 0 {
+            return self.to_cardinal(mantissa);
+        }
+
+        // e.g. 1e-3 => dix puissance moins trois
+        let power = format!(
+            "dix puissance {}",
+            self.int_to_cardinal(BigFloat::from(exponent))?
+        );
+
+        if mantissa == BigFloat::from(1) {
+            Ok(power)
+        } else if mantissa == BigFloat::from(-1) {
+            Ok(format!("moins {}", power))
+        } else {
+            Ok(format!("{} fois {}", self.to_cardinal(mantissa)?, power))
+        }
+    }
+
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
+        // abbreviated as "1er"/"1re" and "2e", not "2ème"
+        Ok(match (num == BigFloat::from(1), self.feminine) {
+            (true, false) => "er",
+            (true, true) => "re",
+            _ => "e",
+        })
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
         if num.is_negative() {
-            Ok(format!("{} avant JC", self.to_cardinal(-num)?))
+            Ok(format!("{} {}", self.to_cardinal(-num)?, ERA_WORDS[0].0))
         } else {
             self.to_cardinal(num)
         }
     }
 
+    fn era_words(&self, era: Era) -> Option<(&'static str, &'static str)> {
+        match era {
+            Era::AnnoDomini => Some(ERA_WORDS[0]),
+            Era::CommonEra => Some(ERA_WORDS[1]),
+            Era::Custom { .. } => None,
+        }
+    }
+
+    fn to_year_range(
+        &self,
+        start: BigFloat,
+        end: BigFloat,
+        _shared_century: bool,
+    ) -> Result<String, Num2Err> {
+        // years are read in full, e.g. "de mille neuf cent quatorze à mille
+        // neuf cent dix-huit"
+        Ok(format!(
+            "de {} à {}",
+            self.to_year(start)?,
+            self.to_year(end)?
+        ))
+    }
+
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "les années quatre-vingt-dix", or "les années mille neuf cents"
+        // for its first decade
+        let low = num % BigFloat::from(100);
+        let masculine = self.with_gender(false);
+        match low.is_zero() {
+            true => Ok(format!("les années {}", masculine.to_year(num)?)),
+            false => Ok(format!("les années {}", masculine.int_to_cardinal(low)?)),
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!(
+            "le {} siècle",
+            self.with_gender(false).to_ordinal(century)?
+        ))
+    }
+
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
         if num.is_inf() {
             Ok(format!(
@@ -353,8 +552,13 @@ impl Language for French {
                 if num.is_negative() { "moins " } else { "" },
                 self.currencies(currency, true)
             ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "moins cinq dollars et cinquante centimes"
+            Ok(format!("moins {}", self.to_currency(num.abs(), currency)?))
         } else if num.frac().is_zero() {
-            let words = self.int_to_cardinal(num)?;
+            let words = self
+                .with_gender(self.currency_feminine(currency))
+                .int_to_cardinal(num)?;
             Ok(format!(
                 "{} {}",
                 words,
@@ -362,8 +566,11 @@ impl Language for French {
             ))
         } else {
             let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
-            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self
+                .with_gender(currency.is_subunit_feminine())
+                .int_to_cardinal(cents_nb)?;
             let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
             let integral_word = self.to_currency(integral_part, currency)?;
 
@@ -373,12 +580,134 @@ impl Language for French {
                 Ok(format!("{} {}", cents_words, cents_suffix))
             } else {
                 Ok(format!(
-                    "{} et {} {}",
-                    integral_word, cents_words, cents_suffix
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
                 ))
             }
         }
     }
+
+    fn currency_connector(&self) -> &'static str {
+        " et "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("au débit")
+    }
+
+    // e.g. "milliard" for 3, or "billion" in the short scale, which skips
+    // the -illiards, past the table after Conway and Wechsler, e.g.
+    // "septendécillion"
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        let (n, ending) = match (self.scale, power) {
+            (_, 0) => return None,
+            (_, 1) => return Some(MEGAS[0]),
+            (Scale::Short, _) => (power - 1, "illion"),
+            (Scale::Long, _) if power % 2 == 1 => (power / 2, "illiard"),
+            (Scale::Long, _) => (power / 2, "illion"),
+        };
+        let index = if ending == "illion" { 2 * n - 1 } else { 2 * n };
+        match MEGAS.get(index) {
+            Some(word) => Some(word),
+            None => {
+                let stem = illion_stem(n)?.replace("dec", "déc");
+                Some(intern(format!("{}{}", stem, ending)))
+            }
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        // "mille" is invariant, e.g. "deux mille"
+        if word == MEGAS[0] {
+            String::from(word)
+        } else {
+            format!("{}s", word)
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "cent"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0]
+    }
+
+    // "tiers" is invariant, the other denominators take an "s", e.g. "deux
+    // tiers", "trois quarts" and "sept treizièmes"
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        let word = match denominator.to_u64() {
+            Some(2) => String::from("demi"),
+            Some(3) => return Some(String::from("tiers")),
+            Some(4) => String::from("quart"),
+            _ => {
+                let ordinal = self.to_ordinal(denominator).ok()?;
+                String::from(ordinal.trim_start_matches("un "))
+            }
+        };
+        match plural {
+            true => Some(format!("{}s", word)),
+            false => Some(word),
+        }
+    }
+
+    // seconds, minutes and hours are feminine, e.g. "vingt et une minutes"
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let words = self.unit_words(unit).ok_or(Num2Err::CannotConvert)?;
+        let feminine = matches!(unit, Unit::Second | Unit::Minute | Unit::Hour);
+        let language = self.with_gender(feminine);
+        Ok(format!(
+            "{} {}",
+            language.to_cardinal(num)?,
+            words[self.unit_plural(num)]
+        ))
+    }
+
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        let (one, many) = match unit {
+            Unit::Millimeter => ("millimètre", "millimètres"),
+            Unit::Centimeter => ("centimètre", "centimètres"),
+            Unit::Meter => ("mètre", "mètres"),
+            Unit::Kilometer => ("kilomètre", "kilomètres"),
+            Unit::Mile => ("mille", "milles"),
+            Unit::Gram => ("gramme", "grammes"),
+            Unit::Kilogram => ("kilogramme", "kilogrammes"),
+            Unit::Liter => ("litre", "litres"),
+            Unit::Second => ("seconde", "secondes"),
+            Unit::Minute => ("minute", "minutes"),
+            Unit::Hour => ("heure", "heures"),
+            Unit::Day => ("jour", "jours"),
+            _ => return unit.custom_words(),
+        };
+        Some([one, many, many, many])
+    }
+
+    // the plural starts at two, e.g. "zéro kilomètre", "un point cinq kilomètre"
+    fn unit_plural(&self, num: BigFloat) -> usize {
+        match num.is_inf() || num.abs() >= BigFloat::from(2) {
+            true => 2,
+            false => 0,
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("pour cent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("pour mille")
+    }
+
+    fn approximate_word(&self) -> Option<&'static str> {
+        Some("environ")
+    }
 }
 
 #[cfg(test)]
@@ -449,6 +778,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_scale_plural() {
+        assert_eq!(
+            Num2Words::new(200).lang(Lang::French).to_words(),
+            Ok(String::from("deux cents"))
+        );
+        assert_eq!(
+            Num2Words::new(201).lang(Lang::French).to_words(),
+            Ok(String::from("deux cent un"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::French).to_words(),
+            Ok(String::from("deux mille"))
+        );
+        assert_eq!(
+            Num2Words::new(80).lang(Lang::French).to_words(),
+            Ok(String::from("quatre-vingts"))
+        );
+        assert_eq!(
+            Num2Words::new(200000).lang(Lang::French).to_words(),
+            Ok(String::from("deux cent mille"))
+        );
+        assert_eq!(
+            Num2Words::new(200000000).lang(Lang::French).to_words(),
+            Ok(String::from("deux cents millions"))
+        );
+    }
+
     #[test]
     fn test_feminine() {
         assert_eq!(
@@ -515,6 +872,13 @@ mod tests {
             Num2Words::new(80).lang(Lang::French_CH).to_words(),
             Ok(String::from("huitante"))
         );
+        assert_eq!(
+            Num2Words::new(84)
+                .lang(Lang::French_CH)
+                .prefer("octante")
+                .to_words(),
+            Ok(String::from("octante-quatre"))
+        );
         assert_eq!(
             Num2Words::new(92).lang(Lang::French_CH).to_words(),
             Ok(String::from("nonante-deux"))
@@ -541,6 +905,64 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_time() {
+        let time = |hour, minute| Num2Words::time(hour, minute).lang(Lang::French).to_words();
+        assert_eq!(time(1, 0), Ok(String::from("une heure")));
+        assert_eq!(time(15, 15), Ok(String::from("trois heures et quart")));
+        assert_eq!(time(3, 30), Ok(String::from("trois heures et demie")));
+        assert_eq!(
+            time(3, 45),
+            Ok(String::from("quatre heures moins le quart"))
+        );
+        assert_eq!(time(3, 50), Ok(String::from("quatre heures moins dix")));
+        assert_eq!(time(12, 30), Ok(String::from("midi et demi")));
+        assert_eq!(time(23, 45), Ok(String::from("minuit moins le quart")));
+        assert_eq!(
+            Num2Words::time(21, 1)
+                .lang(Lang::French)
+                .prefer("24h")
+                .to_words(),
+            Ok(String::from("vingt et une heures une"))
+        );
+    }
+
+    #[test]
+    fn test_phone() {
+        assert_eq!(
+            Num2Words::phone("+33 1 00 45 67 89")
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from(
+                "plus trente-trois, un, zéro zéro, quarante-cinq, soixante-sept, quatre-vingt-neuf"
+            ))
+        );
+        assert_eq!(
+            Num2Words::phone("112").lang(Lang::French).to_words(),
+            Ok(String::from("un douze"))
+        );
+    }
+
+    #[test]
+    fn test_scientific() {
+        assert_eq!(
+            Num2Words::new(2.8e64)
+                .lang(Lang::French)
+                .scientific()
+                .to_words(),
+            Ok(String::from(
+                "deux point huit fois dix puissance soixante-quatre"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(1e-3)
+                .lang(Lang::French)
+                .scientific()
+                .to_words(),
+            Ok(String::from("dix puissance moins trois"))
+        );
+    }
+
     #[test]
     fn test_ordinal_num() {
         assert_eq!(
@@ -555,35 +977,58 @@ mod tests {
                 .lang(Lang::French)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("2ème"))
+            Ok(String::from("2e"))
         );
         assert_eq!(
             Num2Words::new(10)
                 .lang(Lang::French)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("10ème"))
+            Ok(String::from("10e"))
         );
         assert_eq!(
             Num2Words::new(21)
                 .lang(Lang::French)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("21ème"))
+            Ok(String::from("21e"))
         );
         assert_eq!(
             Num2Words::new(102)
                 .lang(Lang::French)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("102ème"))
+            Ok(String::from("102e"))
         );
         assert_eq!(
             Num2Words::new(73)
                 .lang(Lang::French)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("73ème"))
+            Ok(String::from("73e"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .prefer("f")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("1re"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::French)
+                .prefer("f")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("2e"))
+        );
+        assert_eq!(
+            Num2Words::new(100)
+                .lang(Lang::French)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("100e"))
         );
     }
 
@@ -619,7 +1064,7 @@ mod tests {
                 .lang(Lang::French)
                 .currency(Currency::USD)
                 .to_words(),
-            Ok(String::from("quatre mille dollars américain"))
+            Ok(String::from("quatre mille dollars américains"))
         );
         assert_eq!(
             Num2Words::new(1.)
@@ -671,10 +1116,18 @@ mod tests {
             num *= BigFloat::from(1000);
             assert_eq!(
                 Num2Words::new(num).lang(Lang::French).cardinal().to_words(),
-                Ok(String::from(format!("{}{}", un, m)))
+                Ok(format!("{}{}", un, m))
             );
             un = "un ";
         }
+
+        assert_eq!(
+            Num2Words::new(2e102)
+                .lang(Lang::French)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("deux septendécillions"))
+        );
     }
 
     #[test]
@@ -701,4 +1154,173 @@ mod tests {
             Ok(String::from("une infinité de dollars"))
         );
     }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::French).percent().to_words(),
+            Ok(String::from("un pour cent"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::French).percent().to_words(),
+            Ok(String::from("quarante-deux pour cent"))
+        );
+        assert_eq!(
+            Num2Words::new(12).lang(Lang::French).permille().to_words(),
+            Ok(String::from("douze pour mille"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::French)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("vingt et une minutes"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::French)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("vingt et un kilomètres"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::French)
+                .unit(Unit::Liter)
+                .to_words(),
+            Ok(String::from("un point cinq litre"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::French).fraction(3).to_words(),
+            Ok(String::from("un tiers"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::French).fraction(3).to_words(),
+            Ok(String::from("deux tiers"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::French).fraction(4).to_words(),
+            Ok(String::from("trois quarts"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::French).fraction(2).to_words(),
+            Ok(String::from("un demi"))
+        );
+        assert_eq!(
+            Num2Words::new(7).lang(Lang::French).fraction(13).to_words(),
+            Ok(String::from("sept treizièmes"))
+        );
+    }
+
+    #[test]
+    fn test_currency_gender() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::French)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("vingt et une livres"))
+        );
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::French)
+                .currency(Currency::NOK)
+                .to_words(),
+            Ok(String::from("une couronne norvégienne et un centime"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::French)
+                .currency(Currency::CAD)
+                .to_words(),
+            Ok(String::from("deux dollars canadiens"))
+        );
+        let ecu = Currency::custom("écu", "écus", "obole", "oboles", 2).feminine(false, true);
+        assert_eq!(
+            Num2Words::new(1.21)
+                .lang(Lang::French)
+                .currency(ecu)
+                .to_words(),
+            Ok(String::from("un écu et vingt et une oboles"))
+        );
+    }
+
+    #[test]
+    fn test_gender_builder() {
+        assert_eq!(
+            Num2Words::new(1001)
+                .lang(Lang::French)
+                .gender(Gender::Feminine)
+                .to_words(),
+            Ok(String::from("mille une"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::French)
+                .gender(Gender::Feminine)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("1re"))
+        );
+    }
+
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            Num2Words::year_range(1914, 1918)
+                .lang(Lang::French)
+                .to_words(),
+            Ok(String::from(
+                "de mille neuf cent quatorze à mille neuf cent dix-huit"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::French).decade().to_words(),
+            Ok(String::from("les années quatre-vingt-dix"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::French).century().to_words(),
+            Ok(String::from("le vingt et unième siècle"))
+        );
+    }
+
+    #[test]
+    fn test_scale() {
+        let short = |n: f64| {
+            Num2Words::new(n)
+                .lang(Lang::French)
+                .scale(Scale::Short)
+                .to_words()
+        };
+        assert_eq!(short(2e6), Ok(String::from("deux millions")));
+        assert_eq!(short(1e9), Ok(String::from("un billion")));
+        assert_eq!(short(3e12), Ok(String::from("trois trillions")));
+        assert_eq!(
+            Num2Words::new(3e12)
+                .lang(Lang::French)
+                .scale(Scale::Long)
+                .to_words(),
+            Ok(String::from("trois billions"))
+        );
+        assert_eq!(
+            Num2Words::new(1e9)
+                .lang(Lang::French)
+                .scale(Scale::Short)
+                .prefer("f")
+                .to_words(),
+            Ok(String::from("un billion"))
+        );
+    }
 }
@@ -1,14 +1,46 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::{
+    lang::{illion_stem, intern, shares_century, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Era, Language,
+};
 use num_bigfloat::BigFloat;
 
-pub struct Frisian {}
+pub struct Frisian {
+    paren: bool,
+    plural_unit: bool,
+    trema: bool,
+    decimal_word: &'static str,
+}
 
 const UNITS: [&str; 9] = [
     "ien", "twa", "trije", "fjouwer", "fiif", "seis", "sân", "acht", "njoggen",
 ];
 
 const TENS: [&str; 9] = [
-    "tsien", "tweintich", "tritich", "fjirtich", "fyftich", "sechstich", "santich", "tachtig", "njoggentich",
+    "tsien",
+    "tweintich",
+    "tritich",
+    "fjirtich",
+    "fyftich",
+    "sechstich",
+    "santich",
+    "tachtich",
+    "njoggentich",
+];
+
+// irregular ordinals, matched against the end of the cardinal so that
+// "tsien" is checked before "ien"
+const ORDINALS: [(&str, &str); 10] = [
+    ("tsien", "tsiende"),
+    ("ien", "earste"),
+    ("twa", "twadde"),
+    ("trije", "tredde"),
+    ("fjouwer", "fjirde"),
+    ("fiif", "fyfde"),
+    ("seis", "sechsde"),
+    ("sân", "sânde"),
+    ("alve", "alfde"),
+    ("tolve", "tolfde"),
 ];
 
 const TEENS: [&str; 10] = [
@@ -20,7 +52,7 @@ const TEENS: [&str; 10] = [
     "fyftjin",
     "sechstjin",
     "santjin",
-    "achtsje",
+    "achttjin",
     "njoggentjin",
 ];
 
@@ -51,35 +83,103 @@ const MEGAS: [&str; 21] = [
     "deciljard",
 ];
 
-fn space_words(words: &mut Vec<String>) {
-    for x in (0..words.len()).rev() {
-        let word: String = words.get(x).unwrap().clone();
-        if ["minus"].contains(&word.as_str()) {
-            words.insert(x + 1, " ".to_string());
-        } else if ["komma"].contains(&word.as_str()) {
-            words.insert(x, " ".to_string());
-        } else if MEGAS.contains(&word.as_str()) {
-            if x != words.len() - 1 {
+// words read after years before and within the era of Anno Domini and the
+// common era
+const ERA_WORDS: [(&str, &str); 2] = [
+    ("foar kristus", "nei kristus"),
+    ("foar ús jiertelling", "fan ús jiertelling"),
+];
+
+impl Frisian {
+    pub fn new() -> Self {
+        Self {
+            paren: false,
+            plural_unit: false,
+            trema: true,
+            decimal_word: "komma",
+        }
+    }
+
+    fn space_words(&self, words: &mut Vec<String>) {
+        for x in (0..words.len()).rev() {
+            let word: String = words.get(x).unwrap().clone();
+            if ["minus"].contains(&word.as_str()) {
                 words.insert(x + 1, " ".to_string());
-            }
-            if !word.eq("tûzen") {
+            } else if word == self.decimal_word {
                 words.insert(x, " ".to_string());
+            } else if let Some(index) = self.is_scale_word(&word) {
+                if x != words.len() - 1 {
+                    words.insert(x + 1, " ".to_string());
+                }
+                if index != 0 {
+                    words.insert(x, " ".to_string());
+                }
             }
         }
     }
-}
 
-impl Frisian {
-    pub fn new() -> Self {
-        Self {}
+    pub fn with_paren(mut self, paren: bool) -> Self {
+        self.paren = paren;
+        self
+    }
+
+    pub fn with_plural_unit(mut self, plural_unit: bool) -> Self {
+        self.plural_unit = plural_unit;
+        self
+    }
+
+    pub fn with_trema(mut self, trema: bool) -> Self {
+        self.trema = trema;
+        self
+    }
+
+    pub fn with_decimal_word(mut self, decimal_word: &'static str) -> Self {
+        self.decimal_word = decimal_word;
+        self
+    }
+
+    // "en" joining a word ending in an "e", e.g. "trije" + "tweintich",
+    // takes a trema unless the no-trema preference is set, as the
+    // current Frisian spelling "trijeentweintich" does
+    fn junction(&self, word: &str) -> &'static str {
+        if self.trema && word.ends_with('e') {
+            "ën"
+        } else {
+            "en"
+        }
     }
 
-    fn currencies(&self, currency: Currency) -> String {
-        currency.default_string(false)
+    // units stay singular after a number, e.g. "twee euro", unless the
+    // plural-unit preference asks otherwise
+    fn currencies(&self, currency: Currency, plural: bool) -> String {
+        currency.default_string(plural && self.plural_unit)
     }
 
-    fn cents(&self, currency: Currency) -> String {
-        currency.default_subunit_string("cent{}", false)
+    fn cents(&self, currency: Currency, plural: bool) -> String {
+        currency.default_subunit_string("cent{}", plural && self.plural_unit)
+    }
+
+    fn paren_wrap(&self, word: String, negative: bool) -> String {
+        if negative && self.paren {
+            format!("({})", word)
+        } else {
+            word
+        }
+    }
+
+    // e.g. "trijeëntweintich" => "trijeëntweintichste"
+    fn ordinal_word(word: &str) -> String {
+        if let Some((cardinal, ordinal)) = ORDINALS.iter().find(|(c, _)| word.ends_with(c)) {
+            format!("{}{}", &word[..word.len() - cardinal.len()], ordinal)
+        } else if word.ends_with("tich")
+            || word.ends_with("acht")
+            || word.ends_with("hûndert")
+            || MEGAS.iter().any(|m| word.ends_with(m))
+        {
+            format!("{}ste", word)
+        } else {
+            format!("{}de", word)
+        }
     }
 
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
@@ -104,8 +204,11 @@ impl Frisian {
 
         // handling negative values
         let mut words = vec![];
-        if num.is_negative() {
-            words.push(String::from("minus"));
+        let negative = num.is_negative();
+        if negative {
+            if !self.paren {
+                words.push(String::from("minus"));
+            }
             num = -num;
         }
 
@@ -120,18 +223,14 @@ impl Frisian {
                 if hundreds > 1 {
                     words.push(String::from(UNITS[hundreds - 1]));
                 }
-                words.push(String::from("hûndert"));
+                words.push(String::from(self.hundred_word()));
             }
 
             if tens != 0 || units != 0 {
                 if i == 0 && !first_elem {
                     if let Some(last) = words.last() {
-                        if !MEGAS.contains(&last.as_str()) {
-                            if last.ends_with('e') {
-                                words.push(String::from("ën"));
-                            } else {
-                                words.push(String::from("en"));
-                            }
+                        if self.is_scale_word(last).is_none() {
+                            words.push(String::from(self.junction(last)));
                         }
                     }
                 } else {
@@ -152,36 +251,39 @@ impl Frisian {
                         let ten: String = String::from(TENS[tens - 1]);
                         words.push(match units {
                             0 => ten,
-                            _ => {
-                                if UNITS[units - 1].ends_with('e') {
-                                    format!("{}ën{}", UNITS[units - 1], ten)
-                                } else {
-                                    format!("{}en{}", UNITS[units - 1], ten)
-                                }
-                            }
+                            _ => format!(
+                                "{}{}{}",
+                                UNITS[units - 1],
+                                self.junction(UNITS[units - 1]),
+                                ten
+                            ),
                         });
                     }
                 }
             }
 
             if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
-                words.push(String::from(MEGAS[i - 1]));
+                words.push(String::from(
+                    self.scale_word(i).ok_or(Num2Err::CannotConvert)?,
+                ));
             }
         }
 
-        space_words(&mut words);
+        self.space_words(&mut words);
 
-        Ok(words.join(""))
+        Ok(self.paren_wrap(words.join(""), negative))
     }
 
 
     fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
         let integral_part = num.int();
         let mut words: Vec<String> = vec![];
 
+        if negative && !self.paren {
+            words.push(String::from("minus"));
+        }
 
         let integral_word = self.int_to_cardinal(integral_part)?;
         words.push(integral_word);
@@ -189,9 +291,12 @@ impl Frisian {
 
         let mut ordinal_part = num.frac();
         if !ordinal_part.is_zero() {
-            words.push(String::from("komma"));
+            words.push(String::from(self.decimal_word));
         }
-        while !ordinal_part.is_zero() {
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
             words.push(" ".to_string());
@@ -201,9 +306,9 @@ impl Frisian {
             });
         }
 
-        space_words(&mut words);
+        self.space_words(&mut words);
 
-        Ok(words.join(""))
+        Ok(self.paren_wrap(words.join(""), negative))
     }
 }
 
@@ -220,76 +325,17 @@ impl Language for Frisian {
         }
     }
 
-    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let cardinal_word = self.to_cardinal(num)?;
-
-        let mut words: Vec<String> = vec![];
-        let mut split = cardinal_word.split_whitespace().peekable();
-
-        while let Some(w) = split.next() {
-            if split.peek().is_some() {
-                // not last word, no modification needed
-                words.push(String::from(w));
-            } else {
-                // last word, needs to be processed
-                let mut prefix = String::from("");
-                let mut suffix = String::from(w);
-
-                if w.contains('-') {
-                    // e.g. forty-two => forty-second
-                    let mut w_split = w.split('-');
-
-                    if let Some(pre) = w_split.next() {
-                        prefix = format!("{}-", pre);
-                    }
-
-                    if let Some(suf) = w_split.next() {
-                        suffix = String::from(suf);
-                    }
-                }
-
-                suffix = match suffix.as_str() {
-                    "one" => String::from("earste"),
-                    "two" => String::from("twadde"),
-                    "three" => String::from("tredde"),
-                    "four" => String::from("fjirde"),
-                    "five" => String::from("vijfde"),
-                    "six" => String::from("sechsde"),
-                    "seven" => String::from("sânde"),
-                    "eight" => String::from("achtste"),
-                    "nine" => String::from("njoggende"),
-                    "ten" => String::from("tsiende"),
-                    "eleven" => String::from("alfde"),
-                    "twelve" => String::from("tolfde"),
-                    _ => {
-                        if suffix.ends_with("tich") {
-                            format!("{}ste", suffix)
-                        } else {
-                            format!("{}de", suffix)
-                        }
-                    }
-                };
-
-                words.push(format!("{}{}", prefix, suffix))
-            }
-        }
-
-        Ok(words.join(" "))
+    fn ordinal_transform(&self, word: &str) -> String {
+        Self::ordinal_word(word)
     }
 
-    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let tail = (num % BigFloat::from(100)).to_u64().unwrap();
-        let last = tail % 10;
-        Ok(format!(
-            "{}{}",
-            num.to_u128().unwrap(),
-            match (tail / 10 != 1, last) {
-                (true, 1) => "e",
-                (true, 2) => "e",
-                (true, 3) => "e",
-                _ => "e",
-            }
-        ))
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
+        // the ending of the ordinal, e.g. "1ste" for "earste" and "2de" for
+        // "twadde"
+        Ok(match self.to_ordinal(num)?.ends_with("ste") {
+            true => "ste",
+            false => "de",
+        })
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -299,33 +345,76 @@ impl Language for Frisian {
 
         let mut num = num;
 
-        let mut suffix = "";
+        let mut era = None;
         if num.is_negative() {
             num = num.inv_sign();
-            suffix = " foar kristus";
+            era = Some(ERA_WORDS[0].0);
         }
 
-        let bf_100 = BigFloat::from(100);
-
-        let (high, low) = (
-            (num / bf_100).to_i64().unwrap(),
-            (num % bf_100).to_i64().unwrap(),
-        );
-        let year_word = if high == 0 || (high % 10 == 0 && low < 10) || high >= 100 {
-            // if year is 00XX, X00X, or beyond 9999, go cardinal
-            self.int_to_cardinal(num)?
-        } else {
-            let high_word = self.int_to_cardinal(BigFloat::from(high))?;
-            let low_word = if low == 0 {
-                String::from("hûndert")
-            } else {
-                self.int_to_cardinal(BigFloat::from(low))?
-            };
+        // if year is 00XX, X00X, or beyond 9999, go cardinal
+        let halves = num
+            .to_u64()
+            .map(|year| (year / 100, year % 100))
+            .filter(|&(high, low)| high > 0 && high < 100 && !(high % 10 == 0 && low < 10));
+        let year_word = match halves {
+            Some((high, low)) => {
+                let high_word = self.int_to_cardinal(BigFloat::from(high))?;
+                let low_word = self.year_low_half(low)?;
 
-            format!("{}{}", high_word, low_word)
+                format!("{}{}", high_word, low_word)
+            }
+            None => self.int_to_cardinal(num)?,
         };
 
-        Ok(format!("{}{}", year_word, suffix))
+        match era {
+            Some(era) => Ok(format!("{} {}", year_word, era)),
+            None => Ok(year_word),
+        }
+    }
+
+    fn era_words(&self, era: Era) -> Option<(&'static str, &'static str)> {
+        match era {
+            Era::AnnoDomini => Some(ERA_WORDS[0]),
+            Era::CommonEra => Some(ERA_WORDS[1]),
+            Era::Custom { .. } => None,
+        }
+    }
+
+    fn to_year_range(
+        &self,
+        start: BigFloat,
+        end: BigFloat,
+        shared_century: bool,
+    ) -> Result<String, Num2Err> {
+        // e.g. "fan njoggentjinfjirtjin oant achttjin"
+        if shared_century && shares_century(start, end) {
+            let low = (end % BigFloat::from(100)).to_u64().unwrap();
+            return Ok(format!(
+                "fan {} oant {}",
+                self.to_year(start)?,
+                self.year_low_half(low)?
+            ));
+        }
+        Ok(format!(
+            "{} oant {}",
+            self.to_year(start)?,
+            self.to_year(end)?
+        ))
+    }
+
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "de jierren njoggentich", or "de jierren njoggentjinhûndert"
+        // for its first decade
+        let low = num % BigFloat::from(100);
+        match low.is_zero() {
+            true => Ok(format!("de jierren {}", self.to_year(num)?)),
+            false => Ok(format!("de jierren {}", self.int_to_cardinal(low)?)),
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!("de {} ieu", self.to_ordinal(century)?))
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
@@ -333,20 +422,29 @@ impl Language for Frisian {
             Ok(format!(
                 "{}ûneinich {}",
                 if num.is_negative() { "minus " } else { "" },
-                self.currencies(currency)
+                self.currencies(currency, true)
             ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus fiif dollar en fyftich cent"
+            let words = self.to_currency(num.abs(), currency)?;
+            if self.paren {
+                Ok(self.paren_wrap(words, true))
+            } else {
+                Ok(format!("minus {}", words))
+            }
         } else if num.frac().is_zero() {
             let words = self.int_to_cardinal(num)?;
             Ok(format!(
                 "{} {}",
                 words,
-                self.currencies(currency)
+                self.currencies(currency, num.abs() != BigFloat::from(1))
             ))
         } else {
             let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
             let cents_words = self.int_to_cardinal(cents_nb)?;
-            let cents_suffix = self.cents(currency);
+            let cents_suffix = self.cents(currency, cents_nb.abs() != BigFloat::from(1));
             let integral_word = self.to_currency(integral_part, currency)?;
 
             if cents_nb.is_zero() {
@@ -355,12 +453,69 @@ impl Language for Frisian {
                 Ok(format!("{} {}", cents_words, cents_suffix))
             } else {
                 Ok(format!(
-                    "{} en {} {}",
-                    integral_word, cents_words, cents_suffix
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
                 ))
             }
         }
     }
+
+    fn currency_connector(&self) -> &'static str {
+        " en "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("debet")
+    }
+
+    // past the table after Conway and Wechsler, e.g. "undeciljoen"
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match MEGAS.get(power.checked_sub(1)?) {
+            Some(word) => Some(word),
+            None => {
+                let ending = if power % 2 == 1 { "iljard" } else { "iljoen" };
+                Some(intern(format!("{}{}", illion_stem(power / 2)?, ending)))
+            }
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "hûndert"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0]
+    }
+
+    // the ordinal followed by "part", e.g. "twa tredde part"
+    fn denominator_word(&self, denominator: BigFloat, _plural: bool) -> Option<String> {
+        match denominator.to_u64() {
+            Some(2) => Some(String::from("heal")),
+            _ => {
+                let ordinal = self.to_ordinal(denominator).ok()?;
+                Some(format!("{} part", ordinal.trim_start_matches("ien ")))
+            }
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("persint")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("promille")
+    }
+
+    fn approximate_word(&self) -> Option<&'static str> {
+        Some("sawat")
+    }
 }
 
 #[cfg(test)]
@@ -386,7 +541,7 @@ mod tests {
                 .cardinal()
                 .to_words(),
             Ok(String::from(
-                "achtentritich biljoen hûnderttrijeëntweintich miljard hûndertsânenfjirtich miljoen ienentachtigtûzen njoggenhûndertentwaentritich"
+                "achtentritich biljoen hûnderttrijeëntweintich miljard hûndertsânenfjirtich miljoen ienentachtichtûzen njoggenhûndertentwaentritich"
             ))
         );
         assert_eq!(
@@ -410,7 +565,7 @@ mod tests {
         );
         assert_eq!(
             Num2Words::new(102).lang(Lang::Frisian).ordinal().to_words(),
-            Ok(String::from("hûnderttwade"))
+            Ok(String::from("hûnderttwadde"))
         );
         assert_eq!(
             Num2Words::new(73).lang(Lang::Frisian).ordinal().to_words(),
@@ -426,6 +581,66 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ordinal_transform() {
+        let frisian = crate::lang::Frisian::new();
+        assert_eq!(frisian.ordinal_transform("trije"), "tredde");
+        assert_eq!(frisian.ordinal_transform("fjirtich"), "fjirtichste");
+        assert_eq!(
+            Num2Words::new(2_000_008)
+                .lang(Lang::Frisian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("twa miljoen achtste"))
+        );
+    }
+
+    #[test]
+    fn test_is_scale_word() {
+        let frisian = crate::lang::Frisian::new();
+        assert_eq!(frisian.is_scale_word("miljoen"), Some(1));
+        assert_eq!(frisian.is_scale_word("hûndert"), None);
+    }
+
+    #[test]
+    fn test_ordinal_purity() {
+        let ordinals = [
+            "earste",
+            "twadde",
+            "tredde",
+            "fjirde",
+            "fyfde",
+            "sechsde",
+            "sânde",
+            "achtste",
+            "njoggende",
+            "tsiende",
+            "alfde",
+            "tolfde",
+            "trettjinde",
+            "fjirtjinde",
+            "fyftjinde",
+            "sechstjinde",
+            "santjinde",
+            "achttjinde",
+            "njoggentjinde",
+            "tweintichste",
+        ];
+        let dutch = [
+            "twee", "drie", "vier", "vijf", "zes", "zeven", "negen", "elf", "twaalf", "tig",
+        ];
+
+        for (i, ordinal) in ordinals.iter().enumerate() {
+            let words = Num2Words::new(i as i64 + 1)
+                .lang(Lang::Frisian)
+                .ordinal()
+                .to_words()
+                .unwrap();
+            assert_eq!(&words, ordinal);
+            assert!(!dutch.iter().any(|d| words.contains(d)), "{}", words);
+        }
+    }
+
     #[test]
     fn test_ordinal_num() {
         assert_eq!(
@@ -433,35 +648,35 @@ mod tests {
                 .lang(Lang::Frisian)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("10e"))
+            Ok(String::from("10de"))
         );
         assert_eq!(
             Num2Words::new(13)
                 .lang(Lang::Frisian)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("13e"))
+            Ok(String::from("13de"))
         );
         assert_eq!(
             Num2Words::new(21)
                 .lang(Lang::Frisian)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("21e"))
+            Ok(String::from("21ste"))
         );
         assert_eq!(
             Num2Words::new(102)
                 .lang(Lang::Frisian)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("102e"))
+            Ok(String::from("102de"))
         );
         assert_eq!(
             Num2Words::new(73)
                 .lang(Lang::Frisian)
                 .ordinal_num()
                 .to_words(),
-            Ok(String::from("73e"))
+            Ok(String::from("73ste"))
         );
         assert_eq!(
             Num2Words::new(-42)
@@ -600,6 +815,24 @@ mod tests {
             Num2Words::new(1.1).lang(Lang::Frisian).year().to_words(),
             Err(num2words::Num2Err::FloatingYear)
         );
+        // years past a u64 are read as cardinals
+        for year in [1e21, 1e40] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::Frisian).year().to_words(),
+                Num2Words::new(year).lang(Lang::Frisian).to_words()
+            );
+        }
+    }
+
+    #[test]
+    fn test_year_low_half() {
+        use crate::lang::Language;
+
+        let frisian = lang::Frisian::new();
+        assert_eq!(frisian.year_low_half(0), Ok(String::from("hûndert")));
+        assert_eq!(frisian.year_low_half(1), Ok(String::from("ien")));
+        assert_eq!(frisian.year_low_half(5), Ok(String::from("fiif")));
+        assert_eq!(frisian.year_low_half(10), Ok(String::from("tsien")));
     }
 
     #[test]
@@ -634,6 +867,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_trema() {
+        assert_eq!(
+            Num2Words::new(22).lang(Lang::Frisian).to_words(),
+            Ok(String::from("twaentweintich"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Frisian).to_words(),
+            Ok(String::from("trijeëntweintich"))
+        );
+        assert_eq!(
+            Num2Words::new(33).lang(Lang::Frisian).to_words(),
+            Ok(String::from("trijeëntritich"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Frisian)
+                .prefer("no-trema")
+                .to_words(),
+            Ok(String::from("twaentweintich"))
+        );
+        assert_eq!(
+            Num2Words::new(23)
+                .lang(Lang::Frisian)
+                .prefer("no-trema")
+                .to_words(),
+            Ok(String::from("trijeentweintich"))
+        );
+        assert_eq!(
+            Num2Words::new(33)
+                .lang(Lang::Frisian)
+                .prefer("no-trema")
+                .to_words(),
+            Ok(String::from("trijeentritich"))
+        );
+    }
+
     #[test]
     fn test_big_num() {
         use crate::lang::fy::MEGAS;
@@ -675,7 +945,7 @@ mod tests {
                 .lang(Lang::Frisian)
                 .cardinal()
                 .to_words(),
-            Err(num2words::Num2Err::CannotConvert)
+            Ok(String::from("tsien sedeciljard"))
         );
     }
 
@@ -724,4 +994,95 @@ mod tests {
             Ok(String::from("ûneinich dollar"))
         );
     }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Frisian).fraction(3).to_words(),
+            Ok(String::from("twa tredde part"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Frisian).fraction(5).to_words(),
+            Ok(String::from("ien fyfde part"))
+        );
+    }
+
+    #[test]
+    fn test_decimal_word() {
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Frisian)
+                .cardinal()
+                .prefer("punt")
+                .to_words(),
+            Ok(String::from("twa punt fiif"))
+        );
+    }
+
+    #[test]
+    fn test_year_era() {
+        assert_eq!(
+            Num2Words::new(79)
+                .lang(Lang::Frisian)
+                .year()
+                .era(Era::AnnoDomini)
+                .to_words(),
+            Ok(String::from("njoggenensantich nei kristus"))
+        );
+        assert_eq!(
+            Num2Words::new(-44)
+                .lang(Lang::Frisian)
+                .year()
+                .era(Era::CommonEra)
+                .to_words(),
+            Ok(String::from("fjouwerenfjirtich foar ús jiertelling"))
+        );
+    }
+
+    #[test]
+    fn test_year_range() {
+        assert_eq!(
+            Num2Words::year_range(1914, 1918)
+                .lang(Lang::Frisian)
+                .prefer("shared-century")
+                .to_words(),
+            Ok(String::from("fan njoggentjinfjirtjin oant achttjin"))
+        );
+        assert_eq!(
+            Num2Words::year_range(1990, 1e21)
+                .lang(Lang::Frisian)
+                .to_words(),
+            Ok(String::from("njoggentjinnjoggentich oant ien triljard"))
+        );
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Frisian).decade().to_words(),
+            Ok(String::from("de jierren njoggentich"))
+        );
+        assert_eq!(
+            Num2Words::new(1e21).lang(Lang::Frisian).decade().to_words(),
+            Ok(String::from("de jierren ien triljard"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Frisian)
+                .century()
+                .to_words(),
+            Ok(String::from("de ienentweintichste ieu"))
+        );
+    }
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(
+            Num2Words::new(98)
+                .lang(Lang::Frisian)
+                .approximate()
+                .to_words(),
+            Ok(String::from("sawat hûndert"))
+        );
+    }
 }
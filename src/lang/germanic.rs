@@ -0,0 +1,342 @@
+use crate::{
+    lang::{split_groups, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, GroupingScheme,
+};
+use num_bigfloat::BigFloat;
+
+/// Words and joining rules shared by the North Germanic languages, which
+/// only differ in spelling, in where they write spaces and in the "og" read
+/// before the last two digits
+pub struct Germanic {
+    pub units: [&'static str; 20],
+    /// Tens, e.g. "halvtreds" for fifty in Danish
+    pub tens: [&'static str; 10],
+    pub hundred: &'static str,
+    pub thousand: &'static str,
+    /// Singular and plural of each scale word from a million on
+    pub megas: [[&'static str; 2]; 4],
+    pub ordinal_units: [&'static str; 20],
+    pub ordinal_tens: [&'static str; 10],
+    pub ordinal_hundred: &'static str,
+    pub ordinal_thousand: &'static str,
+    /// "ett" counting hundreds and thousands, which are neuter
+    pub neuter_one: &'static str,
+    /// "en" counting millions and money, which are common gender
+    pub common_one: &'static str,
+    /// Read before the last two digits, e.g. "hundre og fem"
+    pub and: Option<&'static str>,
+    /// Separator of the words below a million, empty where they compound,
+    /// e.g. "etthundrafem"
+    pub joiner: &'static str,
+    /// Units come before the tens, e.g. "enogtyve" for 21
+    pub units_first: bool,
+    /// Joins units and cents, e.g. " och "
+    pub connector: &'static str,
+    pub minus: &'static str,
+    pub point: &'static str,
+    pub infinity: &'static str,
+    pub before_christ: &'static str,
+}
+
+#[derive(Clone, Copy)]
+enum Token {
+    Unit(usize),
+    Ten(usize),
+    Hundred,
+    Thousand,
+    Mega(usize, bool),
+    Word(&'static str),
+}
+
+// each token follows its separator, e.g. ("", Ten(2)) for "tjugo" in "etthundratjugo"
+type Tokens = Vec<(&'static str, Token)>;
+
+impl Germanic {
+    fn and_separator(&self, tokens: &mut Tokens) -> &'static str {
+        match self.and {
+            Some(and) => {
+                tokens.push((" ", Token::Word(and)));
+                " "
+            }
+            None => self.joiner,
+        }
+    }
+
+    // e.g. 42 => "fyrtiotvå", "førtito" or "toogfyrre"
+    fn push_below_hundred(&self, num: u64, sep: &'static str, tokens: &mut Tokens) {
+        let (tens, units) = ((num / 10) as usize, (num % 10) as usize);
+        if num < 20 {
+            tokens.push((sep, Token::Unit(num as usize)));
+        } else if units == 0 {
+            tokens.push((sep, Token::Ten(tens)));
+        } else if self.units_first {
+            tokens.push((sep, Token::Unit(units)));
+            tokens.push(("", Token::Word(self.and.unwrap_or_default())));
+            tokens.push(("", Token::Ten(tens)));
+        } else {
+            tokens.push((sep, Token::Ten(tens)));
+            tokens.push(("", Token::Unit(units)));
+        }
+    }
+
+    fn push_below_thousand(&self, num: u64, sep: &'static str, tokens: &mut Tokens) {
+        let (hundreds, rest) = (num / 100, num % 100);
+        if hundreds == 0 {
+            return self.push_below_hundred(rest, sep, tokens);
+        }
+
+        if hundreds == 1 {
+            tokens.push((sep, Token::Word(self.neuter_one)));
+        } else {
+            tokens.push((sep, Token::Unit(hundreds as usize)));
+        }
+        tokens.push((self.joiner, Token::Hundred));
+        if rest > 0 {
+            let sep = self.and_separator(tokens);
+            self.push_below_hundred(rest, sep, tokens);
+        }
+    }
+
+    fn tokens(&self, num: BigFloat) -> Result<Tokens, Num2Err> {
+        let groups = split_groups(num, GroupingScheme::Thousands);
+        if groups.len() > self.megas.len() + 2 {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let mut tokens = vec![];
+        let mut sep = " ";
+        for (i, group) in groups.iter().rev().enumerate().rev() {
+            let group = *group;
+            if group == 0 {
+                continue;
+            }
+
+            match i {
+                // the last two digits take "og" after a scale, e.g. "ett tusen og fem"
+                0 if group < 100 && !tokens.is_empty() => {
+                    let sep = self.and_separator(&mut tokens);
+                    self.push_below_hundred(group, sep, &mut tokens);
+                }
+                0 => self.push_below_thousand(group, sep, &mut tokens),
+                1 => {
+                    if group == 1 {
+                        tokens.push((sep, Token::Word(self.neuter_one)));
+                    } else {
+                        self.push_below_thousand(group, sep, &mut tokens);
+                    }
+                    tokens.push((self.joiner, Token::Thousand));
+                    sep = self.joiner;
+                }
+                _ => {
+                    if group == 1 {
+                        tokens.push((sep, Token::Word(self.common_one)));
+                    } else {
+                        self.push_below_thousand(group, sep, &mut tokens);
+                    }
+                    tokens.push((" ", Token::Mega(i - 2, group != 1)));
+                    sep = " ";
+                }
+            }
+        }
+        Ok(tokens)
+    }
+
+    fn render(&self, token: Token, ordinal: bool) -> String {
+        String::from(match token {
+            Token::Unit(n) if ordinal => self.ordinal_units[n],
+            Token::Unit(n) => self.units[n],
+            Token::Ten(n) if ordinal => self.ordinal_tens[n],
+            Token::Ten(n) => self.tens[n],
+            Token::Hundred if ordinal => self.ordinal_hundred,
+            Token::Hundred => self.hundred,
+            Token::Thousand if ordinal => self.ordinal_thousand,
+            Token::Thousand => self.thousand,
+            // e.g. "miljonte" for the millionth
+            Token::Mega(i, _) if ordinal => return format!("{}te", self.megas[i][0]),
+            Token::Mega(i, plural) => self.megas[i][plural as usize],
+            Token::Word(word) => word,
+        })
+    }
+
+    // only the last token turns into an ordinal, e.g. "tjugoförsta"
+    fn join(&self, tokens: &Tokens, ordinal: bool) -> String {
+        let mut words = String::new();
+        for (i, (sep, token)) in tokens.iter().enumerate() {
+            let word = self.render(*token, ordinal && i + 1 == tokens.len());
+            if i > 0 {
+                // three consonants in a row drop one, e.g. "ettusen"
+                if sep.is_empty() && words.ends_with("tt") && word.starts_with('t') {
+                    words.pop();
+                }
+                words.push_str(sep);
+            }
+            words.push_str(&word);
+        }
+        words
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(self.units[0]));
+        }
+
+        let words = self.join(&self.tokens(num)?, false);
+        if num.is_negative() {
+            Ok(format!("{} {}", self.minus, words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from(self.minus));
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from(self.point));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(self.units[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    pub fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from(self.infinity))
+        } else if num.is_inf_neg() {
+            Ok(format!("{} {}", self.minus, self.infinity))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    pub fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(self.ordinal_units[0]));
+        }
+        Ok(self.join(&self.tokens(num)?, true))
+    }
+
+    pub fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // years from 1100 to 1999 are read in hundreds, e.g. "nittonhundraåttiofyra"
+        let hundreds = num
+            .abs()
+            .to_u64()
+            .filter(|year| (1100..2000).contains(year));
+        let words = if let Some(year) = hundreds {
+            let mut tokens = vec![
+                ("", Token::Unit((year / 100) as usize)),
+                (self.joiner, Token::Hundred),
+            ];
+            match year % 100 {
+                0 => (),
+                rest => {
+                    let sep = self.and_separator(&mut tokens);
+                    self.push_below_hundred(rest, sep, &mut tokens);
+                }
+            }
+            self.join(&tokens, false)
+        } else {
+            self.int_to_cardinal(num.abs())?
+        };
+
+        if num.is_negative() {
+            Ok(format!("{} {}", words, self.before_christ))
+        } else {
+            Ok(words)
+        }
+    }
+
+    // money is common gender, so a single unit reads "en", e.g. "en krona"
+    fn amount(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            Ok(String::from(self.common_one))
+        } else {
+            self.int_to_cardinal(num)
+        }
+    }
+
+    pub fn to_currency(
+        &self,
+        num: BigFloat,
+        currency: Currency,
+        currencies: impl Fn(Currency, bool) -> String,
+        cents: impl Fn(Currency, bool) -> String,
+    ) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                currencies(currency, true)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus fem kronor och femtio öre"
+            let words = self.to_currency(num.abs(), currency, currencies, cents)?;
+            Ok(format!("{} {}", self.minus, words))
+        } else if num.frac().is_zero() {
+            let words = self.amount(num)?;
+            Ok(format!(
+                "{} {}",
+                words,
+                currencies(currency, num != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.amount(cents_nb)?;
+            let cents_suffix = cents(currency, cents_nb != BigFloat::from(1));
+
+            if cents_nb.is_zero() {
+                return Ok(format!(
+                    "{} {}",
+                    self.amount(integral_part)?,
+                    currencies(currency, integral_part != BigFloat::from(1))
+                ));
+            }
+            if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{} {}{}{} {}",
+                    self.amount(integral_part)?,
+                    currencies(currency, integral_part != BigFloat::from(1)),
+                    self.connector,
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    pub fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match power {
+            0 => None,
+            1 => Some(self.thousand),
+            _ => self.megas.get(power - 2).map(|m| m[0]),
+        }
+    }
+
+    pub fn scale_plural(&self, word: &'static str) -> String {
+        match self.megas.iter().find(|m| m[0] == word) {
+            Some(m) => String::from(m[1]),
+            None => String::from(word),
+        }
+    }
+}
@@ -0,0 +1,376 @@
+use crate::{
+    lang::{split_groups, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, GroupingScheme, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Hindi {}
+
+// Every number below a hundred has a word of its own
+#[rustfmt::skip]
+const UNITS: [&str; 100] = [
+    "शून्य", "एक", "दो", "तीन", "चार", "पाँच", "छह", "सात", "आठ", "नौ",
+    "दस", "ग्यारह", "बारह", "तेरह", "चौदह", "पंद्रह", "सोलह", "सत्रह", "अठारह", "उन्नीस",
+    "बीस", "इक्कीस", "बाईस", "तेईस", "चौबीस", "पच्चीस", "छब्बीस", "सत्ताईस", "अट्ठाईस", "उनतीस",
+    "तीस", "इकतीस", "बत्तीस", "तैंतीस", "चौंतीस", "पैंतीस", "छत्तीस", "सैंतीस", "अड़तीस", "उनतालीस",
+    "चालीस", "इकतालीस", "बयालीस", "तैंतालीस", "चवालीस", "पैंतालीस", "छियालीस", "सैंतालीस", "अड़तालीस", "उनचास",
+    "पचास", "इक्यावन", "बावन", "तिरेपन", "चौवन", "पचपन", "छप्पन", "सत्तावन", "अट्ठावन", "उनसठ",
+    "साठ", "इकसठ", "बासठ", "तिरेसठ", "चौंसठ", "पैंसठ", "छियासठ", "सड़सठ", "अड़सठ", "उनहत्तर",
+    "सत्तर", "इकहत्तर", "बहत्तर", "तिहत्तर", "चौहत्तर", "पचहत्तर", "छिहत्तर", "सतहत्तर", "अठहत्तर", "उन्यासी",
+    "अस्सी", "इक्यासी", "बयासी", "तिरासी", "चौरासी", "पचासी", "छियासी", "सत्तासी", "अट्ठासी", "नवासी",
+    "नब्बे", "इक्यानबे", "बानबे", "तिरानबे", "चौरानबे", "पंचानबे", "छियानबे", "सत्तानबे", "अट्ठानबे", "निन्यानबे",
+];
+
+const HUNDRED: &str = "सौ";
+
+// Hazaar for 10^3, then one word per hundredfold, e.g. "लाख" for 10^5 and
+// "करोड़" for 10^7
+const MEGAS: [&str; 8] = ["हज़ार", "लाख", "करोड़", "अरब", "खरब", "नील", "पद्म", "शंख"];
+
+// The first ordinals are irregular, the others add "वाँ" to the cardinal
+const ORDINALS: [&str; 7] = ["", "पहला", "दूसरा", "तीसरा", "चौथा", "पाँचवाँ", "छठा"];
+
+impl Hindi {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::INR if plural_form => String::from("रुपये"),
+            Currency::INR => String::from("रुपया"),
+            Currency::NPR if plural_form => String::from("नेपाली रुपये"),
+            Currency::NPR => String::from("नेपाली रुपया"),
+            Currency::PKR if plural_form => String::from("पाकिस्तानी रुपये"),
+            Currency::PKR => String::from("पाकिस्तानी रुपया"),
+            Currency::DOLLAR | Currency::USD => String::from("डॉलर"),
+            Currency::EUR => String::from("यूरो"),
+            Currency::GBP => String::from("पाउंड"),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::INR | Currency::NPR | Currency::PKR if plural_form => String::from("पैसे"),
+            Currency::INR | Currency::NPR | Currency::PKR => String::from("पैसा"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("सेंट"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+
+    // e.g. 234 => "दो सौ चौंतीस"
+    fn hundreds_to_words(&self, num: u64, words: &mut Vec<&'static str>) {
+        let (hundreds, rest) = (num / 100, num % 100);
+        if hundreds > 0 {
+            words.push(UNITS[hundreds as usize]);
+            words.push(HUNDRED);
+        }
+        if rest > 0 {
+            words.push(UNITS[rest as usize]);
+        }
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let groups = split_groups(num, GroupingScheme::Indian);
+        let words = self.compose_from_groups(&groups, GroupingScheme::Indian)?;
+
+        if num.is_negative() {
+            Ok(format!("ऋण {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from("ऋण"));
+        }
+        words.push(self.int_to_cardinal(integral_part)?);
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("दशमलव"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Hindi {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("अनंत"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("ऋण अनंत"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        match ORDINALS.get(num.to_u64().unwrap_or(0) as usize) {
+            Some(ordinal) if !ordinal.is_empty() => Ok(String::from(*ordinal)),
+            _ => Ok(format!("{}वाँ", self.to_cardinal(num)?)),
+        }
+    }
+
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok(match num.to_u64().unwrap_or(0) {
+            1 => "ला",
+            2 | 3 => "रा",
+            4 => "था",
+            6 => "ठा",
+            _ => "वाँ",
+        })
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // years from 1100 to 1999 are read in hundreds, e.g. "उन्नीस सौ नब्बे"
+        let hundreds = num
+            .abs()
+            .to_u64()
+            .filter(|year| (1100..2000).contains(year));
+        let words = if let Some(year) = hundreds {
+            let mut words = vec![UNITS[(year / 100) as usize], HUNDRED];
+            self.hundreds_to_words(year % 100, &mut words);
+            words.join(" ")
+        } else {
+            self.int_to_cardinal(num.abs())?
+        };
+
+        if num.is_negative() {
+            Ok(format!("{} ईसा पूर्व", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!(
+                "{} {}",
+                words,
+                self.currencies(currency, num.abs() != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency, cents_nb.abs() != BigFloat::from(1));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words.trim_start_matches("ऋण "),
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " और "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).copied()
+    }
+
+    fn grouping_scheme(&self) -> GroupingScheme {
+        GroupingScheme::Indian
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        // the last group holds three digits, the others two
+        let last = groups.len().saturating_sub(1);
+        if scheme != GroupingScheme::Indian
+            || groups
+                .iter()
+                .enumerate()
+                .any(|(i, g)| *g >= if i == last { 1000 } else { 100 })
+        {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        // e.g. [12, 34, 5, 678] => "बारह लाख चौंतीस हज़ार..." for 1,23,45,678
+        let mut words = vec![];
+        for (i, group) in groups.iter().rev().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+            self.hundreds_to_words(*group, &mut words);
+            if i != 0 {
+                words.push(MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?);
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn separators(&self) -> (char, char) {
+        (',', '.')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDRED
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Hindi).cardinal().to_words(),
+            Ok(String::from("शून्य"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Hindi).cardinal().to_words(),
+            Ok(String::from("बयालीस"))
+        );
+        assert_eq!(
+            Num2Words::new(1234).lang(Lang::Hindi).cardinal().to_words(),
+            Ok(String::from("एक हज़ार दो सौ चौंतीस"))
+        );
+        assert_eq!(
+            Num2Words::new(-7).lang(Lang::Hindi).cardinal().to_words(),
+            Ok(String::from("ऋण सात"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5).lang(Lang::Hindi).cardinal().to_words(),
+            Ok(String::from("दो दशमलव पाँच"))
+        );
+    }
+
+    #[test]
+    fn test_lakh_crore() {
+        assert_eq!(
+            Num2Words::new(100_000)
+                .lang(Lang::Hindi)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("एक लाख"))
+        );
+        assert_eq!(
+            Num2Words::new(12_345_678)
+                .lang(Lang::Hindi)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("एक करोड़ तेईस लाख पैंतालीस हज़ार छह सौ अठहत्तर"))
+        );
+        assert_eq!(
+            Num2Words::new(2_500_000)
+                .lang(Lang::Hindi)
+                .to_words_detailed()
+                .map(|d| d.scale),
+            Ok(Some(String::from("लाख")))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Hindi).ordinal().to_words(),
+            Ok(String::from("पहला"))
+        );
+        assert_eq!(
+            Num2Words::new(7).lang(Lang::Hindi).ordinal().to_words(),
+            Ok(String::from("सातवाँ"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Hindi).ordinal_num().to_words(),
+            Ok(String::from("3रा"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Hindi).year().to_words(),
+            Ok(String::from("उन्नीस सौ नब्बे"))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Hindi).year().to_words(),
+            Ok(String::from("दो हज़ार चौबीस"))
+        );
+        // years past a u64 are read as cardinals
+        for year in [1e21, 1e40] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::Hindi).year().to_words(),
+                Num2Words::new(year).lang(Lang::Hindi).to_words()
+            );
+        }
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Hindi)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("एक रुपया"))
+        );
+        assert_eq!(
+            Num2Words::new(250.5)
+                .lang(Lang::Hindi)
+                .currency(Currency::INR)
+                .to_words(),
+            Ok(String::from("दो सौ पचास रुपये और पचास पैसे"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Hindi)
+                .currency(Currency::NPR)
+                .to_words(),
+            Ok(String::from("दो नेपाली रुपये"))
+        );
+    }
+}
@@ -0,0 +1,698 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Italian {}
+
+const UNITS: [&str; 19] = [
+    "uno",
+    "due",
+    "tre",
+    "quattro",
+    "cinque",
+    "sei",
+    "sette",
+    "otto",
+    "nove",
+    "dieci",
+    "undici",
+    "dodici",
+    "tredici",
+    "quattordici",
+    "quindici",
+    "sedici",
+    "diciassette",
+    "diciotto",
+    "diciannove",
+];
+
+const TENS: [&str; 8] = [
+    "venti",
+    "trenta",
+    "quaranta",
+    "cinquanta",
+    "sessanta",
+    "settanta",
+    "ottanta",
+    "novanta",
+];
+
+const UNITS_ORDINAL: [&str; 10] = [
+    "primo", "secondo", "terzo", "quarto", "quinto", "sesto", "settimo", "ottavo", "nono", "decimo",
+];
+
+const MEGAS: [[&str; 2]; 8] = [
+    ["milione", "milioni"],
+    ["miliardo", "miliardi"],
+    ["bilione", "bilioni"],
+    ["biliardo", "biliardi"],
+    ["trilione", "trilioni"],
+    ["triliardo", "triliardi"],
+    ["quadrilione", "quadrilioni"],
+    ["quadriliardo", "quadriliardi"],
+];
+
+impl Italian {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::EUR => String::from("euro"),
+            Currency::DOLLAR | Currency::USD => {
+                String::from(if plural_form { "dollari" } else { "dollaro" })
+            }
+            Currency::GBP => String::from(if plural_form { "sterline" } else { "sterlina" }),
+            Currency::CHF => String::from(if plural_form { "franchi" } else { "franco" }),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    // the number agrees with feminine names, e.g. "una sterlina"
+    fn currency_feminine(&self, currency: Currency) -> bool {
+        match currency {
+            Currency::GBP => true,
+            _ => currency.is_feminine(),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::EUR | Currency::CHF => String::from(if plural_form {
+                "centesimi"
+            } else {
+                "centesimo"
+            }),
+            Currency::GBP => String::from(if plural_form { "pence" } else { "penny" }),
+            Currency::DOLLAR | Currency::USD => String::from("cent"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 128 => "centoventotto", 108 => "centotto"
+    fn triplet_to_word(&self, triplet: u64) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let rest = tens * 10 + units;
+
+        let rest_word = match (tens, units) {
+            (0, 0) => String::new(),
+            (0, _) | (1, _) => String::from(UNITS[rest - 1]),
+            (_, 0) => String::from(TENS[tens - 2]),
+            (_, 1) | (_, 8) => {
+                // the vowel is elided before "uno" and "otto", e.g. "ventuno"
+                let ten = TENS[tens - 2];
+                format!("{}{}", &ten[..ten.len() - 1], UNITS[units - 1])
+            }
+            _ => format!("{}{}", TENS[tens - 2], UNITS[units - 1]),
+        };
+
+        let mut word = String::new();
+        if hundreds > 0 {
+            if hundreds > 1 {
+                word.push_str(UNITS[hundreds - 1]);
+            }
+            // "centuno", "centotto" and "centottanta" but "centodue"
+            if rest_word.starts_with('u') || rest_word.starts_with("ott") {
+                word.push_str("cent");
+            } else {
+                word.push_str("cento");
+            }
+        }
+        word.push_str(&rest_word);
+
+        word
+    }
+
+    // a compound ending in "tre" is stressed, e.g. "ventitré" but "tre"
+    fn accented(&self, word: String) -> String {
+        match word.strip_suffix("tre") {
+            Some(head) if !head.is_empty() => format!("{}tré", head),
+            _ => word,
+        }
+    }
+
+    // "uno" is shortened before a noun, e.g. "un milione" and "ventun euro"
+    fn truncated(&self, word: &str) -> String {
+        match word.strip_suffix("uno") {
+            Some(head) => format!("{}un", head),
+            None => String::from(word),
+        }
+    }
+
+    // a single "uno" agrees with a feminine noun instead, e.g. "una sterlina"
+    // but "ventun sterline"
+    fn counting(&self, word: &str, feminine: bool) -> String {
+        match word {
+            "uno" if feminine => String::from("una"),
+            _ => self.truncated(word),
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("meno"));
+            num = -num;
+        }
+
+        let thousands = self.split_thousands(num);
+
+        // millions and above are separate words, e.g. "due milioni"
+        for (i, triplet) in thousands.iter().enumerate().skip(2).rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            if i - 2 >= MEGAS.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            if *triplet == 1 {
+                words.push(String::from("un"));
+                words.push(String::from(MEGAS[i - 2][0]));
+            } else {
+                words.push(self.accented(self.truncated(&self.triplet_to_word(*triplet))));
+                words.push(String::from(MEGAS[i - 2][1]));
+            }
+        }
+
+        // below a million the number is a single word, e.g. "duemilatré"
+        let mut word = String::new();
+        match thousands.get(1) {
+            None | Some(0) => (),
+            Some(1) => word.push_str("mille"),
+            Some(triplet) => {
+                word.push_str(&self.triplet_to_word(*triplet));
+                word.push_str("mila");
+            }
+        }
+        word.push_str(&self.triplet_to_word(thousands[0]));
+        if !word.is_empty() {
+            words.push(self.accented(word));
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if negative {
+            words.push(String::from("meno"));
+        }
+
+        let integral_word = self.int_to_cardinal(integral_part)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("virgola"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("zero"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Italian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinito"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("meno infinito"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn ordinal_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal = self.to_cardinal(num)?;
+        let mut words: Vec<&str> = cardinal.split(' ').collect();
+
+        // a round scale joins its count, e.g. "milionesimo", "duemilionesimo"
+        match words
+            .last()
+            .and_then(|w| MEGAS.iter().position(|m| m.contains(w)))
+        {
+            Some(power) if words.len() >= 2 => {
+                words.pop();
+                let count = words.pop().unwrap();
+                let count = if count == "un" { "" } else { count };
+                words.push("");
+                let mut head = words.join(" ");
+                head.push_str(count);
+                head.push_str(MEGAS[power][0]);
+                Ok(head)
+            }
+            _ => Ok(cardinal),
+        }
+    }
+
+    fn ordinal_transform(&self, word: &str) -> String {
+        if let Some(i) = UNITS
+            .iter()
+            .take(UNITS_ORDINAL.len())
+            .position(|u| *u == word)
+        {
+            return String::from(UNITS_ORDINAL[i]);
+        }
+
+        if let Some(head) = word.strip_suffix("tré") {
+            // "ventitreesimo"
+            format!("{}treesimo", head)
+        } else if word.ends_with("sei") {
+            // "ventiseiesimo"
+            format!("{}esimo", word)
+        } else if let Some(head) = word.strip_suffix("mila") {
+            // "duemillesimo"
+            format!("{}millesimo", head)
+        } else {
+            // the final vowel is dropped, e.g. "ventesimo", "millesimo"
+            let mut chars = word.chars();
+            chars.next_back();
+            format!("{}esimo", chars.as_str())
+        }
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok("º")
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} a.C.", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "gli anni novanta", or "gli anni millenovecento" for its first
+        // decade
+        let low = num % BigFloat::from(100);
+        match low.is_zero() {
+            true => Ok(format!("gli anni {}", self.to_year(num)?)),
+            false => Ok(format!("gli anni {}", self.int_to_cardinal(low)?)),
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!("il {} secolo", self.to_ordinal(century)?))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "meno cinque euro e cinquanta centesimi"
+            Ok(format!("meno {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            // a round scale takes "di", e.g. "un milione di euro"
+            let (words, of) = match words.rsplit_once(' ') {
+                Some((_, last)) if MEGAS.iter().any(|m| m.contains(&last)) => (words, " di"),
+                _ => (self.counting(&words, self.currency_feminine(currency)), ""),
+            };
+            Ok(format!(
+                "{}{} {}",
+                words,
+                of,
+                self.currencies(currency, num != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.counting(
+                &self.int_to_cardinal(cents_nb)?,
+                currency.is_subunit_feminine(),
+            );
+            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " e "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("a debito")
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match power {
+            0 => None,
+            1 => Some("mille"),
+            _ => MEGAS.get(power - 2).map(|m| m[0]),
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m[0] == word) {
+            Some(m) => String::from(m[1]),
+            None if word == "mille" => String::from("mila"),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "cento"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "mille"
+    }
+
+    // one is read "un" before the denominator, e.g. "un terzo"
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let plural = numerator.abs() != BigFloat::from(1);
+        let word = self
+            .denominator_word(denominator, plural)
+            .ok_or(Num2Err::CannotConvert)?;
+        match numerator.to_i64() {
+            Some(1) => Ok(format!("un {}", word)),
+            Some(-1) => Ok(format!("meno un {}", word)),
+            _ => Ok(format!("{} {}", self.to_cardinal(numerator)?, word)),
+        }
+    }
+
+    // plurals end in "-i", e.g. "due terzi" and "tre mezzi"
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        let word = match denominator.to_u64() {
+            Some(2) => String::from("mezzo"),
+            _ => self.to_ordinal(denominator).ok()?,
+        };
+        match (plural, word.strip_suffix('o')) {
+            (true, Some(stem)) => Some(format!("{}i", stem)),
+            _ => Some(word),
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("per cento")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("per mille")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Italian).cardinal().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Italian).cardinal().to_words(),
+            Ok(String::from("ventuno"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Italian).cardinal().to_words(),
+            Ok(String::from("ventitré"))
+        );
+        assert_eq!(
+            Num2Words::new(28).lang(Lang::Italian).cardinal().to_words(),
+            Ok(String::from("ventotto"))
+        );
+        assert_eq!(
+            Num2Words::new(108)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("centotto"))
+        );
+        assert_eq!(
+            Num2Words::new(180)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("centottanta"))
+        );
+        assert_eq!(
+            Num2Words::new(342)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("trecentoquarantadue"))
+        );
+        assert_eq!(
+            Num2Words::new(-10)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("meno dieci"))
+        );
+    }
+
+    #[test]
+    fn test_thousands() {
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("mille"))
+        );
+        assert_eq!(
+            Num2Words::new(1003)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("milletré"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("duemila"))
+        );
+        assert_eq!(
+            Num2Words::new(21000)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ventunomila"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("un milione"))
+        );
+        assert_eq!(
+            Num2Words::new(21200000)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ventun milioni duecentomila"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("primo"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("decimo"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("undicesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(20).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("ventesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("ventitreesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(26).lang(Lang::Italian).ordinal().to_words(),
+            Ok(String::from("ventiseiesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("duemillesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2000000)
+                .lang(Lang::Italian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("duemilionesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Italian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("21º"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un euro"))
+        );
+        assert_eq!(
+            Num2Words::new(21.01)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("ventun euro e un centesimo"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Italian)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("due dollari e cinquanta cent"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Italian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("un milione di euro"))
+        );
+    }
+
+    #[test]
+    fn test_cardinal_float() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Italian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dodici virgola cinque"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Italian).fraction(3).to_words(),
+            Ok(String::from("un terzo"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Italian).fraction(3).to_words(),
+            Ok(String::from("due terzi"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Italian).fraction(4).to_words(),
+            Ok(String::from("tre quarti"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Italian).fraction(2).to_words(),
+            Ok(String::from("un mezzo"))
+        );
+    }
+
+    #[test]
+    fn test_currency_gender() {
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::Italian)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("una sterlina e un penny"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Italian)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("ventun sterline"))
+        );
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Italian).decade().to_words(),
+            Ok(String::from("gli anni novanta"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Italian)
+                .century()
+                .to_words(),
+            Ok(String::from("il ventunesimo secolo"))
+        );
+    }
+}
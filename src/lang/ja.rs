@@ -0,0 +1,369 @@
+use crate::{
+    lang::{split_groups, whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, GroupingScheme, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Japanese {}
+
+const DIGITS: [&str; 10] = ["零", "一", "二", "三", "四", "五", "六", "七", "八", "九"];
+
+// Tens, hundreds and thousands within a group of four digits
+const PLACES: [&str; 3] = ["十", "百", "千"];
+
+// One word per power of ten thousand, e.g. "万" for 10^4 and "億" for 10^8
+const MEGAS: [&str; 8] = ["万", "億", "兆", "京", "垓", "𥝱", "穣", "溝"];
+
+impl Japanese {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::JPY => String::from("円"),
+            Currency::DOLLAR | Currency::USD => String::from("ドル"),
+            Currency::EUR => String::from("ユーロ"),
+            Currency::GBP => String::from("ポンド"),
+            Currency::CNY => String::from("元"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::JPY => String::from("銭"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("セント"),
+            _ => currency.default_subunit_string("cent{}", false),
+        }
+    }
+
+    // e.g. 1234 => "千二百三十四", 20 => "二十"
+    fn group_to_words(&self, group: u64) -> String {
+        let mut word = String::new();
+        for (place, digit) in [group / 1000 % 10, group / 100 % 10, group / 10 % 10]
+            .iter()
+            .enumerate()
+        {
+            // one is left out before a place, e.g. "十" rather than "一十"
+            match digit {
+                0 => (),
+                1 => word.push_str(PLACES[2 - place]),
+                _ => {
+                    word.push_str(DIGITS[*digit as usize]);
+                    word.push_str(PLACES[2 - place]);
+                }
+            }
+        }
+        match group % 10 {
+            0 => (),
+            units => word.push_str(DIGITS[units as usize]),
+        }
+        word
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(DIGITS[0]));
+        }
+
+        let groups = split_groups(num, GroupingScheme::Myriad);
+        let words = self.compose_from_groups(&groups, GroupingScheme::Myriad)?;
+
+        if num.is_negative() {
+            Ok(format!("マイナス{}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = self.int_to_cardinal(num.int())?;
+        if num.is_negative() && num.int().is_zero() {
+            words = format!("マイナス{}", words);
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push('点');
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push_str(DIGITS[digit.to_u64().unwrap() as usize]);
+        }
+        Ok(words)
+    }
+}
+
+impl Language for Japanese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("無限大"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("マイナス無限大"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("第{}", self.to_cardinal(num)?))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("第{}", whole_digits(num)))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("紀元前{}年", self.to_cardinal(-num)?))
+        } else {
+            Ok(format!("{}年", self.to_cardinal(num)?))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{}{}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "マイナス五ドル五十セント"
+            Ok(format!(
+                "マイナス{}",
+                self.to_currency(num.abs(), currency)?
+            ))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{}{}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{}{}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{}{}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        ""
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).copied()
+    }
+
+    fn grouping_scheme(&self) -> GroupingScheme {
+        GroupingScheme::Myriad
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        if scheme != GroupingScheme::Myriad || groups.iter().any(|g| *g >= 10000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return Ok(String::from(DIGITS[0]));
+        }
+
+        // e.g. [12, 3456] => "十二万三千四百五十六", but "一万" keeps its one
+        let mut words = String::new();
+        for (i, group) in groups.iter().rev().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.push_str(&self.group_to_words(*group));
+                continue;
+            }
+
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            if *group == 1 {
+                words.push_str(DIGITS[1]);
+            } else {
+                words.push_str(&self.group_to_words(*group));
+            }
+            words.push_str(mega);
+        }
+
+        Ok(words)
+    }
+
+    fn separators(&self) -> (char, char) {
+        (',', '.')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        PLACES[1]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        PLACES[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Japanese).cardinal().to_words(),
+            Ok(String::from("零"))
+        );
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("十"))
+        );
+        assert_eq!(
+            Num2Words::new(1234)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("千二百三十四"))
+        );
+        assert_eq!(
+            Num2Words::new(-42)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("マイナス四十二"))
+        );
+        assert_eq!(
+            Num2Words::new(2.75)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("二点七五"))
+        );
+    }
+
+    #[test]
+    fn test_myriads() {
+        assert_eq!(
+            Num2Words::new(10000)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一万"))
+        );
+        assert_eq!(
+            Num2Words::new(123456)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("十二万三千四百五十六"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000_000)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一億"))
+        );
+        assert_eq!(
+            Num2Words::new(200_000_005)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("二億五"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000_000i64)
+                .lang(Lang::Japanese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一兆"))
+        );
+        assert_eq!(
+            Num2Words::new(123456)
+                .lang(Lang::Japanese)
+                .to_words_detailed()
+                .map(|d| d.scale),
+            Ok(Some(String::from("万")))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Japanese).ordinal().to_words(),
+            Ok(String::from("第一"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Japanese).ordinal().to_words(),
+            Ok(String::from("第二十一"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Japanese)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("第3"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::Japanese)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("第1{}", "0".repeat(40)))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Japanese)
+                .currency(Currency::JPY)
+                .to_words(),
+            Ok(String::from("千円"))
+        );
+        assert_eq!(
+            Num2Words::new(15000)
+                .lang(Lang::Japanese)
+                .currency(Currency::JPY)
+                .to_words(),
+            Ok(String::from("一万五千円"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Japanese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("五ドル五十セント"))
+        );
+    }
+}
@@ -0,0 +1,450 @@
+use crate::{
+    lang::{split_groups, whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, GroupingScheme, Language,
+};
+use num_bigfloat::BigFloat;
+use std::str::FromStr;
+
+pub struct Korean {
+    system: NumeralSystem,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum NumeralSystem {
+    #[default]
+    Sino,
+    Native,
+}
+
+impl FromStr for NumeralSystem {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use NumeralSystem::*;
+        Ok(match s.to_lowercase().as_str() {
+            "sino" | "한자어" => Sino,
+            "native" | "고유어" => Native,
+            _ => return Err(()),
+        })
+    }
+}
+
+const DIGITS: [&str; 10] = ["영", "일", "이", "삼", "사", "오", "육", "칠", "팔", "구"];
+
+// Tens, hundreds and thousands within a group of four digits
+const PLACES: [&str; 3] = ["십", "백", "천"];
+
+// One word per power of ten thousand, e.g. "만" for 10^4 and "억" for 10^8
+const MEGAS: [&str; 4] = ["만", "억", "조", "경"];
+
+// Native numerals only count up to ninety-nine
+const NATIVE_UNITS: [&str; 10] = [
+    "", "하나", "둘", "셋", "넷", "다섯", "여섯", "일곱", "여덟", "아홉",
+];
+const NATIVE_TENS: [&str; 10] = [
+    "", "열", "스물", "서른", "마흔", "쉰", "예순", "일흔", "여든", "아흔",
+];
+
+impl Korean {
+    pub fn new() -> Self {
+        Self {
+            system: NumeralSystem::default(),
+        }
+    }
+
+    pub fn with_system(self, system: NumeralSystem) -> Self {
+        Self { system }
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::KRW => String::from("원"),
+            Currency::DOLLAR | Currency::USD => String::from("달러"),
+            Currency::EUR => String::from("유로"),
+            Currency::JPY => String::from("엔"),
+            Currency::CNY => String::from("위안"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::KRW => String::from("전"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("센트"),
+            _ => currency.default_subunit_string("cent{}", false),
+        }
+    }
+
+    // e.g. 1234 => "천이백삼십사", or "천이백서른넷" with native tens and units
+    fn group_to_words(&self, group: u64, native: bool) -> String {
+        let mut word = String::new();
+        for (place, digit) in [group / 1000 % 10, group / 100 % 10, group / 10 % 10]
+            .iter()
+            .enumerate()
+        {
+            if native && place == 2 {
+                word.push_str(NATIVE_TENS[*digit as usize]);
+                continue;
+            }
+            // one is left out before a place, e.g. "십" rather than "일십"
+            match digit {
+                0 => (),
+                1 => word.push_str(PLACES[2 - place]),
+                _ => {
+                    word.push_str(DIGITS[*digit as usize]);
+                    word.push_str(PLACES[2 - place]);
+                }
+            }
+        }
+        match group % 10 {
+            0 => (),
+            units if native => word.push_str(NATIVE_UNITS[units as usize]),
+            units => word.push_str(DIGITS[units as usize]),
+        }
+        word
+    }
+
+    // groups of ten thousand are written apart, e.g. "십이만 삼천사백오십육"
+    fn groups_to_words(&self, groups: &[u64], native: bool) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        for (i, group) in groups.iter().rev().enumerate().rev() {
+            if *group == 0 {
+                continue;
+            }
+            if i == 0 {
+                words.push(self.group_to_words(*group, native));
+                continue;
+            }
+
+            let mega = MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // ten thousand is "만" rather than "일만", but "일억" keeps its one
+            if *group == 1 && i == 1 {
+                words.push(String::from(*mega));
+            } else {
+                words.push(format!("{}{}", self.group_to_words(*group, false), mega));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat, native: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(DIGITS[0]));
+        }
+
+        let groups = split_groups(num, GroupingScheme::Myriad);
+        let words = self.groups_to_words(&groups, native)?;
+
+        if num.is_negative() {
+            Ok(format!("마이너스 {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    // native numerals have no fractions, so decimals are always Sino-Korean
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = self.int_to_cardinal(num.int(), false)?;
+        if num.is_negative() && num.int().is_zero() {
+            words = format!("마이너스 {}", words);
+        }
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push('점');
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push_str(DIGITS[digit.to_u64().unwrap() as usize]);
+        }
+        Ok(words)
+    }
+
+    // e.g. 1 => "첫째", 2 => "둘째", 12 => "열두째", 20 => "스무째"
+    fn native_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from("첫째"));
+        }
+
+        let words = self.int_to_cardinal(num, true)?;
+        let stem = if num == BigFloat::from(2) {
+            words
+        } else if let Some(stem) = words.strip_suffix("하나") {
+            format!("{}한", stem)
+        } else if let Some(stem) = words.strip_suffix("둘") {
+            format!("{}두", stem)
+        } else if let Some(stem) = words.strip_suffix("스물") {
+            format!("{}스무", stem)
+        } else {
+            words
+        };
+        Ok(format!("{}째", stem))
+    }
+}
+
+impl Language for Korean {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("무한대"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("마이너스 무한대"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.system == NumeralSystem::Native)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        match self.system {
+            NumeralSystem::Sino => Ok(format!("제{}", self.to_cardinal(num)?)),
+            NumeralSystem::Native => self.native_ordinal(num),
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("제{}", whole_digits(num)))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("기원전 {}년", self.int_to_cardinal(-num, false)?))
+        } else {
+            Ok(format!("{}년", self.int_to_cardinal(num, false)?))
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "마이너스 오 달러 오십 센트"
+            Ok(format!(
+                "마이너스 {}",
+                self.to_currency(num.abs(), currency)?
+            ))
+        } else if num.frac().is_zero() {
+            // money is always counted with Sino-Korean numerals
+            let words = self.int_to_cardinal(num, false)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb, false)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).copied()
+    }
+
+    fn grouping_scheme(&self) -> GroupingScheme {
+        GroupingScheme::Myriad
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        if scheme != GroupingScheme::Myriad || groups.iter().any(|g| *g >= 10000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return Ok(String::from(DIGITS[0]));
+        }
+
+        self.groups_to_words(groups, self.system == NumeralSystem::Native)
+    }
+
+    fn separators(&self) -> (char, char) {
+        (',', '.')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        PLACES[1]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        PLACES[2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_sino_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Korean).cardinal().to_words(),
+            Ok(String::from("영"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Korean).cardinal().to_words(),
+            Ok(String::from("십육"))
+        );
+        assert_eq!(
+            Num2Words::new(205).lang(Lang::Korean).cardinal().to_words(),
+            Ok(String::from("이백오"))
+        );
+        assert_eq!(
+            Num2Words::new(10000)
+                .lang(Lang::Korean)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("만"))
+        );
+        assert_eq!(
+            Num2Words::new(123456)
+                .lang(Lang::Korean)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("십이만 삼천사백오십육"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000_000)
+                .lang(Lang::Korean)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("일억"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5)
+                .lang(Lang::Korean)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("마이너스 이점오"))
+        );
+    }
+
+    #[test]
+    fn test_native_cardinal() {
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .to_words(),
+            Ok(String::from("셋"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Korean)
+                .prefer("고유어")
+                .to_words(),
+            Ok(String::from("스물하나"))
+        );
+        assert_eq!(
+            Num2Words::new(123)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .to_words(),
+            Ok(String::from("백스물셋"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .to_words(),
+            Ok(String::from("이점오"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Korean).ordinal().to_words(),
+            Ok(String::from("제일"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::Korean)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("제1{}", "0".repeat(40)))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("첫째"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("둘째"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("열두째"))
+        );
+        assert_eq!(
+            Num2Words::new(20)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("스무째"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(15000)
+                .lang(Lang::Korean)
+                .prefer("native")
+                .currency(Currency::KRW)
+                .to_words(),
+            Ok(String::from("만 오천 원"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Korean)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("오 달러 오십 센트"))
+        );
+    }
+}
@@ -1,16 +1,580 @@
 use crate::lang;
 use crate::num2words::Num2Err;
-use crate::Currency;
+use crate::{Currency, Unit};
 use num_bigfloat::BigFloat;
+use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::{Mutex, OnceLock};
+
+/// Hard limit on the number of fraction digits read out, as `BigFloat` only
+/// carries 40 significant decimal digits
+pub const MAX_FRACTION_DIGITS: usize = 40;
+
+/// Grouping of digits under scale words
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GroupingScheme {
+    /// Groups of three digits, e.g. 1,234,567
+    Thousands,
+    /// Three digits followed by groups of two, e.g. 12,34,567
+    Indian,
+    /// Groups of four digits, e.g. 123,4567
+    Myriad,
+}
+
+/// How an ordinal in digits writes zero
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ZeroOrdinal {
+    /// Zero with the ordinal suffix, e.g. 0th
+    Numeral,
+    /// Zero spelled as an ordinal, e.g. zeroth
+    Spelled,
+    /// Zero has no ordinal, see [`Num2Err::ZeroOrdinal`]
+    Error,
+}
+
+/// Grammatical gender of the nouns numbers count, see
+/// [`Num2Words::gender`](crate::Num2Words::gender)
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum Gender {
+    /// e.g. "un" in French and "один" in Ukrainian
+    #[default]
+    Masculine,
+    /// e.g. "une" in French and "одна" in Ukrainian
+    Feminine,
+    /// e.g. "одне" in Ukrainian
+    Neuter,
+}
+
+/// Grammatical case numbers are declined in, see
+/// [`Num2Words::case`](crate::Num2Words::case)
+#[derive(Default, Clone, Copy, Debug, PartialEq)]
+pub enum Case {
+    /// e.g. "два" in Ukrainian
+    #[default]
+    Nominative,
+    /// e.g. "двох" in Ukrainian
+    Genitive,
+    /// e.g. "двом" in Ukrainian
+    Dative,
+    /// e.g. "два" in Ukrainian
+    Accusative,
+    /// e.g. "двома" in Ukrainian
+    Instrumental,
+    /// e.g. "двох" in Ukrainian
+    Locative,
+}
+
+/// Naming of the scale words above a million, see
+/// [`Num2Words::scale`](crate::Num2Words::scale)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Scale {
+    /// Each scale word a thousand times the one before, e.g. "billion" for
+    /// 10^9, as in English
+    Short,
+    /// Each scale word a million times the one before, with a thousand of it
+    /// in between, e.g. "milliard" for 10^9 and "billion" for 10^12, as in
+    /// French
+    Long,
+}
+
+/// Era years are read in, see [`Num2Words::era`](crate::Num2Words::era)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Era {
+    /// Years before Christ and Anno Domini, e.g. "forty-four BC" and
+    /// "seventy-nine AD"
+    AnnoDomini,
+    /// Years before the common era and within it, e.g. "forty-four BCE" and
+    /// "seventy-nine CE"
+    CommonEra,
+    /// Words of one's own read after years before the era and within it,
+    /// either of which may be empty
+    Custom {
+        before: &'static str,
+        after: &'static str,
+    },
+}
+
+/// Builds a third-party language, see [`register_language`]
+pub type LanguageFactory = fn() -> Box<dyn Language>;
 
 /// Defines what is a language
+///
+/// Implement it to plug in a language of your own with
+/// [`register_language`] or [`Num2Words::lang_custom`](crate::Num2Words::lang_custom).
+///
+/// Only the required methods must be written, the others either build on
+/// them or return [`Num2Err::CannotConvert`]. The builder checks numbers
+/// before handing them over: ordinals only receive finite integers that are
+/// not negative, and years only finite integers.
 pub trait Language {
+    /// Reads the number as a cardinal, e.g. `forty-two`
     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err>;
-    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err>;
-    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err>;
+    /// The language agreeing with nouns of the given gender, e.g. reading
+    /// "une" rather than "un" in French
+    ///
+    /// Languages whose numbers don't agree with a gender return `None` and
+    /// are read as they are.
+    fn gendered(&self, _gender: Gender) -> Option<Box<dyn Language>> {
+        None
+    }
+    /// The language declining numbers in the given case, e.g. reading
+    /// "двох" rather than "два" in the Ukrainian genitive
+    ///
+    /// Languages without cases return `None` and are read as they are.
+    fn declined(&self, _case: Case) -> Option<Box<dyn Language>> {
+        None
+    }
+    /// The language naming scale words in the given scale, e.g. reading
+    /// "milliard" rather than "billion" for 10^9 in the long scale
+    ///
+    /// Languages with a single scale return `None` and are read as they are.
+    fn scaled(&self, _scale: Scale) -> Option<Box<dyn Language>> {
+        None
+    }
+    /// Reads the number as an ordinal, e.g. `forty-second`
+    ///
+    /// Only the last word of the cardinal changes, after its last space or
+    /// hyphen, e.g. `two` in "forty-two". Languages with a regular ordinal
+    /// only supply [`Language::ordinal_transform`], while languages that
+    /// inflect every part, e.g. Finnish "kahdeskymmenesensimmäinen", override
+    /// it.
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let cardinal = self.ordinal_cardinal(num)?;
+        let (head, last) = match cardinal.rfind([' ', '-']) {
+            Some(i) => cardinal.split_at(i + 1),
+            None => ("", cardinal.as_str()),
+        };
+        Ok(format!("{}{}", head, self.ordinal_transform(last)))
+    }
+    /// Cardinal an ordinal is built from, see [`Language::to_ordinal`]
+    fn ordinal_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.to_cardinal(num)
+    }
+    /// Ordinal form of the last word of a cardinal, e.g. `second` for `two`
+    ///
+    /// Languages without ordinal forms keep the word unchanged.
+    fn ordinal_transform(&self, word: &str) -> String {
+        String::from(word)
+    }
+    /// Writes the number as an ordinal in digits, e.g. `42nd`
+    ///
+    /// By default the digits are followed by
+    /// [`Language::ordinal_num_suffix`]. Languages writing ordinals in digits
+    /// otherwise, e.g. "第42" in Japanese, write them themselves.
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!(
+            "{}{}",
+            whole_digits(num),
+            self.ordinal_num_suffix(num)?
+        ))
+    }
+    /// Suffix written after the digits of an ordinal, following the
+    /// language's own rules, e.g. `nd` in "42nd" but `th` in "12th"
+    ///
+    /// Defaults to a period, e.g. "42." as in Czech or Danish.
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok(".")
+    }
+    /// How an ordinal in digits writes zero unless a preference says
+    /// otherwise, e.g. `0th`
+    fn zero_ordinal(&self) -> ZeroOrdinal {
+        ZeroOrdinal::Numeral
+    }
+    /// Reads the number as a year, e.g. `nineteen oh-one`
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err>;
+    /// Words read after years before an era and within it, e.g. `("BC",
+    /// "AD")` for [`Era::AnnoDomini`]
+    ///
+    /// Languages without them read years as [`Language::to_year`] does.
+    fn era_words(&self, _era: Era) -> Option<(&'static str, &'static str)> {
+        None
+    }
+    /// Reads a range of years, e.g. `nineteen fourteen to nineteen eighteen`,
+    /// or `from nineteen fourteen to eighteen` when they share their century
+    /// and `shared_century` is set
+    ///
+    /// The years are finite integers and the first is not after the last.
+    /// Languages without year ranges cannot convert.
+    fn to_year_range(
+        &self,
+        _start: BigFloat,
+        _end: BigFloat,
+        _shared_century: bool,
+    ) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+    /// Reads the number as an amount of currency, e.g. `forty-two dollars`
+    ///
+    /// The sign of a negative amount is read once, before the whole amount,
+    /// e.g. `minus five dollars and fifty cents`.
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err>;
+
+    /// Text joining the units and the cents of a currency amount, e.g.
+    /// ` and ` in "five dollars and fifty cents"
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    /// Words read after a negative amount of currency instead of its sign
+    /// with the `debit` preference, e.g. `in debit` in "five dollars in debit"
+    ///
+    /// Languages without them read the sign as usual.
+    fn debit_words(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Reads a currency amount as `(major_words, minor_words, major_unit,
+    /// minor_unit)`, e.g. `("five", "fifty", "dollars", "cents")`
+    ///
+    /// Languages without currency parts cannot convert.
+    fn to_currency_parts(
+        &self,
+        _num: BigFloat,
+        _currency: Currency,
+    ) -> Result<(String, String, String, String), Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads the number followed by its unit of measure, e.g.
+    /// `twenty-one kilometers`
+    ///
+    /// By default the cardinal is followed by the form of
+    /// [`Language::unit_words`] chosen by [`Language::unit_plural`]. Languages
+    /// whose numbers agree with the unit's gender read it themselves.
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let words = self.unit_words(unit).ok_or(Num2Err::CannotConvert)?;
+        Ok(format!(
+            "{} {}",
+            self.to_cardinal(num)?,
+            words[self.unit_plural(num)]
+        ))
+    }
+
+    /// Forms of the unit's word agreeing with one, a few, many and a
+    /// fraction, e.g. `["kilometer", "kilometers", "kilometers", "kilometers"]`
+    ///
+    /// Languages without units only read [`Unit::Custom`].
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        unit.custom_words()
+    }
+
+    /// Index of the form of [`Language::unit_words`] agreeing with `num`: `0`
+    /// for one, `1` for few, `2` for many and `3` for fractions
+    ///
+    /// By default one is singular and every other number plural.
+    fn unit_plural(&self, num: BigFloat) -> usize {
+        if num.is_inf() {
+            2
+        } else if !num.frac().is_zero() {
+            3
+        } else if num.abs() == BigFloat::from(1) {
+            0
+        } else {
+            2
+        }
+    }
+
+    /// Reads the ordinal counted from the end, e.g. `second from last`
+    ///
+    /// Languages without such a reading keep the negative ordinal error.
+    fn to_ordinal_from_end(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::NegativeOrdinal)
+    }
+
+    /// Reads the number as a multiplicative, e.g. `twice`
+    ///
+    /// Languages without multiplicatives cannot convert.
+    fn to_multiplicative(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads the number in scientific notation, e.g. `ten to the third power`
+    ///
+    /// Languages without scientific notation cannot convert.
+    fn to_scientific(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads each non-zero digit with its place value, e.g.
+    /// `two hundreds and four ones`
+    ///
+    /// Languages without place value words cannot convert.
+    fn to_expanded(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads a color code as pairs of hexadecimal digits, e.g.
+    /// `hash one-ay two-bee three-cee` for #1A2B3C
+    ///
+    /// Languages without names for hexadecimal digits cannot convert.
+    fn to_color(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads a phone number from its country code, without the `+`, and its
+    /// groups of digits, e.g. `plus thirty-one, six, one two three four`
+    ///
+    /// Languages without phone number readings cannot convert.
+    fn to_phone(&self, _country_code: Option<&str>, _groups: &[&str]) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads a time of day, e.g. `quarter past three` for 3:15, or `fifteen
+    /// fifteen` on a 24-hour clock
+    ///
+    /// The hour is below 24 and the minute below 60. Languages without clock
+    /// readings cannot convert.
+    fn to_time(&self, _hour: u32, _minute: u32, _clock_24: bool) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads a year ending in zero as its decade, e.g. `the nineteen-nineties`
+    ///
+    /// Languages without decade phrasing cannot convert.
+    fn to_decade(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads a year ending in two zeros as its century, e.g.
+    /// `the twentieth century`
+    ///
+    /// Languages without century phrasing cannot convert.
+    fn to_century(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads the number as a percentage, e.g. `five percent`
+    ///
+    /// By default the cardinal is followed by [`Language::percent_word`],
+    /// languages whose word agrees with the number read it themselves.
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = self.percent_word().ok_or(Num2Err::CannotConvert)?;
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+
+    /// Reads the number as a per-mille value, e.g. `twelve per mille`
+    ///
+    /// By default the cardinal is followed by [`Language::permille_word`].
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = self.permille_word().ok_or(Num2Err::CannotConvert)?;
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+
+    /// Reads the number rounded to its `digits` most significant digits,
+    /// e.g. `about a hundred`
+    ///
+    /// By default the rounded cardinal follows
+    /// [`Language::approximate_word`]. Languages without a hedge word cannot
+    /// convert.
+    fn to_approximate(&self, num: BigFloat, digits: usize) -> Result<String, Num2Err> {
+        let word = self.approximate_word().ok_or(Num2Err::CannotConvert)?;
+        if num.is_inf() {
+            return Err(Num2Err::CannotConvert);
+        }
+        let rounded = round_significant(num, digits);
+        Ok(format!("{} {}", word, self.to_cardinal(rounded)?))
+    }
+
+    /// Reads the number with a fraction of a scale word, e.g.
+    /// `two and a half million`, see [`compact_scale`]
+    ///
+    /// Numbers that don't land on a half or a quarter of a scale word are
+    /// read as cardinals. Languages without such readings cannot convert.
+    fn to_compact(&self, _num: BigFloat) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Reads the fraction `numerator / denominator`, e.g. `seven over thirteen`
+    ///
+    /// Both parts are finite integers and the denominator is not zero. By
+    /// default the numerator is followed by [`Language::denominator_word`],
+    /// languages without it cannot convert.
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let plural = numerator.abs() != BigFloat::from(1);
+        let word = self
+            .denominator_word(denominator, plural)
+            .ok_or(Num2Err::CannotConvert)?;
+        Ok(format!("{} {}", self.to_cardinal(numerator)?, word))
+    }
+
+    /// Word for the denominator of a fraction, in the plural after numerators
+    /// other than one, e.g. `fifth` or `fifths`
+    ///
+    /// Denominators without such a word return `None`.
+    fn denominator_word(&self, _denominator: BigFloat, _plural: bool) -> Option<String> {
+        None
+    }
+
+    /// Reads `whole` and the fraction `numerator / denominator` as a mixed
+    /// number, e.g. `two and a half`
+    ///
+    /// The fraction is below one and not negative, `whole` carries the sign.
+    /// Languages without mixed numbers cannot convert.
+    fn to_mixed_fraction(
+        &self,
+        _whole: BigFloat,
+        _numerator: BigFloat,
+        _denominator: BigFloat,
+    ) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Marks up the words of a cardinal or currency reading as SSML, e.g.
+    /// `<speak>one thousand<break/> two hundred</speak>`
+    ///
+    /// Languages without SSML support return the words unchanged.
+    fn to_ssml(&self, words: String, _currency: Option<Currency>) -> String {
+        words
+    }
+
+    /// Reads a cardinal written in words back into a number, e.g. `402` for
+    /// `four hundred and two`
+    ///
+    /// The words are matched against the language's own cardinals, ignoring
+    /// case, spaces and hyphens. Languages with irregular readings override
+    /// it. Counts of a scale read as a scale themselves, e.g. the European
+    /// Portuguese "mil milhões" from a thousand million on, are not read.
+    fn parse_words(&self, words: &str) -> Result<BigFloat, Num2Err> {
+        crate::words2num::parse_words(self, words)
+    }
+
+    /// Spells a few canary values as cardinals, e.g. 0, 11 and -1, failing
+    /// on the first error or empty reading
+    ///
+    /// It gives a quick smoke test to new languages.
+    fn self_check(&self) -> Result<(), Num2Err> {
+        for num in [0.0, 1.0, 11.0, 100.0, 1000.0, -1.0, 1e6] {
+            if self.to_cardinal(BigFloat::from(num))?.trim().is_empty() {
+                return Err(Num2Err::CannotConvert);
+            }
+        }
+        Ok(())
+    }
+
+    /// Scale word for the given power of the group size, e.g. `2` for
+    /// "million" with groups of a thousand, see [`Language::grouping_scheme`]
+    fn scale_word(&self, power: usize) -> Option<&'static str>;
+
+    /// How digits are grouped under scale words, e.g. by ten thousand for
+    /// "万" and "億"
+    ///
+    /// Languages group by a thousand unless they say otherwise.
+    fn grouping_scheme(&self) -> GroupingScheme {
+        GroupingScheme::Thousands
+    }
+
+    /// Index of a scale word among the scale words, e.g. `1` for "million",
+    /// or `None` if the word isn't one
+    fn is_scale_word(&self, word: &str) -> Option<usize> {
+        (1..=MAX_SCALE)
+            .map_while(|power| self.scale_word(power))
+            .position(|w| w == word)
+    }
+
+    /// Reads already split groups, most significant first, as a cardinal,
+    /// e.g. `[1, 234]` as `one thousand two hundred and thirty-four`
+    ///
+    /// Languages without the grouping scheme cannot convert.
+    fn compose_from_groups(
+        &self,
+        _groups: &[u64],
+        _scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Spells a number from 0 to 999 as a cardinal into `buf` without
+    /// allocating, see [`spell_small_into`]
+    ///
+    /// Languages without it cannot convert.
+    fn spell_small_into<'a>(&self, _n: u16, _buf: &'a mut [u8]) -> Result<&'a str, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    /// Digit grouping and decimal separators, e.g. `(',', '.')` for 1,234.5
+    fn separators(&self) -> (char, char) {
+        (',', '.')
+    }
+
+    /// Plural of a scale word, e.g. `cents` for `cent`
+    ///
+    /// Scale words are invariant unless the language says otherwise.
+    fn scale_plural(&self, word: &'static str) -> String {
+        String::from(word)
+    }
+
+    /// Plural noun for a vague amount of the given power of ten, e.g.
+    /// `hundreds` for 2 and `thousands` for 3
+    ///
+    /// Languages without such nouns read the number as a cardinal.
+    fn vague_plural(&self, _power: usize) -> Option<String> {
+        None
+    }
+
+    /// Collective reading of a group of the given size, e.g. "met z'n
+    /// tweeën" in Dutch
+    ///
+    /// Languages without collective numbers read the number as a cardinal.
+    fn to_collective(&self, _num: BigFloat) -> Option<String> {
+        None
+    }
+
+    /// Word for one hundred, e.g. `hundred`
+    fn hundred_word(&self) -> &'static str;
+
+    /// Word for one thousand, e.g. `thousand`
+    fn thousand_word(&self) -> &'static str;
+
+    /// Word read after a percentage, e.g. `percent`
+    ///
+    /// Languages without it and without their own [`Language::to_percent`]
+    /// cannot convert percentages.
+    fn percent_word(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Word read after a per-mille value, e.g. `per mille`
+    ///
+    /// Languages without it and without their own [`Language::to_permille`]
+    /// cannot convert per-mille values.
+    fn permille_word(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Hedge word read before an approximate number, e.g. `about`
+    ///
+    /// Languages without it and without their own
+    /// [`Language::to_approximate`] cannot read approximations.
+    fn approximate_word(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Word used for `00` when reading a year in pairs, e.g. `hundred` in
+    /// "nineteen hundred"
+    fn year_hundred(&self) -> &'static str {
+        self.hundred_word()
+    }
+
+    /// Word used for a leading zero when reading a year in pairs, e.g. `oh`
+    /// in "nineteen oh-one"
+    ///
+    /// Languages without such a word simply read the digit.
+    fn year_oh(&self) -> Option<&'static str> {
+        None
+    }
+
+    /// Reads the last two digits of a year read in pairs, e.g. `01` in 1901
+    fn year_low_half(&self, low: u64) -> Result<String, Num2Err> {
+        if low == 0 {
+            return Ok(String::from(self.year_hundred()));
+        }
+
+        let low_word = self.to_cardinal(BigFloat::from(low))?;
+        match self.year_oh() {
+            Some(oh) if low < 10 => Ok(format!("{}-{}", oh, low_word)),
+            _ => Ok(low_word),
+        }
+    }
 }
 
 /// Languages available in `num2words`
@@ -75,6 +639,173 @@ pub enum Lang {
     /// );
     /// ```
     Frisian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Pashto).to_words(),
+    ///     Ok(String::from("دوه څلوېښت"))
+    /// );
+    /// ```
+    Pashto,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Belarusian).to_words(),
+    ///     Ok(String::from("сорак два"))
+    /// );
+    /// ```
+    Belarusian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Italian).to_words(),
+    ///     Ok(String::from("quarantadue"))
+    /// );
+    /// ```
+    Italian,
+    /// Portuguese from Portugal
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(16).lang(Lang::Portuguese).to_words(),
+    ///     Ok(String::from("dezasseis"))
+    /// );
+    /// ```
+    Portuguese,
+    /// Portuguese from Brazil
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(16).lang(Lang::Portuguese_BR).to_words(),
+    ///     Ok(String::from("dezesseis"))
+    /// );
+    /// ```
+    Portuguese_BR,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Polish).to_words(),
+    ///     Ok(String::from("czterdzieści dwa"))
+    /// );
+    /// ```
+    Polish,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Arabic).to_words(),
+    ///     Ok(String::from("اثنان وأربعون"))
+    /// );
+    /// ```
+    Arabic,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Japanese).to_words(),
+    ///     Ok(String::from("四十二"))
+    /// );
+    /// ```
+    Japanese,
+    /// Chinese in simplified characters, unless traditional or pinyin is preferred
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Chinese).to_words(),
+    ///     Ok(String::from("四十二"))
+    /// );
+    /// ```
+    Chinese,
+    /// Korean with Sino-Korean numerals, unless native numerals are preferred
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Korean).to_words(),
+    ///     Ok(String::from("사십이"))
+    /// );
+    /// ```
+    Korean,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Hindi).to_words(),
+    ///     Ok(String::from("बयालीस"))
+    /// );
+    /// ```
+    Hindi,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Swedish).to_words(),
+    ///     Ok(String::from("fyrtiotvå"))
+    /// );
+    /// ```
+    Swedish,
+    /// Norwegian Bokmål
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Norwegian).to_words(),
+    ///     Ok(String::from("førtito"))
+    /// );
+    /// ```
+    Norwegian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Danish).to_words(),
+    ///     Ok(String::from("toogfyrre"))
+    /// );
+    /// ```
+    Danish,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Romanian).to_words(),
+    ///     Ok(String::from("patruzeci și doi"))
+    /// );
+    /// ```
+    Romanian,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Czech).to_words(),
+    ///     Ok(String::from("čtyřicet dva"))
+    /// );
+    /// ```
+    Czech,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Slovak).to_words(),
+    ///     Ok(String::from("štyridsaťdva"))
+    /// );
+    /// ```
+    Slovak,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Greek).to_words(),
+    ///     Ok(String::from("σαράντα δύο"))
+    /// );
+    /// ```
+    Greek,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Finnish).to_words(),
+    ///     Ok(String::from("neljäkymmentäkaksi"))
+    /// );
+    /// ```
+    Finnish,
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang(Lang::Esperanto).to_words(),
+    ///     Ok(String::from("kvardek du"))
+    /// );
+    /// ```
+    Esperanto,
+    /// Third-party language registered with [`register_language`]
+    Custom(String),
 }
 
 impl FromStr for Lang {
@@ -82,40 +813,755 @@ impl FromStr for Lang {
 
     /// Parses a string to return a value of this type
     ///
-    /// | Locale    | Lang              | 42            |
-    /// | --------- | ----------------- | ------------- |
-    /// | `en`      | `Lang::English`   | forty-two     |
-    /// | `fr`      | `Lang::French`    | quarante-deux |
-    /// | `fr_BE`   | `Lang::French_BE` | quarante-deux |
-    /// | `fr_CH`   | `Lang::French_CH` | quarante-deux |
-    /// | `uk`      | `Lang::Ukrainian` | сорок два     |
-    /// | `nl`      | `Lang::Dutch`     | tweeënveertig |
-    /// | `fy`      | `Lang::Frisian`   | twaenfjirtich |
+    /// | Locale    | Lang                  | 42                 |
+    /// | --------- | --------------------- | ------------------ |
+    /// | `en`      | `Lang::English`       | forty-two          |
+    /// | `fr`      | `Lang::French`        | quarante-deux      |
+    /// | `fr_BE`   | `Lang::French_BE`     | quarante-deux      |
+    /// | `fr_CH`   | `Lang::French_CH`     | quarante-deux      |
+    /// | `uk`      | `Lang::Ukrainian`     | сорок два          |
+    /// | `nl`      | `Lang::Dutch`         | tweeënveertig      |
+    /// | `fy`      | `Lang::Frisian`       | twaenfjirtich      |
+    /// | `ps`      | `Lang::Pashto`        | دوه څلوېښت         |
+    /// | `be`      | `Lang::Belarusian`    | сорак два          |
+    /// | `it`      | `Lang::Italian`       | quarantadue        |
+    /// | `pt`      | `Lang::Portuguese`    | quarenta e dois    |
+    /// | `pt_BR`   | `Lang::Portuguese_BR` | quarenta e dois    |
+    /// | `pl`      | `Lang::Polish`        | czterdzieści dwa   |
+    /// | `ar`      | `Lang::Arabic`        | اثنان وأربعون      |
+    /// | `ja`      | `Lang::Japanese`      | 四十二           |
+    /// | `zh`      | `Lang::Chinese`       | 四十二           |
+    /// | `ko`      | `Lang::Korean`        | 사십이           |
+    /// | `hi`      | `Lang::Hindi`         | बयालीस             |
+    /// | `sv`      | `Lang::Swedish`       | fyrtiotvå          |
+    /// | `no`      | `Lang::Norwegian`     | førtito            |
+    /// | `da`      | `Lang::Danish`        | toogfyrre          |
+    /// | `ro`      | `Lang::Romanian`      | patruzeci și doi   |
+    /// | `cs`      | `Lang::Czech`         | čtyřicet dva       |
+    /// | `sk`      | `Lang::Slovak`        | štyridsaťdva       |
+    /// | `el`      | `Lang::Greek`         | σαράντα δύο        |
+    /// | `fi`      | `Lang::Finnish`       | neljäkymmentäkaksi |
+    /// | `eo`      | `Lang::Esperanto`     | kvardek du         |
+    ///
+    /// BCP-47 tags such as `fr-CH` or `nl-NL` are accepted too, see
+    /// [`resolve_locale`].
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        match input {
-            "en" => Ok(Self::English),
-            "fr" => Ok(Self::French),
-            "fr_BE" => Ok(Self::French_BE),
-            "fr_CH" => Ok(Self::French_CH),
-            "uk" => Ok(Self::Ukrainian),
-            _ => Err(()),
+        resolve_locale(input).map(|(lang, _)| lang).ok_or(())
+    }
+}
+
+// the language of one of the locales of `FromStr`, or a registered key
+fn from_locale(input: &str) -> Option<Lang> {
+    match input {
+        "en" => Some(Lang::English),
+        "fr" => Some(Lang::French),
+        "fr_BE" => Some(Lang::French_BE),
+        "fr_CH" => Some(Lang::French_CH),
+        "uk" => Some(Lang::Ukrainian),
+        "nl" => Some(Lang::Dutch),
+        "fy" => Some(Lang::Frisian),
+        "ps" => Some(Lang::Pashto),
+        "be" => Some(Lang::Belarusian),
+        "it" => Some(Lang::Italian),
+        "pt" => Some(Lang::Portuguese),
+        "pt_BR" => Some(Lang::Portuguese_BR),
+        "pl" => Some(Lang::Polish),
+        "ar" => Some(Lang::Arabic),
+        "ja" => Some(Lang::Japanese),
+        "zh" => Some(Lang::Chinese),
+        "ko" => Some(Lang::Korean),
+        "hi" => Some(Lang::Hindi),
+        "sv" => Some(Lang::Swedish),
+        "no" => Some(Lang::Norwegian),
+        "da" => Some(Lang::Danish),
+        "ro" => Some(Lang::Romanian),
+        "cs" => Some(Lang::Czech),
+        "sk" => Some(Lang::Slovak),
+        "el" => Some(Lang::Greek),
+        "fi" => Some(Lang::Finnish),
+        "eo" => Some(Lang::Esperanto),
+        _ if registry().lock().unwrap().contains_key(input) => {
+            Some(Lang::Custom(String::from(input)))
+        }
+        _ => None,
+    }
+}
+
+/// Finds the language of a BCP-47 tag such as `nl-NL`, `en-GB` or `fr-CA`,
+/// returning it with the locale it matched
+///
+/// The tag falls back from its region to its language, e.g. `fr-CA` matches
+/// `fr`, while `fr-CH` matches `fr_CH`. Scripts and extensions are ignored.
+///
+/// Example:
+/// ```
+/// use num2words::{resolve_locale, Lang};
+/// assert!(matches!(
+///     resolve_locale("pt-BR"),
+///     Some((Lang::Portuguese_BR, locale)) if locale == "pt_BR"
+/// ));
+/// assert!(matches!(
+///     resolve_locale("nl-NL"),
+///     Some((Lang::Dutch, locale)) if locale == "nl"
+/// ));
+/// assert!(resolve_locale("tlh").is_none());
+/// ```
+pub fn resolve_locale(tag: &str) -> Option<(Lang, String)> {
+    if let Some(lang) = from_locale(tag) {
+        return Some((lang, String::from(tag)));
+    }
+
+    let mut subtags = tag.split(['-', '_']);
+    let language = subtags.next()?.to_ascii_lowercase();
+    // a single letter starts the extensions, e.g. `-u-nu-latn`
+    let region = subtags.take_while(|s| s.len() > 1).find(|s| {
+        (s.len() == 2 && s.chars().all(|c| c.is_ascii_alphabetic()))
+            || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+    });
+
+    let mut candidates = vec![];
+    if let Some(region) = region {
+        candidates.push(format!("{}_{}", language, region.to_ascii_uppercase()));
+    }
+    candidates.push(language);
+
+    candidates
+        .into_iter()
+        .find_map(|locale| Some((from_locale(&locale)?, locale)))
+}
+
+/// Whether the number is 100, 1000, 10000, etc., whose ordinals make simple
+/// fraction denominators, e.g. "hundredth"
+pub fn is_power_of_ten(mut num: BigFloat) -> bool {
+    if num < BigFloat::from(100) || !num.frac().is_zero() {
+        return false;
+    }
+    while num > BigFloat::from(1) {
+        if !(num % BigFloat::from(10)).is_zero() {
+            return false;
+        }
+        num /= BigFloat::from(10);
+    }
+    true
+}
+
+/// Greatest common divisor of two whole numbers, e.g. 6 and 4 => 2
+pub fn gcd(a: BigFloat, b: BigFloat) -> BigFloat {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let rest = a % b;
+        a = b;
+        b = rest;
+    }
+    a
+}
+
+/// Index of the Slavic plural form agreeing with `num`: `0` for one (1, 21,
+/// 31, ...), `1` for few (2-4, 22-24, ...) and `2` for many (0, 5-20, 25, ...)
+pub fn slavic_plural(num: u64) -> usize {
+    match (num % 10, num % 100) {
+        (1, 11) => 2,
+        (1, _) => 0,
+        (2..=4, 12..=14) => 2,
+        (2..=4, _) => 1,
+        _ => 2,
+    }
+}
+
+/// Splits a whole number into groups as the scheme groups its digits, most
+/// significant first, e.g. 1234567 into `[123, 4567]` for myriads
+pub fn split_groups(mut num: BigFloat, scheme: GroupingScheme) -> Vec<u64> {
+    let mut groups = Vec::new();
+    num = num.abs();
+
+    while !num.is_zero() {
+        let size = BigFloat::from(match scheme {
+            GroupingScheme::Thousands => 1000,
+            GroupingScheme::Myriad => 10000,
+            GroupingScheme::Indian if groups.is_empty() => 1000,
+            GroupingScheme::Indian => 100,
+        });
+        groups.push((num % size).to_u64().unwrap());
+        num = (num / size).int();
+    }
+
+    groups.reverse();
+    groups
+}
+
+/// Splits a string of digits into groups as the scheme groups them, most
+/// significant first, e.g. "1234567" into `[123, 4567]` for myriads, however
+/// many digits there are
+pub fn split_digits(digits: &str, scheme: GroupingScheme) -> Vec<u64> {
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+
+    while end > 0 {
+        let size = match scheme {
+            GroupingScheme::Thousands => 3,
+            GroupingScheme::Myriad => 4,
+            GroupingScheme::Indian if groups.is_empty() => 3,
+            GroupingScheme::Indian => 2,
+        };
+        let start = end.saturating_sub(size);
+        groups.push(digits[start..end].parse().unwrap_or(0));
+        end = start;
+    }
+
+    groups.reverse();
+    groups
+}
+
+/// Index of the Polish plural form agreeing with `num`: `0` for one (only 1
+/// itself), `1` for few (2-4, 22-24, ...) and `2` for many (0, 5-21, 25, ...)
+pub fn polish_plural(num: BigFloat) -> usize {
+    let num = num.abs();
+    if num == BigFloat::from(1) {
+        return 0;
+    }
+    match slavic_plural((num % BigFloat::from(100)).to_u64().unwrap()) {
+        1 => 1,
+        _ => 2,
+    }
+}
+
+/// Index of the Czech and Slovak plural form agreeing with `num`: `0` for one
+/// (only 1 itself), `1` for few (2-4) and `2` for many (0, 5 and more, as
+/// compounds such as 22 agree with the whole number)
+pub fn czech_plural(num: BigFloat) -> usize {
+    let num = num.abs();
+    if num == BigFloat::from(1) {
+        0
+    } else if num >= BigFloat::from(2) && num <= BigFloat::from(4) {
+        1
+    } else {
+        2
+    }
+}
+
+/// Splits a finite, non-zero number into a mantissa between 1 and 10 (in
+/// absolute value) and a power of ten, e.g. 2500 into `(2.5, 3)`
+pub fn scientific_parts(num: BigFloat) -> (BigFloat, i64) {
+    let mut mantissa = num;
+    let mut exponent = 0;
+
+    while mantissa.abs() >= BigFloat::from(10) {
+        mantissa /= BigFloat::from(10);
+        exponent += 1;
+    }
+    while mantissa.abs() < BigFloat::from(1) {
+        mantissa *= BigFloat::from(10);
+        exponent -= 1;
+    }
+
+    (mantissa, exponent)
+}
+
+/// Digits of a whole number that isn't negative, however many there are,
+/// e.g. 1e40 => "1" followed by forty zeros
+pub fn whole_digits(num: BigFloat) -> String {
+    let places = place_values(num);
+    let len = places.first().map_or(1, |(_, power)| power + 1);
+    let mut digits = vec!['0'; len];
+    for (digit, power) in places {
+        digits[len - 1 - power] = char::from_digit(digit as u32, 10).unwrap_or('0');
+    }
+    digits.into_iter().collect()
+}
+
+/// Splits a whole number into its non-zero digits along with their power of
+/// ten, most significant first, e.g. 1050 => [(1, 3), (5, 1)]
+pub fn place_values(mut num: BigFloat) -> Vec<(u64, usize)> {
+    let mut places = vec![];
+    let mut power = 0;
+
+    while !num.is_zero() {
+        let digit = (num % BigFloat::from(10)).to_u64().unwrap();
+        if digit != 0 {
+            places.push((digit, power));
+        }
+        num = (num / BigFloat::from(10)).int();
+        power += 1;
+    }
+
+    places.reverse();
+    places
+}
+
+/// Power of ten of a vague multiple of a hundred or a scale word, read as
+/// "hundreds" or "thousands", e.g. 300 => 2 and 5000 => 3 but 100 and 3500
+/// are not vague
+pub fn vague_power(num: BigFloat) -> Option<usize> {
+    if num.is_inf() || num.is_negative() || !num.frac().is_zero() {
+        return None;
+    }
+    match place_values(num)[..] {
+        [(digit, power)] if digit > 1 && (power == 2 || (power >= 3 && power % 3 == 0)) => {
+            Some(power)
+        }
+        _ => None,
+    }
+}
+
+/// Count of the largest scale word of a million or more that the number
+/// lands on, in wholes and quarters below a hundred, e.g. 2_500_000 => (2,
+/// 2, 2) and 750_000_000 => (0, 3, 3), or `None` if the count is whole or
+/// falls between quarters
+pub fn compact_scale(num: BigFloat) -> Option<(u64, u8, usize)> {
+    if num.is_inf() || num.is_negative() {
+        return None;
+    }
+
+    let thousand = BigFloat::from(1000);
+    let mut scale = thousand * thousand;
+    let mut power = 2;
+    while num >= scale * thousand {
+        scale *= thousand;
+        power += 1;
+    }
+
+    // a fraction of the next scale word reads shorter, e.g. "half a billion"
+    for (scale, power) in [(scale * thousand, power + 1), (scale, power)] {
+        let quarters = num / scale * BigFloat::from(4);
+        if !quarters.frac().is_zero() {
+            continue;
+        }
+        match quarters.to_u64()? {
+            quarters if quarters >= 400 || quarters % 4 == 0 => continue,
+            quarters => return Some((quarters / 4, (quarters % 4) as u8, power)),
         }
     }
+    None
 }
 
-pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
+/// Whether two years are read in pairs with the same first pair, e.g. 1914
+/// and 1918, so that a range may leave it out of the last year
+pub fn shares_century(start: BigFloat, end: BigFloat) -> bool {
+    let century = |year: BigFloat| match year.to_u64() {
+        Some(year) if (1000..10000).contains(&year) && year % 100 >= 10 => Some(year / 100),
+        _ => None,
+    };
+    century(start).is_some_and(|century_start| century(end) == Some(century_start))
+}
+
+/// Joins words as a list, e.g. "a, b and c"
+pub fn join_list(words: &[String], conjunction: &str) -> String {
+    match words.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            format!("{} {} {}", rest.join(", "), conjunction, last)
+        }
+        _ => words.concat(),
+    }
+}
+
+/// Writes into a caller provided buffer, failing once it is full
+pub struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl<'a> SliceWriter<'a> {
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+
+    /// Returns what has been written so far
+    pub fn into_str(self) -> &'a str {
+        let buf: &'a [u8] = self.buf;
+        // only whole `str`s are ever written
+        std::str::from_utf8(&buf[..self.len]).unwrap()
+    }
+}
+
+impl std::fmt::Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.len + s.len();
+        if end > self.buf.len() {
+            return Err(std::fmt::Error);
+        }
+        self.buf[self.len..end].copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Keeps a word built at runtime for the rest of the program, as the
+/// `Language` trait hands out `&'static str`
+pub fn leak(word: String) -> &'static str {
+    Box::leak(word.into_boxed_str())
+}
+
+/// Keeps a word built at runtime like [`leak`], but only once however often
+/// it is built
+pub fn intern(word: String) -> &'static str {
+    static WORDS: OnceLock<Mutex<HashMap<String, &'static str>>> = OnceLock::new();
+    let mut words = WORDS
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap();
+    if let Some(interned) = words.get(&word) {
+        return interned;
+    }
+    let interned = leak(word.clone());
+    words.insert(word, interned);
+    interned
+}
+
+/// Last scale word a `BigFloat` reaches, about 10^167 even in groups of a
+/// thousand
+const MAX_SCALE: usize = 55;
+
+/// Latin stem of the `n`th -illion after Conway and Wechsler, to which
+/// languages add their ending, e.g. "m" for "million", "dec" for
+/// "decillion", "tresvigint" for "tresvigintillion" and "millin" for
+/// "millinillion", or `None` for 0
+pub fn illion_stem(n: usize) -> Option<String> {
+    const FIRST: [&str; 10] = [
+        "n", "m", "b", "tr", "quadr", "quint", "sext", "sept", "oct", "non",
+    ];
+    const UNITS: [&str; 10] = [
+        "", "un", "duo", "tre", "quattuor", "quinqua", "se", "septe", "octo", "nove",
+    ];
+    // prefixes with the marks changing the units before them, e.g. "tres"
+    // before an S and "septem" before an M
+    const TENS: [(&str, &str); 10] = [
+        ("", ""),
+        ("deci", "N"),
+        ("viginti", "MS"),
+        ("triginta", "NS"),
+        ("quadraginta", "NS"),
+        ("quinquaginta", "NS"),
+        ("sexaginta", "N"),
+        ("septuaginta", "N"),
+        ("octoginta", "MX"),
+        ("nonaginta", ""),
+    ];
+    const HUNDREDS: [(&str, &str); 10] = [
+        ("", ""),
+        ("centi", "NX"),
+        ("ducenti", "N"),
+        ("trecenti", "NS"),
+        ("quadringenti", "NS"),
+        ("quingenti", "NS"),
+        ("sescenti", "N"),
+        ("septingenti", "N"),
+        ("octingenti", "MX"),
+        ("nongenti", ""),
+    ];
+
+    // e.g. "tresvigint" for 23 and "n" for none
+    let stem = |n: usize| {
+        if n < 10 {
+            return String::from(FIRST[n]);
+        }
+        let (units, (tens, tens_marks), (hundreds, hundreds_marks)) =
+            (n % 10, TENS[n / 10 % 10], HUNDREDS[n / 100]);
+        let marks = match tens.is_empty() {
+            true => hundreds_marks,
+            false => tens_marks,
+        };
+        let unit = match (units, marks) {
+            (3, m) if m.contains('S') || m.contains('X') => "tres",
+            (6, m) if m.contains('S') => "ses",
+            (6, m) if m.contains('X') => "sex",
+            (7, m) if m.contains('M') => "septem",
+            (7, m) if m.contains('N') => "septen",
+            (9, m) if m.contains('M') => "novem",
+            (9, m) if m.contains('N') => "noven",
+            _ => UNITS[units],
+        };
+
+        // the last vowel gives way to the ending, e.g. "viginti" => "vigint"
+        let mut stem = format!("{}{}{}", unit, tens, hundreds);
+        stem.pop();
+        stem
+    };
+
+    if n == 0 {
+        return None;
+    }
+
+    // past the 999th, each group of three digits gets its own stem, joined
+    // by "illi", e.g. "millinillion" for the 1000th
+    let mut groups = vec![];
+    let mut rest = n;
+    while rest > 0 {
+        groups.push(stem(rest % 1000));
+        rest /= 1000;
+    }
+    groups.reverse();
+    Some(groups.join("illi"))
+}
+
+/// Splits a phone number such as `+31 6-1234 5678` into its country code
+/// and its groups of digits, or `None` if it holds anything else
+pub fn split_phone(number: &str) -> Option<(Option<&str>, Vec<&str>)> {
+    let number = number.trim();
+    let (country_code, rest) = match number.strip_prefix('+') {
+        Some(rest) => {
+            let end = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            (Some(&rest[..end]), &rest[end..])
+        }
+        None => (None, number),
+    };
+    if country_code == Some("") {
+        return None;
+    }
+
+    let groups: Vec<&str> = rest
+        .split([' ', '-', '.', '(', ')', '/'])
+        .filter(|g| !g.is_empty())
+        .collect();
+    if groups.is_empty() || !groups.iter().all(|g| g.chars().all(|c| c.is_ascii_digit())) {
+        return None;
+    }
+    Some((country_code, groups))
+}
+
+/// Whether the number needs scale words above a million, which simple
+/// readings avoid
+pub fn is_really_big(num: BigFloat) -> bool {
+    !num.is_inf() && num.abs() >= BigFloat::from(1e9)
+}
+
+/// Rounds the number to its `digits` most significant digits, e.g. 1940 =>
+/// 2000 with one digit and 1940 => 1900 with two
+pub fn round_significant(num: BigFloat, digits: usize) -> BigFloat {
+    if num.is_inf() || num.is_zero() {
+        return num;
+    }
+
+    let (mut mantissa, mut exponent) = scientific_parts(num);
+    for _ in 1..digits {
+        mantissa *= BigFloat::from(10);
+        exponent -= 1;
+    }
+    let mut rounded = (mantissa.abs() + BigFloat::from(0.5)).int();
+    for _ in 0..exponent.unsigned_abs() {
+        if exponent > 0 {
+            rounded *= BigFloat::from(10);
+        } else {
+            rounded /= BigFloat::from(10);
+        }
+    }
+
+    if num.is_negative() {
+        -rounded
+    } else {
+        rounded
+    }
+}
+
+/// Wraps words in `<speak>`, adding a `<break/>` after each group ending in
+/// one of the `scales` words and substituting the currency `unit` by its code
+pub fn ssml(words: &str, scales: &[&str], unit: Option<(&str, Currency)>) -> String {
+    let mut tokens: Vec<String> = vec![];
+    let mut split = words.split(' ').peekable();
+
+    while let Some(w) = split.next() {
+        if split.peek().is_some() && scales.iter().any(|s| w.ends_with(s)) {
+            tokens.push(format!("{}<break/>", w));
+        } else {
+            tokens.push(String::from(w));
+        }
+    }
+
+    let mut words = tokens.join(" ");
+    if let Some((unit, currency)) = unit {
+        words = words.replacen(
+            unit,
+            &format!("<sub alias=\"{}\">{:?}</sub>", unit, currency),
+            1,
+        );
+    }
+
+    format!("<speak>{}</speak>", words)
+}
+
+/// Registers a third-party language, making it available as
+/// `Lang::Custom(key)`
+///
+/// Registering an existing key replaces its language.
+///
+/// Example:
+/// ```
+/// use num2words::{register_language, Lang, Num2Words};
+/// # use num2words::{Currency, Language, Num2Err};
+/// # use num_bigfloat::BigFloat;
+/// # struct Digits;
+/// # impl Language for Digits {
+/// #     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+/// #         Ok(num.to_i64().unwrap().to_string())
+/// #     }
+/// #     fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+/// #         self.to_cardinal(num)
+/// #     }
+/// #     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+/// #         self.to_cardinal(num)
+/// #     }
+/// #     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+/// #         self.to_cardinal(num)
+/// #     }
+/// #     fn to_currency(&self, num: BigFloat, _: Currency) -> Result<String, Num2Err> {
+/// #         self.to_cardinal(num)
+/// #     }
+/// #     fn scale_word(&self, _: usize) -> Option<&'static str> { None }
+/// #     fn hundred_word(&self) -> &'static str { "100" }
+/// #     fn thousand_word(&self) -> &'static str { "1000" }
+/// # }
+/// register_language("digits", || Box::new(Digits));
+/// assert_eq!(
+///     Num2Words::new(42)
+///         .lang(Lang::Custom(String::from("digits")))
+///         .to_words(),
+///     Ok(String::from("42"))
+/// );
+/// ```
+pub fn register_language(key: &str, factory: LanguageFactory) {
+    registry()
+        .lock()
+        .unwrap()
+        .insert(String::from(key), factory);
+}
+
+/// Lists the built-in languages followed by the registered ones, see
+/// [`register_language`]
+///
+/// Example:
+/// ```
+/// use num2words::{supported_languages, Num2Words};
+/// for lang in supported_languages() {
+///     assert!(Num2Words::new(42).lang(lang).to_words().is_ok());
+/// }
+/// ```
+pub fn supported_languages() -> Vec<Lang> {
+    let mut langs = vec![
+        Lang::English,
+        Lang::French,
+        Lang::French_BE,
+        Lang::French_CH,
+        Lang::Ukrainian,
+        Lang::Dutch,
+        Lang::Frisian,
+        Lang::Pashto,
+        Lang::Belarusian,
+        Lang::Italian,
+        Lang::Portuguese,
+        Lang::Portuguese_BR,
+        Lang::Polish,
+        Lang::Arabic,
+        Lang::Japanese,
+        Lang::Chinese,
+        Lang::Korean,
+        Lang::Hindi,
+        Lang::Swedish,
+        Lang::Norwegian,
+        Lang::Danish,
+        Lang::Romanian,
+        Lang::Czech,
+        Lang::Slovak,
+        Lang::Greek,
+        Lang::Finnish,
+        Lang::Esperanto,
+    ];
+    let mut keys: Vec<String> = registry().lock().unwrap().keys().cloned().collect();
+    keys.sort();
+    langs.extend(keys.into_iter().map(Lang::Custom));
+    langs
+}
+
+fn registry() -> &'static Mutex<HashMap<String, LanguageFactory>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, LanguageFactory>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Spells a number from 0 to 999 as a cardinal into `buf` without
+/// allocating, returning the written part of `buf`
+///
+/// Returns [`Num2Err::CannotConvert`] if the number is above 999, if `buf`
+/// is too small or if the language does not support it. Only English and
+/// Dutch support it.
+///
+/// Example:
+/// ```
+/// use num2words::{spell_small_into, Lang};
+/// let mut buf = [0; 64];
+/// assert_eq!(
+///     spell_small_into(&Lang::English, 42, &mut buf),
+///     Ok("forty-two")
+/// );
+/// ```
+pub fn spell_small_into<'a>(lang: &Lang, n: u16, buf: &'a mut [u8]) -> Result<&'a str, Num2Err> {
     match lang {
+        Lang::English => lang::English::new(false, false).spell_small_into(n, buf),
+        Lang::Dutch => lang::Dutch::new().spell_small_into(n, buf),
+        _ => to_language(lang, &[])?.spell_small_into(n, buf),
+    }
+}
+
+pub fn to_language(lang: &Lang, preferences: &[String]) -> Result<Box<dyn Language>, Num2Err> {
+    Ok(match lang {
         Lang::English => {
             let last = preferences
                 .iter()
                 .rev()
-                .find(|v| ["oh", "nil"].contains(&v.as_str()));
-
-            if let Some(v) = last {
-                return Box::new(lang::English::new(v == "oh", v == "nil"));
-            }
+                .find(|v| ["oh", "nil"].contains(&v.as_str()))
+                .map(|v| v.as_str());
+            let paren = preferences.iter().any(|v| v == "paren");
+            let currency_and = preferences.iter().any(|v| v == "currency-and");
+            let decimal_currency = preferences.iter().any(|v| v == "decimal-currency");
+            let abbrev = preferences.iter().any(|v| v == "abbrev");
+            let simple = preferences.iter().any(|v| v == "simple");
+            let fraction_group = match preferences
+                .iter()
+                .rev()
+                .find(|v| {
+                    ["mixed", "mixed-pairs", "mixed-triples", "whole-fraction"]
+                        .contains(&v.as_str())
+                })
+                .map(|v| v.as_str())
+            {
+                Some("mixed-pairs") => 2,
+                Some("mixed-triples") => 3,
+                Some("whole-fraction") => MAX_FRACTION_DIGITS,
+                _ => 1,
+            };
+            let names = preferences
+                .iter()
+                .rev()
+                .find(|v| ["native", "iso"].contains(&v.as_str()))
+                .map(|v| v.as_str());
+            let decimal_scale = preferences.iter().any(|v| v == "decimal-scale");
+            let phone_double = preferences.iter().any(|v| v == "double");
+            let negative = preferences.iter().any(|v| v == "negative");
+            let milliard = preferences.iter().any(|v| v == "milliard");
+            let decimal_word = match preferences
+                .iter()
+                .rev()
+                .find(|v| ["point", "comma"].contains(&v.as_str()))
+                .map(|v| v.as_str())
+            {
+                Some("comma") => "comma",
+                _ => "point",
+            };
 
-            Box::new(lang::English::new(false, false))
+            Box::new(
+                lang::English::new(last == Some("oh"), last == Some("nil"))
+                    .with_paren(paren)
+                    .with_currency_and(currency_and)
+                    .with_decimal_currency(decimal_currency)
+                    .with_abbrev(abbrev)
+                    .with_simple(simple)
+                    .with_fraction_group(fraction_group)
+                    .with_currency_names(names == Some("native"), names == Some("iso"))
+                    .with_decimal_scale(decimal_scale)
+                    .with_phone_double(phone_double)
+                    .with_negative(negative)
+                    .with_decimal_word(decimal_word)
+                    .with_milliard(milliard),
+            )
         }
         Lang::French => {
             let feminine = preferences
@@ -150,16 +1596,20 @@ pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
                 .iter()
                 .find(|v: &&String| ["reformed", "1990", "rectifié", "rectification"].contains(&v.as_str()))
                 .is_some();
+            let octante = preferences.iter().any(|v| v == "octante");
 
-            Box::new(lang::French::new(feminine, reformed, lang::fr::RegionFrench::CH))
+            Box::new(
+                lang::French::new(feminine, reformed, lang::fr::RegionFrench::CH)
+                    .with_octante(octante),
+            )
         }
         Lang::Ukrainian => {
-            let declension: lang::uk::Declension = preferences
+            let declension: Case = preferences
                 .iter()
                 .rev()
                 .find_map(|d| d.parse().ok())
                 .unwrap_or_default();
-            let gender: lang::uk::Gender = preferences
+            let gender: Gender = preferences
                 .iter()
                 .rev()
                 .find_map(|d| d.parse().ok())
@@ -172,10 +1622,125 @@ pub fn to_language(lang: Lang, preferences: Vec<String>) -> Box<dyn Language> {
             Box::new(lang::Ukrainian::new(gender, number, declension))
         }
         Lang::Dutch => {
-            Box::new(lang::Dutch::new())
+            let paren = preferences.iter().any(|v| v == "paren");
+            let decimal_currency = preferences.iter().any(|v| v == "decimal-currency");
+            let plural_unit = preferences.iter().any(|v| v == "plural-unit");
+            let simple = preferences.iter().any(|v| v == "simple");
+            let show_positive = preferences.iter().any(|v| v == "show-positive");
+            let whole_fraction = preferences.iter().any(|v| v == "whole-fraction");
+            let ordinal_num_long = preferences.iter().any(|v| v == "ste-de");
+            let negative = preferences
+                .iter()
+                .any(|v| ["negative", "negatief"].contains(&v.as_str()));
+            let decimal_word = match preferences
+                .iter()
+                .rev()
+                .find(|v| ["komma", "punt"].contains(&v.as_str()))
+                .map(|v| v.as_str())
+            {
+                Some("punt") => "punt",
+                _ => "komma",
+            };
+
+            Box::new(
+                lang::Dutch::new()
+                    .with_paren(paren)
+                    .with_decimal_currency(decimal_currency)
+                    .with_plural_unit(plural_unit)
+                    .with_simple(simple)
+                    .with_show_positive(show_positive)
+                    .with_negative(negative)
+                    .with_decimal_word(decimal_word)
+                    .with_whole_fraction(whole_fraction)
+                    .with_ordinal_num_long(ordinal_num_long),
+            )
         }
         Lang::Frisian => {
-            Box::new(lang::Frisian::new())
+            let paren = preferences.iter().any(|v| v == "paren");
+            let plural_unit = preferences.iter().any(|v| v == "plural-unit");
+            let trema = !preferences.iter().any(|v| v == "no-trema");
+            let decimal_word = match preferences
+                .iter()
+                .rev()
+                .find(|v| ["komma", "punt"].contains(&v.as_str()))
+                .map(|v| v.as_str())
+            {
+                Some("punt") => "punt",
+                _ => "komma",
+            };
+
+            Box::new(
+                lang::Frisian::new()
+                    .with_paren(paren)
+                    .with_plural_unit(plural_unit)
+                    .with_trema(trema)
+                    .with_decimal_word(decimal_word),
+            )
         }
-    }
+        Lang::Pashto => Box::new(lang::Pashto::new()),
+        Lang::Belarusian => Box::new(lang::Belarusian::new()),
+        Lang::Italian => Box::new(lang::Italian::new()),
+        Lang::Portuguese | Lang::Portuguese_BR => {
+            let feminine = preferences
+                .iter()
+                .any(|v| ["feminine", "feminino", "f"].contains(&v.as_str()));
+            let region = if matches!(lang, Lang::Portuguese_BR) {
+                lang::pt::RegionPortuguese::BR
+            } else {
+                lang::pt::RegionPortuguese::PT
+            };
+
+            Box::new(lang::Portuguese::new(feminine, region))
+        }
+        Lang::Polish => Box::new(lang::Polish::new()),
+        Lang::Arabic => {
+            let feminine = preferences
+                .iter()
+                .any(|v| ["feminine", "f", "مؤنث"].contains(&v.as_str()));
+
+            Box::new(lang::Arabic::new(feminine))
+        }
+        Lang::Japanese => Box::new(lang::Japanese::new()),
+        Lang::Chinese => {
+            let script = preferences
+                .iter()
+                .rev()
+                .find_map(|d| d.parse().ok())
+                .unwrap_or_default();
+
+            Box::new(lang::Chinese::new().with_script(script))
+        }
+        Lang::Korean => {
+            let system = preferences
+                .iter()
+                .rev()
+                .find_map(|d| d.parse().ok())
+                .unwrap_or_default();
+
+            Box::new(lang::Korean::new().with_system(system))
+        }
+        Lang::Hindi => Box::new(lang::Hindi::new()),
+        Lang::Swedish => Box::new(lang::Swedish::new()),
+        Lang::Norwegian => Box::new(lang::Norwegian::new()),
+        Lang::Danish => Box::new(lang::Danish::new()),
+        Lang::Romanian => {
+            let feminine = preferences
+                .iter()
+                .any(|v| ["feminine", "feminin", "f"].contains(&v.as_str()));
+
+            Box::new(lang::Romanian::new(feminine))
+        }
+        Lang::Czech => Box::new(lang::Czech::new()),
+        Lang::Slovak => Box::new(lang::Slovak::new()),
+        Lang::Greek => Box::new(lang::Greek::new()),
+        Lang::Finnish => Box::new(lang::Finnish::new()),
+        Lang::Esperanto => Box::new(lang::Esperanto::new()),
+        Lang::Custom(key) => {
+            let factory = registry().lock().unwrap().get(key).copied();
+            match factory {
+                Some(factory) => factory(),
+                None => return Err(Num2Err::CannotConvert),
+            }
+        }
+    })
 }
@@ -1,3 +1,4 @@
+#[allow(clippy::module_inception)]
 mod lang;
 mod en;
 mod fr;
@@ -7,12 +8,112 @@ mod nl;
 
 mod fy;
 
+mod ps;
+
+mod be;
+
+mod it;
+
+mod pt;
+
+mod pl;
+
+mod ar;
+
+mod ja;
+
+mod zh;
+
+mod ko;
+
+mod hi;
+
+mod germanic;
+mod sv;
+mod no;
+mod da;
+
+mod ro;
+
+mod cs;
+
+mod sk;
+
+mod el;
+
+mod fi;
+
+mod eo;
+
+mod data;
+
+mod rbnf;
+
 pub use en::English;
 pub use fr::French;
 pub use uk::Ukrainian;
 pub use nl::Dutch;
 pub use fy::Frisian;
+pub use ps::Pashto;
+pub use be::Belarusian;
+pub use it::Italian;
+pub use pt::Portuguese;
+pub use pl::Polish;
+pub use ar::Arabic;
+pub use ja::Japanese;
+pub use zh::Chinese;
+pub use ko::Korean;
+pub use hi::Hindi;
+pub use sv::Swedish;
+pub use no::Norwegian;
+pub use da::Danish;
+pub use ro::Romanian;
+pub use cs::Czech;
+pub use sk::Slovak;
+pub use el::Greek;
+pub use fi::Finnish;
+pub use eo::Esperanto;
+
+pub use data::DataDrivenLanguage;
+pub use data::LanguageDefinition;
+
+pub use rbnf::register_rbnf;
+pub use rbnf::RbnfLanguage;
+pub(crate) use rbnf::rbnf_language;
 
 pub use lang::to_language;
+pub use lang::Case;
+pub use lang::Era;
+pub use lang::Gender;
+pub use lang::GroupingScheme;
 pub use lang::Lang;
 pub use lang::Language;
+pub use lang::register_language;
+pub use lang::resolve_locale;
+pub use lang::spell_small_into;
+pub use lang::supported_languages;
+pub use lang::LanguageFactory;
+pub use lang::Scale;
+pub use lang::ZeroOrdinal;
+pub(crate) use lang::compact_scale;
+pub(crate) use lang::czech_plural;
+pub(crate) use lang::gcd;
+pub(crate) use lang::illion_stem;
+pub(crate) use lang::intern;
+pub(crate) use lang::is_power_of_ten;
+pub(crate) use lang::is_really_big;
+pub(crate) use lang::join_list;
+pub(crate) use lang::place_values;
+pub(crate) use lang::polish_plural;
+pub(crate) use lang::round_significant;
+pub(crate) use lang::scientific_parts;
+pub(crate) use lang::shares_century;
+pub(crate) use lang::slavic_plural;
+pub(crate) use lang::split_digits;
+pub(crate) use lang::split_groups;
+pub(crate) use lang::split_phone;
+pub(crate) use lang::ssml;
+pub(crate) use lang::vague_power;
+pub(crate) use lang::whole_digits;
+pub(crate) use lang::SliceWriter;
+pub(crate) use lang::MAX_FRACTION_DIGITS;
@@ -1,7 +1,26 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::{
+    lang::{
+        compact_scale, illion_stem, intern, is_power_of_ten, is_really_big, join_list,
+        place_values, scientific_parts, shares_century, ssml, GroupingScheme, SliceWriter,
+        MAX_FRACTION_DIGITS,
+    },
+    num2words::Num2Err,
+    Currency, Era, Language, Unit,
+};
 use num_bigfloat::BigFloat;
+use std::fmt::Write;
 
-pub struct Dutch {}
+pub struct Dutch {
+    paren: bool,
+    decimal_currency: bool,
+    plural_unit: bool,
+    simple: bool,
+    show_positive: bool,
+    minus: &'static str,
+    decimal_word: &'static str,
+    whole_fraction: bool,
+    ordinal_num_long: bool,
+}
 
 const UNITS: [&str; 9] = [
     "één", "twee", "drie", "vier", "vijf", "zes", "zeven", "acht", "negen",
@@ -27,6 +46,10 @@ const TEENS: [&str; 10] = [
 // As defined by the AHD4, CED, RHD2, W3 and UM authorities
 // For more information, see
 // https://en.wikipedia.org/wiki/Names_of_large_numbers
+// irregular ordinals, matched against the end of the cardinal, e.g.
+// "honderdéén" => "honderdeerste"
+const ORDINALS: [(&str, &str); 3] = [("één", "eerste"), ("drie", "derde"), ("acht", "achtste")];
+
 const MEGAS: [&str; 21] = [
     "duizend",
     "miljoen",
@@ -51,35 +74,217 @@ const MEGAS: [&str; 21] = [
     "deciljard",
 ];
 
-fn space_words(words: &mut Vec<String>) {
-    for x in (0..words.len()).rev() {
-        let word: String = words.get(x).unwrap().clone();
-        if ["minus"].contains(&word.as_str()) {
-            words.insert(x + 1, " ".to_string());
-        } else if ["komma"].contains(&word.as_str()) {
-            words.insert(x, " ".to_string());
-        } else if MEGAS.contains(&word.as_str()) {
-            if x != words.len() - 1 {
+// words read after years before and within the era of Anno Domini and the
+// common era
+const ERA_WORDS: [(&str, &str); 2] = [
+    ("voor christus", "na christus"),
+    ("voor onze jaartelling", "onze jaartelling"),
+];
+
+impl Dutch {
+    pub fn new() -> Self {
+        Self {
+            paren: false,
+            decimal_currency: false,
+            plural_unit: false,
+            simple: false,
+            show_positive: false,
+            minus: "minus",
+            decimal_word: "komma",
+            whole_fraction: false,
+            ordinal_num_long: false,
+        }
+    }
+
+    // the sign read before a negative number, e.g. "minus "
+    fn sign(&self, negative: bool) -> String {
+        match negative {
+            true => format!("{} ", self.minus),
+            false => String::new(),
+        }
+    }
+
+    fn space_words(&self, words: &mut Vec<String>) {
+        for x in (0..words.len()).rev() {
+            let word: String = words.get(x).unwrap().clone();
+            if word == self.minus {
                 words.insert(x + 1, " ".to_string());
-            }
-            if !word.eq("duizend") {
+            } else if word == self.decimal_word {
                 words.insert(x, " ".to_string());
+            } else if let Some(index) = self.is_scale_word(&word) {
+                if x != words.len() - 1 {
+                    words.insert(x + 1, " ".to_string());
+                }
+                if index != 0 {
+                    words.insert(x, " ".to_string());
+                }
             }
         }
     }
-}
 
-impl Dutch {
-    pub fn new() -> Self {
-        Self {}
+    pub fn with_paren(mut self, paren: bool) -> Self {
+        self.paren = paren;
+        self
+    }
+
+    pub fn with_plural_unit(mut self, plural_unit: bool) -> Self {
+        self.plural_unit = plural_unit;
+        self
+    }
+
+    pub fn with_decimal_currency(mut self, decimal_currency: bool) -> Self {
+        self.decimal_currency = decimal_currency;
+        self
+    }
+
+    pub fn with_simple(mut self, simple: bool) -> Self {
+        self.simple = simple;
+        self
+    }
+
+    pub fn with_show_positive(mut self, show_positive: bool) -> Self {
+        self.show_positive = show_positive;
+        self
+    }
+
+    pub fn with_negative(mut self, negative: bool) -> Self {
+        self.minus = if negative { "negatief" } else { "minus" };
+        self
+    }
+
+    pub fn with_decimal_word(mut self, decimal_word: &'static str) -> Self {
+        self.decimal_word = decimal_word;
+        self
+    }
+
+    pub fn with_whole_fraction(mut self, whole_fraction: bool) -> Self {
+        self.whole_fraction = whole_fraction;
+        self
+    }
+
+    pub fn with_ordinal_num_long(mut self, ordinal_num_long: bool) -> Self {
+        self.ordinal_num_long = ordinal_num_long;
+        self
+    }
+
+    // e.g. "met z'n tweeën", "met z'n vieren", "met z'n vijven"
+    fn collective_word(word: String) -> String {
+        let word = if word.ends_with('e') {
+            format!("{}ën", word)
+        } else if let Some(stem) = word.strip_suffix('f') {
+            format!("{}ven", stem)
+        } else if word.ends_with("zes") {
+            format!("{}sen", word)
+        } else {
+            format!("{}en", word)
+        };
+        format!("met z'n {}", word)
+    }
+
+    // units stay singular after a number, e.g. "twee euro", unless the
+    // plural-unit preference asks otherwise
+    fn currencies(&self, currency: Currency, plural: bool) -> String {
+        match (currency, plural && self.plural_unit) {
+            (Currency::Custom { .. }, plural) => currency.default_string(plural),
+            (_, true) => Self::plural(currency.default_string(false)),
+            (_, false) => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural: bool) -> String {
+        match (currency, plural && self.plural_unit) {
+            (Currency::Custom { .. }, plural) => currency.default_subunit_string("cent{}", plural),
+            (_, true) => Self::plural(currency.default_subunit_string("cent{}", false)),
+            (_, false) => currency.default_subunit_string("cent{}", false),
+        }
+    }
+
+    // e.g. "euro's", "dollars" and "centen"
+    fn plural(word: String) -> String {
+        if word.ends_with(['a', 'i', 'o', 'u', 'y']) {
+            format!("{}'s", word)
+        } else if ["e", "ar", "el", "em", "en", "er", "je"]
+            .iter()
+            .any(|end| word.ends_with(end))
+        {
+            format!("{}s", word)
+        } else {
+            format!("{}en", word)
+        }
+    }
+
+    // the cardinal without the sign added by show-positive
+    fn plain_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if self.simple && is_really_big(num) {
+            Ok(format!(
+                "{}een heel groot getal",
+                self.sign(num.is_negative())
+            ))
+        } else if num.is_inf_pos() {
+            Ok(String::from("oneindig"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("negatief oneindig"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn paren_wrap(&self, word: String, negative: bool) -> String {
+        if negative && self.paren {
+            format!("({})", word)
+        } else {
+            word
+        }
     }
 
-    fn currencies(&self, currency: Currency) -> String {
-        currency.default_string(false)
+    // e.g. "drieënzeventig" => "drieënzeventigste"
+    fn ordinal_word(word: &str) -> String {
+        if let Some((cardinal, ordinal)) = ORDINALS.iter().find(|(c, _)| word.ends_with(c)) {
+            format!("{}{}", &word[..word.len() - cardinal.len()], ordinal)
+        } else if word.ends_with("tig")
+            || word.ends_with("honderd")
+            || MEGAS.iter().any(|m| word.ends_with(m))
+        {
+            format!("{}ste", word)
+        } else {
+            format!("{}de", word)
+        }
+    }
+
+    // "en" takes a trema only where its "e" would otherwise be read together
+    // with the vowel before it, e.g. "tweeën" and "drieën" but "vieren"
+    fn junction(word: &str) -> &'static str {
+        if word.ends_with(['a', 'e', 'i', 'o', 'u']) {
+            "ën"
+        } else {
+            "en"
+        }
     }
 
-    fn cents(&self, currency: Currency) -> String {
-        currency.default_subunit_string("cent{}", false)
+    // e.g. 1 => "tiental", 4 => "tienduizendtal"
+    fn place_word(&self, power: usize, plural: bool) -> Result<String, Num2Err> {
+        if power == 0 {
+            return Ok(String::from(if plural { "eenheden" } else { "eenheid" }));
+        }
+
+        let scale = match power / 3 {
+            0 => "",
+            i => self.scale_word(i).ok_or(Num2Err::CannotConvert)?,
+        };
+        let prefix = match power % 3 {
+            0 => "",
+            1 => "tien",
+            _ => self.hundred_word(),
+        };
+
+        Ok(format!(
+            "{}{}{}",
+            prefix,
+            scale,
+            if plural { "tallen" } else { "tal" }
+        ))
     }
 
     fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
@@ -103,85 +308,38 @@ impl Dutch {
         }
 
         // handling negative values
-        let mut words = vec![];
-        if num.is_negative() {
-            words.push(String::from("minus"));
+        let negative = num.is_negative();
+        if negative {
             num = -num;
         }
 
-        // iterate over thousands
-        let mut first_elem = true;
-        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
-            let hundreds = (triplet / 100 % 10) as usize;
-            let tens = (triplet / 10 % 10) as usize;
-            let units = (triplet % 10) as usize;
-
-            if hundreds > 0 {
-                if hundreds > 1 {
-                    words.push(String::from(UNITS[hundreds - 1]));
-                }
-                words.push(String::from("honderd"));
-            }
-
-            if tens != 0 || units != 0 {
-                if i == 0 && !first_elem {
-                    if let Some(last) = words.last() {
-                        if !MEGAS.contains(&last.as_str()) {
-                            if last.ends_with('e') {
-                                words.push(String::from("ën"));
-                            } else {
-                                words.push(String::from("en"));
-                            }
-                        }
-                    }
-                } else {
-                    first_elem = false;
-                }
-
-                match tens {
-                    0 => {
-                        // case 102 => [one hundred] two
-                        words.push(String::from(UNITS[units - 1]));
-                    }
-                    1 => {
-                        // case 112 => [one hundred] twelve
-                        words.push(String::from(TEENS[units]));
-                    }
-                    _ => {
-                        // case 142 => [one hundred] forty-two
-                        let ten: String = String::from(TENS[tens - 1]);
-                        words.push(match units {
-                            0 => ten,
-                            _ => {
-                                if UNITS[units - 1].ends_with('e') {
-                                    format!("{}ën{}", UNITS[units - 1], ten)
-                                } else {
-                                    format!("{}en{}", UNITS[units - 1], ten)
-                                }
-                            }
-                        });
-                    }
-                }
-            }
+        let groups: Vec<u64> = self.split_thousands(num).into_iter().rev().collect();
+        let words = self.compose_from_groups(&groups, GroupingScheme::Thousands)?;
 
-            if i != 0 && triplet != &0 {
-                if i > MEGAS.len() {
-                    return Err(Num2Err::CannotConvert);
-                }
-                words.push(String::from(MEGAS[i - 1]));
-            }
+        if negative && !self.paren {
+            Ok(format!("{} {}", self.minus, words))
+        } else {
+            Ok(self.paren_wrap(words, negative))
         }
+    }
 
-        space_words(&mut words);
 
-        Ok(words.join(""))
+    fn digit_to_word(&self, digit: u64) -> String {
+        match digit {
+            0 => String::from("nul"),
+            i => String::from(UNITS[i as usize - 1]),
+        }
     }
 
-
     fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
         let integral_part = num.int();
         let mut words: Vec<String> = vec![];
 
+        if negative && !self.paren {
+            words.push(String::from(self.minus));
+        }
 
         let integral_word = self.int_to_cardinal(integral_part)?;
         words.push(integral_word);
@@ -189,107 +347,199 @@ impl Dutch {
 
         let mut ordinal_part = num.frac();
         if !ordinal_part.is_zero() {
-            words.push(String::from("komma"));
+            words.push(String::from(self.decimal_word));
         }
-        while !ordinal_part.is_zero() {
+        let mut digits = vec![];
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
             let digit = (ordinal_part * BigFloat::from(10)).int();
             ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            digits.push(digit.to_u64().unwrap());
+        }
+        // with whole-fraction only the leading zeros are read one by one,
+        // e.g. "twaalf komma nul éénenvijftig"
+        let zeros = match self.whole_fraction {
+            true => digits.iter().take_while(|&&d| d == 0).count(),
+            false => digits.len(),
+        };
+        for digit in &digits[..zeros] {
+            words.push(" ".to_string());
+            words.push(self.digit_to_word(*digit));
+        }
+        if zeros < digits.len() {
+            let value = digits[zeros..].iter().fold(0, |acc, d| acc * 10 + d);
             words.push(" ".to_string());
-            words.push(match digit.to_u64().unwrap() {
-                0 => String::from("nul"),
-                i => String::from(UNITS[i as usize - 1]),
-            });
+            words.push(self.int_to_cardinal(BigFloat::from(value))?);
         }
 
-        space_words(&mut words);
+        self.space_words(&mut words);
 
-        Ok(words.join(""))
+        Ok(self.paren_wrap(words.join(""), negative))
     }
 }
 
 impl Language for Dutch {
     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
-        if num.is_inf_pos() {
-            Ok(String::from("oneindig"))
-        } else if num.is_inf_neg() {
-            Ok(String::from("negatief oneindig"))
-        } else if num.frac().is_zero() {
-            self.int_to_cardinal(num)
+        let words = self.plain_cardinal(num)?;
+        if self.show_positive && !num.is_negative() {
+            Ok(format!("plus {}", words))
         } else {
-            self.float_to_cardinal(num)
+            Ok(words)
         }
     }
 
-    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let cardinal_word = self.to_cardinal(num)?;
+    fn ordinal_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.int_to_cardinal(num)
+    }
 
-        let mut words: Vec<String> = vec![];
-        let mut split = cardinal_word.split_whitespace().peekable();
+    fn ordinal_transform(&self, word: &str) -> String {
+        Self::ordinal_word(word)
+    }
 
-        while let Some(w) = split.next() {
-            if split.peek().is_some() {
-                // not last word, no modification needed
-                words.push(String::from(w));
-            } else {
-                // last word, needs to be processed
-                let mut prefix = String::from("");
-                let mut suffix = String::from(w);
+    fn to_ordinal_from_end(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("{} van achteren", self.to_ordinal(num)?))
+    }
 
-                if w.contains('-') {
-                    // e.g. forty-two => forty-second
-                    let mut w_split = w.split('-');
+    // e.g. 3:20 => "tien voor half vier", or "vijftien uur twintig" on a
+    // 24-hour clock
+    fn to_time(&self, hour: u32, minute: u32, clock_24: bool) -> Result<String, Num2Err> {
+        let cardinal = |n: u32| self.int_to_cardinal(BigFloat::from(n));
 
-                    if let Some(pre) = w_split.next() {
-                        prefix = format!("{}-", pre);
-                    }
+        if clock_24 {
+            return match minute {
+                0 => Ok(format!("{} uur", cardinal(hour)?)),
+                _ => Ok(format!("{} uur {}", cardinal(hour)?, cardinal(minute)?)),
+            };
+        }
 
-                    if let Some(suf) = w_split.next() {
-                        suffix = String::from(suf);
-                    }
-                }
+        let hour_word = |hour: u32| match hour % 12 {
+            0 => cardinal(12),
+            h => cardinal(h),
+        };
+        let minutes_word = |minutes: u32| match minutes {
+            15 => Ok(String::from("kwart")),
+            _ => cardinal(minutes),
+        };
 
-                suffix = match suffix.as_str() {
-                    "one" => String::from("eerste"),
-                    "two" => String::from("tweede"),
-                    "three" => String::from("derde"),
-                    "four" => String::from("vierde"),
-                    "five" => String::from("vijfde"),
-                    "six" => String::from("zesde"),
-                    "seven" => String::from("zevende"),
-                    "eight" => String::from("achtste"),
-                    "nine" => String::from("negende"),
-                    "ten" => String::from("tiende"),
-                    "eleven" => String::from("elfde"),
-                    "twelve" => String::from("twaalfde"),
-                    _ => {
-                        if suffix.ends_with("tig") {
-                            format!("{}ste", suffix)
-                        } else {
-                            format!("{}de", suffix)
-                        }
-                    }
-                };
+        // the half hour is counted towards the next hour, e.g. "half vier"
+        match minute {
+            0 => Ok(format!("{} uur", hour_word(hour)?)),
+            1..=15 => Ok(format!(
+                "{} over {}",
+                minutes_word(minute)?,
+                hour_word(hour)?
+            )),
+            16..=29 => Ok(format!(
+                "{} voor half {}",
+                minutes_word(30 - minute)?,
+                hour_word(hour + 1)?
+            )),
+            30 => Ok(format!("half {}", hour_word(hour + 1)?)),
+            31..=44 => Ok(format!(
+                "{} over half {}",
+                minutes_word(minute - 30)?,
+                hour_word(hour + 1)?
+            )),
+            _ => Ok(format!(
+                "{} voor {}",
+                minutes_word(60 - minute)?,
+                hour_word(hour + 1)?
+            )),
+        }
+    }
 
-                words.push(format!("{}{}", prefix, suffix))
-            }
+    // the digits one by one, e.g. "nul zes, één twee drie vier"
+    fn to_phone(&self, country_code: Option<&str>, groups: &[&str]) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if let Some(code) = country_code {
+            let code = code.parse::<u64>().map_err(|_| Num2Err::CannotConvert)?;
+            words.push(format!(
+                "plus {}",
+                self.int_to_cardinal(BigFloat::from(code))?
+            ));
+        }
+        for group in groups {
+            let digits: Vec<String> = group
+                .bytes()
+                .map(|d| self.digit_to_word((d - b'0') as u64))
+                .collect();
+            words.push(digits.join(" "));
         }
+        Ok(words.join(", "))
+    }
+
+    fn to_scientific(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf() || num.is_zero() {
+            return self.to_cardinal(num);
+        }
+
+        let (mantissa, exponent) = scientific_parts(num);
+        if exponent == 0 {
+            return self.to_cardinal(mantissa);
+        }
+
+        // e.g. 1e-3 => tien tot de minus derde macht
+        let power = format!(
+            "tien tot de {}{} macht",
+            self.sign(exponent < 0),
+            self.to_ordinal(BigFloat::from(exponent.unsigned_abs()))?
+        );
 
-        Ok(words.join(" "))
+        if mantissa == BigFloat::from(1) {
+            Ok(power)
+        } else if mantissa == BigFloat::from(-1) {
+            Ok(format!("{} {}", self.minus, power))
+        } else {
+            Ok(format!("{} maal {}", self.to_cardinal(mantissa)?, power))
+        }
     }
 
-    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
-        let tail = (num % BigFloat::from(100)).to_u64().unwrap();
-        let last = tail % 10;
-        Ok(format!(
-            "{}{}",
-            num.to_u128().unwrap(),
-            match (tail / 10 != 1, last) {
-                (true, 1) => "e",
-                (true, 2) => "e",
-                (true, 3) => "e",
-                _ => "e",
-            }
-        ))
+    fn to_expanded(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let places = place_values(num);
+        if places.is_empty() {
+            return self.plain_cardinal(num);
+        }
+
+        let mut words = vec![];
+        for (digit, power) in places {
+            words.push(format!(
+                "{} {}",
+                self.int_to_cardinal(BigFloat::from(digit))?,
+                self.place_word(power, digit != 1)?
+            ));
+        }
+
+        Ok(join_list(&words, "en"))
+    }
+
+    // only the tens are read, e.g. 1990 => "de jaren negentig"
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "de jaren negentig", or "de jaren negentienhonderd" for its
+        // first decade
+        let low = num % BigFloat::from(100);
+        match low.is_zero() {
+            true => Ok(format!("de jaren {}", self.to_year(num)?)),
+            false => Ok(format!("de jaren {}", self.int_to_cardinal(low)?)),
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!("de {} eeuw", self.to_ordinal(century)?))
+    }
+
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
+        if !self.ordinal_num_long {
+            return Ok("e");
+        }
+        // the ending of the ordinal, e.g. "1ste" for "eerste" and "2de" for
+        // "tweede"
+        Ok(match self.to_ordinal(num)?.ends_with("ste") {
+            true => "ste",
+            false => "de",
+        })
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -299,54 +549,114 @@ impl Language for Dutch {
 
         let mut num = num;
 
-        let mut suffix = "";
+        let mut era = None;
         if num.is_negative() {
             num = num.inv_sign();
-            suffix = " voor christus";
+            era = Some(ERA_WORDS[0].0);
         }
 
-        let bf_100 = BigFloat::from(100);
-
-        let (high, low) = (
-            (num / bf_100).to_i64().unwrap(),
-            (num % bf_100).to_i64().unwrap(),
-        );
-        let year_word = if high == 0 || (high % 10 == 0 && low < 10) || high >= 100 {
-            // if year is 00XX, X00X, or beyond 9999, go cardinal
-            self.int_to_cardinal(num)?
-        } else {
-            let high_word = self.int_to_cardinal(BigFloat::from(high))?;
-            let low_word = if low == 0 {
-                String::from("honderd")
-            } else {
-                self.int_to_cardinal(BigFloat::from(low))?
-            };
+        // if year is 00XX, X00X, or beyond 9999, go cardinal
+        let halves = num
+            .to_u64()
+            .map(|year| (year / 100, year % 100))
+            .filter(|&(high, low)| high > 0 && high < 100 && !(high % 10 == 0 && low < 10));
+        let year_word = match halves {
+            Some((high, low)) => {
+                let high_word = self.int_to_cardinal(BigFloat::from(high))?;
+                let low_word = self.year_low_half(low)?;
 
-            format!("{}{}", high_word, low_word)
+                format!("{}{}", high_word, low_word)
+            }
+            None => self.int_to_cardinal(num)?,
         };
 
-        Ok(format!("{}{}", year_word, suffix))
+        match era {
+            Some(era) => Ok(format!("{} {}", year_word, era)),
+            None => Ok(year_word),
+        }
+    }
+
+    fn era_words(&self, era: Era) -> Option<(&'static str, &'static str)> {
+        match era {
+            Era::AnnoDomini => Some(ERA_WORDS[0]),
+            Era::CommonEra => Some(ERA_WORDS[1]),
+            Era::Custom { .. } => None,
+        }
+    }
+
+    fn to_year_range(
+        &self,
+        start: BigFloat,
+        end: BigFloat,
+        shared_century: bool,
+    ) -> Result<String, Num2Err> {
+        // e.g. "van negentienveertig tot vijfenveertig"
+        if shared_century && shares_century(start, end) {
+            let low = (end % BigFloat::from(100)).to_u64().unwrap();
+            return Ok(format!(
+                "van {} tot {}",
+                self.to_year(start)?,
+                self.year_low_half(low)?
+            ));
+        }
+        Ok(format!(
+            "{} tot {}",
+            self.to_year(start)?,
+            self.to_year(end)?
+        ))
     }
 
     fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
         if num.is_inf() {
             Ok(format!(
                 "{}oneindig {}",
-                if num.is_negative() { "minus " } else { "" },
-                self.currencies(currency)
+                self.sign(num.is_negative()),
+                self.currencies(currency, true)
             ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus vijf dollar en vijftig cent"
+            let words = self.to_currency(num.abs(), currency)?;
+            if self.paren {
+                Ok(self.paren_wrap(words, true))
+            } else {
+                Ok(format!("{} {}", self.minus, words))
+            }
         } else if num.frac().is_zero() {
             let words = self.int_to_cardinal(num)?;
             Ok(format!(
                 "{} {}",
                 words,
-                self.currencies(currency)
+                self.currencies(currency, num.abs() != BigFloat::from(1))
+            ))
+        } else if self.decimal_currency && currency.subunit_exponent() > 0 {
+            // e.g. 5.50 => vijf komma vijf nul euro
+            let ratio = currency.subunit_ratio();
+            let cents_nb = ((num * BigFloat::from(ratio)).int() % BigFloat::from(ratio))
+                .abs()
+                .to_u64()
+                .unwrap();
+            let digits = format!(
+                "{:0width$}",
+                cents_nb,
+                width = currency.subunit_exponent() as usize
+            );
+            let digit_words: Vec<String> = digits
+                .bytes()
+                .map(|d| self.digit_to_word((d - b'0') as u64))
+                .collect();
+            Ok(format!(
+                "{} {} {} {}",
+                self.int_to_cardinal(num.int())?,
+                self.decimal_word,
+                digit_words.join(" "),
+                self.currencies(currency, true)
             ))
         } else {
             let integral_part = num.int();
-            let cents_nb = (num * BigFloat::from(100)).int() % BigFloat::from(100);
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
             let cents_words = self.int_to_cardinal(cents_nb)?;
-            let cents_suffix = self.cents(currency);
+            let cents_suffix = self.cents(currency, cents_nb.abs() != BigFloat::from(1));
             let integral_word = self.to_currency(integral_part, currency)?;
 
             if cents_nb.is_zero() {
@@ -355,282 +665,1346 @@ impl Language for Dutch {
                 Ok(format!("{} {}", cents_words, cents_suffix))
             } else {
                 Ok(format!(
-                    "{} en {} {}",
-                    integral_word, cents_words, cents_suffix
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
                 ))
             }
         }
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+    // e.g. "twee en een half miljoen" and "driekwart miljard"
+    fn to_compact(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let (whole, quarters, scale) = match compact_scale(num.abs()) {
+            Some((whole, quarters, power)) => match self.scale_word(power) {
+                Some(scale) => (whole, quarters, scale),
+                None => return self.to_cardinal(num),
+            },
+            None => return self.to_cardinal(num),
+        };
 
-    #[test]
-    fn test_cardinal() {
-        assert_eq!(
-            Num2Words::new(0).lang(Lang::Dutch).cardinal().to_words(),
-            Ok(String::from("nul"))
-        );
-        assert_eq!(
-            Num2Words::new(-10)
-                .lang(Lang::Dutch)
-                .cardinal()
+        let part = ["een kwart", "een half", "driekwart"][quarters as usize - 1];
+        let words = match whole {
+            0 => format!("{} {}", part, scale),
+            _ => format!(
+                "{} en {} {}",
+                self.to_cardinal(BigFloat::from(whole))?,
+                part,
+                scale
+            ),
+        };
+        Ok(format!("{}{}", self.sign(num.is_negative()), words))
+    }
+
+    fn to_fraction(&self, numerator: BigFloat, denominator: BigFloat) -> Result<String, Num2Err> {
+        let numerator_word = self.int_to_cardinal(numerator)?;
+        let plural = numerator.abs() != BigFloat::from(1);
+
+        match self.denominator_word(denominator, plural) {
+            Some(word) => Ok(format!("{} {}", numerator_word, word)),
+            None => Ok(format!(
+                "{} over {}",
+                numerator_word,
+                self.int_to_cardinal(denominator)?
+            )),
+        }
+    }
+
+    // denominators stay singular, e.g. "twee derde", except "halve"
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        let word = match denominator.to_u64() {
+            Some(2) if plural => "halve",
+            Some(2) => "half",
+            Some(3) => "derde",
+            Some(4) => "kwart",
+            Some(5) => "vijfde",
+            Some(6) => "zesde",
+            Some(7) => "zevende",
+            Some(8) => "achtste",
+            Some(9) => "negende",
+            Some(10) => "tiende",
+            Some(11) => "elfde",
+            Some(12) => "twaalfde",
+            _ if is_power_of_ten(denominator) => {
+                // e.g. "honderd" => "honderdste", "één miljoen" => "miljoenste"
+                let cardinal = self.int_to_cardinal(denominator).ok()?;
+                return Some(format!("{}ste", cardinal.trim_start_matches("één ")));
+            }
+            _ => return None,
+        };
+        Some(String::from(word))
+    }
+
+    fn to_mixed_fraction(
+        &self,
+        whole: BigFloat,
+        numerator: BigFloat,
+        denominator: BigFloat,
+    ) -> Result<String, Num2Err> {
+        let fraction = self.to_fraction(numerator, denominator)?;
+        // the article rather than the numeral, e.g. "twee en een half"
+        let fraction = match fraction.strip_prefix("één ") {
+            Some(rest) if !rest.starts_with("over ") => format!("een {}", rest),
+            _ => fraction,
+        };
+        Ok(format!("{} en {}", self.int_to_cardinal(whole)?, fraction))
+    }
+
+    fn to_ssml(&self, words: String, currency: Option<Currency>) -> String {
+        let unit = currency.map(|c| {
+            let plural = self.currencies(c, true);
+            if words.contains(&plural) {
+                plural
+            } else {
+                self.currencies(c, false)
+            }
+        });
+        ssml(&words, &MEGAS, unit.as_deref().zip(currency))
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " en "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("debet")
+    }
+
+    // past the table after Conway and Wechsler, e.g. "undeciljoen"
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match MEGAS.get(power.checked_sub(1)?) {
+            Some(word) => Some(word),
+            None => {
+                let ending = if power % 2 == 1 { "iljard" } else { "iljoen" };
+                Some(intern(format!("{}{}", illion_stem(power / 2)?, ending)))
+            }
+        }
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        if scheme != GroupingScheme::Thousands || groups.iter().any(|g| *g >= 1000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return Ok(String::from("nul"));
+        }
+
+        let mut words = vec![];
+
+        // iterate over thousands
+        let mut first_elem = true;
+        for (i, triplet) in groups.iter().rev().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if hundreds > 0 {
+                if hundreds > 1 {
+                    words.push(String::from(UNITS[hundreds - 1]));
+                }
+                words.push(String::from(self.hundred_word()));
+            }
+
+            if tens != 0 || units != 0 {
+                if i == 0 && !first_elem {
+                    if let Some(last) = words.last() {
+                        if self.is_scale_word(last).is_none() {
+                            words.push(String::from(Self::junction(last)));
+                        }
+                    }
+                } else {
+                    first_elem = false;
+                }
+
+                match tens {
+                    0 => {
+                        // case 102 => [one hundred] two
+                        words.push(String::from(UNITS[units - 1]));
+                    }
+                    1 => {
+                        // case 112 => [one hundred] twelve
+                        words.push(String::from(TEENS[units]));
+                    }
+                    _ => {
+                        // case 142 => [one hundred] forty-two
+                        let ten: String = String::from(TENS[tens - 1]);
+                        words.push(match units {
+                            0 => ten,
+                            _ => format!(
+                                "{}{}{}",
+                                UNITS[units - 1],
+                                Self::junction(UNITS[units - 1]),
+                                ten
+                            ),
+                        });
+                    }
+                }
+            }
+
+            if i != 0 && triplet != &0 {
+                words.push(String::from(
+                    self.scale_word(i).ok_or(Num2Err::CannotConvert)?,
+                ));
+            }
+        }
+
+        self.space_words(&mut words);
+
+        Ok(words.join(""))
+    }
+
+    fn spell_small_into<'a>(&self, n: u16, buf: &'a mut [u8]) -> Result<&'a str, Num2Err> {
+        if n > 999 {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let hundreds = (n / 100) as usize;
+        let tens = (n / 10 % 10) as usize;
+        let units = (n % 10) as usize;
+
+        let mut writer = SliceWriter::new(buf);
+        let mut spell = || -> std::fmt::Result {
+            if n == 0 {
+                return write!(writer, "nul");
+            }
+            if hundreds > 1 {
+                write!(writer, "{}", UNITS[hundreds - 1])?;
+            }
+            if hundreds > 0 {
+                write!(writer, "{}", self.hundred_word())?;
+            }
+            match (tens, units) {
+                (0, 0) => Ok(()),
+                (0, _) => write!(writer, "{}", UNITS[units - 1]),
+                (1, _) => write!(writer, "{}", TEENS[units]),
+                (_, 0) => write!(writer, "{}", TENS[tens - 1]),
+                _ => write!(
+                    writer,
+                    "{}{}{}",
+                    UNITS[units - 1],
+                    Self::junction(UNITS[units - 1]),
+                    TENS[tens - 1]
+                ),
+            }
+        };
+        spell().map_err(|_| Num2Err::CannotConvert)?;
+
+        Ok(writer.into_str())
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "honderd"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0]
+    }
+
+    // measures keep the singular after a number, e.g. "vijf kilometer", but
+    // units of time other than "uur" do not, e.g. "vijf minuten"
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        let (one, many) = match unit {
+            Unit::Millimeter => ("millimeter", "millimeter"),
+            Unit::Centimeter => ("centimeter", "centimeter"),
+            Unit::Meter => ("meter", "meter"),
+            Unit::Kilometer => ("kilometer", "kilometer"),
+            Unit::Mile => ("mijl", "mijl"),
+            Unit::Gram => ("gram", "gram"),
+            Unit::Kilogram => ("kilogram", "kilogram"),
+            Unit::Liter => ("liter", "liter"),
+            Unit::Second => ("seconde", "seconden"),
+            Unit::Minute => ("minuut", "minuten"),
+            Unit::Hour => ("uur", "uur"),
+            Unit::Day => ("dag", "dagen"),
+            _ => return unit.custom_words(),
+        };
+        Some([one, many, many, many])
+    }
+
+    // e.g. "honderden", "duizenden" and "miljoenen"
+    fn vague_plural(&self, power: usize) -> Option<String> {
+        match power {
+            2 => Some(format!("{}en", self.hundred_word())),
+            _ => Some(format!("{}en", self.scale_word(power / 3)?)),
+        }
+    }
+
+    // round scale numbers read as their plural noun, e.g. "met z'n
+    // duizenden", other multiples of a hundred have no collective
+    fn to_collective(&self, num: BigFloat) -> Option<String> {
+        if num.is_inf() || num.is_negative() || !num.frac().is_zero() || num <= BigFloat::from(1) {
+            return None;
+        }
+        if !(num % BigFloat::from(100)).is_zero() {
+            let words = self.int_to_cardinal(num).ok()?;
+            return Some(Self::collective_word(words));
+        }
+        match place_values(num)[..] {
+            [(1, power)] if power == 2 || power % 3 == 0 => {
+                Some(format!("met z'n {}", self.vague_plural(power)?))
+            }
+            _ => None,
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("procent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("promille")
+    }
+
+    fn approximate_word(&self) -> Option<&'static str> {
+        Some("ongeveer")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Dutch).cardinal().to_words(),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            Num2Words::new(-10)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus tien"))
+        );
+        assert_eq!(
+            Num2Words::new(38123147081932i64)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from(
+                "achtendertig biljoen honderddrieëntwintig miljard honderdzevenenveertig miljoen éénentachtigduizend negenhonderdentweeëndertig"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(100000000000i64)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("honderd miljard"))
+        );
+    }
+
+    #[test]
+    fn test_show_positive() {
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::Dutch)
+                .prefer("show-positive")
+                .to_words(),
+            Ok(String::from("plus tien"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("show-positive")
+                .to_words(),
+            Ok(String::from("plus nul"))
+        );
+        assert_eq!(
+            Num2Words::new(-10)
+                .lang(Lang::Dutch)
+                .prefer("show-positive")
+                .to_words(),
+            Ok(String::from("minus tien"))
+        );
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::Dutch)
+                .prefer("show-positive")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("tiende"))
+        );
+    }
+
+    #[test]
+    fn test_compose_from_groups() {
+        let dutch = crate::lang::Dutch::new();
+        assert_eq!(
+            dutch.compose_from_groups(&[1, 234, 567], GroupingScheme::Thousands),
+            Ok(String::from(
+                "één miljoen tweehonderdvierendertigduizend vijfhonderdenzevenenzestig"
+            ))
+        );
+        assert_eq!(
+            dutch.compose_from_groups(&[2, 0, 5], GroupingScheme::Thousands),
+            Ok(String::from("twee miljoen vijf"))
+        );
+        assert_eq!(
+            dutch.compose_from_groups(&[0, 0], GroupingScheme::Thousands),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            dutch.compose_from_groups(&[1234], GroupingScheme::Thousands),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            dutch.compose_from_groups(&[12, 34, 567], GroupingScheme::Indian),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_junction() {
+        assert_eq!(
+            Num2Words::new(22).lang(Lang::Dutch).to_words(),
+            Ok(String::from("tweeëntwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Dutch).to_words(),
+            Ok(String::from("drieëntwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(43).lang(Lang::Dutch).to_words(),
+            Ok(String::from("drieënveertig"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Dutch).to_words(),
+            Ok(String::from("éénentwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(44).lang(Lang::Dutch).to_words(),
+            Ok(String::from("vierenveertig"))
+        );
+        assert_eq!(
+            Num2Words::new(1003).lang(Lang::Dutch).to_words(),
+            Ok(String::from("éénduizend drie"))
+        );
+    }
+
+    #[test]
+    fn test_expanded() {
+        assert_eq!(
+            Num2Words::new(234).lang(Lang::Dutch).expanded().to_words(),
+            Ok(String::from(
+                "twee honderdtallen, drie tientallen en vier eenheden"
+            ))
+        );
+        assert_eq!(
+            Num2Words::new(1050).lang(Lang::Dutch).expanded().to_words(),
+            Ok(String::from("één duizendtal en vijf tientallen"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(-5).lang(Lang::Dutch).percent().to_words(),
+            Ok(String::from("minus vijf procent"))
+        );
+        assert_eq!(
+            Num2Words::new(12).lang(Lang::Dutch).permille().to_words(),
+            Ok(String::from("twaalf promille"))
+        );
+        assert_eq!(
+            Num2Words::new(0.5).lang(Lang::Dutch).percent().to_words(),
+            Ok(String::from("nul komma vijf procent"))
+        );
+    }
+
+    #[test]
+    fn test_vague_plural() {
+        assert_eq!(
+            Num2Words::new(300)
+                .lang(Lang::Dutch)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("honderden"))
+        );
+        assert_eq!(
+            Num2Words::new(4000)
+                .lang(Lang::Dutch)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("duizenden"))
+        );
+        assert_eq!(
+            Num2Words::new(50_000)
+                .lang(Lang::Dutch)
+                .prefer("vague-plural")
+                .to_words(),
+            Ok(String::from("vijftigduizend"))
+        );
+    }
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Dutch)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("één miljoen"))
+        );
+        assert_eq!(
+            Num2Words::new(1e15)
+                .lang(Lang::Dutch)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("een heel groot getal"))
+        );
+        assert_eq!(
+            Num2Words::new(-1e15)
+                .lang(Lang::Dutch)
+                .prefer("simple")
+                .to_words(),
+            Ok(String::from("minus een heel groot getal"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("tiende"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("éénentwintigste"))
+        );
+        assert_eq!(
+            Num2Words::new(102).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("honderdtweede"))
+        );
+        assert_eq!(
+            Num2Words::new(73).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("drieënzeventigste"))
+        );
+        assert_eq!(
+            Num2Words::new(-1).lang(Lang::Dutch).ordinal().to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(1.2).lang(Lang::Dutch).ordinal().to_words(),
+            Err(num2words::Num2Err::FloatingOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_transform() {
+        let dutch = crate::lang::Dutch::new();
+        assert_eq!(dutch.ordinal_transform("drie"), "derde");
+        assert_eq!(dutch.ordinal_transform("veertig"), "veertigste");
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("éénduizendste"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_008)
+                .lang(Lang::Dutch)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("twee miljoen achtste"))
+        );
+    }
+
+    #[test]
+    fn test_is_scale_word() {
+        let dutch = crate::lang::Dutch::new();
+        assert_eq!(dutch.is_scale_word("duizend"), Some(0));
+        assert_eq!(dutch.is_scale_word("miljoen"), Some(1));
+        assert_eq!(dutch.is_scale_word("honderd"), None);
+        assert_eq!(
+            Num2Words::new(2_001_000).lang(Lang::Dutch).to_words(),
+            Ok(String::from("twee miljoen éénduizend"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_from_end() {
+        assert_eq!(
+            Num2Words::new(-1)
+                .lang(Lang::Dutch)
+                .ordinal()
+                .prefer("from-end")
+                .to_words(),
+            Ok(String::from("eerste van achteren"))
+        );
+        assert_eq!(
+            Num2Words::new(-2)
+                .lang(Lang::Dutch)
+                .ordinal()
+                .prefer("from-end")
+                .to_words(),
+            Ok(String::from("tweede van achteren"))
+        );
+        assert_eq!(
+            Num2Words::new(-2).lang(Lang::Dutch).ordinal().to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_irregular() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("eerste"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("derde"))
+        );
+        assert_eq!(
+            Num2Words::new(8).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("achtste"))
+        );
+        assert_eq!(
+            Num2Words::new(101).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("honderdeerste"))
+        );
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Dutch).ordinal().to_words(),
+            Ok(String::from("éénduizendste"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num() {
+        assert_eq!(
+            Num2Words::new(10)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("10e"))
+        );
+        assert_eq!(
+            Num2Words::new(13)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("13e"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("21e"))
+        );
+        assert_eq!(
+            Num2Words::new(102)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("102e"))
+        );
+        assert_eq!(
+            Num2Words::new(73)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("73e"))
+        );
+        assert_eq!(
+            Num2Words::new(-42)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::NegativeOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(7.3)
+                .lang(Lang::Dutch)
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::FloatingOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num_zero() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Dutch).ordinal_num().to_words(),
+            Ok(String::from("0e"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("zero-numeral")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("0e"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("zero-spelled")
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("nulde"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("zero-error")
+                .ordinal_num()
+                .to_words(),
+            Err(num2words::Num2Err::ZeroOrdinal)
+        );
+    }
+
+    #[test]
+    fn test_cardinal_float() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twaalf komma vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twaalf komma vijf één"))
+        );
+        assert_eq!(
+            Num2Words::new(12.53)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twaalf komma vijf drie"))
+        );
+        assert_eq!(
+            Num2Words::new(12.59)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("twaalf komma vijf negen"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::Dutch)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("één dollar en één cent"))
+        );
+        assert_eq!(
+            Num2Words::new(4000)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("vierduizend US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(1.)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("één euro"))
+        );
+        assert_eq!(
+            Num2Words::new(0.20)
+                .lang(Lang::Dutch)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("twintig cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .currency(Currency::DOLLAR)
+                .to_words(),
+            Ok(String::from("nul dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("twee euro"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("twee US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(-5)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus vijf US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(-5.5)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus vijf US dollar en vijftig cent"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("twee euro en vijftig cent"))
+        );
+    }
+
+    #[test]
+    fn test_currency_negative_zero() {
+        assert_eq!(
+            Num2Words::new(-0.00)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("nul US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.001)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("nul US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.01)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("minus één cent"))
+        );
+    }
+
+    #[test]
+    fn test_currency_below_unit() {
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("één cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.05)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("vijf cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.99)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("negenennegentig cent"))
+        );
+        assert_eq!(
+            Num2Words::new(0.00)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("nul US dollar"))
+        );
+        assert_eq!(
+            Num2Words::new(0.001)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("nul US dollar"))
+        );
+    }
+
+    #[test]
+    fn test_currency_connector() {
+        assert_eq!(crate::lang::Dutch::new().currency_connector(), " en ");
+        assert_eq!(
+            Num2Words::new(1.50)
+                .lang(Lang::Dutch)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("één US dollar en vijftig cent"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("negentiennegentig"))
+        );
+        assert_eq!(
+            Num2Words::new(5555).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijfenvijftigvijfenvijftig"))
+        );
+        assert_eq!(
+            Num2Words::new(2022).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("twintigtweeëntwintig"))
+        );
+        assert_eq!(
+            Num2Words::new(2001).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("tweeduizend één"))
+        );
+        assert_eq!(
+            Num2Words::new(1901).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("negentienéén"))
+        );
+        assert_eq!(
+            Num2Words::new(1910).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("negentientien"))
+        );
+        assert_eq!(
+            Num2Words::new(5500).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijfenvijftighonderd"))
+        );
+        assert_eq!(
+            Num2Words::new(500).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijfhonderd"))
+        );
+        assert_eq!(
+            Num2Words::new(50).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijftig"))
+        );
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            Num2Words::new(-44).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vierenveertig voor christus"))
+        );
+        assert_eq!(
+            Num2Words::new(1.1).lang(Lang::Dutch).year().to_words(),
+            Err(num2words::Num2Err::FloatingYear)
+        );
+        // years past a u64 are read as cardinals
+        for year in [1e21, 1e40] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::Dutch).year().to_words(),
+                Num2Words::new(year).lang(Lang::Dutch).to_words()
+            );
+        }
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990).lang(Lang::Dutch).decade().to_words(),
+            Ok(String::from("de jaren negentig"))
+        );
+        assert_eq!(
+            Num2Words::new(1900).lang(Lang::Dutch).decade().to_words(),
+            Ok(String::from("de jaren negentienhonderd"))
+        );
+        assert_eq!(
+            Num2Words::new(1e21).lang(Lang::Dutch).decade().to_words(),
+            Ok(String::from("de jaren één triljard"))
+        );
+        assert_eq!(
+            Num2Words::new(1900).lang(Lang::Dutch).century().to_words(),
+            Ok(String::from("de twintigste eeuw"))
+        );
+        assert_eq!(
+            Num2Words::new(1995).lang(Lang::Dutch).decade().to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_year_negative_round() {
+        assert_eq!(
+            Num2Words::new(-50).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijftig voor christus"))
+        );
+        assert_eq!(
+            Num2Words::new(-500).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijfhonderd voor christus"))
+        );
+        assert_eq!(
+            Num2Words::new(-1500).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijftienhonderd voor christus"))
+        );
+        assert_eq!(
+            Num2Words::new(1500).lang(Lang::Dutch).year().to_words(),
+            Ok(String::from("vijftienhonderd"))
+        );
+        assert_eq!(
+            Num2Words::new(-500)
+                .lang(Lang::Dutch)
+                .prefer("paren")
+                .year()
+                .to_words(),
+            Ok(String::from("vijfhonderd voor christus"))
+        );
+    }
+
+    #[test]
+    fn test_year_low_half() {
+        use crate::lang::Language;
+
+        let dutch = lang::Dutch::new();
+        assert_eq!(dutch.year_low_half(0), Ok(String::from("honderd")));
+        assert_eq!(dutch.year_low_half(1), Ok(String::from("één")));
+        assert_eq!(dutch.year_low_half(5), Ok(String::from("vijf")));
+        assert_eq!(dutch.year_low_half(10), Ok(String::from("tien")));
+    }
+
+    #[test]
+    fn test_hundred_thousand_word() {
+        use crate::lang::Language;
+
+        let dutch = lang::Dutch::new();
+        assert_eq!(dutch.hundred_word(), "honderd");
+        assert_eq!(dutch.thousand_word(), "duizend");
+    }
+
+    #[test]
+    fn test_prefer() {
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            Num2Words::new(0)
+                .lang(Lang::Dutch)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("nul"))
+        );
+        assert_eq!(
+            Num2Words::new(0.005)
+                .lang(Lang::Dutch)
+                .prefer("oh")
+                .to_words(),
+            Ok(String::from("nul komma nul nul vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(2.05)
+                .lang(Lang::Dutch)
+                .prefer("nil")
+                .to_words(),
+            Ok(String::from("twee komma nul vijf"))
+        );
+    }
+
+    #[test]
+    fn test_decimal_currency() {
+        assert_eq!(
+            Num2Words::new(5.50)
+                .lang(Lang::Dutch)
+                .prefer("decimal-currency")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("vijf komma vijf nul euro"))
+        );
+        assert_eq!(
+            Num2Words::new(5.05)
+                .lang(Lang::Dutch)
+                .prefer("decimal-currency")
+                .currency(Currency::EUR)
                 .to_words(),
-            Ok(String::from("minus tien"))
+            Ok(String::from("vijf komma nul vijf euro"))
         );
+    }
+
+    #[test]
+    fn test_ssml() {
         assert_eq!(
-            Num2Words::new(38123147081932i64)
+            Num2Words::new(1234)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("ssml")
                 .to_words(),
             Ok(String::from(
-                "achtendertig biljoen honderddrieëntwintig miljard honderdzevenenveertig miljoen éénentachtigduizend negenhonderdentweeëndertig"
+                "<speak>éénduizend<break/> tweehonderdenvierendertig</speak>"
             ))
         );
         assert_eq!(
-            Num2Words::new(100000000000i64)
+            Num2Words::new(5.50)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("ssml")
+                .currency(Currency::USD)
                 .to_words(),
-            Ok(String::from("honderd miljard"))
+            Ok(String::from(
+                "<speak>vijf <sub alias=\"US dollar\">USD</sub> en vijftig cent</speak>"
+            ))
         );
     }
 
     #[test]
-    fn test_ordinal() {
-        assert_eq!(
-            Num2Words::new(10).lang(Lang::Dutch).ordinal().to_words(),
-            Ok(String::from("tiende"))
-        );
+    fn test_fraction() {
         assert_eq!(
-            Num2Words::new(21).lang(Lang::Dutch).ordinal().to_words(),
-            Ok(String::from("éénentwintigste"))
+            Num2Words::new(7).lang(Lang::Dutch).fraction(13).to_words(),
+            Ok(String::from("zeven over dertien"))
         );
         assert_eq!(
-            Num2Words::new(102).lang(Lang::Dutch).ordinal().to_words(),
-            Ok(String::from("honderdtweede"))
+            Num2Words::new(1).lang(Lang::Dutch).fraction(100).to_words(),
+            Ok(String::from("één honderdste"))
         );
         assert_eq!(
-            Num2Words::new(73).lang(Lang::Dutch).ordinal().to_words(),
-            Ok(String::from("drieënzeventigste"))
+            Num2Words::new(2).lang(Lang::Dutch).fraction(3).to_words(),
+            Ok(String::from("twee derde"))
         );
         assert_eq!(
-            Num2Words::new(-1).lang(Lang::Dutch).ordinal().to_words(),
-            Err(num2words::Num2Err::NegativeOrdinal)
+            Num2Words::new(3).lang(Lang::Dutch).fraction(4).to_words(),
+            Ok(String::from("drie kwart"))
         );
         assert_eq!(
-            Num2Words::new(1.2).lang(Lang::Dutch).ordinal().to_words(),
-            Err(num2words::Num2Err::FloatingOrdinal)
+            Num2Words::new(1)
+                .lang(Lang::Dutch)
+                .fraction(1000000)
+                .to_words(),
+            Ok(String::from("één miljoenste"))
         );
     }
 
     #[test]
-    fn test_ordinal_num() {
-        assert_eq!(
-            Num2Words::new(10)
-                .lang(Lang::Dutch)
-                .ordinal_num()
-                .to_words(),
-            Ok(String::from("10e"))
-        );
+    fn test_time() {
+        let time = |hour, minute| Num2Words::time(hour, minute).lang(Lang::Dutch).to_words();
+        assert_eq!(time(3, 0), Ok(String::from("drie uur")));
+        assert_eq!(time(3, 15), Ok(String::from("kwart over drie")));
+        assert_eq!(time(3, 20), Ok(String::from("tien voor half vier")));
+        assert_eq!(time(15, 30), Ok(String::from("half vier")));
+        assert_eq!(time(3, 35), Ok(String::from("vijf over half vier")));
+        assert_eq!(time(3, 45), Ok(String::from("kwart voor vier")));
+        assert_eq!(time(23, 55), Ok(String::from("vijf voor twaalf")));
         assert_eq!(
-            Num2Words::new(13)
+            Num2Words::time(15, 20)
                 .lang(Lang::Dutch)
-                .ordinal_num()
+                .prefer("24h")
                 .to_words(),
-            Ok(String::from("13e"))
+            Ok(String::from("vijftien uur twintig"))
         );
+    }
+
+    #[test]
+    fn test_phone() {
         assert_eq!(
-            Num2Words::new(21)
-                .lang(Lang::Dutch)
-                .ordinal_num()
-                .to_words(),
-            Ok(String::from("21e"))
+            Num2Words::phone("+31 6 1234").lang(Lang::Dutch).to_words(),
+            Ok(String::from("plus éénendertig, zes, één twee drie vier"))
         );
+    }
+
+    #[test]
+    fn test_scientific() {
         assert_eq!(
-            Num2Words::new(102)
+            Num2Words::new(2.8e64)
                 .lang(Lang::Dutch)
-                .ordinal_num()
+                .scientific()
                 .to_words(),
-            Ok(String::from("102e"))
+            Ok(String::from(
+                "twee komma acht maal tien tot de vierenzestigste macht"
+            ))
         );
         assert_eq!(
-            Num2Words::new(73)
+            Num2Words::new(-1e-3)
                 .lang(Lang::Dutch)
-                .ordinal_num()
+                .scientific()
                 .to_words(),
-            Ok(String::from("73e"))
+            Ok(String::from("minus tien tot de minus derde macht"))
         );
+    }
+
+    #[test]
+    fn test_mixed_fraction() {
         assert_eq!(
-            Num2Words::new(-42)
+            Num2Words::new(5)
                 .lang(Lang::Dutch)
-                .ordinal_num()
+                .fraction(2)
+                .prefer("mixed-number")
                 .to_words(),
-            Err(num2words::Num2Err::NegativeOrdinal)
+            Ok(String::from("twee en een half"))
         );
         assert_eq!(
-            Num2Words::new(7.3)
+            Num2Words::new_fraction(7, 4)
                 .lang(Lang::Dutch)
-                .ordinal_num()
+                .prefer("mixed-number")
                 .to_words(),
-            Err(num2words::Num2Err::FloatingOrdinal)
+            Ok(String::from("één en drie kwart"))
         );
     }
 
     #[test]
-    fn test_cardinal_float() {
+    fn test_collective() {
         assert_eq!(
-            Num2Words::new(12.5)
+            Num2Words::new(2)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("twaalf komma vijf"))
+            Ok(String::from("met z'n tweeën"))
         );
         assert_eq!(
-            Num2Words::new(12.51)
+            Num2Words::new(3)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("twaalf komma vijf één"))
+            Ok(String::from("met z'n drieën"))
         );
         assert_eq!(
-            Num2Words::new(12.53)
+            Num2Words::new(4)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("twaalf komma vijf drie"))
+            Ok(String::from("met z'n vieren"))
         );
         assert_eq!(
-            Num2Words::new(12.59)
+            Num2Words::new(5)
                 .lang(Lang::Dutch)
-                .cardinal()
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("twaalf komma vijf negen"))
+            Ok(String::from("met z'n vijven"))
         );
-    }
-
-    #[test]
-    fn test_currency() {
         assert_eq!(
-            Num2Words::new(1.01)
+            Num2Words::new(6)
                 .lang(Lang::Dutch)
-                .currency(Currency::DOLLAR)
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("één dollar en één cent"))
+            Ok(String::from("met z'n zessen"))
         );
         assert_eq!(
-            Num2Words::new(4000)
+            Num2Words::new(10)
                 .lang(Lang::Dutch)
-                .currency(Currency::USD)
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("vierduizend US dollar"))
+            Ok(String::from("met z'n tienen"))
         );
         assert_eq!(
-            Num2Words::new(1.)
+            Num2Words::new(100)
                 .lang(Lang::Dutch)
-                .currency(Currency::EUR)
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("één euro"))
+            Ok(String::from("met z'n honderden"))
         );
         assert_eq!(
-            Num2Words::new(0.20)
+            Num2Words::new(1000)
                 .lang(Lang::Dutch)
-                .currency(Currency::DOLLAR)
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("twintig cent"))
+            Ok(String::from("met z'n duizenden"))
         );
         assert_eq!(
-            Num2Words::new(0)
+            Num2Words::new(2000)
                 .lang(Lang::Dutch)
-                .currency(Currency::DOLLAR)
+                .prefer("collective")
                 .to_words(),
-            Ok(String::from("nul dollar"))
+            Ok(String::from("tweeduizend"))
         );
     }
 
     #[test]
-    fn test_year() {
-        assert_eq!(
-            Num2Words::new(1990).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("negentiennegentig"))
-        );
-        assert_eq!(
-            Num2Words::new(5555).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("vijfenvijftigvijfenvijftig"))
-        );
-        assert_eq!(
-            Num2Words::new(2022).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("twintigtweeëntwintig"))
-        );
+    fn test_collective_other_outputs() {
+        let words = |num: i64, collective: bool| {
+            let words = Num2Words::new(num).lang(Lang::Dutch);
+            match collective {
+                true => words.prefer("collective"),
+                false => words,
+            }
+        };
         assert_eq!(
-            Num2Words::new(2001).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("tweeduizend één"))
+            words(1905, true).year().to_words(),
+            Ok(String::from("negentienvijf"))
         );
         assert_eq!(
-            Num2Words::new(1901).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("negentienéén"))
+            words(25, true).percent().to_words(),
+            words(25, false).percent().to_words()
         );
         assert_eq!(
-            Num2Words::new(1910).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("negentientien"))
+            words(98, true).approximate().to_words(),
+            words(98, false).approximate().to_words()
         );
         assert_eq!(
-            Num2Words::new(5500).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("vijfenvijftighonderd"))
+            words(2000, true).scientific().to_words(),
+            words(2000, false).scientific().to_words()
         );
         assert_eq!(
-            Num2Words::new(500).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("vijfhonderd"))
+            words(2, true).unit(Unit::Minute).to_words(),
+            Ok(String::from("twee minuten"))
         );
+    }
+
+    #[test]
+    fn test_plural_unit() {
         assert_eq!(
-            Num2Words::new(50).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("vijftig"))
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .prefer("plural-unit")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("twee euro's"))
         );
         assert_eq!(
-            Num2Words::new(0).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("nul"))
+            Num2Words::new(1)
+                .lang(Lang::Dutch)
+                .prefer("plural-unit")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("één euro"))
         );
         assert_eq!(
-            Num2Words::new(-44).lang(Lang::Dutch).year().to_words(),
-            Ok(String::from("vierenveertig voor christus"))
+            Num2Words::new(2.5)
+                .lang(Lang::Dutch)
+                .prefer("plural-unit")
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("twee euro's en vijftig centen"))
         );
         assert_eq!(
-            Num2Words::new(1.1).lang(Lang::Dutch).year().to_words(),
-            Err(num2words::Num2Err::FloatingYear)
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .prefer("plural-unit")
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("twee US dollars"))
         );
     }
 
     #[test]
-    fn test_prefer() {
+    fn test_paren() {
         assert_eq!(
-            Num2Words::new(0)
+            Num2Words::new(-123)
                 .lang(Lang::Dutch)
-                .prefer("oh")
+                .prefer("paren")
                 .to_words(),
-            Ok(String::from("nul"))
+            Ok(String::from("(honderddrieëntwintig)"))
         );
         assert_eq!(
-            Num2Words::new(0)
+            Num2Words::new(-12.5)
                 .lang(Lang::Dutch)
-                .prefer("nil")
+                .prefer("paren")
                 .to_words(),
-            Ok(String::from("nul"))
+            Ok(String::from("(twaalf komma vijf)"))
         );
         assert_eq!(
-            Num2Words::new(0.005)
+            Num2Words::new(123)
                 .lang(Lang::Dutch)
-                .prefer("oh")
+                .prefer("paren")
                 .to_words(),
-            Ok(String::from("nul komma nul nul vijf"))
+            Ok(String::from("honderddrieëntwintig"))
         );
         assert_eq!(
-            Num2Words::new(2.05)
-                .lang(Lang::Dutch)
-                .prefer("nil")
-                .to_words(),
-            Ok(String::from("twee komma nul vijf"))
+            Num2Words::new(-0.5).lang(Lang::Dutch).to_words(),
+            Ok(String::from("minus nul komma vijf"))
         );
     }
 
@@ -675,7 +2049,7 @@ mod tests {
                 .lang(Lang::Dutch)
                 .cardinal()
                 .to_words(),
-            Err(num2words::Num2Err::CannotConvert)
+            Ok(String::from("tien sedeciljard"))
         );
     }
 
@@ -724,4 +2098,156 @@ mod tests {
             Ok(String::from("oneindig dollar"))
         );
     }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Dutch)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("vijf kilometer"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Dutch)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("twee minuten"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Dutch)
+                .unit(Unit::Day)
+                .to_words(),
+            Ok(String::from("één dag"))
+        );
+    }
+
+    #[test]
+    fn test_negative_word() {
+        assert_eq!(
+            Num2Words::new(-10)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .prefer("negatief")
+                .to_words(),
+            Ok(String::from("negatief tien"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .prefer("negatief")
+                .to_words(),
+            Ok(String::from("negatief twee komma vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(-5)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .prefer("negatief")
+                .to_words(),
+            Ok(String::from("negatief vijf euro"))
+        );
+    }
+
+    #[test]
+    fn test_decimal_word() {
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Dutch)
+                .cardinal()
+                .prefer("punt")
+                .to_words(),
+            Ok(String::from("twee punt vijf"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .prefer("punt")
+                .prefer("decimal-currency")
+                .to_words(),
+            Ok(String::from("vijf punt vijf nul euro"))
+        );
+    }
+
+    #[test]
+    fn test_whole_fraction() {
+        assert_eq!(
+            Num2Words::new(12.51)
+                .lang(Lang::Dutch)
+                .prefer("whole-fraction")
+                .to_words(),
+            Ok(String::from("twaalf komma éénenvijftig"))
+        );
+        assert_eq!(
+            Num2Words::new(3.05)
+                .lang(Lang::Dutch)
+                .prefer("whole-fraction")
+                .to_words(),
+            Ok(String::from("drie komma nul vijf"))
+        );
+    }
+
+    #[test]
+    fn test_currency_debit() {
+        assert_eq!(
+            Num2Words::new(-5.5)
+                .lang(Lang::Dutch)
+                .currency(Currency::EUR)
+                .prefer("debit")
+                .to_words(),
+            Ok(String::from("vijf euro en vijftig cent debet"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_num_long() {
+        let ordinal_num = |n: i64| {
+            Num2Words::new(n)
+                .lang(Lang::Dutch)
+                .prefer("ste-de")
+                .ordinal_num()
+                .to_words()
+        };
+        assert_eq!(ordinal_num(1), Ok(String::from("1ste")));
+        assert_eq!(ordinal_num(2), Ok(String::from("2de")));
+        assert_eq!(ordinal_num(8), Ok(String::from("8ste")));
+        assert_eq!(ordinal_num(19), Ok(String::from("19de")));
+        assert_eq!(ordinal_num(20), Ok(String::from("20ste")));
+        assert_eq!(ordinal_num(1000), Ok(String::from("1000ste")));
+    }
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(
+            Num2Words::new(3_141_592)
+                .lang(Lang::Dutch)
+                .approximate()
+                .to_words(),
+            Ok(String::from("ongeveer drie miljoen"))
+        );
+        assert_eq!(
+            Num2Words::new(3_141_592)
+                .lang(Lang::Dutch)
+                .approximate()
+                .significant_digits(2)
+                .to_words(),
+            Ok(String::from("ongeveer drie miljoen honderdduizend"))
+        );
+    }
+
+    #[test]
+    fn test_compact() {
+        let compact = |n: f64| Num2Words::new(n).lang(Lang::Dutch).compact().to_words();
+        assert_eq!(compact(2.5e6), Ok(String::from("twee en een half miljoen")));
+        assert_eq!(compact(7.5e8), Ok(String::from("driekwart miljard")));
+        assert_eq!(
+            compact(1.25e6),
+            Ok(String::from("één en een kwart miljoen"))
+        );
+        assert_eq!(compact(3e6), Ok(String::from("drie miljoen")));
+    }
 }
@@ -0,0 +1,224 @@
+use crate::{lang::germanic::Germanic, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Norwegian {}
+
+// Bokmål, with the tens read before the units, e.g. "førtito"
+const WORDS: Germanic = Germanic {
+    units: [
+        "null", "en", "to", "tre", "fire", "fem", "seks", "sju", "åtte", "ni", "ti", "elleve",
+        "tolv", "tretten", "fjorten", "femten", "seksten", "sytten", "atten", "nitten",
+    ],
+    tens: [
+        "", "ti", "tjue", "tretti", "førti", "femti", "seksti", "sytti", "åtti", "nitti",
+    ],
+    hundred: "hundre",
+    thousand: "tusen",
+    megas: [
+        ["million", "millioner"],
+        ["milliard", "milliarder"],
+        ["billion", "billioner"],
+        ["billiard", "billiarder"],
+    ],
+    ordinal_units: [
+        "nullte",
+        "første",
+        "andre",
+        "tredje",
+        "fjerde",
+        "femte",
+        "sjette",
+        "sjuende",
+        "åttende",
+        "niende",
+        "tiende",
+        "ellevte",
+        "tolvte",
+        "trettende",
+        "fjortende",
+        "femtende",
+        "sekstende",
+        "syttende",
+        "attende",
+        "nittende",
+    ],
+    ordinal_tens: [
+        "",
+        "tiende",
+        "tjuende",
+        "trettiende",
+        "førtiende",
+        "femtiende",
+        "sekstiende",
+        "syttiende",
+        "åttiende",
+        "nittiende",
+    ],
+    ordinal_hundred: "hundrede",
+    ordinal_thousand: "tusende",
+    neuter_one: "ett",
+    common_one: "en",
+    and: Some("og"),
+    joiner: " ",
+    units_first: false,
+    connector: " og ",
+    minus: "minus",
+    point: "komma",
+    infinity: "uendelig",
+    before_christ: "før Kristus",
+};
+
+impl Norwegian {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::NOK if plural_form => String::from("kroner"),
+            Currency::NOK => String::from("krone"),
+            Currency::SEK if plural_form => String::from("svenske kroner"),
+            Currency::SEK => String::from("svensk krone"),
+            Currency::DKK if plural_form => String::from("danske kroner"),
+            Currency::DKK => String::from("dansk krone"),
+            Currency::DOLLAR | Currency::USD => String::from("dollar"),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from("pund"),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::NOK | Currency::SEK | Currency::DKK => String::from("øre"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("cent"),
+            Currency::GBP => String::from("pence"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+}
+
+impl Language for Norwegian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_cardinal(num)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_ordinal(num)
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_year(num)
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        WORDS.to_currency(
+            num,
+            currency,
+            |c, plural| self.currencies(c, plural),
+            |c, plural| self.cents(c, plural),
+        )
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        WORDS.connector
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        WORDS.scale_word(power)
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        WORDS.scale_plural(word)
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        WORDS.hundred
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        WORDS.thousand
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("prosent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("promille")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Norwegian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("førtito"))
+        );
+        assert_eq!(
+            Num2Words::new(125)
+                .lang(Lang::Norwegian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ett hundre og tjuefem"))
+        );
+        assert_eq!(
+            Num2Words::new(1005)
+                .lang(Lang::Norwegian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ett tusen og fem"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000)
+                .lang(Lang::Norwegian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("tre millioner"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Norwegian).ordinal().to_words(),
+            Ok(String::from("andre"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Norwegian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("tjueførste"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Norwegian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("5."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(2.25)
+                .lang(Lang::Norwegian)
+                .currency(Currency::NOK)
+                .to_words(),
+            Ok(String::from("to kroner og tjuefem øre"))
+        );
+    }
+}
@@ -0,0 +1,794 @@
+use crate::{
+    lang::{polish_plural, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language, Unit,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Polish {}
+
+const UNITS: [&str; 9] = [
+    "jeden",
+    "dwa",
+    "trzy",
+    "cztery",
+    "pięć",
+    "sześć",
+    "siedem",
+    "osiem",
+    "dziewięć",
+];
+
+const TENS: [&str; 9] = [
+    "dziesięć",
+    "dwadzieścia",
+    "trzydzieści",
+    "czterdzieści",
+    "pięćdziesiąt",
+    "sześćdziesiąt",
+    "siedemdziesiąt",
+    "osiemdziesiąt",
+    "dziewięćdziesiąt",
+];
+
+const TEENS: [&str; 10] = [
+    "dziesięć",
+    "jedenaście",
+    "dwanaście",
+    "trzynaście",
+    "czternaście",
+    "piętnaście",
+    "szesnaście",
+    "siedemnaście",
+    "osiemnaście",
+    "dziewiętnaście",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "sto",
+    "dwieście",
+    "trzysta",
+    "czterysta",
+    "pięćset",
+    "sześćset",
+    "siedemset",
+    "osiemset",
+    "dziewięćset",
+];
+
+// One, few and many forms, e.g. "tysiąc", "dwa tysiące", "pięć tysięcy"
+const MEGAS: [[&str; 3]; 8] = [
+    ["tysiąc", "tysiące", "tysięcy"],
+    ["milion", "miliony", "milionów"],
+    ["miliard", "miliardy", "miliardów"],
+    ["bilion", "biliony", "bilionów"],
+    ["biliard", "biliardy", "biliardów"],
+    ["trylion", "tryliony", "trylionów"],
+    ["tryliard", "tryliardy", "tryliardów"],
+    ["kwadrylion", "kwadryliony", "kwadrylionów"],
+];
+
+const UNITS_ORDINAL: [&str; 9] = [
+    "pierwszy",
+    "drugi",
+    "trzeci",
+    "czwarty",
+    "piąty",
+    "szósty",
+    "siódmy",
+    "ósmy",
+    "dziewiąty",
+];
+
+const TENS_ORDINAL: [&str; 9] = [
+    "dziesiąty",
+    "dwudziesty",
+    "trzydziesty",
+    "czterdziesty",
+    "pięćdziesiąty",
+    "sześćdziesiąty",
+    "siedemdziesiąty",
+    "osiemdziesiąty",
+    "dziewięćdziesiąty",
+];
+
+const TEENS_ORDINAL: [&str; 10] = [
+    "dziesiąty",
+    "jedenasty",
+    "dwunasty",
+    "trzynasty",
+    "czternasty",
+    "piętnasty",
+    "szesnasty",
+    "siedemnasty",
+    "osiemnasty",
+    "dziewiętnasty",
+];
+
+const HUNDREDS_ORDINAL: [&str; 9] = [
+    "setny",
+    "dwusetny",
+    "trzechsetny",
+    "czterechsetny",
+    "pięćsetny",
+    "sześćsetny",
+    "siedemsetny",
+    "osiemsetny",
+    "dziewięćsetny",
+];
+
+const MEGAS_ORDINAL: [&str; 8] = [
+    "tysięczny",
+    "milionowy",
+    "miliardowy",
+    "bilionowy",
+    "biliardowy",
+    "trylionowy",
+    "tryliardowy",
+    "kwadrylionowy",
+];
+
+// prefixes of compound ordinals, e.g. "dwu" in "dwutysięczny"
+const UNITS_PREFIX: [&str; 9] = [
+    "jedno",
+    "dwu",
+    "trzy",
+    "cztero",
+    "pięcio",
+    "sześcio",
+    "siedmio",
+    "ośmio",
+    "dziewięcio",
+];
+
+const TENS_PREFIX: [&str; 9] = [
+    "dziesięcio",
+    "dwudziesto",
+    "trzydziesto",
+    "czterdziesto",
+    "pięćdziesięcio",
+    "sześćdziesięcio",
+    "siedemdziesięcio",
+    "osiemdziesięcio",
+    "dziewięćdziesięcio",
+];
+
+const TEENS_PREFIX: [&str; 10] = [
+    "dziesięcio",
+    "jedenasto",
+    "dwunasto",
+    "trzynasto",
+    "czternasto",
+    "piętnasto",
+    "szesnasto",
+    "siedemnasto",
+    "osiemnasto",
+    "dziewiętnasto",
+];
+
+const HUNDREDS_PREFIX: [&str; 9] = [
+    "stu",
+    "dwustu",
+    "trzystu",
+    "czterystu",
+    "pięćset",
+    "sześćset",
+    "siedemset",
+    "osiemset",
+    "dziewięćset",
+];
+
+impl Polish {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, form: usize) -> String {
+        match currency {
+            Currency::PLN => String::from(["złoty", "złote", "złotych"][form]),
+            Currency::DOLLAR | Currency::USD => String::from(["dolar", "dolary", "dolarów"][form]),
+            Currency::EUR => String::from("euro"),
+            _ => currency.default_string(form != 0),
+        }
+    }
+
+    fn cents(&self, currency: Currency, form: usize) -> String {
+        match currency {
+            Currency::PLN => String::from(["grosz", "grosze", "groszy"][form]),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => {
+                String::from(["cent", "centy", "centów"][form])
+            }
+            _ => currency.default_subunit_string("cent{}", form != 0),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn triplet_to_words(&self, triplet: u64) -> Vec<String> {
+        let mut words = vec![];
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+
+        if hundreds > 0 {
+            words.push(String::from(HUNDREDS[hundreds - 1]));
+        }
+
+        match tens {
+            0 => (),
+            1 => words.push(String::from(TEENS[units])),
+            _ => words.push(String::from(TENS[tens - 1])),
+        }
+
+        if tens != 1 && units > 0 {
+            words.push(String::from(UNITS[units - 1]));
+        }
+
+        words
+    }
+
+    // e.g. 2 => "dwu", 25 => "dwudziestopięcio", 1 => "" as in "tysięczny"
+    fn triplet_to_prefix(&self, triplet: u64) -> String {
+        if triplet == 1 {
+            return String::new();
+        }
+
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut word = String::new();
+
+        if hundreds > 0 {
+            word.push_str(HUNDREDS_PREFIX[hundreds - 1]);
+        }
+
+        match tens {
+            0 => (),
+            1 => word.push_str(TEENS_PREFIX[units]),
+            _ => word.push_str(TENS_PREFIX[tens - 1]),
+        }
+
+        if tens != 1 && units > 0 {
+            word.push_str(UNITS_PREFIX[units - 1]);
+        }
+
+        word
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("minus"));
+            num = -num;
+        }
+
+        // iterate over thousands
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            // one is left out before a scale word, e.g. "tysiąc", "milion"
+            if i == 0 || *triplet != 1 {
+                words.extend(self.triplet_to_words(*triplet));
+            }
+
+            if i != 0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let form = polish_plural(BigFloat::from(*triplet));
+                words.push(String::from(MEGAS[i - 1][form]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    // whole numbers counting feminine nouns end in "jedna" and "dwie", e.g.
+    // "jedna minuta" and "dwadzieścia dwie minuty", but "dwadzieścia jeden
+    // minut" keeps "jeden"
+    fn feminine_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let words = self.to_cardinal(num)?;
+        if num.is_inf() || !num.frac().is_zero() {
+            return Ok(words);
+        }
+        if num.abs() == BigFloat::from(1) {
+            return Ok(words.replace("jeden", "jedna"));
+        }
+        match words.strip_suffix("dwa") {
+            Some(rest) if rest.is_empty() || rest.ends_with(' ') => Ok(format!("{}dwie", rest)),
+            _ => Ok(words),
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if negative {
+            words.push(String::from("minus"));
+        }
+
+        let integral_word = self.int_to_cardinal(integral_part)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("przecinek"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("zero"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Polish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("nieskończoność"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus nieskończoność"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from("zerowy"));
+        }
+
+        let thousands = self.split_thousands(num);
+        // only the lowest non-zero group is read as an ordinal
+        let (i, triplet) = thousands
+            .iter()
+            .enumerate()
+            .find(|(_, triplet)| **triplet != 0)
+            .unwrap();
+
+        let mut words: Vec<String> = vec![];
+        let mut lowest = BigFloat::from(*triplet);
+        for _ in 0..i {
+            lowest *= BigFloat::from(1000);
+        }
+        let higher = num - lowest;
+        if !higher.is_zero() {
+            words.push(self.int_to_cardinal(higher)?);
+        }
+
+        if i != 0 {
+            // e.g. "dwutysięczny", "milionowy"
+            if i > MEGAS_ORDINAL.len() {
+                return Err(Num2Err::CannotConvert);
+            }
+            words.push(format!(
+                "{}{}",
+                self.triplet_to_prefix(*triplet),
+                MEGAS_ORDINAL[i - 1]
+            ));
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let tens = (triplet / 10 % 10) as usize;
+            let units = (triplet % 10) as usize;
+
+            if tens == 0 && units == 0 {
+                words.push(String::from(HUNDREDS_ORDINAL[hundreds - 1]));
+            } else {
+                // e.g. "sto dwudziesty trzeci"
+                if hundreds > 0 {
+                    words.push(String::from(HUNDREDS[hundreds - 1]));
+                }
+                match (tens, units) {
+                    (1, _) => words.push(String::from(TEENS_ORDINAL[units])),
+                    (_, 0) => words.push(String::from(TENS_ORDINAL[tens - 1])),
+                    (0, _) => words.push(String::from(UNITS_ORDINAL[units - 1])),
+                    _ => {
+                        words.push(String::from(TENS_ORDINAL[tens - 1]));
+                        words.push(String::from(UNITS_ORDINAL[units - 1]));
+                    }
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} p.n.e.", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, 2)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus pięć złotych pięćdziesiąt groszy"
+            Ok(format!("minus {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!(
+                "{} {}",
+                words,
+                self.currencies(currency, polish_plural(num))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency, polish_plural(cents_nb));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDREDS[0]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0][0]
+    }
+
+    // seconds, minutes, hours and miles are feminine, e.g. "dwie minuty"
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let words = self.unit_words(unit).ok_or(Num2Err::CannotConvert)?;
+        let cardinal = match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Mile => self.feminine_cardinal(num)?,
+            _ => self.to_cardinal(num)?,
+        };
+        Ok(format!("{} {}", cardinal, words[self.unit_plural(num)]))
+    }
+
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        match unit {
+            Unit::Millimeter => Some(["milimetr", "milimetry", "milimetrów", "milimetra"]),
+            Unit::Centimeter => Some(["centymetr", "centymetry", "centymetrów", "centymetra"]),
+            Unit::Meter => Some(["metr", "metry", "metrów", "metra"]),
+            Unit::Kilometer => Some(["kilometr", "kilometry", "kilometrów", "kilometra"]),
+            Unit::Mile => Some(["mila", "mile", "mil", "mili"]),
+            Unit::Gram => Some(["gram", "gramy", "gramów", "grama"]),
+            Unit::Kilogram => Some(["kilogram", "kilogramy", "kilogramów", "kilograma"]),
+            Unit::Liter => Some(["litr", "litry", "litrów", "litra"]),
+            Unit::Second => Some(["sekunda", "sekundy", "sekund", "sekundy"]),
+            Unit::Minute => Some(["minuta", "minuty", "minut", "minuty"]),
+            Unit::Hour => Some(["godzina", "godziny", "godzin", "godziny"]),
+            Unit::Day => Some(["dzień", "dni", "dni", "dnia"]),
+            _ => unit.custom_words(),
+        }
+    }
+
+    fn unit_plural(&self, num: BigFloat) -> usize {
+        if num.is_inf() {
+            2
+        } else if !num.frac().is_zero() {
+            3
+        } else {
+            polish_plural(num)
+        }
+    }
+
+    // "promil" agrees with the number, e.g. "dwa promile", "pięć promili" and
+    // "dwa przecinek pięć promila"
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let word = ["promil", "promile", "promili", "promila"][self.unit_plural(num)];
+        Ok(format!("{} {}", self.to_cardinal(num)?, word))
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("procent")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Polish).cardinal().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Polish).cardinal().to_words(),
+            Ok(String::from("dwadzieścia jeden"))
+        );
+        assert_eq!(
+            Num2Words::new(-115)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus sto piętnaście"))
+        );
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dwanaście przecinek pięć"))
+        );
+    }
+
+    #[test]
+    fn test_plural_forms() {
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("tysiąc"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dwa tysiące"))
+        );
+        assert_eq!(
+            Num2Words::new(5000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("pięć tysięcy"))
+        );
+        assert_eq!(
+            Num2Words::new(12000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dwanaście tysięcy"))
+        );
+        assert_eq!(
+            Num2Words::new(21000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dwadzieścia jeden tysięcy"))
+        );
+        assert_eq!(
+            Num2Words::new(22000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dwadzieścia dwa tysiące"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("milion"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000)
+                .lang(Lang::Polish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("trzy miliony"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("pierwszy"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("sto dwudziesty trzeci"))
+        );
+        assert_eq!(
+            Num2Words::new(2000).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("dwutysięczny"))
+        );
+        assert_eq!(
+            Num2Words::new(1001).lang(Lang::Polish).ordinal().to_words(),
+            Ok(String::from("tysiąc pierwszy"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Polish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("3."))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("jeden złoty"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwa złote"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("dwadzieścia jeden złotych"))
+        );
+        assert_eq!(
+            Num2Words::new(5.22)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("pięć złotych dwadzieścia dwa grosze"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Polish)
+                .currency(Currency::PLN)
+                .to_words(),
+            Ok(String::from("jeden grosz"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Polish).percent().to_words(),
+            Ok(String::from("dwa procent"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Polish).permille().to_words(),
+            Ok(String::from("trzy promile"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Polish).permille().to_words(),
+            Ok(String::from("pięć promili"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Polish).permille().to_words(),
+            Ok(String::from("jeden promil"))
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang(Lang::Polish)
+                .permille()
+                .to_words(),
+            Ok(String::from("nieskończoność promili"))
+        );
+        assert_eq!(
+            Num2Words::new(f64::NEG_INFINITY)
+                .lang(Lang::Polish)
+                .permille()
+                .to_words(),
+            Ok(String::from("minus nieskończoność promili"))
+        );
+    }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("jeden kilometr"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Polish)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("dwa kilometry"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Polish)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("pięć kilometrów"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Polish)
+                .unit(Unit::Gram)
+                .to_words(),
+            Ok(String::from("dwadzieścia dwa gramy"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Polish)
+                .unit(Unit::Minute)
+                .to_words(),
+            Ok(String::from("jedna minuta"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Polish)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("dwie godziny"))
+        );
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Polish)
+                .unit(Unit::Second)
+                .to_words(),
+            Ok(String::from("dwadzieścia jeden sekund"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Polish)
+                .unit(Unit::Mile)
+                .to_words(),
+            Ok(String::from("dwadzieścia dwie mile"))
+        );
+    }
+}
@@ -0,0 +1,415 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Pashto {}
+
+const UNITS: [&str; 9] = [
+    "یو", "دوه", "درې", "څلور", "پنځه", "شپږ", "اووه", "اته", "نهه",
+];
+
+// Numbers below a hundred are mostly irregular (e.g. 23 is "درویشت" while 25
+// is "پنځه ویشت"), so they are all listed
+#[rustfmt::skip]
+const BELOW_HUNDRED: [&str; 99] = [
+    "یو", "دوه", "درې", "څلور", "پنځه", "شپږ", "اووه", "اته", "نهه",
+    "لس", "یوولس", "دولس", "دیارلس", "څوارلس", "پنځلس", "شپاړس", "اوولس", "اتلس", "نولس",
+    "شل", "یوویشت", "دوه ویشت", "درویشت", "څلرویشت", "پنځه ویشت", "شپږویشت", "اوه ویشت", "اته ویشت", "نهه ویشت",
+    "دېرش", "یو دېرش", "دوه دېرش", "درې دېرش", "څلور دېرش", "پنځه دېرش", "شپږ دېرش", "اوه دېرش", "اته دېرش", "نهه دېرش",
+    "څلوېښت", "یو څلوېښت", "دوه څلوېښت", "درې څلوېښت", "څلور څلوېښت", "پنځه څلوېښت", "شپږ څلوېښت", "اوه څلوېښت", "اته څلوېښت", "نهه څلوېښت",
+    "پنځوس", "یو پنځوس", "دوه پنځوس", "درې پنځوس", "څلور پنځوس", "پنځه پنځوس", "شپږ پنځوس", "اوه پنځوس", "اته پنځوس", "نهه پنځوس",
+    "شپېته", "یو شپېته", "دوه شپېته", "درې شپېته", "څلور شپېته", "پنځه شپېته", "شپږ شپېته", "اوه شپېته", "اته شپېته", "نهه شپېته",
+    "اویا", "یو اویا", "دوه اویا", "درې اویا", "څلور اویا", "پنځه اویا", "شپږ اویا", "اوه اویا", "اته اویا", "نهه اویا",
+    "اتیا", "یو اتیا", "دوه اتیا", "درې اتیا", "څلور اتیا", "پنځه اتیا", "شپږ اتیا", "اوه اتیا", "اته اتیا", "نهه اتیا",
+    "نوي", "یو نوي", "دوه نوي", "درې نوي", "څلور نوي", "پنځه نوي", "شپږ نوي", "اوه نوي", "اته نوي", "نهه نوي",
+];
+
+const HUNDRED: &str = "سل";
+
+const HUNDREDS: &str = "سوه";
+
+// Singular and plural forms, e.g. "زر" (1000) but "دوه زره" (2000)
+const MEGAS: [[&str; 2]; 7] = [
+    ["زر", "زره"],
+    ["میلیون", "میلیونه"],
+    ["میلیارد", "میلیارده"],
+    ["ټریلیون", "ټریلیونه"],
+    ["کواډریلیون", "کواډریلیونه"],
+    ["کوینټیلیون", "کوینټیلیونه"],
+    ["سکسټیلیون", "سکسټیلیونه"],
+];
+
+impl Pashto {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AFN => String::from("افغانۍ"),
+            Currency::DOLLAR => String::from("ډالر"),
+            Currency::USD => String::from("امریکايي ډالر"),
+            Currency::EUR => String::from("یورو"),
+            _ => currency.default_string(false),
+        }
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        match currency {
+            Currency::AFN => String::from("پول"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("سنټ"),
+            _ => currency.default_subunit_string("cent{}", false),
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("صفر"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("منفي"));
+            num = -num;
+        }
+
+        // iterate over thousands
+        let mut first_elem = true;
+        for (i, triplet) in self.split_thousands(num).iter().enumerate().rev() {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let below_hundred = (triplet % 100) as usize;
+
+            if hundreds == 1 {
+                // case 100 => "سل" rather than "one hundred"
+                words.push(String::from(self.hundred_word()));
+                first_elem = false;
+            } else if hundreds > 1 {
+                words.push(String::from(UNITS[hundreds - 1]));
+                words.push(String::from(HUNDREDS));
+                first_elem = false;
+            }
+
+            if below_hundred != 0 {
+                if i == 0 && !first_elem {
+                    words.push(String::from("او"));
+                } else {
+                    first_elem = false;
+                }
+
+                // case 1000 => "زر" rather than "one thousand"
+                if !(i == 1 && hundreds == 0 && below_hundred == 1) {
+                    words.push(String::from(BELOW_HUNDRED[below_hundred - 1]));
+                }
+            }
+
+            if i != 0 && triplet != &0 {
+                if i > MEGAS.len() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                words.push(String::from(MEGAS[i - 1][(*triplet != 1) as usize]));
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        let integral_word = self.int_to_cardinal(integral_part)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("اعشاریه"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("صفر"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+}
+
+impl Language for Pashto {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("بې نهایته"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("منفي بې نهایته"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from("لومړی"));
+        }
+        let cardinal_word = self.to_cardinal(num)?;
+
+        let mut words: Vec<String> = vec![];
+        let mut split = cardinal_word.split_whitespace().peekable();
+
+        while let Some(w) = split.next() {
+            if split.peek().is_some() {
+                // not last word, no modification needed
+                words.push(String::from(w));
+            } else {
+                // last word, needs to be processed
+                words.push(match w {
+                    "دوه" => String::from("دوهم"),
+                    "درې" => String::from("درېیم"),
+                    _ => format!("{}م", w.strip_suffix('ه').unwrap_or(w)),
+                });
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok("م")
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} له میلاد مخکې", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once rather than for both units and cents
+            Ok(format!("منفي {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(format!("{} {}", words, self.currencies(currency)))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb)?;
+            let cents_suffix = self.cents(currency);
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " او "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        MEGAS.get(power.checked_sub(1)?).map(|m| m[0])
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDRED
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        MEGAS[0][0]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("صفر"))
+        );
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("یو"))
+        );
+        assert_eq!(
+            Num2Words::new(11).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("یوولس"))
+        );
+        assert_eq!(
+            Num2Words::new(20).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("شل"))
+        );
+        assert_eq!(
+            Num2Words::new(23).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("درویشت"))
+        );
+        assert_eq!(
+            Num2Words::new(25).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("پنځه ویشت"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("دوه څلوېښت"))
+        );
+        assert_eq!(
+            Num2Words::new(99).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("نهه نوي"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("سل"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("سل او درویشت"))
+        );
+        assert_eq!(
+            Num2Words::new(300).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("درې سوه"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Pashto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("زر"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Pashto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("دوه زره"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Pashto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("یو میلیون"))
+        );
+        assert_eq!(
+            Num2Words::new(-10).lang(Lang::Pashto).cardinal().to_words(),
+            Ok(String::from("منفي لس"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Pashto).ordinal().to_words(),
+            Ok(String::from("لومړی"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Pashto).ordinal().to_words(),
+            Ok(String::from("دوهم"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Pashto).ordinal().to_words(),
+            Ok(String::from("پنځم"))
+        );
+        assert_eq!(
+            Num2Words::new(10).lang(Lang::Pashto).ordinal().to_words(),
+            Ok(String::from("لسم"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Pashto)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("5م"))
+        );
+    }
+
+    #[test]
+    fn test_cardinal_float() {
+        assert_eq!(
+            Num2Words::new(12.5)
+                .lang(Lang::Pashto)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("دولس اعشاریه پنځه"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Pashto)
+                .currency(Currency::AFN)
+                .to_words(),
+            Ok(String::from("پنځه افغانۍ"))
+        );
+        assert_eq!(
+            Num2Words::new(1.5)
+                .lang(Lang::Pashto)
+                .currency(Currency::AFN)
+                .to_words(),
+            Ok(String::from("یو افغانۍ او پنځوس پول"))
+        );
+    }
+
+    #[test]
+    fn test_big_num() {
+        use crate::lang::ps::MEGAS;
+        use num_bigfloat::BigFloat;
+
+        let mut num = BigFloat::from(1000);
+        for m in MEGAS.iter().skip(1) {
+            num *= BigFloat::from(1000);
+            assert_eq!(
+                Num2Words::new(num).lang(Lang::Pashto).cardinal().to_words(),
+                Ok(format!("یو {}", m[0]))
+            );
+        }
+
+        assert_eq!(
+            Num2Words::new(1e30)
+                .lang(Lang::Pashto)
+                .cardinal()
+                .to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+}
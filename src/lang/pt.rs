@@ -0,0 +1,782 @@
+use crate::{lang::MAX_FRACTION_DIGITS, num2words::Num2Err, Currency, Gender, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Portuguese {
+    feminine: bool,
+    region: RegionPortuguese,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum RegionPortuguese {
+    #[default]
+    PT,
+    BR,
+}
+
+const UNITS: [&str; 9] = [
+    "um", "dois", "três", "quatro", "cinco", "seis", "sete", "oito", "nove",
+];
+
+const TENS: [&str; 9] = [
+    "dez",
+    "vinte",
+    "trinta",
+    "quarenta",
+    "cinquenta",
+    "sessenta",
+    "setenta",
+    "oitenta",
+    "noventa",
+];
+
+const HUNDREDS: [&str; 9] = [
+    "cento",
+    "duzentos",
+    "trezentos",
+    "quatrocentos",
+    "quinhentos",
+    "seiscentos",
+    "setecentos",
+    "oitocentos",
+    "novecentos",
+];
+
+const UNITS_ORDINAL: [&str; 9] = [
+    "primeiro", "segundo", "terceiro", "quarto", "quinto", "sexto", "sétimo", "oitavo", "nono",
+];
+
+const TENS_ORDINAL: [&str; 9] = [
+    "décimo",
+    "vigésimo",
+    "trigésimo",
+    "quadragésimo",
+    "quinquagésimo",
+    "sexagésimo",
+    "septuagésimo",
+    "octogésimo",
+    "nonagésimo",
+];
+
+const HUNDREDS_ORDINAL: [&str; 9] = [
+    "centésimo",
+    "ducentésimo",
+    "trecentésimo",
+    "quadringentésimo",
+    "quingentésimo",
+    "sexcentésimo",
+    "septingentésimo",
+    "octingentésimo",
+    "noningentésimo",
+];
+
+// short scale, one word per power of a thousand
+const MEGAS_BR: [[&str; 2]; 7] = [
+    ["milhão", "milhões"],
+    ["bilhão", "bilhões"],
+    ["trilhão", "trilhões"],
+    ["quatrilhão", "quatrilhões"],
+    ["quintilhão", "quintilhões"],
+    ["sextilhão", "sextilhões"],
+    ["septilhão", "septilhões"],
+];
+
+// long scale, one word per power of a million
+const MEGAS_PT: [[&str; 2]; 4] = [
+    ["milhão", "milhões"],
+    ["bilião", "biliões"],
+    ["trilião", "triliões"],
+    ["quatrilião", "quatriliões"],
+];
+
+const SCALES_PT: [&str; 8] = [
+    "mil",
+    "milhão",
+    "mil milhões",
+    "bilião",
+    "mil biliões",
+    "trilião",
+    "mil triliões",
+    "quatrilião",
+];
+
+impl Portuguese {
+    pub fn new(feminine: bool, region: RegionPortuguese) -> Self {
+        Self { feminine, region }
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::BRL => String::from(if plural_form { "reais" } else { "real" }),
+            Currency::EUR => String::from(if plural_form { "euros" } else { "euro" }),
+            Currency::DOLLAR | Currency::USD => {
+                String::from(if plural_form { "dólares" } else { "dólar" })
+            }
+            Currency::GBP => String::from(if plural_form { "libras" } else { "libra" }),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    // the number agrees with feminine names, e.g. "duas libras"
+    fn currency_feminine(&self, currency: Currency) -> bool {
+        match currency {
+            Currency::GBP => true,
+            _ => currency.is_feminine(),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match (currency, self.region) {
+            (Currency::EUR, RegionPortuguese::PT) => String::from("cêntimo{}"),
+            (Currency::BRL | Currency::EUR | Currency::DOLLAR | Currency::USD, _) => {
+                String::from("centavo{}")
+            }
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+        .replace("{}", if plural_form { "s" } else { "" })
+    }
+
+    fn teen(&self, units: usize) -> &'static str {
+        match (units, self.region) {
+            (0, _) => "dez",
+            (1, _) => "onze",
+            (2, _) => "doze",
+            (3, _) => "treze",
+            (4, RegionPortuguese::PT) => "catorze",
+            (4, RegionPortuguese::BR) => "quatorze",
+            (5, _) => "quinze",
+            (6, RegionPortuguese::PT) => "dezasseis",
+            (6, RegionPortuguese::BR) => "dezesseis",
+            (7, RegionPortuguese::PT) => "dezassete",
+            (7, RegionPortuguese::BR) => "dezessete",
+            (8, _) => "dezoito",
+            (9, RegionPortuguese::PT) => "dezanove",
+            _ => "dezenove",
+        }
+    }
+
+    // "duzentas", "uma" and "duas" agree with a feminine noun
+    fn feminize(&self, word: &str, feminine: bool) -> String {
+        if !feminine {
+            return String::from(word);
+        }
+        match word {
+            "um" => String::from("uma"),
+            "dois" => String::from("duas"),
+            _ => match word.strip_suffix("os") {
+                Some(head) if word != "cento" => format!("{}as", head),
+                _ => String::from(word),
+            },
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 123 => "cento e vinte e três", 100 => "cem"
+    fn triplet_to_words(&self, triplet: u64, feminine: bool) -> String {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(if triplet == 100 {
+                String::from("cem")
+            } else {
+                self.feminize(HUNDREDS[hundreds - 1], feminine)
+            });
+        }
+
+        match tens {
+            0 => (),
+            1 => words.push(String::from(self.teen(units))),
+            _ => words.push(String::from(TENS[tens - 1])),
+        }
+
+        if tens != 1 && units > 0 {
+            words.push(self.feminize(UNITS[units - 1], feminine));
+        }
+
+        words.join(" e ")
+    }
+
+    // scale word read after the group of the given power of a thousand
+    fn group_scale(&self, thousands: &[u64], i: usize) -> Result<String, Num2Err> {
+        let plural = |one: bool, mega: [&str; 2]| String::from(mega[if one { 0 } else { 1 }]);
+        match (i, self.region) {
+            (0, _) => Ok(String::new()),
+            (1, _) => Ok(String::from("mil")),
+            (_, RegionPortuguese::BR) => match MEGAS_BR.get(i - 2) {
+                Some(mega) => Ok(plural(thousands[i] == 1, *mega)),
+                None => Err(Num2Err::CannotConvert),
+            },
+            (_, RegionPortuguese::PT) => {
+                let power = i / 2;
+                let mega = match MEGAS_PT.get(power - 1) {
+                    Some(mega) => *mega,
+                    None => return Err(Num2Err::CannotConvert),
+                };
+                if i % 2 == 1 {
+                    if thousands[i - 1] == 0 {
+                        // e.g. "mil milhões"
+                        Ok(format!("mil {}", mega[1]))
+                    } else {
+                        Ok(String::from("mil"))
+                    }
+                } else {
+                    // "um milhão" but "mil e um milhões"
+                    let high = thousands.get(i + 1).copied().unwrap_or(0);
+                    Ok(plural(thousands[i] == 1 && high == 0, mega))
+                }
+            }
+        }
+    }
+
+    fn int_to_cardinal(&self, mut num: BigFloat, feminine: bool) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        // handling negative values
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(String::from("menos"));
+            num = -num;
+        }
+
+        // read each group with its scale word, e.g. "dois mil"
+        let thousands = self.split_thousands(num);
+        let mut groups: Vec<(u64, String)> = vec![];
+        for (i, triplet) in thousands.iter().enumerate().rev() {
+            if *triplet == 0 {
+                continue;
+            }
+
+            let scale = self.group_scale(&thousands, i)?;
+            // "mil" is never counted, "um milhão" is
+            let thousand = i % 2 == 1 && (i == 1 || self.region == RegionPortuguese::PT);
+            let group = if *triplet == 1 && thousand {
+                scale
+            } else {
+                // thousands agree with the noun, millions are masculine
+                let count = self.triplet_to_words(*triplet, feminine && i <= 1);
+                format!("{} {}", count, scale).trim_end().to_string()
+            };
+            groups.push((*triplet, group));
+        }
+
+        // the last group is joined with "e" when it is round or below a
+        // hundred, e.g. "mil e cem", "mil e vinte" but "mil cento e um"
+        let last = groups.len() - 1;
+        for (i, (triplet, group)) in groups.into_iter().enumerate() {
+            if i == last && i != 0 && (triplet < 100 || triplet % 100 == 0) {
+                words.push(String::from("e"));
+            }
+            words.push(group);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let negative = num.is_negative();
+        let num = num.abs();
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if negative {
+            words.push(String::from("menos"));
+        }
+
+        let integral_word = self.int_to_cardinal(integral_part, self.feminine)?;
+        words.push(integral_word);
+
+        let mut ordinal_part = num.frac();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("vírgula"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(match digit.to_u64().unwrap() {
+                0 => String::from("zero"),
+                i => String::from(UNITS[i as usize - 1]),
+            });
+        }
+        Ok(words.join(" "))
+    }
+
+    // e.g. 123 => "centésimo vigésimo terceiro"
+    fn triplet_to_ordinal(&self, triplet: u64) -> Vec<&'static str> {
+        let hundreds = (triplet / 100 % 10) as usize;
+        let tens = (triplet / 10 % 10) as usize;
+        let units = (triplet % 10) as usize;
+        let mut words = vec![];
+
+        if hundreds > 0 {
+            words.push(HUNDREDS_ORDINAL[hundreds - 1]);
+        }
+        if tens > 0 {
+            words.push(TENS_ORDINAL[tens - 1]);
+        }
+        if units > 0 {
+            words.push(UNITS_ORDINAL[units - 1]);
+        }
+
+        words
+    }
+}
+
+impl Language for Portuguese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinito"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("menos infinito"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.feminine)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn gendered(&self, gender: Gender) -> Option<Box<dyn Language>> {
+        Some(Box::new(Portuguese::new(
+            gender == Gender::Feminine,
+            self.region,
+        )))
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() || num >= BigFloat::from(1_000_000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if num.is_zero() {
+            return Ok(String::from("zero"));
+        }
+
+        // e.g. "milésimo", "segundo milésimo"
+        let num = num.to_u64().unwrap();
+        let mut words: Vec<String> = vec![];
+        let thousands = num / 1000;
+        if thousands > 0 {
+            words.extend(
+                self.triplet_to_ordinal(thousands)
+                    .into_iter()
+                    .map(String::from)
+                    .filter(|_| thousands != 1),
+            );
+            words.push(String::from("milésimo"));
+        }
+        words.extend(
+            self.triplet_to_ordinal(num % 1000)
+                .into_iter()
+                .map(String::from),
+        );
+
+        if self.feminine {
+            // "primeira", "vigésima segunda"
+            for word in words.iter_mut() {
+                if let Some(head) = word.strip_suffix('o') {
+                    *word = format!("{}a", head);
+                }
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn ordinal_num_suffix(&self, _num: BigFloat) -> Result<&'static str, Num2Err> {
+        Ok(if self.feminine { "ª" } else { "º" })
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} a.C.", self.int_to_cardinal(-num, false)?))
+        } else {
+            self.int_to_cardinal(num, false)
+        }
+    }
+
+    fn to_decade(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // e.g. "os anos noventa", or "os anos mil e novecentos" for its first
+        // decade
+        let low = num % BigFloat::from(100);
+        match low.is_zero() {
+            true => Ok(format!("os anos {}", self.to_year(num)?)),
+            false => Ok(format!("os anos {}", self.int_to_cardinal(low, false)?)),
+        }
+    }
+
+    fn to_century(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // read as the cardinal of its roman numeral, e.g. "o século vinte"
+        let century = (num / BigFloat::from(100)).int() + BigFloat::from(1);
+        Ok(format!(
+            "o século {}",
+            self.int_to_cardinal(century, false)?
+        ))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                self.currencies(currency, true)
+            ))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "menos cinco reais e cinquenta centavos"
+            Ok(format!("menos {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num, self.currency_feminine(currency))?;
+            // a round scale takes "de", e.g. "um milhão de reais"
+            let of = match words.rsplit_once(' ') {
+                Some((_, last)) if last.ends_with("ão") || last.ends_with("ões") => " de",
+                _ => "",
+            };
+            Ok(format!(
+                "{}{} {}",
+                words,
+                of,
+                self.currencies(currency, num != BigFloat::from(1))
+            ))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_words = self.int_to_cardinal(cents_nb, currency.is_subunit_feminine())?;
+            let cents_suffix = self.cents(currency, cents_nb != BigFloat::from(1));
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " e "
+    }
+
+    fn debit_words(&self) -> Option<&'static str> {
+        Some("a débito")
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match (power, self.region) {
+            (0, _) => None,
+            (1, _) => Some("mil"),
+            (_, RegionPortuguese::BR) => MEGAS_BR.get(power - 2).map(|m| m[0]),
+            (_, RegionPortuguese::PT) => SCALES_PT.get(power - 1).copied(),
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS_BR
+            .iter()
+            .chain(MEGAS_PT.iter())
+            .find(|m| m[0] == word)
+        {
+            Some(m) => String::from(m[1]),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "cem"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "mil"
+    }
+
+    // denominators beyond ten other than powers of ten are read as "avos",
+    // e.g. "sete treze avos"
+    fn denominator_word(&self, denominator: BigFloat, plural: bool) -> Option<String> {
+        let word = match denominator.to_u64()? {
+            2 => String::from("meio"),
+            3 => String::from("terço"),
+            4..=10 | 100 | 1000 => self.to_ordinal(denominator).ok()?,
+            _ => return Some(format!("{} avos", self.to_cardinal(denominator).ok()?)),
+        };
+        match plural {
+            true => Some(format!("{}s", word)),
+            false => Some(word),
+        }
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("por cento")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("por mil")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("dezasseis"))
+        );
+        assert_eq!(
+            Num2Words::new(16).lang(Lang::Portuguese_BR).to_words(),
+            Ok(String::from("dezesseis"))
+        );
+        assert_eq!(
+            Num2Words::new(100).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("cem"))
+        );
+        assert_eq!(
+            Num2Words::new(123).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("cento e vinte e três"))
+        );
+        assert_eq!(
+            Num2Words::new(-42).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("menos quarenta e dois"))
+        );
+    }
+
+    #[test]
+    fn test_thousands() {
+        assert_eq!(
+            Num2Words::new(1000).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil"))
+        );
+        assert_eq!(
+            Num2Words::new(1100).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil e cem"))
+        );
+        assert_eq!(
+            Num2Words::new(1101).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("mil cento e um"))
+        );
+        assert_eq!(
+            Num2Words::new(2500000).lang(Lang::Portuguese).to_words(),
+            Ok(String::from("dois milhões e quinhentos mil"))
+        );
+    }
+
+    #[test]
+    fn test_scales() {
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese_BR)
+                .to_words(),
+            Ok(String::from("um bilhão"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("mil milhões"))
+        );
+        assert_eq!(
+            Num2Words::new(1_002_000_000)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("mil e dois milhões"))
+        );
+        assert_eq!(
+            Num2Words::new(1_000_000_000_000i64)
+                .lang(Lang::Portuguese)
+                .to_words(),
+            Ok(String::from("um bilião"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(252)
+                .lang(Lang::Portuguese)
+                .prefer("feminine")
+                .to_words(),
+            Ok(String::from("duzentas e cinquenta e duas"))
+        );
+        assert_eq!(
+            Num2Words::new(201000)
+                .lang(Lang::Portuguese)
+                .prefer("feminine")
+                .to_words(),
+            Ok(String::from("duzentas e uma mil"))
+        );
+        assert_eq!(
+            Num2Words::new(200000000)
+                .lang(Lang::Portuguese)
+                .prefer("feminine")
+                .to_words(),
+            Ok(String::from("duzentos milhões"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("primeiro"))
+        );
+        assert_eq!(
+            Num2Words::new(123)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("centésimo vigésimo terceiro"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Portuguese)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("segundo milésimo"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Portuguese)
+                .prefer("feminine")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("vigésima segunda"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Portuguese_BR)
+                .currency(Currency::BRL)
+                .to_words(),
+            Ok(String::from("um real"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Portuguese_BR)
+                .currency(Currency::BRL)
+                .to_words(),
+            Ok(String::from("dois reais e cinquenta centavos"))
+        );
+        assert_eq!(
+            Num2Words::new(0.01)
+                .lang(Lang::Portuguese)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("um cêntimo"))
+        );
+        assert_eq!(
+            Num2Words::new(1000000)
+                .lang(Lang::Portuguese)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("um milhão de euros"))
+        );
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Portuguese)
+                .fraction(2)
+                .to_words(),
+            Ok(String::from("um meio"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Portuguese)
+                .fraction(3)
+                .to_words(),
+            Ok(String::from("dois terços"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Portuguese)
+                .fraction(10)
+                .to_words(),
+            Ok(String::from("três décimos"))
+        );
+        assert_eq!(
+            Num2Words::new(7)
+                .lang(Lang::Portuguese)
+                .fraction(13)
+                .to_words(),
+            Ok(String::from("sete treze avos"))
+        );
+    }
+
+    #[test]
+    fn test_currency_gender() {
+        assert_eq!(
+            Num2Words::new(202)
+                .lang(Lang::Portuguese)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("duzentas e duas libras"))
+        );
+        let coroa = Currency::custom("coroa", "coroas", "real", "réis", 2).feminine(true, false);
+        assert_eq!(
+            Num2Words::new(1.01)
+                .lang(Lang::Portuguese)
+                .currency(coroa)
+                .to_words(),
+            Ok(String::from("uma coroa e um real"))
+        );
+    }
+
+    #[test]
+    fn test_decade_century() {
+        assert_eq!(
+            Num2Words::new(1990)
+                .lang(Lang::Portuguese)
+                .decade()
+                .to_words(),
+            Ok(String::from("os anos noventa"))
+        );
+        assert_eq!(
+            Num2Words::new(2000)
+                .lang(Lang::Portuguese)
+                .century()
+                .to_words(),
+            Ok(String::from("o século vinte e um"))
+        );
+    }
+}
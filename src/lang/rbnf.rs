@@ -0,0 +1,723 @@
+use crate::{
+    lang::{intern, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language,
+};
+use num_bigfloat::BigFloat;
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+// Rules nest through their substitutions, a rule set substituting itself
+// without making the number smaller would otherwise never end
+const MAX_DEPTH: usize = 64;
+
+#[derive(Clone, Debug)]
+enum Base {
+    // e.g. `100:`, dividing the number by `divisor` for its substitutions
+    Value { value: u64, divisor: u64 },
+    // `-x:`
+    Negative,
+    // `x.x:`
+    Improper,
+    // `0.x:`
+    Proper,
+    // `Inf:`
+    Infinity,
+}
+
+#[derive(Clone, Debug)]
+enum Target {
+    // the rule set of the rule
+    Same,
+    // another rule set, e.g. `%%tieth`
+    Set(String),
+    // digits, grouped by thousands with `#,##0`
+    Digits { grouped: bool },
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Part {
+    // `<<`, the number divided by the divisor or its integral part
+    Quotient,
+    // `>>`, the rest of the division or the fraction digits
+    Remainder,
+    // `==`, the number itself
+    Whole,
+}
+
+#[derive(Clone, Debug)]
+enum Token {
+    Text(String),
+    Substitution(Part, Target),
+    // `[...]`, left out when the rest of the division is zero
+    Optional(Vec<Token>),
+    // `$(...)$`, plural forms aren't supported
+    Plural,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    base: Base,
+    tokens: Vec<Token>,
+}
+
+#[derive(Clone, Debug)]
+struct RuleSet {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+/// Language spelling numbers from ICU/CLDR Rule-Based Number Format (RBNF)
+/// rules
+///
+/// The rules are given in the ICU text syntax, e.g. as published in CLDR's
+/// `rbnf` data: rule sets named `%set:` followed by rules such as
+/// `20: twenty[->>];`. Cardinals are read with `%spellout-cardinal` (or
+/// `%spellout-numbering`), ordinals with `%spellout-ordinal`, ordinals in
+/// digits with `%digits-ordinal` and years with `%spellout-numbering-year`.
+/// Plural forms (`$(...)$`) and currencies aren't supported and return
+/// [`Num2Err::CannotConvert`].
+///
+/// Example:
+/// ```
+/// use num2words::{Num2Words, RbnfLanguage};
+/// let language = RbnfLanguage::new(
+///     "%spellout-cardinal:
+///         0: cero; 1: uno; 2: dos; 3: tres; 4: cuatro;
+///         5: cinco; 6: seis; 7: siete; 8: ocho; 9: nueve;
+///         10: diez; 11: once; 12: doce; 13: trece; 14: catorce; 15: quince;
+///         16: dieciséis; 17: diecisiete; 18: dieciocho; 19: diecinueve;
+///         20: veinte; 21: veinti>>;
+///         30: treinta[ y >>]; 40: cuarenta[ y >>]; 50: cincuenta[ y >>];",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     Num2Words::new(42).lang_custom(language).to_words(),
+///     Ok(String::from("cuarenta y dos"))
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct RbnfLanguage {
+    sets: Vec<RuleSet>,
+    hundred: &'static str,
+    thousand: &'static str,
+}
+
+impl RbnfLanguage {
+    /// Reads the rules, returning [`Num2Err::CannotConvert`] if they aren't
+    /// valid or refer to a rule set they don't define
+    pub fn new(rules: &str) -> Result<Self, Num2Err> {
+        let mut sets: Vec<RuleSet> = vec![];
+
+        for chunk in rules.split(';') {
+            let mut chunk = chunk.trim_start();
+            if chunk.starts_with('%') {
+                let (name, rest) = chunk.split_once(':').ok_or(Num2Err::CannotConvert)?;
+                sets.push(RuleSet {
+                    name: String::from(name.trim()),
+                    rules: vec![],
+                });
+                chunk = rest.trim_start();
+            }
+            if chunk.is_empty() {
+                continue;
+            }
+
+            let set = sets.last_mut().ok_or(Num2Err::CannotConvert)?;
+            // lenient parsing rules are only of use to parsers
+            if set.name.starts_with("%%lenient-parse") {
+                continue;
+            }
+            let previous = set.rules.iter().rev().find_map(|r| match r.base {
+                Base::Value { value, .. } => Some(value),
+                _ => None,
+            });
+            if let Some(rule) = parse_rule(chunk, previous)? {
+                set.rules.push(rule);
+            }
+        }
+
+        // every referenced rule set must exist
+        let names: Vec<&str> = sets.iter().map(|s| s.name.as_str()).collect();
+        for set in &sets {
+            for rule in &set.rules {
+                if !references_known_sets(&rule.tokens, &names) {
+                    return Err(Num2Err::CannotConvert);
+                }
+            }
+        }
+
+        let mut language = Self {
+            sets,
+            hundred: "100",
+            thousand: "1000",
+        };
+        if let Ok(words) = language.to_cardinal(BigFloat::from(100)) {
+            language.hundred = intern(words);
+        }
+        if let Ok(words) = language.to_cardinal(BigFloat::from(1000)) {
+            language.thousand = intern(words);
+        }
+        Ok(language)
+    }
+
+    /// Spells the number with the named rule set, e.g.
+    /// `%spellout-cardinal-feminine`
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::RbnfLanguage;
+    /// let language = RbnfLanguage::new(
+    ///     "%spellout-cardinal-masculine: 0: cero; 1: un; 2: dos;
+    ///      %spellout-cardinal-feminine: 0: cero; 1: una; 2: dos;",
+    /// )
+    /// .unwrap();
+    /// assert_eq!(
+    ///     language.format(1.into(), "%spellout-cardinal-feminine"),
+    ///     Ok(String::from("una"))
+    /// );
+    /// ```
+    pub fn format(&self, num: BigFloat, set: &str) -> Result<String, Num2Err> {
+        let set = self.set(set).ok_or(Num2Err::CannotConvert)?;
+        self.format_with(num, set, 0)
+    }
+
+    fn set(&self, name: &str) -> Option<&RuleSet> {
+        self.sets.iter().find(|s| s.name == name)
+    }
+
+    // the rule set named `name`, or else the first public one starting with it
+    fn set_like(&self, name: &str) -> Option<&RuleSet> {
+        self.set(name).or_else(|| {
+            self.sets
+                .iter()
+                .find(|s| s.name.starts_with(name) && !s.name.starts_with("%%"))
+        })
+    }
+
+    fn cardinal_set(&self) -> Option<&RuleSet> {
+        self.set_like("%spellout-cardinal")
+            .or_else(|| self.set("%spellout-numbering"))
+            .or_else(|| self.sets.iter().find(|s| !s.name.starts_with("%%")))
+    }
+
+    fn format_with(&self, num: BigFloat, set: &RuleSet, depth: usize) -> Result<String, Num2Err> {
+        if depth > MAX_DEPTH {
+            return Err(Num2Err::CannotConvert);
+        }
+
+        let find = |wanted: fn(&Base) -> bool| set.rules.iter().find(|r| wanted(&r.base));
+        let rule = if num.is_negative() {
+            find(|b| matches!(b, Base::Negative))
+        } else if num.is_inf() {
+            find(|b| matches!(b, Base::Infinity))
+        } else if !num.frac().is_zero() {
+            match num.int().is_zero() {
+                true => find(|b| matches!(b, Base::Proper))
+                    .or_else(|| find(|b| matches!(b, Base::Improper))),
+                false => find(|b| matches!(b, Base::Improper)),
+            }
+        } else {
+            set.rules.iter().rev().find(|r| match r.base {
+                Base::Value { value, .. } => BigFloat::from(value) <= num,
+                _ => false,
+            })
+        }
+        .ok_or(Num2Err::CannotConvert)?;
+
+        self.format_tokens(&rule.tokens, &rule.base, num, set, depth)
+    }
+
+    fn format_tokens(
+        &self,
+        tokens: &[Token],
+        base: &Base,
+        num: BigFloat,
+        set: &RuleSet,
+        depth: usize,
+    ) -> Result<String, Num2Err> {
+        let mut words = String::new();
+
+        for token in tokens {
+            match token {
+                Token::Text(text) => words.push_str(text),
+                Token::Plural => return Err(Num2Err::CannotConvert),
+                Token::Optional(tokens) => {
+                    let omitted = match base {
+                        Base::Value { divisor, .. } => (num % BigFloat::from(*divisor)).is_zero(),
+                        _ => num.frac().is_zero(),
+                    };
+                    if !omitted {
+                        words.push_str(&self.format_tokens(tokens, base, num, set, depth)?);
+                    }
+                }
+                Token::Substitution(part, target) => {
+                    let target = match target {
+                        Target::Same => Target::Set(set.name.clone()),
+                        other => other.clone(),
+                    };
+                    words.push_str(&self.substitute(*part, &target, base, num, depth)?);
+                }
+            }
+        }
+
+        Ok(words)
+    }
+
+    fn substitute(
+        &self,
+        part: Part,
+        target: &Target,
+        base: &Base,
+        num: BigFloat,
+        depth: usize,
+    ) -> Result<String, Num2Err> {
+        let num = match (base, part) {
+            (_, Part::Whole) => num,
+            (Base::Value { divisor, .. }, Part::Quotient) => (num / BigFloat::from(*divisor)).int(),
+            (Base::Value { divisor, .. }, Part::Remainder) => num % BigFloat::from(*divisor),
+            (Base::Negative, Part::Remainder) => num.abs(),
+            (Base::Improper, Part::Quotient) => num.int(),
+            // fraction digits are read one by one, e.g. "point seven five"
+            (Base::Improper, Part::Remainder) | (Base::Proper, Part::Remainder) => {
+                let mut digits = vec![];
+                let mut fraction = num.frac().abs();
+                for _ in 0..MAX_FRACTION_DIGITS {
+                    if fraction.is_zero() {
+                        break;
+                    }
+                    fraction *= BigFloat::from(10);
+                    digits.push(self.format_target(fraction.int(), target, depth)?);
+                    fraction = fraction.frac();
+                }
+                return Ok(digits.join(" "));
+            }
+            _ => return Err(Num2Err::CannotConvert),
+        };
+
+        self.format_target(num, target, depth)
+    }
+
+    fn format_target(
+        &self,
+        num: BigFloat,
+        target: &Target,
+        depth: usize,
+    ) -> Result<String, Num2Err> {
+        match target {
+            Target::Set(name) => {
+                let set = self.set(name).ok_or(Num2Err::CannotConvert)?;
+                self.format_with(num, set, depth + 1)
+            }
+            Target::Digits { grouped } => digits(num, *grouped),
+            Target::Same => Err(Num2Err::CannotConvert),
+        }
+    }
+}
+
+// e.g. 1234.5 => "1,234.5" when grouped
+fn digits(num: BigFloat, grouped: bool) -> Result<String, Num2Err> {
+    let integral = num.int().abs().to_u128().ok_or(Num2Err::CannotConvert)?;
+    let mut words = integral.to_string();
+    if grouped {
+        let mut i = words.len();
+        while i > 3 {
+            i -= 3;
+            words.insert(i, ',');
+        }
+    }
+    if num.is_negative() {
+        words.insert(0, '-');
+    }
+
+    let mut fraction = num.frac().abs();
+    if !fraction.is_zero() {
+        words.push('.');
+    }
+    for _ in 0..MAX_FRACTION_DIGITS {
+        if fraction.is_zero() {
+            break;
+        }
+        fraction *= BigFloat::from(10);
+        words.push_str(&fraction.int().to_u64().unwrap().to_string());
+        fraction = fraction.frac();
+    }
+
+    Ok(words)
+}
+
+// e.g. "20: twenty[->>]", numbered after `previous` when it has no descriptor
+fn parse_rule(chunk: &str, previous: Option<u64>) -> Result<Option<Rule>, Num2Err> {
+    let descriptor = chunk
+        .split_once(':')
+        .and_then(|(descriptor, body)| Some((parse_base(descriptor.trim())?, body)));
+    let (base, body) = match descriptor {
+        // `x.0` only picks the rule used to parse numbers
+        Some((None, _)) => return Ok(None),
+        Some((Some(base), body)) => (base, body),
+        None => {
+            let value = previous.map_or(0, |v| v + 1);
+            (value_base(value, 10, 0)?, chunk)
+        }
+    };
+
+    // a leading apostrophe keeps the whitespace after it
+    let body = body.trim_start();
+    let body = body.strip_prefix('\'').unwrap_or(body);
+    let mut chars = body.chars().peekable();
+    let tokens = parse_tokens(&mut chars, false)?;
+
+    Ok(Some(Rule { base, tokens }))
+}
+
+// `None` for rules that don't take part in formatting
+fn parse_base(descriptor: &str) -> Option<Option<Base>> {
+    match descriptor {
+        "-x" => return Some(Some(Base::Negative)),
+        "x.x" => return Some(Some(Base::Improper)),
+        "0.x" => return Some(Some(Base::Proper)),
+        "Inf" => return Some(Some(Base::Infinity)),
+        "x.0" | "NaN" => return Some(None),
+        _ => (),
+    }
+
+    let lowered = descriptor.trim_end_matches('>');
+    let shift = descriptor.len() - lowered.len();
+    let (value, radix) = match lowered.split_once('/') {
+        Some((value, radix)) => (value, radix.trim().parse().ok()?),
+        None => (lowered, 10),
+    };
+    let value: String = value
+        .chars()
+        .filter(|c| !matches!(c, ',' | '\'' | ' '))
+        .collect();
+    if value.is_empty() || !value.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    value_base(value.parse().ok()?, radix, shift).ok().map(Some)
+}
+
+// the divisor is the highest power of the radix up to the value, lowered
+// once for each `>` in the descriptor
+fn value_base(value: u64, radix: u64, shift: usize) -> Result<Base, Num2Err> {
+    if radix < 2 {
+        return Err(Num2Err::CannotConvert);
+    }
+    let mut exponent: usize = 0;
+    let mut power: u64 = 1;
+    while let Some(next) = power.checked_mul(radix) {
+        if next > value {
+            break;
+        }
+        power = next;
+        exponent += 1;
+    }
+    let exponent = exponent.checked_sub(shift).ok_or(Num2Err::CannotConvert)?;
+    let divisor = radix.pow(exponent as u32);
+
+    Ok(Base::Value { value, divisor })
+}
+
+fn parse_tokens<I>(
+    chars: &mut std::iter::Peekable<I>,
+    optional: bool,
+) -> Result<Vec<Token>, Num2Err>
+where
+    I: Iterator<Item = char>,
+{
+    let mut tokens = vec![];
+    let mut text = String::new();
+
+    while let Some(c) = chars.next() {
+        let part = match c {
+            '<' | '←' => Some(Part::Quotient),
+            '>' | '→' => Some(Part::Remainder),
+            '=' => Some(Part::Whole),
+            _ => None,
+        };
+        let special =
+            part.is_some() || matches!(c, '[' | ']') || (c == '$' && chars.peek() == Some(&'('));
+        if special && !text.is_empty() {
+            tokens.push(Token::Text(std::mem::take(&mut text)));
+        }
+
+        match (c, part) {
+            (_, Some(part)) => {
+                let mut inner = String::new();
+                loop {
+                    match chars.next() {
+                        Some(close) if close == c => break,
+                        Some(other) => inner.push(other),
+                        None => return Err(Num2Err::CannotConvert),
+                    }
+                }
+                // `>>>` reads the rest like `>>`
+                if c == '>' && inner.is_empty() && chars.peek() == Some(&'>') {
+                    chars.next();
+                }
+                tokens.push(Token::Substitution(part, parse_target(&inner)?));
+            }
+            ('[', _) if !optional => tokens.push(Token::Optional(parse_tokens(chars, true)?)),
+            (']', _) if optional => return Ok(tokens),
+            ('[', _) | (']', _) => return Err(Num2Err::CannotConvert),
+            ('$', _) if chars.peek() == Some(&'(') => {
+                let mut last = c;
+                loop {
+                    match chars.next() {
+                        Some('$') if last == ')' => break,
+                        Some(other) => last = other,
+                        None => return Err(Num2Err::CannotConvert),
+                    }
+                }
+                tokens.push(Token::Plural);
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if optional {
+        return Err(Num2Err::CannotConvert);
+    }
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+    Ok(tokens)
+}
+
+fn parse_target(inner: &str) -> Result<Target, Num2Err> {
+    if inner.is_empty() {
+        Ok(Target::Same)
+    } else if inner.starts_with('%') {
+        Ok(Target::Set(String::from(inner)))
+    } else if inner.starts_with('#') || inner.starts_with('0') {
+        Ok(Target::Digits {
+            grouped: inner.contains(','),
+        })
+    } else {
+        Err(Num2Err::CannotConvert)
+    }
+}
+
+fn references_known_sets(tokens: &[Token], names: &[&str]) -> bool {
+    tokens.iter().all(|token| match token {
+        Token::Substitution(_, Target::Set(name)) => names.contains(&name.as_str()),
+        Token::Optional(tokens) => references_known_sets(tokens, names),
+        _ => true,
+    })
+}
+
+impl Language for RbnfLanguage {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let set = self.cardinal_set().ok_or(Num2Err::CannotConvert)?;
+        self.format_with(num, set, 0)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let set = self
+            .set_like("%spellout-ordinal")
+            .ok_or(Num2Err::CannotConvert)?;
+        self.format_with(num, set, 0)
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.format(num, "%digits-ordinal")
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        match self.set("%spellout-numbering-year") {
+            Some(set) => self.format_with(num, set, 0),
+            None => self.to_cardinal(num),
+        }
+    }
+
+    fn to_currency(&self, _num: BigFloat, _currency: Currency) -> Result<String, Num2Err> {
+        Err(Num2Err::CannotConvert)
+    }
+
+    fn scale_word(&self, _power: usize) -> Option<&'static str> {
+        None
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        self.hundred
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        self.thousand
+    }
+}
+
+/// Registers RBNF rules under a locale, making them available with
+/// [`Num2Words::rbnf`](crate::Num2Words::rbnf), see [`RbnfLanguage`]
+///
+/// Registering an existing locale replaces its rules. Returns
+/// [`Num2Err::CannotConvert`] if the rules aren't valid.
+///
+/// Example:
+/// ```
+/// use num2words::{register_rbnf, Num2Words};
+/// register_rbnf(
+///     "es",
+///     "%spellout-numbering: =%spellout-cardinal-masculine=;
+///      %spellout-cardinal-masculine: 0: cero; 1: uno; 2: dos; 3: tres;",
+/// )
+/// .unwrap();
+/// assert_eq!(
+///     Num2Words::new(3).rbnf("es-419").to_words(),
+///     Ok(String::from("tres"))
+/// );
+/// ```
+pub fn register_rbnf(locale: &str, rules: &str) -> Result<(), Num2Err> {
+    let language = RbnfLanguage::new(rules)?;
+    registry()
+        .lock()
+        .unwrap()
+        .insert(String::from(locale), language);
+    Ok(())
+}
+
+/// Rules registered for the locale, or else for its language, e.g. `es` for
+/// `es-419`
+pub(crate) fn rbnf_language(locale: &str) -> Option<RbnfLanguage> {
+    let registry = registry().lock().unwrap();
+    let mut locale = locale;
+    loop {
+        if let Some(language) = registry.get(locale) {
+            return Some(language.clone());
+        }
+        locale = &locale[..locale.rfind(['-', '_'])?];
+    }
+}
+
+fn registry() -> &'static Mutex<HashMap<String, RbnfLanguage>> {
+    static REGISTRY: OnceLock<Mutex<HashMap<String, RbnfLanguage>>> = OnceLock::new();
+    REGISTRY.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    const ENGLISH: &str = "
+        %spellout-numbering:
+            =%spellout-cardinal=;
+        %spellout-cardinal:
+            -x: minus >>;
+            x.x: << point >>;
+            Inf: infinity;
+            0: zero; one; two; three; four; five; six; seven; eight; nine;
+            ten; eleven; twelve; thirteen; fourteen; fifteen; sixteen;
+                seventeen; eighteen; nineteen;
+            20: twenty[->>];
+            30: thirty[->>];
+            40: forty[->>];
+            100: << hundred[ >>];
+            1000: << thousand[ >>];
+            1,000,000: << million[ >>];
+            1,000,000,000: =#,##0=;
+        %%tieth:
+            0: tieth; 1: ty-=%spellout-ordinal=;
+        %%th:
+            0: th; 1: ' =%spellout-ordinal=;
+        %spellout-ordinal:
+            -x: minus >>;
+            0: zeroth; first; second; third; fourth; fifth; sixth; seventh;
+                eighth; ninth; tenth; eleventh; twelfth; 13: =%spellout-cardinal=th;
+            20: twen>%%tieth>;
+            30: thir>%%tieth>;
+            40: for>%%tieth>;
+            100: <%spellout-cardinal< hundred>%%th>;
+            1000: <%spellout-cardinal< thousand>%%th>;
+    ";
+
+    fn english() -> RbnfLanguage {
+        RbnfLanguage::new(ENGLISH).unwrap()
+    }
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42).lang_custom(english()).to_words(),
+            Ok(String::from("forty-two"))
+        );
+        assert_eq!(
+            Num2Words::new(40).lang_custom(english()).to_words(),
+            Ok(String::from("forty"))
+        );
+        assert_eq!(
+            Num2Words::new(1234).lang_custom(english()).to_words(),
+            Ok(String::from("one thousand two hundred thirty-four"))
+        );
+        assert_eq!(
+            Num2Words::new(-3.25).lang_custom(english()).to_words(),
+            Ok(String::from("minus three point two five"))
+        );
+        assert_eq!(
+            Num2Words::new(5e9).lang_custom(english()).to_words(),
+            Ok(String::from("5,000,000,000"))
+        );
+        assert_eq!(
+            Num2Words::new(f64::INFINITY)
+                .lang_custom(english())
+                .to_words(),
+            Ok(String::from("infinity"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang_custom(english())
+                .ordinal()
+                .to_words(),
+            Ok(String::from("forty-second"))
+        );
+        assert_eq!(
+            Num2Words::new(40)
+                .lang_custom(english())
+                .ordinal()
+                .to_words(),
+            Ok(String::from("fortieth"))
+        );
+        assert_eq!(
+            Num2Words::new(1300)
+                .lang_custom(english())
+                .ordinal()
+                .to_words(),
+            Ok(String::from("one thousand three hundredth"))
+        );
+        assert_eq!(
+            Num2Words::new(7)
+                .lang_custom(english())
+                .ordinal_num()
+                .to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_registry() {
+        register_rbnf("en-x-test", ENGLISH).unwrap();
+        assert_eq!(
+            Num2Words::new(19).rbnf("en-x-test").to_words(),
+            Ok(String::from("nineteen"))
+        );
+        assert_eq!(
+            Num2Words::new(19).rbnf("en-x-test-GB").to_words(),
+            Ok(String::from("nineteen"))
+        );
+        assert_eq!(
+            Num2Words::new(19).rbnf("xx-unknown").to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_invalid_rules() {
+        assert!(RbnfLanguage::new("%spellout-numbering: 0: =%missing=;").is_err());
+        assert!(RbnfLanguage::new("%spellout-numbering: 0: zero[;").is_err());
+        assert!(RbnfLanguage::new("0: zero;").is_err());
+    }
+}
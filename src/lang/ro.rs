@@ -0,0 +1,608 @@
+use crate::{
+    lang::{whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Romanian {
+    gender: Gender,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Gender {
+    Masculine,
+    Feminine,
+    // masculine in the singular and feminine in the plural, e.g. "un
+    // milion" but "două milioane"
+    Neuter,
+}
+
+const UNITS: [&str; 20] = [
+    "zero",
+    "unu",
+    "doi",
+    "trei",
+    "patru",
+    "cinci",
+    "șase",
+    "șapte",
+    "opt",
+    "nouă",
+    "zece",
+    "unsprezece",
+    "doisprezece",
+    "treisprezece",
+    "paisprezece",
+    "cincisprezece",
+    "șaisprezece",
+    "șaptesprezece",
+    "optsprezece",
+    "nouăsprezece",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "douăzeci",
+    "treizeci",
+    "patruzeci",
+    "cincizeci",
+    "șaizeci",
+    "șaptezeci",
+    "optzeci",
+    "nouăzeci",
+];
+
+// Long scale, e.g. "bilion" is 10^12
+const MEGAS: [[&str; 2]; 5] = [
+    ["milion", "milioane"],
+    ["miliard", "miliarde"],
+    ["bilion", "bilioane"],
+    ["biliard", "biliarde"],
+    ["trilion", "trilioane"],
+];
+
+impl Romanian {
+    pub fn new(feminine: bool) -> Self {
+        Self {
+            gender: match feminine {
+                true => Gender::Feminine,
+                false => Gender::Masculine,
+            },
+        }
+    }
+
+    fn gender(&self) -> Gender {
+        self.gender
+    }
+
+    // ordinals of neuter nouns take the masculine, e.g. "al doilea scaun"
+    fn feminine(&self) -> bool {
+        self.gender == Gender::Feminine
+    }
+
+    // singular, plural and gender of the currency
+    fn currencies(&self, currency: Currency) -> (String, String, Gender) {
+        let (singular, plural, gender) = match currency {
+            Currency::RON => ("leu", "lei", Gender::Masculine),
+            Currency::MDL => ("leu moldovenesc", "lei moldovenești", Gender::Masculine),
+            Currency::DOLLAR | Currency::USD => ("dolar", "dolari", Gender::Masculine),
+            Currency::EUR => ("euro", "euro", Gender::Masculine),
+            Currency::GBP => ("liră sterlină", "lire sterline", Gender::Feminine),
+            _ => {
+                let gender = match currency.is_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Masculine,
+                };
+                return (
+                    currency.default_string(false),
+                    currency.default_string(true),
+                    gender,
+                );
+            }
+        };
+        (String::from(singular), String::from(plural), gender)
+    }
+
+    fn cents(&self, currency: Currency) -> (String, String, Gender) {
+        let (singular, plural) = match currency {
+            Currency::RON | Currency::MDL => ("ban", "bani"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => ("cent", "cenți"),
+            Currency::GBP => ("penny", "pence"),
+            _ => {
+                let gender = match currency.is_subunit_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Masculine,
+                };
+                return (
+                    currency.default_subunit_string("cent{}", false),
+                    currency.default_subunit_string("cent{}", true),
+                    gender,
+                );
+            }
+        };
+        (
+            String::from(singular),
+            String::from(plural),
+            Gender::Masculine,
+        )
+    }
+
+    fn unit(&self, num: usize, gender: Gender) -> &'static str {
+        match (num, gender) {
+            (1, Gender::Feminine) => "una",
+            (2, Gender::Masculine) => "doi",
+            (2, _) => "două",
+            (12, Gender::Masculine) => "doisprezece",
+            (12, _) => "douăsprezece",
+            _ => UNITS[num],
+        }
+    }
+
+    // e.g. 22 => "douăzeci și doi", or "douăzeci și două" in the feminine
+    fn below_hundred(&self, num: u64, gender: Gender) -> String {
+        let (tens, units) = ((num / 10) as usize, (num % 10) as usize);
+        if num < 20 {
+            String::from(self.unit(num as usize, gender))
+        } else if units == 0 {
+            String::from(TENS[tens])
+        } else {
+            format!("{} și {}", TENS[tens], self.unit(units, gender))
+        }
+    }
+
+    // e.g. 215 => "două sute cincisprezece"
+    fn below_thousand(&self, num: u64, gender: Gender) -> String {
+        let mut words = vec![];
+        match num / 100 {
+            0 => (),
+            1 => words.push(String::from("o sută")),
+            2 => words.push(String::from("două sute")),
+            hundreds => words.push(format!("{} sute", UNITS[hundreds as usize])),
+        }
+        match num % 100 {
+            0 => (),
+            rest => words.push(self.below_hundred(rest, gender)),
+        }
+        words.join(" ")
+    }
+
+    // counts of twenty and more take "de" before a noun, e.g. "douăzeci de
+    // lei" but "nouăsprezece lei" and "o sută unu lei"
+    fn counted(
+        &self,
+        num: BigFloat,
+        singular: &str,
+        plural: &str,
+        gender: Gender,
+    ) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            let article = if gender == Gender::Feminine {
+                "o"
+            } else {
+                "un"
+            };
+            return Ok(format!("{} {}", article, singular));
+        }
+
+        let words = self.int_to_cardinal(num, gender)?;
+        let rest = (num.abs() % BigFloat::from(100)).to_u64().unwrap();
+        let of = if num.abs() < BigFloat::from(20) || (1..20).contains(&rest) {
+            ""
+        } else {
+            " de"
+        };
+        Ok(format!("{}{} {}", words, of, plural))
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat, gender: Gender) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let mut words = vec![];
+        for (i, triplet) in self
+            .split_thousands(num.abs().int())
+            .iter()
+            .enumerate()
+            .rev()
+        {
+            if *triplet == 0 {
+                continue;
+            }
+            let triplet_bf = BigFloat::from(*triplet);
+            words.push(match i {
+                0 => self.below_thousand(*triplet, gender),
+                1 => self.counted(triplet_bf, "mie", "mii", Gender::Feminine)?,
+                _ => {
+                    let mega = MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+                    self.counted(triplet_bf, mega[0], mega[1], Gender::Neuter)?
+                }
+            });
+        }
+
+        if num.is_negative() {
+            Ok(format!("minus {}", words.join(" ")))
+        } else {
+            Ok(words.join(" "))
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() && integral_part.is_zero() {
+            words.push(String::from("minus"));
+        }
+        words.push(self.int_to_cardinal(integral_part, self.gender())?);
+
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(String::from("virgulă"));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // e.g. "doi" => "doilea", "opt" => "optulea", "mie" => "miilea"
+    fn masculine_ordinal(&self, word: &str) -> String {
+        match word {
+            "mie" => String::from("miilea"),
+            _ if word.ends_with(|c| "aeiouăâî".contains(c)) => format!("{}lea", word),
+            _ => format!("{}ulea", word),
+        }
+    }
+
+    // e.g. "două" => "doua", "trei" => "treia", "cinci" => "cincea"
+    fn feminine_ordinal(&self, word: &str) -> String {
+        match word {
+            "mie" | "mii" => String::from("mia"),
+            "sute" => String::from("suta"),
+            _ if word.ends_with("ei") || word.ends_with('e') => format!("{}a", word),
+            _ if word.ends_with('a') => String::from(word),
+            _ => match word.strip_suffix(|c| c == 'ă' || c == 'u') {
+                Some(stem) => format!("{}a", stem),
+                None => match word.strip_suffix('i') {
+                    Some(stem) => format!("{}ea", stem),
+                    None => format!("{}a", word),
+                },
+            },
+        }
+    }
+}
+
+impl Language for Romanian {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("infinit"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("minus infinit"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num, self.gender())
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn gendered(&self, gender: crate::Gender) -> Option<Box<dyn Language>> {
+        Some(Box::new(Romanian {
+            gender: match gender {
+                crate::Gender::Masculine => Gender::Masculine,
+                crate::Gender::Feminine => Gender::Feminine,
+                crate::Gender::Neuter => Gender::Neuter,
+            },
+        }))
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num == BigFloat::from(1) {
+            return Ok(String::from(if self.feminine() {
+                "prima"
+            } else {
+                "primul"
+            }));
+        }
+
+        // only the last word takes the ending, e.g. "al douăzeci și doilea"
+        let words = self.int_to_cardinal(num, self.gender())?;
+        let (head, last) = match words.rsplit_once(' ') {
+            Some((head, last)) => (format!("{} ", head), last),
+            None => (String::new(), words.as_str()),
+        };
+        if self.feminine() {
+            Ok(format!("a {}{}", head, self.feminine_ordinal(last)))
+        } else {
+            Ok(format!("al {}{}", head, self.masculine_ordinal(last)))
+        }
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let value = whole_digits(num);
+        if self.feminine() {
+            Ok(format!("a {}-a", value))
+        } else if value == "1" {
+            Ok(String::from("1-ul"))
+        } else {
+            Ok(format!("al {}-lea", value))
+        }
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!(
+                "{} înainte de Hristos",
+                self.int_to_cardinal(-num, Gender::Masculine)?
+            ))
+        } else {
+            self.int_to_cardinal(num, Gender::Masculine)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (singular, plural, gender) = self.currencies(currency);
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, plural))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "minus cinci lei și cincizeci de bani"
+            Ok(format!("minus {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            self.counted(num, &singular, &plural, gender)
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let (cent, cents, cents_gender) = self.cents(currency);
+            let cents_words = self.counted(cents_nb, &cent, &cents, cents_gender)?;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(cents_words)
+            } else {
+                Ok(format!(
+                    "{}{}{}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " și "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match power {
+            0 => None,
+            1 => Some("mie"),
+            _ => MEGAS.get(power - 2).map(|m| m[0]),
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        ('.', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m[0] == word) {
+            Some(m) => String::from(m[1]),
+            None if word == "mie" => String::from("mii"),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "sută"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "mie"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Romanian).cardinal().to_words(),
+            Ok(String::from("zero"))
+        );
+        assert_eq!(
+            Num2Words::new(12)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("doisprezece"))
+        );
+        assert_eq!(
+            Num2Words::new(215)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("două sute cincisprezece"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("o mie"))
+        );
+        assert_eq!(
+            Num2Words::new(21_000)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("douăzeci și una de mii"))
+        );
+        assert_eq!(
+            Num2Words::new(2_000_000)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("două milioane"))
+        );
+        assert_eq!(
+            Num2Words::new(-2.5)
+                .lang(Lang::Romanian)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus doi virgulă cinci"))
+        );
+    }
+
+    #[test]
+    fn test_feminine() {
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Romanian)
+                .prefer("feminine")
+                .to_words(),
+            Ok(String::from("douăzeci și două"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Romanian)
+                .prefer("f")
+                .ordinal()
+                .to_words(),
+            Ok(String::from("a doua"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Romanian).ordinal().to_words(),
+            Ok(String::from("primul"))
+        );
+        assert_eq!(
+            Num2Words::new(8).lang(Lang::Romanian).ordinal().to_words(),
+            Ok(String::from("al optulea"))
+        );
+        assert_eq!(
+            Num2Words::new(22).lang(Lang::Romanian).ordinal().to_words(),
+            Ok(String::from("al douăzeci și doilea"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Romanian)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("al o miilea"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Romanian)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("al 3-lea"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::Romanian)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("al 1{}-lea", "0".repeat(40)))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("un leu"))
+        );
+        assert_eq!(
+            Num2Words::new(19)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("nouăsprezece lei"))
+        );
+        assert_eq!(
+            Num2Words::new(20)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("douăzeci de lei"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Romanian)
+                .currency(Currency::MDL)
+                .to_words(),
+            Ok(String::from("doi lei moldovenești"))
+        );
+        assert_eq!(
+            Num2Words::new(101)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("o sută unu lei"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Romanian)
+                .currency(Currency::RON)
+                .to_words(),
+            Ok(String::from("doi lei și cincizeci de bani"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Romanian)
+                .currency(Currency::GBP)
+                .to_words(),
+            Ok(String::from("două lire sterline"))
+        );
+    }
+
+    #[test]
+    fn test_gender_builder() {
+        let gendered = |n: i64, gender| {
+            Num2Words::new(n)
+                .lang(Lang::Romanian)
+                .gender(gender)
+                .to_words()
+        };
+        assert_eq!(
+            gendered(21, Gender::Feminine),
+            Ok(String::from("douăzeci și una"))
+        );
+        // neuter nouns count as masculine in the singular and feminine in
+        // the plural, e.g. "un scaun" but "două scaune"
+        assert_eq!(gendered(1, Gender::Neuter), Ok(String::from("unu")));
+        assert_eq!(gendered(2, Gender::Neuter), Ok(String::from("două")));
+    }
+}
@@ -0,0 +1,555 @@
+use crate::{
+    lang::{czech_plural, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, Language,
+};
+use num_bigfloat::BigFloat;
+
+pub struct Slovak {}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Gender {
+    Masculine,
+    Feminine,
+    Neuter,
+}
+
+const UNITS: [&str; 20] = [
+    "nula",
+    "jeden",
+    "dva",
+    "tri",
+    "štyri",
+    "päť",
+    "šesť",
+    "sedem",
+    "osem",
+    "deväť",
+    "desať",
+    "jedenásť",
+    "dvanásť",
+    "trinásť",
+    "štrnásť",
+    "pätnásť",
+    "šestnásť",
+    "sedemnásť",
+    "osemnásť",
+    "devätnásť",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "",
+    "dvadsať",
+    "tridsať",
+    "štyridsať",
+    "päťdesiat",
+    "šesťdesiat",
+    "sedemdesiat",
+    "osemdesiat",
+    "deväťdesiat",
+];
+
+const HUNDREDS: [&str; 10] = [
+    "",
+    "sto",
+    "dvesto",
+    "tristo",
+    "štyristo",
+    "päťsto",
+    "šesťsto",
+    "sedemsto",
+    "osemsto",
+    "deväťsto",
+];
+
+// One, few and many forms and the gender they are counted in, e.g.
+// "dva milióny" but "dve miliardy"
+const MEGAS: [([&str; 3], Gender); 4] = [
+    (["milión", "milióny", "miliónov"], Gender::Masculine),
+    (["miliarda", "miliardy", "miliárd"], Gender::Feminine),
+    (["bilión", "bilióny", "biliónov"], Gender::Masculine),
+    (["biliarda", "biliardy", "biliárd"], Gender::Feminine),
+];
+
+const UNITS_ORDINAL: [&str; 20] = [
+    "nultý",
+    "prvý",
+    "druhý",
+    "tretí",
+    "štvrtý",
+    "piaty",
+    "šiesty",
+    "siedmy",
+    "ôsmy",
+    "deviaty",
+    "desiaty",
+    "jedenásty",
+    "dvanásty",
+    "trinásty",
+    "štrnásty",
+    "pätnásty",
+    "šestnásty",
+    "sedemnásty",
+    "osemnásty",
+    "devätnásty",
+];
+
+const TENS_ORDINAL: [&str; 10] = [
+    "",
+    "",
+    "dvadsiaty",
+    "tridsiaty",
+    "štyridsiaty",
+    "päťdesiaty",
+    "šesťdesiaty",
+    "sedemdesiaty",
+    "osemdesiaty",
+    "deväťdesiaty",
+];
+
+const HUNDREDS_ORDINAL: [&str; 10] = [
+    "",
+    "stý",
+    "dvojstý",
+    "trojstý",
+    "štvorstý",
+    "päťstý",
+    "šesťstý",
+    "sedemstý",
+    "osemstý",
+    "deväťstý",
+];
+
+const MEGAS_ORDINAL: [&str; 5] = ["tisíci", "miliónty", "miliardtý", "bilióntý", "biliardtý"];
+
+// prefixes of compound ordinals, e.g. "dvoj" in "dvojtisíci"
+const UNITS_PREFIX: [&str; 10] = [
+    "", "", "dvoj", "troj", "štvor", "päť", "šesť", "sedem", "osem", "deväť",
+];
+
+impl Slovak {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    // one, few and many forms and gender of the currency
+    fn currencies(&self, currency: Currency) -> ([String; 3], Gender) {
+        let (forms, gender) = match currency {
+            Currency::CZK => (["koruna", "koruny", "korún"], Gender::Feminine),
+            Currency::DOLLAR | Currency::USD => (["dolár", "doláre", "dolárov"], Gender::Masculine),
+            Currency::EUR => (["euro", "eurá", "eur"], Gender::Neuter),
+            _ => {
+                let (one, many) = (
+                    currency.default_string(false),
+                    currency.default_string(true),
+                );
+                let gender = match currency.is_feminine() {
+                    true => Gender::Feminine,
+                    false => Gender::Masculine,
+                };
+                return ([one, many.clone(), many], gender);
+            }
+        };
+        (forms.map(String::from), gender)
+    }
+
+    fn cents(&self, currency: Currency) -> [String; 3] {
+        match currency {
+            Currency::CZK => ["halier", "haliere", "halierov"].map(String::from),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => {
+                ["cent", "centy", "centov"].map(String::from)
+            }
+            _ => {
+                let one = currency.default_subunit_string("cent{}", false);
+                let many = currency.default_subunit_string("cent{}", true);
+                [one, many.clone(), many]
+            }
+        }
+    }
+
+    fn split_thousands(&self, mut num: BigFloat) -> Vec<u64> {
+        let mut thousands = Vec::new();
+        let bf_1000 = BigFloat::from(1000);
+
+        while !num.is_zero() {
+            thousands.push((num % bf_1000).to_u64().unwrap());
+            num /= bf_1000;
+        }
+
+        thousands
+    }
+
+    // e.g. 122 => "stodvadsaťdva"; one and two agree with the counted noun
+    // only when they stand alone, e.g. "dve koruny" but "dvadsaťdva korún"
+    fn triplet_to_words(&self, triplet: u64, gender: Gender) -> String {
+        let mut words = String::new();
+        let hundreds = (triplet / 100 % 10) as usize;
+        let rest = (triplet % 100) as usize;
+
+        words.push_str(HUNDREDS[hundreds]);
+        if rest >= 20 {
+            words.push_str(TENS[rest / 10]);
+        }
+
+        match (rest, gender) {
+            (0, _) => (),
+            (1, Gender::Feminine) => words.push_str("jedna"),
+            (1, Gender::Neuter) => words.push_str("jedno"),
+            (2, Gender::Feminine | Gender::Neuter) => words.push_str("dve"),
+            (20.., _) => match rest % 10 {
+                0 => (),
+                units => words.push_str(UNITS[units]),
+            },
+            _ => words.push_str(UNITS[rest]),
+        }
+
+        words
+    }
+
+    // numbers below a million are written as one word, e.g. "dvetisícpäťsto",
+    // while the larger scales stand apart, e.g. "dva milióny päťstotisíc"
+    fn counted(&self, num: BigFloat, gender: Gender) -> Result<String, Num2Err> {
+        let thousands = self.split_thousands(num);
+        let mut words = vec![];
+
+        for (i, triplet) in thousands.iter().enumerate().skip(2).rev() {
+            if *triplet == 0 {
+                continue;
+            }
+            let (forms, mega_gender) = MEGAS.get(i - 2).ok_or(Num2Err::CannotConvert)?;
+            // one is left out before a scale word, e.g. "milión"
+            if *triplet != 1 {
+                words.push(self.triplet_to_words(*triplet, *mega_gender));
+            }
+            words.push(String::from(forms[czech_plural(BigFloat::from(*triplet))]));
+        }
+
+        let mut below_million = String::new();
+        match thousands.get(1) {
+            None | Some(0) => (),
+            Some(1) => below_million.push_str("tisíc"),
+            Some(triplet) => {
+                below_million.push_str(&self.triplet_to_words(*triplet, Gender::Feminine));
+                below_million.push_str("tisíc");
+            }
+        }
+        if let Some(triplet) = thousands.first() {
+            below_million.push_str(&self.triplet_to_words(*triplet, gender));
+        }
+        if !below_million.is_empty() {
+            words.push(below_million);
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(UNITS[0]));
+        }
+
+        let words = self.counted(num.abs(), Gender::Masculine)?;
+        if num.is_negative() {
+            Ok(format!("mínus {}", words))
+        } else {
+            Ok(words)
+        }
+    }
+
+    // the whole part counts "celá", e.g. "dve celé päť"
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let integral_part = num.int().abs();
+        let mut words: Vec<String> = vec![];
+
+        if num.is_negative() {
+            words.push(String::from("mínus"));
+        }
+        if integral_part.is_zero() {
+            words.push(String::from(UNITS[0]));
+        } else {
+            words.push(self.counted(integral_part, Gender::Feminine)?);
+        }
+        words.push(String::from(
+            ["celá", "celé", "celých"][czech_plural(integral_part)],
+        ));
+
+        let mut ordinal_part = num.frac().abs();
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(String::from(UNITS[digit.to_u64().unwrap() as usize]));
+        }
+        Ok(words.join(" "))
+    }
+
+    // one, few, many and decimal forms of a neuter noun read after the
+    // number, e.g. "dve percentá" or "dva celé päť percenta"
+    fn percentage(&self, num: BigFloat, forms: [&str; 4]) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[2]))
+        } else if num.is_negative() {
+            Ok(format!("mínus {}", self.percentage(num.abs(), forms)?))
+        } else if num.frac().is_zero() {
+            let words = match num.is_zero() {
+                true => String::from(UNITS[0]),
+                false => self.counted(num, Gender::Neuter)?,
+            };
+            Ok(format!("{} {}", words, forms[czech_plural(num)]))
+        } else {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[3]))
+        }
+    }
+}
+
+impl Language for Slovak {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from("nekonečno"))
+        } else if num.is_inf_neg() {
+            Ok(String::from("mínus nekonečno"))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_zero() {
+            return Ok(String::from(UNITS_ORDINAL[0]));
+        }
+
+        let thousands = self.split_thousands(num);
+        // only the lowest non-zero group is read as an ordinal
+        let (i, triplet) = thousands
+            .iter()
+            .enumerate()
+            .find(|(_, triplet)| **triplet != 0)
+            .unwrap();
+
+        let mut words: Vec<String> = vec![];
+        let mut lowest = BigFloat::from(*triplet);
+        for _ in 0..i {
+            lowest *= BigFloat::from(1000);
+        }
+        let higher = num - lowest;
+        if !higher.is_zero() {
+            words.push(self.int_to_cardinal(higher)?);
+        }
+
+        if i != 0 {
+            let mega = MEGAS_ORDINAL.get(i - 1).ok_or(Num2Err::CannotConvert)?;
+            // e.g. "tisíci", "dvojtisíci", but "dvadsať tisíci"
+            match *triplet {
+                1 => words.push(String::from(*mega)),
+                2..=9 => words.push(format!("{}{}", UNITS_PREFIX[*triplet as usize], mega)),
+                _ => {
+                    words.push(self.int_to_cardinal(BigFloat::from(*triplet))?);
+                    words.push(String::from(*mega));
+                }
+            }
+        } else {
+            let hundreds = (triplet / 100 % 10) as usize;
+            let rest = (triplet % 100) as usize;
+
+            // e.g. "sto dvadsiaty tretí"
+            match (hundreds, rest) {
+                (_, 0) => words.push(String::from(HUNDREDS_ORDINAL[hundreds])),
+                (0, _) => (),
+                _ => words.push(String::from(HUNDREDS[hundreds])),
+            }
+            if rest >= 20 {
+                words.push(String::from(TENS_ORDINAL[rest / 10]));
+            }
+            match if rest >= 20 { rest % 10 } else { rest } {
+                0 => (),
+                units => words.push(String::from(UNITS_ORDINAL[units])),
+            }
+        }
+
+        Ok(words.join(" "))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_negative() {
+            Ok(format!("{} pred naším letopočtom", self.to_cardinal(-num)?))
+        } else {
+            self.to_cardinal(num)
+        }
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        let (forms, gender) = self.currencies(currency);
+        if num.is_inf() {
+            Ok(format!("{} {}", self.to_cardinal(num)?, forms[2]))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "mínus päť korún päťdesiat halierov"
+            Ok(format!("mínus {}", self.to_currency(num.abs(), currency)?))
+        } else if num.frac().is_zero() {
+            let words = match num.is_zero() {
+                true => String::from(UNITS[0]),
+                false => self.counted(num, gender)?,
+            };
+            Ok(format!("{} {}", words, forms[czech_plural(num)]))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let cents_gender = match currency.is_subunit_feminine() {
+                true => Gender::Feminine,
+                false => Gender::Masculine,
+            };
+            let cents_words = self.counted(cents_nb, cents_gender)?;
+            let cents_suffix = &self.cents(currency)[czech_plural(cents_nb)];
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                Ok(integral_word)
+            } else if integral_part.is_zero() {
+                Ok(format!("{} {}", cents_words, cents_suffix))
+            } else {
+                Ok(format!(
+                    "{}{}{} {}",
+                    integral_word,
+                    self.currency_connector(),
+                    cents_words,
+                    cents_suffix
+                ))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match power {
+            1 => Some("tisíc"),
+            _ => MEGAS.get(power.checked_sub(2)?).map(|m| m.0[0]),
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        match MEGAS.iter().find(|m| m.0[0] == word) {
+            Some(m) => String::from(m.0[2]),
+            None => String::from(word),
+        }
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        "sto"
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "tisíc"
+    }
+
+    fn to_percent(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.percentage(num, ["percento", "percentá", "percent", "percenta"])
+    }
+
+    fn to_permille(&self, num: BigFloat) -> Result<String, Num2Err> {
+        self.percentage(num, ["promile"; 4])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Slovak).cardinal().to_words(),
+            Ok(String::from("štyridsaťdva"))
+        );
+        assert_eq!(
+            Num2Words::new(2500)
+                .lang(Lang::Slovak)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dvetisícpäťsto"))
+        );
+        assert_eq!(
+            Num2Words::new(2_500_000)
+                .lang(Lang::Slovak)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("dva milióny päťstotisíc"))
+        );
+        assert_eq!(
+            Num2Words::new(5_000_000_000_u64)
+                .lang(Lang::Slovak)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("päť miliárd"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.5)
+                .lang(Lang::Slovak)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("mínus jedna celá päť"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("prvý"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Slovak).ordinal().to_words(),
+            Ok(String::from("dvadsiaty prvý"))
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Slovak)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("dve eurá"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Slovak)
+                .currency(Currency::CZK)
+                .to_words(),
+            Ok(String::from("päť korún päťdesiat halierov"))
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            Num2Words::new(1).lang(Lang::Slovak).percent().to_words(),
+            Ok(String::from("jedno percento"))
+        );
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Slovak).percent().to_words(),
+            Ok(String::from("tri percentá"))
+        );
+        assert_eq!(
+            Num2Words::new(5).lang(Lang::Slovak).percent().to_words(),
+            Ok(String::from("päť percent"))
+        );
+        assert_eq!(
+            Num2Words::new(2).lang(Lang::Slovak).permille().to_words(),
+            Ok(String::from("dve promile"))
+        );
+    }
+}
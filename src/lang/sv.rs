@@ -0,0 +1,274 @@
+use crate::{lang::germanic::Germanic, num2words::Num2Err, Currency, Language};
+use num_bigfloat::BigFloat;
+
+pub struct Swedish {}
+
+const WORDS: Germanic = Germanic {
+    units: [
+        "noll", "ett", "två", "tre", "fyra", "fem", "sex", "sju", "åtta", "nio", "tio", "elva",
+        "tolv", "tretton", "fjorton", "femton", "sexton", "sjutton", "arton", "nitton",
+    ],
+    tens: [
+        "", "tio", "tjugo", "trettio", "fyrtio", "femtio", "sextio", "sjuttio", "åttio", "nittio",
+    ],
+    hundred: "hundra",
+    thousand: "tusen",
+    megas: [
+        ["miljon", "miljoner"],
+        ["miljard", "miljarder"],
+        ["biljon", "biljoner"],
+        ["biljard", "biljarder"],
+    ],
+    ordinal_units: [
+        "nollte",
+        "första",
+        "andra",
+        "tredje",
+        "fjärde",
+        "femte",
+        "sjätte",
+        "sjunde",
+        "åttonde",
+        "nionde",
+        "tionde",
+        "elfte",
+        "tolfte",
+        "trettonde",
+        "fjortonde",
+        "femtonde",
+        "sextonde",
+        "sjuttonde",
+        "artonde",
+        "nittonde",
+    ],
+    ordinal_tens: [
+        "",
+        "tionde",
+        "tjugonde",
+        "trettionde",
+        "fyrtionde",
+        "femtionde",
+        "sextionde",
+        "sjuttionde",
+        "åttionde",
+        "nittionde",
+    ],
+    ordinal_hundred: "hundrade",
+    ordinal_thousand: "tusende",
+    neuter_one: "ett",
+    common_one: "en",
+    and: None,
+    joiner: "",
+    units_first: false,
+    connector: " och ",
+    minus: "minus",
+    point: "komma",
+    infinity: "oändligheten",
+    before_christ: "före Kristus",
+};
+
+impl Swedish {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn currencies(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::SEK if plural_form => String::from("kronor"),
+            Currency::SEK => String::from("krona"),
+            Currency::DKK if plural_form => String::from("danska kronor"),
+            Currency::DKK => String::from("dansk krona"),
+            Currency::NOK if plural_form => String::from("norska kronor"),
+            Currency::NOK => String::from("norsk krona"),
+            Currency::DOLLAR | Currency::USD => String::from("dollar"),
+            Currency::EUR => String::from("euro"),
+            Currency::GBP => String::from("pund"),
+            _ => currency.default_string(plural_form),
+        }
+    }
+
+    fn cents(&self, currency: Currency, plural_form: bool) -> String {
+        match currency {
+            Currency::SEK | Currency::DKK | Currency::NOK => String::from("öre"),
+            Currency::DOLLAR | Currency::USD | Currency::EUR => String::from("cent"),
+            Currency::GBP => String::from("pence"),
+            _ => currency.default_subunit_string("cent{}", plural_form),
+        }
+    }
+}
+
+impl Language for Swedish {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_cardinal(num)
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_ordinal(num)
+    }
+
+    fn ordinal_num_suffix(&self, num: BigFloat) -> Result<&'static str, Num2Err> {
+        let value = (num % BigFloat::from(100)).to_u64().unwrap();
+        // first and second take ":a", the others ":e", e.g. "21:a" but "11:e"
+        Ok(match (value % 10, value % 100) {
+            (1 | 2, 11 | 12) => ":e",
+            (1 | 2, _) => ":a",
+            _ => ":e",
+        })
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        WORDS.to_year(num)
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        WORDS.to_currency(
+            num,
+            currency,
+            |c, plural| self.currencies(c, plural),
+            |c, plural| self.cents(c, plural),
+        )
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        WORDS.connector
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        WORDS.scale_word(power)
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn scale_plural(&self, word: &'static str) -> String {
+        WORDS.scale_plural(word)
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        WORDS.hundred
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        WORDS.thousand
+    }
+
+    fn percent_word(&self) -> Option<&'static str> {
+        Some("procent")
+    }
+
+    fn permille_word(&self) -> Option<&'static str> {
+        Some("promille")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Swedish).cardinal().to_words(),
+            Ok(String::from("noll"))
+        );
+        assert_eq!(
+            Num2Words::new(42).lang(Lang::Swedish).cardinal().to_words(),
+            Ok(String::from("fyrtiotvå"))
+        );
+        assert_eq!(
+            Num2Words::new(1234)
+                .lang(Lang::Swedish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("ettusentvåhundratrettiofyra"))
+        );
+        assert_eq!(
+            Num2Words::new(2_500_000)
+                .lang(Lang::Swedish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("två miljoner femhundratusen"))
+        );
+        assert_eq!(
+            Num2Words::new(-1.5)
+                .lang(Lang::Swedish)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("minus ett komma fem"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal() {
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Swedish).ordinal().to_words(),
+            Ok(String::from("tredje"))
+        );
+        assert_eq!(
+            Num2Words::new(21).lang(Lang::Swedish).ordinal().to_words(),
+            Ok(String::from("tjugoförsta"))
+        );
+        assert_eq!(
+            Num2Words::new(1000)
+                .lang(Lang::Swedish)
+                .ordinal()
+                .to_words(),
+            Ok(String::from("ettusende"))
+        );
+        assert_eq!(
+            Num2Words::new(22)
+                .lang(Lang::Swedish)
+                .ordinal_num()
+                .to_words(),
+            Ok(String::from("22:a"))
+        );
+    }
+
+    #[test]
+    fn test_year() {
+        assert_eq!(
+            Num2Words::new(1984).lang(Lang::Swedish).year().to_words(),
+            Ok(String::from("nittonhundraåttiofyra"))
+        );
+        // years past a u64 are read as cardinals
+        for year in [1e21, 1e40] {
+            assert_eq!(
+                Num2Words::new(year).lang(Lang::Swedish).year().to_words(),
+                Num2Words::new(year).lang(Lang::Swedish).to_words()
+            );
+        }
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Swedish)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("en euro"))
+        );
+        assert_eq!(
+            Num2Words::new(5.5)
+                .lang(Lang::Swedish)
+                .currency(Currency::NOK)
+                .to_words(),
+            Ok(String::from("fem norska kronor och femtio öre"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Swedish)
+                .currency(Currency::SEK)
+                .to_words(),
+            Ok(String::from("två kronor och femtio öre"))
+        );
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Swedish)
+                .currency(Currency::SEK)
+                .to_words(),
+            Ok(String::from("en krona"))
+        );
+    }
+}
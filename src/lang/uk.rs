@@ -1,4 +1,8 @@
-use crate::{num2words::Num2Err, Currency, Language};
+use crate::{
+    lang::{slavic_plural, whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Case, Currency, Gender, Language, Unit,
+};
 use num_bigfloat::BigFloat;
 use std::str::FromStr;
 
@@ -8,20 +12,9 @@ use std::str::FromStr;
 // § 106. Ordinal numerals declension / Відмінювання порядкових числівників
 // § 107. Fractional numerals declension / Відмінювання дробових числівників
 
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum Declension {
-    #[default]
-    Nominative,
-    Genitive,
-    Dative,
-    Accusative,
-    Instrumental,
-    Locative,
-}
-
-impl Declension {
+impl Case {
     fn index(&self) -> usize {
-        use Declension::*;
+        use Case::*;
         match self {
             Nominative => 0,
             Genitive => 1,
@@ -33,11 +26,11 @@ impl Declension {
     }
 }
 
-impl FromStr for Declension {
+impl FromStr for Case {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        use Declension::*;
+        use Case::*;
 
         Ok(match s.to_lowercase().as_str() {
             "н" | "називний" | "nom" | "nominative" => Nominative,
@@ -51,14 +44,6 @@ impl FromStr for Declension {
     }
 }
 
-#[derive(Default, Clone, Copy, PartialEq, Debug)]
-pub enum Gender {
-    #[default]
-    Masculine,
-    Feminine,
-    Neuter,
-}
-
 impl FromStr for Gender {
     type Err = ();
 
@@ -118,7 +103,7 @@ impl GrammaticalNumber {
 pub struct Ukrainian {
     gender: Gender,
     number: GrammaticalNumber,
-    declension: Declension,
+    declension: Case,
 }
 
 impl Ukrainian {
@@ -134,7 +119,7 @@ impl Ukrainian {
             ..*self
         }
     }
-    fn set_declension(&self, declension: Declension) -> Self {
+    fn set_declension(&self, declension: Case) -> Self {
         Self {
             declension,
             ..*self
@@ -166,8 +151,8 @@ impl Ukrainian {
 
     fn agreement_with_units(&self, tens: usize, units: usize) -> Ukrainian {
         if units == 0 || units > 4 || tens == 1 {
-            if self.declension == Declension::Nominative {
-                self.plural().set_declension(Declension::Genitive)
+            if self.declension == Case::Nominative {
+                self.plural().set_declension(Case::Genitive)
             } else {
                 self.plural()
             }
@@ -369,6 +354,12 @@ const NOUN_2ST_GROUP_SOFT_DECLENSIONS: [[&str; 6]; 2] = [ //юань
     [ "і", "ів", "ям", "і", "ями", "ях" ],
 ];
 
+#[rustfmt::skip]
+const NOUN_2ST_GROUP_SOFT_DECLENSIONS_VOWEL: [[&str; 6]; 2] = [ //лей
+    [ "й", "я",  "ю",  "я", "єм",  "ї"  ],
+    [ "ї", "їв", "ям", "ї", "ями", "ях" ],
+];
+
 #[rustfmt::skip]
 const NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL: [[&str; 6]; 2] = [ //рупія
     [ "я", "ї", "ї",  "я", "єю",  "ї"  ],
@@ -400,7 +391,7 @@ const YEAR: [[&str; 6]; 2] = [
 ];
 
 impl Ukrainian {
-    pub fn new(gender: Gender, number: GrammaticalNumber, declension: Declension) -> Self {
+    pub fn new(gender: Gender, number: GrammaticalNumber, declension: Case) -> Self {
         Self {
             gender,
             number,
@@ -412,25 +403,44 @@ impl Ukrainian {
         let number_idx = self.number.index();
         let declension_idx = self.declension.index();
         match currency {
-            Currency::AED => format!(
+            Currency::AED | Currency::MAD => format!(
                 "дирхам{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::AFN => String::from("афгані"),
             Currency::ARS
             | Currency::CLP
             | Currency::COP
+            | Currency::CUP
+            | Currency::DOP
             | Currency::MXN
             | Currency::PESO
             | Currency::PHP
             | Currency::UYU => String::from("песо"),
             Currency::AUD
+            | Currency::BBD
+            | Currency::BMD
+            | Currency::BND
+            | Currency::BSD
+            | Currency::BZD
             | Currency::CAD
             | Currency::DOLLAR
+            | Currency::FJD
+            | Currency::GYD
             | Currency::HKD
+            | Currency::JMD
+            | Currency::KYD
+            | Currency::LRD
+            | Currency::MILL
+            | Currency::NAD
             | Currency::NZD
+            | Currency::SBD
             | Currency::SGD
+            | Currency::SRD
+            | Currency::TTD
             | Currency::TWD
-            | Currency::USD => format!(
+            | Currency::USD
+            | Currency::XCD => format!(
                 "долар{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -438,7 +448,16 @@ impl Ukrainian {
                 "реал{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::CHF => format!(
+            Currency::BIF
+            | Currency::CDF
+            | Currency::CHF
+            | Currency::DJF
+            | Currency::GNF
+            | Currency::KMF
+            | Currency::RWF
+            | Currency::XAF
+            | Currency::XOF
+            | Currency::XPF => format!(
                 "франк{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -450,16 +469,42 @@ impl Ukrainian {
                 "колон{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::DINAR | Currency::DZD | Currency::KWD => format!(
+            Currency::CZK => format!(
+                "крон{}",
+                NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::BHD
+            | Currency::DINAR
+            | Currency::DZD
+            | Currency::IQD
+            | Currency::JOD
+            | Currency::KWD
+            | Currency::LYD
+            | Currency::RSD
+            | Currency::TND => format!(
                 "динар{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::EUR => String::from("євро"),
-            Currency::GBP => format!(
+            Currency::EGP
+            | Currency::FKP
+            | Currency::GBP
+            | Currency::GIP
+            | Currency::LBP
+            | Currency::SDG
+            | Currency::SHP
+            | Currency::SSP
+            | Currency::SYP => format!(
                 "фунт{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::IDR | Currency::INR => format!(
+            Currency::IDR
+            | Currency::INR
+            | Currency::LKR
+            | Currency::MUR
+            | Currency::NPR
+            | Currency::PKR
+            | Currency::SCR => format!(
                 "рупі{}",
                 NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
             ),
@@ -487,7 +532,7 @@ impl Ukrainian {
                 "рингіт{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::NOK => format!(
+            Currency::DKK | Currency::ISK | Currency::NOK | Currency::SEK => format!(
                 "крон{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -503,11 +548,20 @@ impl Ukrainian {
                 }[declension_idx];
                 format!("злот{}", flextion)
             }
-            Currency::QAR | Currency::RIYAL | Currency::SAR => format!(
+            Currency::IRR
+            | Currency::OMR
+            | Currency::QAR
+            | Currency::RIYAL
+            | Currency::SAR
+            | Currency::YER => format!(
                 "ріал{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::RUB => format!(
+            Currency::MDL | Currency::RON => format!(
+                "ле{}",
+                NOUN_2ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
+            ),
+            Currency::BYN | Currency::RUB => format!(
                 "рубл{}",
                 NOUN_1ST_GROUP_SOFT_DECLENSIONS_VOWEL[number_idx][declension_idx]
             ),
@@ -528,17 +582,35 @@ impl Ukrainian {
                 "ранд{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::HUF => format!(
+                "форинт{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::KES | Currency::SOS | Currency::TZS | Currency::UGX => format!(
+                "шилінг{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            _ => currency.default_string(self.number == GrammaticalNumber::Plural),
         }
     }
 
     fn currency_properties(&self, currency: Currency) -> Ukrainian {
         match currency {
-            Currency::INR
+            Currency::DKK
+            | Currency::INR
+            | Currency::ISK
             | Currency::JPY
             | Currency::KRW
+            | Currency::LKR
+            | Currency::MUR
             | Currency::NOK
+            | Currency::NPR
+            | Currency::PKR
+            | Currency::SCR
+            | Currency::SEK
             | Currency::TRY
             | Currency::UAH => self.feminine(),
+            _ if currency.is_feminine() => self.feminine(),
             _ => self.masculine(),
         }
     }
@@ -551,26 +623,51 @@ impl Ukrainian {
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
+            Currency::AFN => format!(
+                "пул{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
             Currency::ARS
+            | Currency::BRL
             | Currency::CLP
             | Currency::COP
+            | Currency::CUP
+            | Currency::DOP
             | Currency::MXN
             | Currency::PESO
             | Currency::PHP
-            | Currency::UYU
-            | Currency::BRL => String::from("сентаво"),
+            | Currency::UYU => String::from("сентаво"),
             Currency::AUD
+            | Currency::BBD
+            | Currency::BMD
+            | Currency::BND
+            | Currency::BSD
+            | Currency::BZD
             | Currency::CAD
             | Currency::DOLLAR
+            | Currency::FJD
+            | Currency::GYD
             | Currency::HKD
+            | Currency::JMD
+            | Currency::KYD
+            | Currency::LRD
+            | Currency::NAD
             | Currency::NZD
+            | Currency::SBD
             | Currency::SGD
+            | Currency::SRD
+            | Currency::TTD
             | Currency::TWD
-            | Currency::USD => format!(
+            | Currency::USD
+            | Currency::XCD => format!(
                 "цент{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::CHF => format!(
+            Currency::MILL => format!(
+                "міл{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::CDF | Currency::CHF | Currency::MAD => format!(
                 "сантим{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -579,7 +676,16 @@ impl Ukrainian {
                 NOUN_2ST_GROUP_SOFT_DECLENSIONS[number_idx][declension_idx]
             ),
             Currency::CRC => String::from("сантимо"),
-            Currency::DINAR | Currency::DZD | Currency::KWD => format!(
+            Currency::CZK => format!(
+                "гелер{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::BHD
+            | Currency::DINAR
+            | Currency::DZD
+            | Currency::IQD
+            | Currency::JOD
+            | Currency::KWD => format!(
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -587,7 +693,7 @@ impl Ukrainian {
                 "євроцент{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::GBP => format!(
+            Currency::FKP | Currency::GBP | Currency::GIP | Currency::SHP => format!(
                 "пенс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -595,7 +701,7 @@ impl Ukrainian {
                 "сен{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::INR => format!(
+            Currency::INR | Currency::NPR | Currency::PKR => format!(
                 "пайс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -619,17 +725,21 @@ impl Ukrainian {
                 "сен{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::NOK => String::from("оре"),
+            Currency::DKK | Currency::NOK | Currency::SEK => String::from("оре"),
             Currency::PEN => String::from("сентімо"),
             Currency::PLN => format!(
                 "грош{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::QAR | Currency::RIYAL | Currency::SAR => format!(
+            Currency::QAR | Currency::RIYAL | Currency::SAR | Currency::YER => format!(
                 "філс{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
-            Currency::RUB => format!(
+            Currency::MDL | Currency::RON => format!(
+                "бан{}",
+                NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
+            ),
+            Currency::BYN | Currency::RUB => format!(
                 "копійк{}",
                 NOUN_1ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -643,7 +753,10 @@ impl Ukrainian {
             ),
             Currency::UAH => String::from(KOPIYKAS[number_idx][declension_idx]),
             Currency::VND => String::from("су"),
-            Currency::ZAR => format!(
+            Currency::Custom { .. } => {
+                currency.default_subunit_string("", self.number == GrammaticalNumber::Plural)
+            }
+            _ => format!(
                 "цент{}",
                 NOUN_2ST_GROUP_HARD_DECLENSIONS[number_idx][declension_idx]
             ),
@@ -652,7 +765,10 @@ impl Ukrainian {
 
     fn currency_fraction_properties(&self, currency: Currency) -> Ukrainian {
         match currency {
-            Currency::ILS | Currency::TRY | Currency::RUB | Currency::UAH => self.feminine(),
+            Currency::BYN | Currency::ILS | Currency::TRY | Currency::RUB | Currency::UAH => {
+                self.feminine()
+            }
+            _ if currency.is_subunit_feminine() => self.feminine(),
             _ => self.masculine(),
         }
     }
@@ -744,7 +860,10 @@ impl Ukrainian {
             return self.int_to_cardinal(whole);
         }
         let mut denominator = BigFloat::from(1);
-        while !numerator.frac().is_zero() {
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if numerator.frac().is_zero() {
+                break;
+            }
             //TODO: we should use non-floating point format because of limited precision
             numerator *= BigFloat::from(10);
             denominator *= BigFloat::from(10);
@@ -809,6 +928,17 @@ impl Language for Ukrainian {
         }
     }
 
+    fn gendered(&self, gender: Gender) -> Option<Box<dyn Language>> {
+        Some(Box::new(Self { gender, ..*self }))
+    }
+
+    fn declined(&self, declension: Case) -> Option<Box<dyn Language>> {
+        Some(Box::new(Self {
+            declension,
+            ..*self
+        }))
+    }
+
     fn to_ordinal(&self, mut num: BigFloat) -> Result<String, Num2Err> {
         let flexion = self.ordinal_flexion(num);
 
@@ -846,25 +976,24 @@ impl Language for Ukrainian {
                     //п’ятсоттридцятитрьохтисячний
                     let mut word = String::new();
                     if hundreds > 0 {
-                        word.push_str(HUNDREDS[hundreds - 1][Declension::Genitive.index()]);
+                        word.push_str(HUNDREDS[hundreds - 1][Case::Genitive.index()]);
                     }
                     if tens == 1 {
                         word.push_str(&format!(
                             "{}{}",
                             TEENS_BASES[units],
-                            TEENS_FLEXIONS[Declension::Genitive.index()]
+                            TEENS_FLEXIONS[Case::Genitive.index()]
                         ));
                     } else {
                         if tens > 1 {
-                            word.push_str(TENS[tens - 2][Declension::Genitive.index()]);
+                            word.push_str(TENS[tens - 2][Case::Genitive.index()]);
                         }
                         match units {
                             1 => word.push_str(ONE_BASE),
                             2 => word.push_str(
-                                GENDERED[1][Gender::Masculine.index()]
-                                    [Declension::Genitive.index()],
+                                GENDERED[1][Gender::Masculine.index()][Case::Genitive.index()],
                             ),
-                            3..=9 => word.push_str(UNITS[units - 3][Declension::Genitive.index()]),
+                            3..=9 => word.push_str(UNITS[units - 3][Case::Genitive.index()]),
                             _ => (),
                         }
                     }
@@ -875,7 +1004,7 @@ impl Language for Ukrainian {
                 } else {
                     if hundreds > 0 {
                         words.push(String::from(
-                            HUNDREDS[hundreds - 1][Declension::Nominative.index()],
+                            HUNDREDS[hundreds - 1][Case::Nominative.index()],
                         ));
                     }
                     if tens == 1 {
@@ -884,8 +1013,7 @@ impl Language for Ukrainian {
                         words.push(format!("{}{flexion}", ORDINAL_TENS_BASES[tens - 1]));
                     } else {
                         if tens > 1 {
-                            words
-                                .push(String::from(TENS[tens - 2][Declension::Nominative.index()]));
+                            words.push(String::from(TENS[tens - 2][Case::Nominative.index()]));
                         }
                         let flexion = self.ordinal_flexion(BigFloat::from(units as u8));
                         words.push(format!("{}{flexion}", ORDINAL_UNIT_BASES[units - 1]));
@@ -896,7 +1024,7 @@ impl Language for Ukrainian {
 
             if hundreds > 0 {
                 words.push(String::from(
-                    HUNDREDS[hundreds - 1][Declension::Nominative.index()],
+                    HUNDREDS[hundreds - 1][Case::Nominative.index()],
                 ));
             }
 
@@ -910,21 +1038,18 @@ impl Language for Ukrainian {
                 words.push(format!(
                     "{}{}",
                     TEENS_BASES[units],
-                    TEENS_FLEXIONS[Declension::Nominative.index()]
+                    TEENS_FLEXIONS[Case::Nominative.index()]
                 ));
             } else {
                 if tens > 1 {
-                    words.push(String::from(TENS[tens - 2][Declension::Nominative.index()]));
+                    words.push(String::from(TENS[tens - 2][Case::Nominative.index()]));
                 }
                 if units == 1 || units == 2 {
                     words.push(String::from(
-                        GENDERED[units - 1][properties.gender.index()]
-                            [Declension::Nominative.index()],
+                        GENDERED[units - 1][properties.gender.index()][Case::Nominative.index()],
                     ));
                 } else if units > 0 {
-                    words.push(String::from(
-                        UNITS[units - 3][Declension::Nominative.index()],
-                    ));
+                    words.push(String::from(UNITS[units - 3][Case::Nominative.index()]));
                 }
             }
 
@@ -943,7 +1068,7 @@ impl Language for Ukrainian {
 
     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
         let flexion = self.ordinal_flexion_short(num);
-        Ok(format!("{}-{flexion}", num.to_u128().unwrap()))
+        Ok(format!("{}-{flexion}", whole_digits(num)))
     }
 
     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
@@ -978,10 +1103,12 @@ impl Language for Ukrainian {
                 currency_lang.to_cardinal(num)?,
                 target_lang.currencies(currency)
             ))
+        } else if num.is_negative() {
+            Ok(format!("мінус {}", self.to_currency(-num, currency)?))
         } else {
             let whole = num.int();
-            let fraction = num.frac();
-            if fraction.is_zero() || num.is_inf() {
+            let fraction = (num.frac() * BigFloat::from(currency.subunit_ratio())).int();
+            if fraction.is_zero() {
                 let currency_lang = self.currency_properties(currency);
                 let target_lang = currency_lang.agreement_with_num(whole);
                 Ok(format!(
@@ -990,7 +1117,6 @@ impl Language for Ukrainian {
                     target_lang.currencies(currency)
                 ))
             } else if whole.is_zero() {
-                let fraction = fraction * BigFloat::from(100).int();
                 let currency_lang = self.currency_fraction_properties(currency);
                 let target_lang = currency_lang.agreement_with_num(fraction);
                 Ok(format!(
@@ -1000,13 +1126,81 @@ impl Language for Ukrainian {
                 ))
             } else {
                 Ok(format!(
-                    "{} {}",
+                    "{}{}{}",
                     self.to_currency(whole, currency)?,
-                    self.to_currency(fraction, currency)?,
+                    self.currency_connector(),
+                    self.to_currency(num.frac(), currency)?,
                 ))
             }
         }
     }
+
+    fn currency_connector(&self) -> &'static str {
+        " "
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        match power {
+            1 => Some("тисяча"),
+            _ => MEGA_BASES.get(power.checked_sub(1)?).copied(),
+        }
+    }
+
+    fn separators(&self) -> (char, char) {
+        (' ', ',')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        HUNDREDS[0][Case::Nominative.index()]
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        "тисяча"
+    }
+
+    // seconds, minutes, hours and miles are feminine, e.g. "дві хвилини"
+    fn to_unit(&self, num: BigFloat, unit: Unit) -> Result<String, Num2Err> {
+        let words = self.unit_words(unit).ok_or(Num2Err::CannotConvert)?;
+        let language = match unit {
+            Unit::Second | Unit::Minute | Unit::Hour | Unit::Mile => self.feminine(),
+            _ => self.masculine(),
+        };
+        Ok(format!(
+            "{} {}",
+            language.to_cardinal(num)?,
+            words[self.unit_plural(num)]
+        ))
+    }
+
+    // nominative forms, fractions take the genitive singular, e.g.
+    // "дві цілих пʼять десятих кілометра"
+    fn unit_words(&self, unit: Unit) -> Option<[&'static str; 4]> {
+        match unit {
+            Unit::Millimeter => Some(["міліметр", "міліметри", "міліметрів", "міліметра"]),
+            Unit::Centimeter => Some(["сантиметр", "сантиметри", "сантиметрів", "сантиметра"]),
+            Unit::Meter => Some(["метр", "метри", "метрів", "метра"]),
+            Unit::Kilometer => Some(["кілометр", "кілометри", "кілометрів", "кілометра"]),
+            Unit::Mile => Some(["миля", "милі", "миль", "милі"]),
+            Unit::Gram => Some(["грам", "грами", "грамів", "грама"]),
+            Unit::Kilogram => Some(["кілограм", "кілограми", "кілограмів", "кілограма"]),
+            Unit::Liter => Some(["літр", "літри", "літрів", "літра"]),
+            Unit::Second => Some(["секунда", "секунди", "секунд", "секунди"]),
+            Unit::Minute => Some(["хвилина", "хвилини", "хвилин", "хвилини"]),
+            Unit::Hour => Some(["година", "години", "годин", "години"]),
+            Unit::Day => Some(["день", "дні", "днів", "дня"]),
+            _ => unit.custom_words(),
+        }
+    }
+
+    fn unit_plural(&self, num: BigFloat) -> usize {
+        if num.is_inf() {
+            2
+        } else if !num.frac().is_zero() {
+            3
+        } else {
+            slavic_plural((num.abs() % BigFloat::from(100)).to_u64().unwrap())
+        }
+    }
 }
 
 #[cfg(test)]
@@ -1149,13 +1343,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             }
             .agreement_with_units(0, 0),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Case::Genitive
             },
             "failed agreement: 0"
         );
@@ -1163,13 +1357,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             },
             "failed agreement: 1"
         );
@@ -1177,13 +1371,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             }
             .agreement_with_units(8, 2),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             },
             "failed agreement: 82"
         );
@@ -1191,13 +1385,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Dative
+                declension: Case::Dative
             }
             .agreement_with_units(1, 1),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Dative
+                declension: Case::Dative
             },
             "failed agreement: 11"
         );
@@ -1205,13 +1399,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Instrumental
+                declension: Case::Instrumental
             }
             .agreement_with_units(5, 4),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Instrumental
+                declension: Case::Instrumental
             },
             "failed agreement: 54"
         );
@@ -1219,13 +1413,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             }
             .agreement_with_units(1, 8),
             Ukrainian {
                 gender: Gender::Masculine,
                 number: GrammaticalNumber::Plural,
-                declension: Declension::Genitive
+                declension: Case::Genitive
             },
             "failed agreement: 18"
         );
@@ -1233,13 +1427,13 @@ mod tests {
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             }
             .agreement_with_units(0, 1),
             Ukrainian {
                 gender: Gender::Feminine,
                 number: GrammaticalNumber::Singular,
-                declension: Declension::Nominative
+                declension: Case::Nominative
             },
             "failed agreement: 1 feminine"
         );
@@ -1413,7 +1607,7 @@ mod tests {
             Ukrainian::new(
                 Gender::Neuter,
                 GrammaticalNumber::Singular,
-                Declension::Accusative
+                Case::Accusative
             )
             .float_to_cardinal(BigFloat::from(973.0)),
             Ok(String::from("девʼятсот сімдесят три"))
@@ -1467,4 +1661,147 @@ mod tests {
             Ok(String::from("нескінченність доларів"))
         );
     }
+
+    #[test]
+    fn test_unit() {
+        assert_eq!(
+            Num2Words::new(21)
+                .lang(Lang::Ukrainian)
+                .unit(Unit::Kilometer)
+                .to_words(),
+            Ok(String::from("двадцять один кілометр"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::Ukrainian)
+                .unit(Unit::Hour)
+                .to_words(),
+            Ok(String::from("дві години"))
+        );
+        assert_eq!(
+            Num2Words::new(5)
+                .lang(Lang::Ukrainian)
+                .unit(Unit::Kilogram)
+                .to_words(),
+            Ok(String::from("пʼять кілограмів"))
+        );
+    }
+
+    #[test]
+    fn test_currency_subunit_exponent() {
+        assert_eq!(
+            Num2Words::new(42.5)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::JPY)
+                .to_words(),
+            Ok(String::from("сорок дві єни"))
+        );
+        assert_eq!(
+            Num2Words::new(1.234)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::KWD)
+                .to_words(),
+            Ok(String::from("один динар двісті тридцять чотири філси"))
+        );
+    }
+
+    #[test]
+    fn test_currency_catalog() {
+        assert_eq!(
+            Num2Words::new(5.2)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::DKK)
+                .to_words(),
+            Ok(String::from("пʼять крон двадцять оре"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::XOF)
+                .to_words(),
+            Ok(String::from("три франки"))
+        );
+    }
+
+    #[test]
+    fn test_currency_custom() {
+        let bitcoin = Currency::custom("біткоїн", "біткоїнів", "сатоші", "сатоші", 8);
+        assert_eq!(
+            Num2Words::new(1.00000005)
+                .lang(Lang::Ukrainian)
+                .currency(bitcoin)
+                .to_words(),
+            Ok(String::from("один біткоїн пʼять сатоші"))
+        );
+    }
+
+    #[test]
+    fn test_currency_negative() {
+        assert_eq!(
+            Num2Words::new(-5.5)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("мінус пʼять євро пʼятдесят євроцентів"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.5)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("мінус пʼятдесят євроцентів"))
+        );
+    }
+
+    #[test]
+    fn test_gender_builder() {
+        let gendered = |n: i64, gender| {
+            Num2Words::new(n)
+                .lang(Lang::Ukrainian)
+                .gender(gender)
+                .to_words()
+        };
+        assert_eq!(
+            gendered(21, Gender::Feminine),
+            Ok(String::from("двадцять одна"))
+        );
+        assert_eq!(gendered(2, Gender::Feminine), Ok(String::from("дві")));
+        assert_eq!(gendered(1, Gender::Neuter), Ok(String::from("одне")));
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Ukrainian)
+                .prefer("ж")
+                .gender(Gender::Masculine)
+                .to_words(),
+            Ok(String::from("один"))
+        );
+    }
+
+    #[test]
+    fn test_case_builder() {
+        let declined = |n: i64, case| {
+            Num2Words::new(n)
+                .lang(Lang::Ukrainian)
+                .case(case)
+                .to_words()
+        };
+        assert_eq!(declined(2, Case::Genitive), Ok(String::from("двох")));
+        assert_eq!(declined(2, Case::Dative), Ok(String::from("двом")));
+        assert_eq!(declined(2, Case::Instrumental), Ok(String::from("двома")));
+        assert_eq!(
+            Num2Words::new(1)
+                .lang(Lang::Ukrainian)
+                .gender(Gender::Feminine)
+                .case(Case::Genitive)
+                .to_words(),
+            Ok(String::from("одної"))
+        );
+        assert_eq!(
+            Num2Words::new(2)
+                .lang(Lang::English)
+                .case(Case::Genitive)
+                .to_words(),
+            Ok(String::from("two"))
+        );
+    }
 }
@@ -0,0 +1,478 @@
+use crate::{
+    lang::{split_groups, whole_digits, MAX_FRACTION_DIGITS},
+    num2words::Num2Err,
+    Currency, GroupingScheme, Language,
+};
+use num_bigfloat::BigFloat;
+use std::str::FromStr;
+
+pub struct Chinese {
+    script: Script,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Debug)]
+pub enum Script {
+    #[default]
+    Simplified,
+    Traditional,
+    Pinyin,
+}
+
+impl FromStr for Script {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use Script::*;
+        Ok(match s.to_lowercase().as_str() {
+            "simplified" | "简体" => Simplified,
+            "traditional" | "繁體" => Traditional,
+            "pinyin" | "拼音" => Pinyin,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl Script {
+    fn index(&self) -> usize {
+        use Script::*;
+        match self {
+            Simplified => 0,
+            Traditional => 1,
+            Pinyin => 2,
+        }
+    }
+}
+
+// Simplified, traditional and pinyin forms of each word
+const DIGITS: [[&str; 3]; 10] = [
+    ["零", "零", "líng"],
+    ["一", "一", "yī"],
+    ["二", "二", "èr"],
+    ["三", "三", "sān"],
+    ["四", "四", "sì"],
+    ["五", "五", "wǔ"],
+    ["六", "六", "liù"],
+    ["七", "七", "qī"],
+    ["八", "八", "bā"],
+    ["九", "九", "jiǔ"],
+];
+
+// Tens, hundreds and thousands within a group of four digits
+const PLACES: [[&str; 3]; 3] = [
+    ["十", "十", "shí"],
+    ["百", "百", "bǎi"],
+    ["千", "千", "qiān"],
+];
+
+// One word per power of ten thousand, e.g. "万" for 10^4 and "亿" for 10^8
+const MEGAS: [[&str; 3]; 4] = [
+    ["万", "萬", "wàn"],
+    ["亿", "億", "yì"],
+    ["兆", "兆", "zhào"],
+    ["京", "京", "jīng"],
+];
+
+// Year digits read one by one, with "〇" for zero, e.g. "二〇二四年"
+const YEAR_ZERO: [&str; 3] = ["〇", "〇", "líng"];
+
+impl Chinese {
+    pub fn new() -> Self {
+        Self {
+            script: Script::default(),
+        }
+    }
+
+    pub fn with_script(self, script: Script) -> Self {
+        Self { script }
+    }
+
+    fn pick(&self, word: [&'static str; 3]) -> &'static str {
+        word[self.script.index()]
+    }
+
+    // characters are written together, pinyin syllables apart
+    fn join(&self, words: Vec<&str>) -> String {
+        words.join(if self.script == Script::Pinyin {
+            " "
+        } else {
+            ""
+        })
+    }
+
+    fn currencies(&self, currency: Currency) -> String {
+        String::from(match currency {
+            Currency::CNY => self.pick(["元", "元", "yuán"]),
+            Currency::DOLLAR | Currency::USD => self.pick(["美元", "美元", "měi yuán"]),
+            Currency::EUR => self.pick(["欧元", "歐元", "ōu yuán"]),
+            Currency::JPY => self.pick(["日元", "日圓", "rì yuán"]),
+            _ => return currency.default_string(false),
+        })
+    }
+
+    fn cents(&self, currency: Currency) -> String {
+        String::from(match currency {
+            Currency::DOLLAR | Currency::USD => self.pick(["美分", "美分", "měi fēn"]),
+            Currency::EUR => self.pick(["欧分", "歐分", "ōu fēn"]),
+            _ => return currency.default_subunit_string("cent{}", false),
+        })
+    }
+
+    // e.g. 105 => "一百零五", 1050 => "一千零五十"
+    fn group_to_words(&self, group: u64, words: &mut Vec<&'static str>) {
+        let mut started = false;
+        let mut zero = false;
+        for place in (0..4).rev() {
+            let digit = (group / 10u64.pow(place) % 10) as usize;
+            if digit == 0 {
+                zero |= started;
+                continue;
+            }
+            if zero {
+                words.push(self.pick(DIGITS[0]));
+                zero = false;
+            }
+            // a leading one is left out before ten, e.g. "十五" but "一百一十五"
+            if !(digit == 1 && place == 1 && words.is_empty()) {
+                words.push(self.pick(DIGITS[digit]));
+            }
+            if place > 0 {
+                words.push(self.pick(PLACES[place as usize - 1]));
+            }
+            started = true;
+        }
+    }
+
+    fn int_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        // special case zero
+        if num.is_zero() {
+            return Ok(String::from(self.pick(DIGITS[0])));
+        }
+
+        let groups = split_groups(num, GroupingScheme::Myriad);
+        let words = self.compose_from_groups(&groups, GroupingScheme::Myriad)?;
+
+        if num.is_negative() {
+            Ok(self.join(vec![self.pick(["负", "負", "fù"]), &words]))
+        } else {
+            Ok(words)
+        }
+    }
+
+    fn float_to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut integral = self.int_to_cardinal(num.int())?;
+        if num.is_negative() && num.int().is_zero() {
+            integral = self.join(vec![self.pick(["负", "負", "fù"]), &integral]);
+        }
+
+        let mut words = vec![];
+        let mut ordinal_part = num.frac().abs();
+        if !ordinal_part.is_zero() {
+            words.push(self.pick(["点", "點", "diǎn"]));
+        }
+        for _ in 0..MAX_FRACTION_DIGITS {
+            if ordinal_part.is_zero() {
+                break;
+            }
+            let digit = (ordinal_part * BigFloat::from(10)).int();
+            ordinal_part = (ordinal_part * BigFloat::from(10)).frac();
+            words.push(self.pick(DIGITS[digit.to_u64().unwrap() as usize]));
+        }
+        words.insert(0, &integral);
+        Ok(self.join(words))
+    }
+
+    // yuan are split into jiao and fen, e.g. 0.25 => "二角五分"
+    fn yuan_cents(&self, cents: u64, after_yuan: bool) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        let (jiao, fen) = (cents / 10, cents % 10);
+        if jiao > 0 {
+            words.push(self.int_to_cardinal(BigFloat::from(jiao))?);
+            words.push(String::from(self.pick(["角", "角", "jiǎo"])));
+        } else if after_yuan {
+            words.push(String::from(self.pick(DIGITS[0])));
+        }
+        if fen > 0 {
+            words.push(self.int_to_cardinal(BigFloat::from(fen))?);
+            words.push(String::from(self.pick(["分", "分", "fēn"])));
+        }
+        Ok(self.join(words.iter().map(String::as_str).collect()))
+    }
+}
+
+impl Language for Chinese {
+    fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        if num.is_inf_pos() {
+            Ok(String::from(self.pick(["无穷大", "無窮大", "wú qióng dà"])))
+        } else if num.is_inf_neg() {
+            Ok(String::from(self.pick([
+                "负无穷大",
+                "負無窮大",
+                "fù wú qióng dà",
+            ])))
+        } else if num.frac().is_zero() {
+            self.int_to_cardinal(num)
+        } else {
+            self.float_to_cardinal(num)
+        }
+    }
+
+    fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(self.join(vec![self.pick(["第", "第", "dì"]), &self.to_cardinal(num)?]))
+    }
+
+    fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+        Ok(format!("第{}", whole_digits(num)))
+    }
+
+    fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+        let mut words = vec![];
+        if num.is_negative() {
+            words.push(self.pick(["公元前", "公元前", "gōng yuán qián"]));
+        }
+        let year = num.abs().to_u128().ok_or(Num2Err::CannotConvert)?;
+        for digit in year.to_string().chars() {
+            words.push(match digit.to_digit(10).unwrap() {
+                0 => self.pick(YEAR_ZERO),
+                d => self.pick(DIGITS[d as usize]),
+            });
+        }
+        words.push(self.pick(["年", "年", "nián"]));
+        Ok(self.join(words))
+    }
+
+    fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+        if num.is_inf() {
+            Ok(self.join(vec![&self.to_cardinal(num)?, &self.currencies(currency)]))
+        } else if num.is_negative() {
+            // the sign is read once, e.g. "负五元五角"
+            Ok(self.join(vec![
+                self.pick(["负", "負", "fù"]),
+                &self.to_currency(num.abs(), currency)?,
+            ]))
+        } else if num.frac().is_zero() {
+            let words = self.int_to_cardinal(num)?;
+            Ok(self.join(vec![&words, &self.currencies(currency)]))
+        } else {
+            let integral_part = num.int();
+            let ratio = BigFloat::from(currency.subunit_ratio());
+            let cents_nb = (num * ratio).int() % ratio;
+            let integral_word = self.to_currency(integral_part, currency)?;
+
+            if cents_nb.is_zero() {
+                return Ok(integral_word);
+            }
+            let cents_words = if matches!(currency, Currency::CNY) {
+                self.yuan_cents(cents_nb.to_u64().unwrap(), !integral_part.is_zero())?
+            } else {
+                self.join(vec![
+                    &self.int_to_cardinal(cents_nb)?,
+                    &self.cents(currency),
+                ])
+            };
+
+            if integral_part.is_zero() {
+                Ok(cents_words)
+            } else {
+                Ok(self.join(vec![&integral_word, &cents_words]))
+            }
+        }
+    }
+
+    fn currency_connector(&self) -> &'static str {
+        ""
+    }
+
+    fn scale_word(&self, power: usize) -> Option<&'static str> {
+        Some(self.pick(*MEGAS.get(power.checked_sub(1)?)?))
+    }
+
+    fn grouping_scheme(&self) -> GroupingScheme {
+        GroupingScheme::Myriad
+    }
+
+    fn compose_from_groups(
+        &self,
+        groups: &[u64],
+        scheme: GroupingScheme,
+    ) -> Result<String, Num2Err> {
+        if scheme != GroupingScheme::Myriad || groups.iter().any(|g| *g >= 10000) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if groups.iter().all(|g| *g == 0) {
+            return Ok(String::from(self.pick(DIGITS[0])));
+        }
+
+        // a gap of zeros reads as a single "零", e.g. 100000005 => "一亿零五"
+        let mut words = vec![];
+        let mut zero = false;
+        for (i, group) in groups.iter().rev().enumerate().rev() {
+            if *group == 0 {
+                zero |= !words.is_empty();
+                continue;
+            }
+            if !words.is_empty() && (zero || *group < 1000) {
+                words.push(self.pick(DIGITS[0]));
+            }
+            zero = false;
+
+            self.group_to_words(*group, &mut words);
+            if i != 0 {
+                words.push(self.pick(*MEGAS.get(i - 1).ok_or(Num2Err::CannotConvert)?));
+            }
+        }
+
+        Ok(self.join(words))
+    }
+
+    fn separators(&self) -> (char, char) {
+        (',', '.')
+    }
+
+    fn hundred_word(&self) -> &'static str {
+        self.pick(PLACES[1])
+    }
+
+    fn thousand_word(&self) -> &'static str {
+        self.pick(PLACES[2])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn test_cardinal() {
+        assert_eq!(
+            Num2Words::new(0).lang(Lang::Chinese).cardinal().to_words(),
+            Ok(String::from("零"))
+        );
+        assert_eq!(
+            Num2Words::new(15).lang(Lang::Chinese).cardinal().to_words(),
+            Ok(String::from("十五"))
+        );
+        assert_eq!(
+            Num2Words::new(115)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一百一十五"))
+        );
+        assert_eq!(
+            Num2Words::new(-3).lang(Lang::Chinese).cardinal().to_words(),
+            Ok(String::from("负三"))
+        );
+        assert_eq!(
+            Num2Words::new(2.5)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("二点五"))
+        );
+    }
+
+    #[test]
+    fn test_zero_insertion() {
+        assert_eq!(
+            Num2Words::new(105)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一百零五"))
+        );
+        assert_eq!(
+            Num2Words::new(1050)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一千零五十"))
+        );
+        assert_eq!(
+            Num2Words::new(10005)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一万零五"))
+        );
+        assert_eq!(
+            Num2Words::new(100_000_005)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("一亿零五"))
+        );
+        assert_eq!(
+            Num2Words::new(120_000)
+                .lang(Lang::Chinese)
+                .cardinal()
+                .to_words(),
+            Ok(String::from("十二万"))
+        );
+    }
+
+    #[test]
+    fn test_script() {
+        assert_eq!(
+            Num2Words::new(20_000)
+                .lang(Lang::Chinese)
+                .prefer("traditional")
+                .to_words(),
+            Ok(String::from("二萬"))
+        );
+        assert_eq!(
+            Num2Words::new(105)
+                .lang(Lang::Chinese)
+                .prefer("pinyin")
+                .to_words(),
+            Ok(String::from("yī bǎi líng wǔ"))
+        );
+    }
+
+    #[test]
+    fn test_ordinal_and_year() {
+        assert_eq!(
+            Num2Words::new(3).lang(Lang::Chinese).ordinal().to_words(),
+            Ok(String::from("第三"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40)
+                .lang(Lang::Chinese)
+                .ordinal_num()
+                .to_words(),
+            Ok(format!("第1{}", "0".repeat(40)))
+        );
+        assert_eq!(
+            Num2Words::new(2024).lang(Lang::Chinese).year().to_words(),
+            Ok(String::from("二〇二四年"))
+        );
+        assert_eq!(
+            Num2Words::new(1e40).lang(Lang::Chinese).year().to_words(),
+            Err(num2words::Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_currency() {
+        assert_eq!(
+            Num2Words::new(5.25)
+                .lang(Lang::Chinese)
+                .currency(Currency::CNY)
+                .to_words(),
+            Ok(String::from("五元二角五分"))
+        );
+        assert_eq!(
+            Num2Words::new(1.05)
+                .lang(Lang::Chinese)
+                .currency(Currency::CNY)
+                .to_words(),
+            Ok(String::from("一元零五分"))
+        );
+        assert_eq!(
+            Num2Words::new(3.5)
+                .lang(Lang::Chinese)
+                .currency(Currency::USD)
+                .to_words(),
+            Ok(String::from("三美元五十美分"))
+        );
+    }
+}
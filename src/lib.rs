@@ -18,7 +18,10 @@
  * ```
  *
  * The builder `Num2Words` can take any of these methods: `lang`, `cardinal`,
- * `ordinal`, `ordinal_num`, `year`, and `currency`.
+ * `ordinal`, `ordinal_num`, `year`, `multiplicative`, `scientific`,
+ * `approximate`, `compact`, `expanded`, `percent`, `permille`, `decade`,
+ * `century`, `currency`, `currency_label`, `currency_position`, `unit`,
+ * `precision`, `round`, `gender`, `case`, `era`, and `scale`.
  *
  * ```rust
  * use num2words::*;
@@ -60,7 +63,7 @@
  *
  * You can also change the language via the CLI argument `--lang [locale]` and
  * provide a specific output type or a currency with the argument
- * `--to [cardinal|ordinal|ordinal_num|year|ISO 4217]`.
+ * `--to [cardinal|ordinal|ordinal_num|year|multiplicative|scientific|approximate|compact|expanded|percent|permille|decade|century|ISO 4217|unit symbol]`.
  *
  * For more information about the usage of `num2words` please refer to the docs
  * or via the following command:
@@ -74,15 +77,35 @@
  *
  * Here is a list of all of the supported languages:
  *
- * | Flag | Code              | Locale    | Language    | 42            |
- * | ---- | ----------------- | --------- | ----------- | ------------- |
- * | 🇺🇸🇬🇧 | `Lang::English`   | `en`      | English     | forty-two     |
- * | 🇫🇷🇨🇦 | `Lang::French`    | `fr`      | French      | quarante-deux |
- * | 🇧🇪🇨🇩 | `Lang::French_BE` | `fr_BE`   | French (BE) | quarante-deux |
- * | 🇨🇭   | `Lang::French_CH` | `fr_CH`   | French (CH) | quarante-deux |
- * | 🇺🇦   | `Lang::Ukrainian` | `uk`      | Ukrainian   | сорок два     |
- * | 🇳🇱   | `Lang::Dutch`     | `nl`      | Dutch       | tweeenveertig |
- * | 🏴󠁮󠁬󠁦󠁲󠁿   | `Lang::Frisian`   | `fy`      | Frisian     | twaenfjirtich |
+ * | Flag | Code                  | Locale    | Language         | 42                 |
+ * | ---- | --------------------- | --------- | ---------------- | ------------------ |
+ * | 🇺🇸🇬🇧 | `Lang::English`       | `en`      | English          | forty-two          |
+ * | 🇫🇷🇨🇦 | `Lang::French`        | `fr`      | French           | quarante-deux      |
+ * | 🇧🇪🇨🇩 | `Lang::French_BE`     | `fr_BE`   | French (BE)      | quarante-deux      |
+ * | 🇨🇭   | `Lang::French_CH`     | `fr_CH`   | French (CH)      | quarante-deux      |
+ * | 🇺🇦   | `Lang::Ukrainian`     | `uk`      | Ukrainian        | сорок два          |
+ * | 🇳🇱   | `Lang::Dutch`         | `nl`      | Dutch            | tweeenveertig      |
+ * | 🏴󠁮󠁬󠁦󠁲󠁿   | `Lang::Frisian`       | `fy`      | Frisian          | twaenfjirtich      |
+ * | 🇦🇫   | `Lang::Pashto`        | `ps`      | Pashto           | دوه څلوېښت         |
+ * | 🇧🇾   | `Lang::Belarusian`    | `be`      | Belarusian       | сорак два          |
+ * | 🇮🇹   | `Lang::Italian`       | `it`      | Italian          | quarantadue        |
+ * | 🇵🇹   | `Lang::Portuguese`    | `pt`      | Portuguese       | quarenta e dois    |
+ * | 🇧🇷   | `Lang::Portuguese_BR` | `pt_BR`   | Portuguese (BR)  | quarenta e dois    |
+ * | 🇵🇱   | `Lang::Polish`        | `pl`      | Polish           | czterdzieści dwa   |
+ * | 🇸🇦   | `Lang::Arabic`        | `ar`      | Arabic           | اثنان وأربعون      |
+ * | 🇯🇵   | `Lang::Japanese`      | `ja`      | Japanese         | 四十二                |
+ * | 🇨🇳   | `Lang::Chinese`       | `zh`      | Chinese          | 四十二                |
+ * | 🇰🇷   | `Lang::Korean`        | `ko`      | Korean           | 사십이                |
+ * | 🇮🇳   | `Lang::Hindi`         | `hi`      | Hindi            | बयालीस             |
+ * | 🇸🇪   | `Lang::Swedish`       | `sv`      | Swedish          | fyrtiotvå          |
+ * | 🇳🇴   | `Lang::Norwegian`     | `no`      | Norwegian        | førtito            |
+ * | 🇩🇰   | `Lang::Danish`        | `da`      | Danish           | toogfyrre          |
+ * | 🇷🇴   | `Lang::Romanian`      | `ro`      | Romanian         | patruzeci și doi   |
+ * | 🇨🇿   | `Lang::Czech`         | `cs`      | Czech            | čtyřicet dva       |
+ * | 🇸🇰   | `Lang::Slovak`        | `sk`      | Slovak           | štyridsaťdva       |
+ * | 🇬🇷   | `Lang::Greek`         | `el`      | Greek            | σαράντα δύο        |
+ * | 🇫🇮   | `Lang::Finnish`       | `fi`      | Finnish          | neljäkymmentäkaksi |
+ * | 🟩   | `Lang::Esperanto`     | `eo`      | Esperanto        | kvardek du         |
  *
  * This list can be expanded! Contributions are welcomed.
  *
@@ -91,19 +114,33 @@
  * Here is a list of all of the supported outputs types (with the associated
  * command-line interface code):
  *
- * | Library method   | CLI argument  | Example output                         |
- * | ---------------- | ------------- | -------------------------------------- |
- * | `.cardinal()`    | `cardinal`    | forty-two (42)                         |
- * | `.ordinal()`     | `ordinal`     | forty-second (42)                      |
- * | `.ordinal_num()` | `ordinal_num` | 42nd (42)                              |
- * | `.year()`        | `year`        | nineteen oh-one (1901)                 |
- * | `.currency(cur)` | ISO 4217 code | forty-two dollars and one cent (42.01) |
+ * | Library method      | CLI argument     | Example output                         |
+ * |---------------------|------------------|------------------------------------------|
+ * | `.cardinal()`       | `cardinal`       | forty-two (42)                         |
+ * | `.ordinal()`        | `ordinal`        | forty-second (42)                      |
+ * | `.ordinal_num()`    | `ordinal_num`    | 42nd (42)                              |
+ * | `.year()`           | `year`           | nineteen oh-one (1901)                 |
+ * | `.multiplicative()` | `multiplicative` | twice (2)                              |
+ * | `.scientific()`     | `scientific`     | ten to the third power (1e3)           |
+ * | `.approximate()`    | `approximate`    | about a hundred (98)                   |
+ * | `.compact()`        | `compact`        | two and a half million (2.5e6)         |
+ * | `.expanded()`       | `expanded`       | two hundreds and four ones (204)       |
+ * | `.percent()`        | `percent`        | five percent (5)                       |
+ * | `.permille()`       | `permille`       | twelve per mille (12)                  |
+ * | `.decade()`         | `decade`         | the nineteen-nineties (1990)           |
+ * | `.century()`        | `century`        | the twentieth century (1900)           |
+ * | `.currency(cur)`    | ISO 4217 code    | forty-two dollars and one cent (42.01) |
+ * | `.unit(unit)`       | unit symbol      | twenty-one kilometers (21 km)          |
  *
  * ### Supported currencies
  *
  * Three-letter enum variants corresponds to the currency's ISO 4217 code, but
  * there are exceptions to accomodate generic terminologies: `DINAR`, `DOLLAR`,
- * `PESO` and `RIYAL`.
+ * `MILL`, `PESO` and `RIYAL`.
+ *
+ * Every currency in circulation is covered, and can be looked up by its code
+ * with `Currency::from_code`. Other currencies, e.g. cryptocurrencies, can be
+ * defined by their names with `Currency::custom`.
  *
  * A summary of all of the supported currencies are available in the
  * documentation of [`Currency`].
@@ -118,9 +155,17 @@ mod num2words;
 mod currency;
 mod lang;
 mod output;
+mod unit;
+mod words2num;
 
-pub use crate::num2words::{Num2Err, Num2Words};
+pub use crate::num2words::{
+    CurrencyLabel, CurrencyPosition, Num2Err, Num2Words, Num2WordsDetails, RoundingMode,
+};
 pub use currency::Currency;
-pub use lang::Lang;
-use lang::Language;
+pub use lang::{
+    register_language, register_rbnf, resolve_locale, spell_small_into, supported_languages,
+    DataDrivenLanguage, Case, Era, Gender, GroupingScheme, Lang, Language, LanguageDefinition,
+    LanguageFactory, RbnfLanguage, Scale, ZeroOrdinal,
+};
+pub use unit::Unit;
 use output::Output;
@@ -1,4 +1,7 @@
-use crate::{lang, Currency, Lang, Output};
+use crate::{
+    lang, Case, Currency, Era, Gender, GroupingScheme, Lang, Language, Output, Scale, Unit,
+    ZeroOrdinal,
+};
 use num_bigfloat::BigFloat;
 
 /// Error type returned by the builder
@@ -11,9 +14,9 @@ pub enum Num2Err {
     ///
     /// Example:
     /// ```
-    /// use num2words::{Num2Err, Num2Words};
+    /// use num2words::{Lang, Num2Err, Num2Words};
     /// assert_eq!(
-    ///     Num2Words::new(1e100).to_words(),
+    ///     Num2Words::new(1e100).lang(Lang::Italian).to_words(),
     ///     Err(Num2Err::CannotConvert)
     /// );
     /// ```
@@ -73,6 +76,28 @@ pub enum Num2Err {
     /// );
     /// ```
     InfiniteYear,
+    /// Digits grouped differently than the language groups them
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::parse_grouped("1.234.567", Lang::English).err(),
+    ///     Some(Num2Err::GroupingMismatch)
+    /// );
+    /// ```
+    GroupingMismatch,
+    /// Request of zero as an ordinal in digits where zero has no ordinal
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Err, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(0).ordinal_num().prefer("zero-error").to_words(),
+    ///     Err(Num2Err::ZeroOrdinal)
+    /// );
+    /// ```
+    ZeroOrdinal,
 }
 
 impl std::fmt::Display for Num2Err {
@@ -87,18 +112,84 @@ impl std::fmt::Display for Num2Err {
                 Num2Err::FloatingYear => "cannot treat float as year",
                 Num2Err::InfiniteOrdinal => "cannot treat infinity as ordinal",
                 Num2Err::InfiniteYear => "cannot treat infinity as year",
+                Num2Err::GroupingMismatch => "digits are not grouped as the language groups them",
+                Num2Err::ZeroOrdinal => "cannot treat zero as ordinal",
             }
         )
     }
 }
 
+/// Rounding applied to the number by [`Num2Words::precision`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RoundingMode {
+    /// Drop the digits beyond the precision, e.g. 2.678 => 2.67
+    Truncate,
+    /// Round halves away from zero, e.g. 2.675 => 2.68
+    HalfUp,
+    /// Round halves to the even digit, e.g. 2.665 => 2.66 and 2.675 => 2.68
+    HalfEven,
+}
+
+/// How [`Num2Words::currency_label`] names the currency of an amount
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurrencyLabel {
+    /// Its name, read by the language, e.g. "five hundred dollars"
+    Name,
+    /// Its ISO 4217 code, e.g. "USD five hundred"
+    Code,
+    /// Its symbol, e.g. "$ five hundred", or its code without one
+    Symbol,
+}
+
+/// Where [`Num2Words::currency_position`] places the code or symbol of a
+/// currency
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CurrencyPosition {
+    /// Before the amount, e.g. "USD five hundred"
+    Before,
+    /// After the amount, e.g. "five hundred USD"
+    After,
+}
+
+/// Output of [`Num2Words::to_words_detailed`]
+#[derive(Debug, PartialEq)]
+pub struct Num2WordsDetails {
+    /// Number in words
+    pub words: String,
+    /// Highest scale word of the number, e.g. `million` for 1234567
+    pub scale: Option<String>,
+    /// Number of digits of the integral part
+    pub digits: usize,
+    /// Whether the number has a fractional part
+    pub fraction: bool,
+}
+
 /// Builder for `num2words`
 pub struct Num2Words {
     num: BigFloat,
     lang: Lang,
     output: Output,
     currency: Currency,
+    unit: Unit,
+    currency_label: CurrencyLabel,
+    currency_position: CurrencyPosition,
+    precision: Option<usize>,
+    rounding: Option<RoundingMode>,
+    gender: Option<Gender>,
+    case: Option<Case>,
+    era: Option<Era>,
+    scale: Option<Scale>,
+    year_end: Option<BigFloat>,
+    denominator: BigFloat,
+    max_words: Option<usize>,
+    significant_digits: usize,
+    grouped: Option<String>,
+    digits: Option<String>,
     preferences: Vec<String>,
+    custom: Option<Box<dyn Language>>,
+    rbnf: Option<String>,
+    phone: Option<String>,
+    time: Option<(u32, u32)>,
 }
 
 impl Num2Words {
@@ -125,12 +216,186 @@ impl Num2Words {
             lang: Lang::English,
             output: Output::Cardinal,
             currency: Currency::DOLLAR,
+            unit: Unit::Meter,
+            currency_label: CurrencyLabel::Name,
+            currency_position: CurrencyPosition::Before,
+            precision: None,
+            rounding: None,
+            gender: None,
+            case: None,
+            era: None,
+            scale: None,
+            year_end: None,
+            denominator: BigFloat::from(1),
+            max_words: None,
+            significant_digits: 1,
+            grouped: None,
+            digits: None,
             preferences: vec![],
+            custom: None,
+            rbnf: None,
+            phone: None,
+            time: None,
+        }
+    }
+
+    /// Creates a new builder reading the fraction `numerator / denominator`,
+    /// the same as `Num2Words::new(numerator).fraction(denominator)`
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new_fraction(3, 4).lang(Lang::Dutch).to_words(),
+    ///     Ok(String::from("drie kwart"))
+    /// );
+    /// ```
+    pub fn new_fraction<T, U>(numerator: T, denominator: U) -> Self
+    where
+        T: Into<BigFloat>,
+        U: Into<BigFloat>,
+    {
+        Self::new(numerator).fraction(denominator)
+    }
+
+    /// Creates a new builder reading a whole number from its digits, exactly
+    /// however many there are
+    ///
+    /// Numbers are otherwise held with 40 significant digits, enough for any
+    /// `i128` or `u128`, and larger ones lose their last digits. Cardinals
+    /// of more digits are read from their groups of digits by the languages
+    /// composing them, see [`Language::compose_from_groups`], e.g. English
+    /// and Dutch, with no limit on their digits: past their tables, scale
+    /// words are named after Conway and Wechsler, e.g. "one millinillion"
+    /// for a one with 3003 zeros. Other languages and strings holding anything but digits
+    /// return [`Num2Err::CannotConvert`]. Other outputs read the number held
+    /// with its first 40 digits.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::from_digits("1000000000000000000000000000000000000000000001").to_words(),
+    ///     Ok(String::from("one quattuordecillion and one"))
+    /// );
+    /// ```
+    pub fn from_digits(digits: &str) -> Self {
+        // the first 40 digits scaled by the rest, as a BigFloat parsing all
+        // of them would drop their magnitude
+        let significant = digits.trim_start_matches('0');
+        let num = match significant.len() {
+            len if len > 40 && significant.bytes().all(|d| d.is_ascii_digit()) => {
+                BigFloat::parse(&format!("{}e{}", &significant[..40], len - 40))
+            }
+            _ => BigFloat::parse(digits),
         }
+        .filter(|num| !num.is_nan());
+        let mut builder = Self::new(num.unwrap_or_default());
+        builder.digits = Some(String::from(digits));
+        builder
+    }
+
+    /// Creates a new builder reading a phone number, e.g. `+31 6 1234 5678`
+    ///
+    /// The digits are read in the groups they are written in, the way the
+    /// language reads phone numbers, e.g. in pairs in French. The country
+    /// code after a `+` is read as a number. Only English, French and Dutch
+    /// read phone numbers, other languages and numbers holding anything but
+    /// digits, spaces and `-./()` return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::phone("+31 6 1234 5678").to_words(),
+    ///     Ok(String::from(
+    ///         "plus thirty-one, six, one two three four, five six seven eight"
+    ///     ))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::phone("06 12 34 56 78").lang(Lang::French).to_words(),
+    ///     Ok(String::from(
+    ///         "zéro six, douze, trente-quatre, cinquante-six, soixante-dix-huit"
+    ///     ))
+    /// );
+    /// ```
+    pub fn phone(number: &str) -> Self {
+        let mut builder = Self::new(0);
+        builder.output = Output::Phone;
+        builder.phone = Some(String::from(number));
+        builder
+    }
+
+    /// Creates a new builder reading a range of years, e.g. "nineteen
+    /// fourteen to nineteen eighteen"
+    ///
+    /// With the `shared-century` preference, a century shared by both years
+    /// is only read once where the language allows it, e.g. "from nineteen
+    /// fourteen to eighteen". Only English, French, Dutch and Frisian read
+    /// ranges of years, other languages and ranges ending before they start
+    /// return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::year_range(1914, 1918).to_words(),
+    ///     Ok(String::from("nineteen fourteen to nineteen eighteen"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::year_range(1940, 1945)
+    ///         .lang(Lang::Dutch)
+    ///         .prefer("shared-century")
+    ///         .to_words(),
+    ///     Ok(String::from("van negentienveertig tot vijfenveertig"))
+    /// );
+    /// ```
+    pub fn year_range<T, U>(start: T, end: U) -> Self
+    where
+        T: Into<BigFloat>,
+        U: Into<BigFloat>,
+    {
+        let mut builder = Self::new(start);
+        builder.output = Output::YearRange;
+        builder.year_end = Some(end.into());
+        builder
+    }
+
+    /// Creates a new builder reading a time of day, e.g. "quarter past three"
+    /// for 3:15
+    ///
+    /// The time is read as on a 12-hour clock unless the `24h` preference is
+    /// given. Only English, French and Dutch read times, other languages and
+    /// hours above 23 or minutes above 59 return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::time(3, 15).to_words(),
+    ///     Ok(String::from("quarter past three"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::time(15, 15).lang(Lang::Dutch).to_words(),
+    ///     Ok(String::from("kwart over drie"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::time(15, 15).lang(Lang::French).prefer("24h").to_words(),
+    ///     Ok(String::from("quinze heures quinze"))
+    /// );
+    /// ```
+    pub fn time(hour: u32, minute: u32) -> Self {
+        let mut builder = Self::new(u64::from(hour) * 60 + u64::from(minute));
+        builder.output = Output::Time;
+        builder.time = Some((hour, minute));
+        builder
     }
 
     /// Creates a new builder from a string
     ///
+    /// Hexadecimal color codes such as `#1A2B3C` are parsed too, and read
+    /// with the `color` preference.
+    ///
     /// Example:
     /// ```
     /// use num2words::Num2Words;
@@ -144,7 +409,12 @@ impl Num2Words {
     /// );
     /// ```
     pub fn parse(num: &str) -> Option<Self> {
-        let num = BigFloat::parse(num)?;
+        let num = match num.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => {
+                BigFloat::from(u32::from_str_radix(hex, 16).ok()?)
+            }
+            _ => BigFloat::parse(num)?,
+        };
         if num.is_nan() {
             return None;
         }
@@ -153,10 +423,127 @@ impl Num2Words {
             lang: Lang::English,
             output: Output::Cardinal,
             currency: Currency::DOLLAR,
+            unit: Unit::Meter,
+            currency_label: CurrencyLabel::Name,
+            currency_position: CurrencyPosition::Before,
+            precision: None,
+            rounding: None,
+            gender: None,
+            case: None,
+            era: None,
+            scale: None,
+            year_end: None,
+            denominator: BigFloat::from(1),
+            max_words: None,
+            significant_digits: 1,
+            grouped: None,
+            digits: None,
             preferences: vec![],
+            custom: None,
+            rbnf: None,
+            phone: None,
+            time: None,
         })
     }
 
+    /// Creates a new builder from a string grouped the way the language
+    /// groups digits, e.g. `1.234.567,89` in Dutch, `12,34,567` in Hindi and
+    /// `123,4567` in Japanese, see [`Language::grouping_scheme`]
+    ///
+    /// Ungrouped digits are accepted too. Any other grouping returns
+    /// [`Num2Err::GroupingMismatch`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::parse_grouped("1,234.5", Lang::English)
+    ///         .unwrap()
+    ///         .to_words(),
+    ///     Ok(String::from("one thousand two hundred and thirty-four point five"))
+    /// );
+    /// ```
+    pub fn parse_grouped(num: &str, lang: Lang) -> Result<Self, Num2Err> {
+        let language = lang::to_language(&lang, &[])?;
+        let (group, decimal) = language.separators();
+        let digits = num.strip_prefix('-').unwrap_or(num);
+        let mut parts = digits.split(decimal);
+        let integral: Vec<&str> = parts.next().unwrap_or_default().split(group).collect();
+        let fraction = parts.next();
+
+        let is_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+        // digits in the last group and in the ones before it
+        let (last, size) = match language.grouping_scheme() {
+            GroupingScheme::Thousands => (3, 3),
+            GroupingScheme::Indian => (3, 2),
+            GroupingScheme::Myriad => (4, 4),
+        };
+        // e.g. 1,234,567 or 1234567, but neither 1,2345 nor 12,34
+        let grouped = integral.len() == 1
+            || integral.iter().enumerate().all(|(i, g)| match i {
+                i if i == integral.len() - 1 => g.len() == last,
+                0 => g.len() <= size,
+                _ => g.len() == size,
+            });
+        if !integral.iter().all(|g| is_digits(g))
+            || !grouped
+            || fraction.is_some_and(|f| !is_digits(f))
+            || parts.next().is_some()
+        {
+            return Err(Num2Err::GroupingMismatch);
+        }
+
+        let mut normalized = format!("{}{}", &num[..num.len() - digits.len()], integral.concat());
+        if let Some(fraction) = fraction {
+            normalized = format!("{}.{}", normalized, fraction);
+        }
+
+        let mut builder = Self::parse(&normalized).ok_or(Num2Err::CannotConvert)?;
+        builder.lang = lang;
+        builder.grouped = Some(String::from(num));
+        Ok(builder)
+    }
+
+    /// Reads a cardinal written in words back into a number, e.g. `402` for
+    /// `four hundred and two`
+    ///
+    /// Case, spaces and hyphens are ignored. Words the language does not use
+    /// in its own cardinals return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// use num_bigfloat::BigFloat;
+    /// assert_eq!(
+    ///     Num2Words::parse_words("four hundred and two", Lang::English),
+    ///     Ok(BigFloat::from(402))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::parse_words("tweeduizend drieënveertig", Lang::Dutch),
+    ///     Ok(BigFloat::from(2043))
+    /// );
+    /// ```
+    pub fn parse_words(words: &str, lang: Lang) -> Result<BigFloat, Num2Err> {
+        lang::to_language(&lang, &[])?.parse_words(words)
+    }
+
+    /// Returns the string the builder was parsed from by
+    /// [`Num2Words::parse_grouped`], keeping its grouping
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::parse_grouped("1.234.567", Lang::Dutch)
+    ///         .unwrap()
+    ///         .grouped(),
+    ///     Some("1.234.567")
+    /// );
+    /// ```
+    pub fn grouped(&self) -> Option<&str> {
+        self.grouped.as_deref()
+    }
+
     /// Sets the language of the output
     ///
     /// For all of the available languages, see [`Lang`].
@@ -171,6 +558,67 @@ impl Num2Words {
     /// ```
     pub fn lang(mut self, lang: Lang) -> Self {
         self.lang = lang;
+        self.custom = None;
+        self.rbnf = None;
+        self
+    }
+
+    /// Sets the output language to a language of your own, without
+    /// registering it, see [`Language`]
+    ///
+    /// Preferences are left to the language itself.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// # use num2words::{Currency, Language, Num2Err};
+    /// # use num_bigfloat::BigFloat;
+    /// # struct Digits;
+    /// # impl Language for Digits {
+    /// #     fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+    /// #         Ok(num.to_i64().unwrap().to_string())
+    /// #     }
+    /// #     fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+    /// #         self.to_cardinal(num)
+    /// #     }
+    /// #     fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+    /// #         self.to_cardinal(num)
+    /// #     }
+    /// #     fn to_currency(&self, num: BigFloat, _: Currency) -> Result<String, Num2Err> {
+    /// #         self.to_cardinal(num)
+    /// #     }
+    /// #     fn scale_word(&self, _: usize) -> Option<&'static str> { None }
+    /// #     fn hundred_word(&self) -> &'static str { "100" }
+    /// #     fn thousand_word(&self) -> &'static str { "1000" }
+    /// # }
+    /// assert_eq!(
+    ///     Num2Words::new(42).lang_custom(Digits).to_words(),
+    ///     Ok(String::from("42"))
+    /// );
+    /// ```
+    pub fn lang_custom<L: Language + 'static>(mut self, language: L) -> Self {
+        self.custom = Some(Box::new(language));
+        self.rbnf = None;
+        self
+    }
+
+    /// Sets the output language to the RBNF rules registered for the
+    /// locale, or else for its language, see [`register_rbnf`](crate::register_rbnf)
+    ///
+    /// Returns [`Num2Err::CannotConvert`] if no rules were registered.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{register_rbnf, Num2Words};
+    /// register_rbnf("la", "%spellout-numbering: nihil; unus; duo; tres;").unwrap();
+    /// assert_eq!(
+    ///     Num2Words::new(2).rbnf("la-VA").to_words(),
+    ///     Ok(String::from("duo"))
+    /// );
+    /// ```
+    pub fn rbnf(mut self, locale: &str) -> Self {
+        self.rbnf = Some(String::from(locale));
+        self.custom = None;
         self
     }
 
@@ -234,116 +682,1595 @@ impl Num2Words {
         self
     }
 
-    /// Sets the output to the currency it has been given
+    /// Sets the era a year is read in, with the words of the language for
+    /// years before it and within it
     ///
-    /// For all of the available currencies, see [`Currency`].
+    /// Without it, years within the era are read on their own and years
+    /// before it as the language reads them, e.g. "forty-four BC". Languages
+    /// without words for the era read years as usual, except for the words of
+    /// an [`Era::Custom`].
     ///
     /// Example:
     /// ```
-    /// use num2words::{Num2Words, Currency};
+    /// use num2words::{Era, Lang, Num2Words};
     /// assert_eq!(
-    ///     Num2Words::new(42.01).currency(Currency::DOLLAR).to_words(),
-    ///     Ok(String::from("forty-two dollars and one cent"))
+    ///     Num2Words::new(79).year().era(Era::AnnoDomini).to_words(),
+    ///     Ok(String::from("seventy-nine AD"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(-44)
+    ///         .lang(Lang::Dutch)
+    ///         .year()
+    ///         .era(Era::CommonEra)
+    ///         .to_words(),
+    ///     Ok(String::from("vierenveertig voor onze jaartelling"))
     /// );
     /// ```
-    pub fn currency(mut self, currency: Currency) -> Self {
-        self.output = Output::Currency;
-        self.currency = currency;
+    pub fn era(mut self, era: Era) -> Self {
+        self.era = Some(era);
         self
     }
 
-    /// Adds a preference parameter
-    ///
-    /// # English language accepts:
-    /// oh and/or nil as replacements for "zero"
+    /// Sets the scale in which scale words above a million are named
     ///
-    /// # French language accepts:
-    /// feminine/f/féminin/feminin
+    /// Without it, English reads the short scale and French the long scale.
+    /// The English long scale names a thousand millions "thousand million",
+    /// or "milliard" with the milliard preference. Languages with a single
+    /// scale read numbers as usual.
     ///
-    /// reformed/1990/rectifié/rectification
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words, Scale};
+    /// assert_eq!(
+    ///     Num2Words::new(2e9).scale(Scale::Long).to_words(),
+    ///     Ok(String::from("two thousand million"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2e12).scale(Scale::Long).to_words(),
+    ///     Ok(String::from("two billion"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2e9)
+    ///         .lang(Lang::French)
+    ///         .scale(Scale::Short)
+    ///         .to_words(),
+    ///     Ok(String::from("deux billions"))
+    /// );
+    /// ```
+    pub fn scale(mut self, scale: Scale) -> Self {
+        self.scale = Some(scale);
+        self
+    }
+
+    /// Sets the type of output to multiplicative (`twice`)
     ///
-    /// # Ukrainian language supports grammatical categories (bold - default):
-    /// Number: **singular/sing/однина/од**, plural/pl/множина/мн
+    /// Only English supports multiplicatives, other languages return
+    /// [`Num2Err::CannotConvert`].
     ///
-    /// Gender: **masculine/m/чоловічий/чол/ч**, feminine/f/жіночий/жін/ж, neuter/n/середній/сер/с
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(10).multiplicative().to_words(),
+    ///     Ok(String::from("tenfold"))
+    /// );
+    /// ```
+    pub fn multiplicative(mut self) -> Self {
+        self.output = Output::Multiplicative;
+        self
+    }
+
+    /// Sets the type of output to scientific (`ten to the third power`)
     ///
-    /// Declension: **nominative/nom/називний/н**, genitive/gen/родовий/р, dative/dat/давальний/д,
-    /// accusative/acc/знахідний/з, instrumental/inc/орудний/о, locative/loc/місцевий/м
+    /// Only English, Dutch and French support scientific notation, other
+    /// languages return [`Num2Err::CannotConvert`].
     ///
-    /// Examples:
+    /// Example:
     /// ```
-    /// use num2words::{Num2Words, Lang};
+    /// use num2words::Num2Words;
     /// assert_eq!(
-    ///     Num2Words::new(0.05).prefer("oh").to_words(),
-    ///     Ok(String::from("point oh five"))
+    ///     Num2Words::new(1e21).scientific().to_words(),
+    ///     Ok(String::from("ten to the twenty-first power"))
     /// );
     /// assert_eq!(
-    ///     Num2Words::new(161).lang(Lang::French).prefer("f").prefer("reformed").to_words(),
-    ///     Ok(String::from("cent-soixante-et-une"))
+    ///     Num2Words::new(2.8e64).scientific().to_words(),
+    ///     Ok(String::from("two point eight times ten to the sixty-fourth power"))
+    /// );
+    /// ```
+    pub fn scientific(mut self) -> Self {
+        self.output = Output::Scientific;
+        self
+    }
+
+    /// Sets the type of output to approximate (`about a hundred`)
+    ///
+    /// The number is rounded to its most significant digit, or to as many
+    /// as set with [`Num2Words::significant_digits`], and read after a hedge
+    /// word. English, Dutch, Frisian and French support approximations,
+    /// other languages return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1950).approximate().to_words(),
+    ///     Ok(String::from("roughly two thousand"))
     /// );
     /// assert_eq!(
-    ///     Num2Words::new(51).lang(Lang::Ukrainian).prefer("орудний").to_words(),
-    ///     Ok(String::from("пʼятдесятьма одним"))
+    ///     Num2Words::new(3_141_592).lang(Lang::Dutch).approximate().to_words(),
+    ///     Ok(String::from("ongeveer drie miljoen"))
     /// );
     /// ```
-    pub fn prefer<T>(mut self, prefer: T) -> Self
-    where
-        T: Into<String>,
-    {
-        self.preferences.push(prefer.into());
+    pub fn approximate(mut self) -> Self {
+        self.output = Output::Approximate;
         self
     }
 
-    /// Builds the output
-    pub fn to_words(self) -> Result<String, Num2Err> {
-        let lang = lang::to_language(self.lang, self.preferences);
-        match self.output {
-            Output::Cardinal => lang.to_cardinal(self.num),
-            Output::Currency => lang.to_currency(self.num, self.currency),
-            Output::Ordinal => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteOrdinal);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingOrdinal);
-                }
-                if self.num.is_negative() {
-                    return Err(Num2Err::NegativeOrdinal);
-                }
-                lang.to_ordinal(self.num)
-            }
-            Output::OrdinalNum => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteOrdinal);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingOrdinal);
-                }
-                if self.num.is_negative() {
-                    return Err(Num2Err::NegativeOrdinal);
-                }
-                lang.to_ordinal_num(self.num)
-            }
-            Output::Year => {
-                if self.num.is_inf() {
-                    return Err(Num2Err::InfiniteYear);
-                }
-                if !self.num.frac().is_zero() {
-                    return Err(Num2Err::FloatingYear);
-                }
-                lang.to_year(self.num)
-            }
-        }
+    /// Sets the type of output to compact (`two and a half million`)
+    ///
+    /// Numbers of a million or more that land on a half or a quarter of their
+    /// scale word are read with that fraction, other numbers as cardinals.
+    /// Only English and Dutch support compact readings, other languages
+    /// return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000).compact().to_words(),
+    ///     Ok(String::from("two and a half million"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(2_500_000).lang(Lang::Dutch).compact().to_words(),
+    ///     Ok(String::from("twee en een half miljoen"))
+    /// );
+    /// ```
+    pub fn compact(mut self) -> Self {
+        self.output = Output::Compact;
+        self
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::*;
+    /// Sets the type of output to expanded (`two hundreds and four ones`)
+    ///
+    /// Each non-zero digit is read with its place value. Only English and
+    /// Dutch support expanded readings, other languages return
+    /// [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(234).expanded().to_words(),
+    ///     Ok(String::from("two hundreds, three tens and four ones"))
+    /// );
+    /// ```
+    pub fn expanded(mut self) -> Self {
+        self.output = Output::Expanded;
+        self
+    }
+
+    /// Sets the type of output to percent (`five percent`)
+    ///
+    /// Signed and fractional values read like cardinals, e.g. "minus five
+    /// percent", and the word agrees with the number where the language
+    /// requires it, e.g. "dvě procenta" but "pět procent" in Czech. Languages
+    /// without a word for percent return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(-5).percent().to_words(),
+    ///     Ok(String::from("minus five percent"))
+    /// );
+    /// ```
+    pub fn percent(mut self) -> Self {
+        self.output = Output::Percent;
+        self
+    }
+
+    /// Sets the type of output to per mille (`twelve per mille`)
+    ///
+    /// Languages without a word for per mille return
+    /// [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(12).permille().to_words(),
+    ///     Ok(String::from("twelve per mille"))
+    /// );
+    /// ```
+    pub fn permille(mut self) -> Self {
+        self.output = Output::Permille;
+        self
+    }
+
+    /// Sets the type of output to decade (`the nineteen-nineties`)
+    ///
+    /// The number must be a positive year ending in zero. Only English,
+    /// French, Dutch, Frisian, Italian and Portuguese support decades, other
+    /// languages return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1990).decade().to_words(),
+    ///     Ok(String::from("the nineteen-nineties"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1990).lang(Lang::Dutch).decade().to_words(),
+    ///     Ok(String::from("de jaren negentig"))
+    /// );
+    /// ```
+    pub fn decade(mut self) -> Self {
+        self.output = Output::Decade;
+        self
+    }
+
+    /// Sets the type of output to century (`the twentieth century`)
+    ///
+    /// The number must be a positive year ending in two zeros. Only English,
+    /// French, Dutch, Frisian, Italian and Portuguese support centuries, other
+    /// languages return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(1900).century().to_words(),
+    ///     Ok(String::from("the twentieth century"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1900).lang(Lang::French).century().to_words(),
+    ///     Ok(String::from("le vingtième siècle"))
+    /// );
+    /// ```
+    pub fn century(mut self) -> Self {
+        self.output = Output::Century;
+        self
+    }
+
+    /// Sets the output to a fraction of the number over the given denominator
+    ///
+    /// The fraction is reduced first, so 6/4 reads as three halves and 4/2 as
+    /// two, or as "one and a half" with the `mixed-number` preference. Simple
+    /// denominators are read as ordinals, others use "over" phrasing. Languages
+    /// without a word for the denominator return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(3).fraction(4).to_words(),
+    ///     Ok(String::from("three quarters"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(7).fraction(13).to_words(),
+    ///     Ok(String::from("seven over thirteen"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(5).fraction(2).prefer("mixed-number").to_words(),
+    ///     Ok(String::from("two and a half"))
+    /// );
+    /// ```
+    pub fn fraction<T>(mut self, denominator: T) -> Self
+    where
+        T: Into<BigFloat>,
+    {
+        self.output = Output::Fraction;
+        self.denominator = denominator.into();
+        self
+    }
+
+    /// Sets the output to the currency it has been given
+    ///
+    /// For all of the available currencies, see [`Currency`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Currency};
+    /// assert_eq!(
+    ///     Num2Words::new(42.01).currency(Currency::DOLLAR).to_words(),
+    ///     Ok(String::from("forty-two dollars and one cent"))
+    /// );
+    /// ```
+    pub fn currency(mut self, currency: Currency) -> Self {
+        self.output = Output::Currency;
+        self.currency = currency;
+        self
+    }
+
+    /// Sets how the currency of [`Num2Words::currency`] is named: by its
+    /// name, by its ISO 4217 code or by its symbol
+    ///
+    /// Codes and symbols stand for the currency's units, its subunits are
+    /// still named. Currencies without a code are named as usual.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, CurrencyLabel, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(500.5)
+    ///         .currency(Currency::USD)
+    ///         .currency_label(CurrencyLabel::Code)
+    ///         .to_words(),
+    ///     Ok(String::from("USD five hundred and fifty cents"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(20)
+    ///         .currency(Currency::EUR)
+    ///         .currency_label(CurrencyLabel::Symbol)
+    ///         .to_words(),
+    ///     Ok(String::from("€ twenty"))
+    /// );
+    /// ```
+    pub fn currency_label(mut self, label: CurrencyLabel) -> Self {
+        self.currency_label = label;
+        self
+    }
+
+    /// Sets whether the code or symbol of a currency is placed before the
+    /// amount, as it is by default, or after it
+    ///
+    /// Names are always placed where the language reads them, e.g. after the
+    /// amount in "five hundred dollars".
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, CurrencyLabel, CurrencyPosition, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(500)
+    ///         .currency(Currency::USD)
+    ///         .currency_label(CurrencyLabel::Code)
+    ///         .currency_position(CurrencyPosition::After)
+    ///         .to_words(),
+    ///     Ok(String::from("five hundred USD"))
+    /// );
+    /// ```
+    pub fn currency_position(mut self, position: CurrencyPosition) -> Self {
+        self.currency_position = position;
+        self
+    }
+
+    /// Sets the output to the number followed by the unit of measure it has
+    /// been given
+    ///
+    /// The unit agrees with the number, e.g. "dwa kilometry" but "pięć
+    /// kilometrów" in Polish. Units a language has no word for can be given
+    /// as a [`Unit::Custom`], other units return [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Lang, Num2Words, Unit};
+    /// assert_eq!(
+    ///     Num2Words::new(21).unit(Unit::Kilometer).to_words(),
+    ///     Ok(String::from("twenty-one kilometers"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(21)
+    ///         .lang(Lang::Ukrainian)
+    ///         .unit(Unit::Kilometer)
+    ///         .to_words(),
+    ///     Ok(String::from("двадцять один кілометр"))
+    /// );
+    /// ```
+    pub fn unit(mut self, unit: Unit) -> Self {
+        self.output = Output::Unit;
+        self.unit = unit;
+        self
+    }
+
+    /// Limits a cardinal output to the given number of words
+    ///
+    /// Longer readings fall back to an approximation of the number, see
+    /// [`Num2Words::approximate`]. Readings stay whole in languages without
+    /// approximations.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(3_141_592).max_words(5).to_words(),
+    ///     Ok(String::from("roughly three million"))
+    /// );
+    /// ```
+    pub fn max_words(mut self, max_words: usize) -> Self {
+        self.max_words = Some(max_words);
+        self
+    }
+
+    /// Sets how many significant digits approximations keep, one by default
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(2_487_000)
+    ///         .approximate()
+    ///         .significant_digits(2)
+    ///         .to_words(),
+    ///     Ok(String::from("roughly two million five hundred thousand"))
+    /// );
+    /// ```
+    pub fn significant_digits(mut self, digits: usize) -> Self {
+        self.significant_digits = digits.max(1);
+        self
+    }
+
+    /// Rounds the number to the given number of decimal places before it is
+    /// read, half up unless [`Num2Words::round`] sets another mode
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::Num2Words;
+    /// assert_eq!(
+    ///     Num2Words::new(0.1 + 0.2).precision(2).to_words(),
+    ///     Ok(String::from("point three"))
+    /// );
+    /// ```
+    pub fn precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        self
+    }
+
+    /// Sets how the number is rounded to the places of
+    /// [`Num2Words::precision`], or to a whole number without it
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, RoundingMode};
+    /// assert_eq!(
+    ///     Num2Words::new(2.5).round(RoundingMode::HalfEven).to_words(),
+    ///     Ok(String::from("two"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1.999)
+    ///         .precision(2)
+    ///         .round(RoundingMode::Truncate)
+    ///         .to_words(),
+    ///     Ok(String::from("one point nine nine"))
+    /// );
+    /// ```
+    pub fn round(mut self, mode: RoundingMode) -> Self {
+        self.rounding = Some(mode);
+        self
+    }
+
+    /// Sets the gender of the nouns the number counts, for the languages
+    /// whose numbers agree with it
+    ///
+    /// It takes over the gender given by a preference, e.g. "feminine". Other
+    /// languages read the number as usual.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Gender, Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(21)
+    ///         .lang(Lang::French)
+    ///         .gender(Gender::Feminine)
+    ///         .to_words(),
+    ///     Ok(String::from("vingt et une"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(1)
+    ///         .lang(Lang::Ukrainian)
+    ///         .gender(Gender::Neuter)
+    ///         .to_words(),
+    ///     Ok(String::from("одне"))
+    /// );
+    /// ```
+    pub fn gender(mut self, gender: Gender) -> Self {
+        self.gender = Some(gender);
+        self
+    }
+
+    /// Sets the grammatical case the number is declined in, for the
+    /// languages that decline numbers, so that it fits the sentence around it
+    ///
+    /// It takes over the case given by a preference, e.g. "genitive". Other
+    /// languages read the number as usual.
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Case, Lang, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2)
+    ///         .lang(Lang::Ukrainian)
+    ///         .case(Case::Genitive)
+    ///         .to_words(),
+    ///     Ok(String::from("двох"))
+    /// );
+    /// ```
+    pub fn case(mut self, case: Case) -> Self {
+        self.case = Some(case);
+        self
+    }
+
+    /// Adds a preference parameter
+    ///
+    /// # All languages accept:
+    /// zero-numeral, zero-spelled or zero-error to write zero as an ordinal in
+    /// digits as "0th", as "zeroth" or not at all, overriding the language's
+    /// [`Language::zero_ordinal`]
+    ///
+    /// 24h to read times of [`Num2Words::time`] on a 24-hour clock, e.g.
+    /// "fifteen fifteen" rather than "quarter past three"
+    ///
+    /// shared-century to read a century shared by both years of
+    /// [`Num2Words::year_range`] once, e.g. "from nineteen fourteen to
+    /// eighteen", in English, Dutch and Frisian
+    ///
+    /// # English language accepts:
+    /// oh and/or nil as replacements for "zero"
+    ///
+    /// currency-and to only say "and" in currencies, e.g. "one hundred twenty-three"
+    /// but "one hundred and twenty-three dollars"
+    ///
+    /// abbrev to read round thousands and millions informally, e.g. "five kay"
+    /// for 5000 and "two mil" for 2000000
+    ///
+    /// mixed to read the fraction digit by digit, e.g. "three point one four
+    /// one five nine", or mixed-pairs/mixed-triples to read its digits in
+    /// groups, e.g. "three point fourteen fifteen nine"
+    ///
+    /// color to read a color code parsed by [`Num2Words::parse`] in pairs of
+    /// hexadecimal digits, e.g. "hash one-ay two-bee three-cee" for #1A2B3C
+    ///
+    /// native or iso to name currencies by their short name or their code,
+    /// e.g. "five dollars" or "five USD" rather than "five US dollars"
+    ///
+    /// decimal-scale to read millions and above as a short decimal, e.g. "one
+    /// point five million" for 1500000
+    ///
+    /// double to read repeated digits of phone numbers as "double" and
+    /// "triple", e.g. "double five" for 55
+    ///
+    /// point or comma to read the decimal separator, e.g. "two comma five"
+    /// rather than "two point five"
+    ///
+    /// milliard to name a thousand of each -illion in the long scale, e.g.
+    /// "two milliard" rather than "two thousand million", see
+    /// [`Num2Words::scale`]
+    ///
+    /// debit to read negative currency amounts as a debit, e.g. "five dollars
+    /// in debit" rather than "minus five dollars", in English, French,
+    /// Italian, Portuguese, Dutch and Frisian
+    ///
+    /// # English, Dutch and Frisian languages accept:
+    /// paren to read negative numbers in parentheses, e.g. "(forty-two)"
+    ///
+    /// # English and Dutch languages accept:
+    /// decimal-currency to read cents as decimals, e.g. "five point five zero euros"
+    ///
+    /// whole-fraction to read the fraction as a whole number after its leading
+    /// zeros, e.g. "twelve point fifty-one" or "twaalf komma éénenvijftig"
+    ///
+    /// negative (or negatief in Dutch) to read the sign of negative numbers
+    /// as "negative" rather than "minus", e.g. "negative five dollars"
+    ///
+    /// ssml to mark up cardinal and currency output for text-to-speech, e.g.
+    /// "<speak>one thousand<break/> two hundred and thirty-four</speak>"
+    ///
+    /// simple to avoid scale words above a million, reading larger numbers as
+    /// "a really big number"
+    ///
+    /// from-end to read negative ordinals counted from the end, e.g.
+    /// "second from last" for -2
+    ///
+    /// vague-plural to read round hundreds and scale words vaguely, e.g.
+    /// "thousands" for 3000
+    ///
+    /// mixed-number to read fractions above one as a whole number and a
+    /// fraction, e.g. "two and a half" for 5/2
+    ///
+    /// # Dutch and Frisian languages accept:
+    /// plural-unit to pluralize currency units, e.g. "twee euro's" rather than
+    /// "twee euro"
+    ///
+    /// komma or punt to read the decimal separator, e.g. "twee punt vijf"
+    /// rather than "twee komma vijf"
+    ///
+    /// # Frisian language accepts:
+    /// no-trema to join units ending in a vowel without a trema, e.g.
+    /// "trijeentweintich" rather than "trijeëntweintich"
+    ///
+    /// # Dutch language accepts:
+    /// collective to read groups, e.g. "met z'n tweeën"
+    ///
+    /// show-positive to read cardinals that aren't negative with a sign, e.g.
+    /// "plus tien"
+    ///
+    /// ste-de to end ordinals in digits as their words end, e.g. "1ste" and
+    /// "2de" rather than "1e" and "2e"
+    ///
+    /// # French language accepts:
+    /// feminine/f/féminin/feminin, also writing "1re" rather than "1er"
+    ///
+    /// reformed/1990/rectifié/rectification
+    ///
+    /// octante (Swiss French only)
+    ///
+    /// # Portuguese language accepts:
+    /// feminine/f/feminino
+    ///
+    /// # Arabic language accepts:
+    /// feminine/f/مؤنث
+    ///
+    /// # Romanian language accepts:
+    /// feminine/f/feminin
+    ///
+    /// # Chinese language accepts (bold - default):
+    /// Script: **simplified/简体**, traditional/繁體, pinyin/拼音
+    ///
+    /// # Korean language accepts (bold - default):
+    /// Numerals: **sino/한자어**, native/고유어 (up to ninety-nine, e.g. "스물하나")
+    ///
+    /// # Ukrainian language supports grammatical categories (bold - default):
+    /// Number: **singular/sing/однина/од**, plural/pl/множина/мн
+    ///
+    /// Gender: **masculine/m/чоловічий/чол/ч**, feminine/f/жіночий/жін/ж, neuter/n/середній/сер/с
+    ///
+    /// Declension: **nominative/nom/називний/н**, genitive/gen/родовий/р, dative/dat/давальний/д,
+    /// accusative/acc/знахідний/з, instrumental/inc/орудний/о, locative/loc/місцевий/м
+    ///
+    /// Examples:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// assert_eq!(
+    ///     Num2Words::new(0.05).prefer("oh").to_words(),
+    ///     Ok(String::from("point oh five"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(161).lang(Lang::French).prefer("f").prefer("reformed").to_words(),
+    ///     Ok(String::from("cent-soixante-et-une"))
+    /// );
+    /// assert_eq!(
+    ///     Num2Words::new(51).lang(Lang::Ukrainian).prefer("орудний").to_words(),
+    ///     Ok(String::from("пʼятдесятьма одним"))
+    /// );
+    /// ```
+    pub fn prefer<T>(mut self, prefer: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.preferences.push(prefer.into());
+        self
+    }
+
+    /// Builds the output
+    pub fn to_words(mut self) -> Result<String, Num2Err> {
+        self.apply_rounding();
+        let lang = self.language()?;
+        self.convert(lang.as_ref())
+    }
+
+    /// Builds the currency output as separate parts, in the order
+    /// `(major_words, minor_words, major_unit, minor_unit)`
+    ///
+    /// The sign of negative amounts is read with the major part. Only English
+    /// supports currency parts, other languages return
+    /// [`Num2Err::CannotConvert`].
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Currency, Num2Words};
+    /// assert_eq!(
+    ///     Num2Words::new(2.01).currency(Currency::EUR).to_currency_parts(),
+    ///     Ok((
+    ///         String::from("two"),
+    ///         String::from("one"),
+    ///         String::from("euros"),
+    ///         String::from("cent"),
+    ///     ))
+    /// );
+    /// ```
+    pub fn to_currency_parts(mut self) -> Result<(String, String, String, String), Num2Err> {
+        self.apply_rounding();
+        let lang = self.language()?;
+        lang.to_currency_parts(self.currency_amount(), self.currency)
+    }
+
+    /// Builds the output along with details about the conversion
+    ///
+    /// Example:
+    /// ```
+    /// use num2words::{Num2Words, Lang};
+    /// let details = Num2Words::new(1234567.5).to_words_detailed().unwrap();
+    /// assert_eq!(details.scale, Some(String::from("million")));
+    /// assert_eq!(details.digits, 7);
+    /// assert!(details.fraction);
+    /// ```
+    pub fn to_words_detailed(mut self) -> Result<Num2WordsDetails, Num2Err> {
+        self.apply_rounding();
+        let lang = self.language()?;
+        let words = self.convert(lang.as_ref())?;
+
+        if self.num.is_inf() {
+            return Ok(Num2WordsDetails {
+                words,
+                scale: None,
+                digits: 0,
+                fraction: false,
+            });
+        }
+
+        let mut digits = 1;
+        let mut integral = (self.num.int().abs() / BigFloat::from(10)).int();
+        while !integral.is_zero() {
+            integral = (integral / BigFloat::from(10)).int();
+            digits += 1;
+        }
+
+        // Indian grouping puts three digits under the first scale word, then
+        // two under each of the others
+        let power = match lang.grouping_scheme() {
+            GroupingScheme::Thousands => (digits - 1) / 3,
+            GroupingScheme::Myriad => (digits - 1) / 4,
+            GroupingScheme::Indian => (digits.max(2) - 2) / 2,
+        };
+
+        Ok(Num2WordsDetails {
+            words,
+            scale: lang.scale_word(power).map(String::from),
+            digits,
+            fraction: !self.num.frac().is_zero(),
+        })
+    }
+
+    // rounds the number as set by `precision` and `round`
+    fn apply_rounding(&mut self) {
+        if self.precision.is_none() && self.rounding.is_none() {
+            return;
+        }
+        let decimals = self.precision.unwrap_or(0);
+        self.num = match self.rounding.unwrap_or(RoundingMode::HalfUp) {
+            RoundingMode::Truncate => {
+                let mut scale = BigFloat::from(1);
+                for _ in 0..decimals {
+                    scale *= BigFloat::from(10);
+                }
+                (self.num * scale).int() / scale
+            }
+            RoundingMode::HalfUp => self
+                .num
+                .round(decimals, num_bigfloat::RoundingMode::FromZero),
+            RoundingMode::HalfEven => self.num.round(decimals, num_bigfloat::RoundingMode::ToEven),
+        };
+    }
+
+    // an amount reading as zero units and zero cents carries no sign, e.g.
+    // -0.001 dollars reads as "zero dollars" rather than "minus zero dollars"
+    fn currency_amount(&self) -> BigFloat {
+        let ratio = BigFloat::from(self.currency.subunit_ratio());
+        if (self.num * ratio).int().is_zero() {
+            self.num.abs()
+        } else {
+            self.num
+        }
+    }
+
+    // reads digits beyond the 40 a BigFloat holds in the language's groups,
+    // e.g. 46 digits in groups of a thousand
+    fn exact_cardinal(&self, lang: &dyn Language, digits: &str) -> Result<String, Num2Err> {
+        if digits.is_empty() || !digits.bytes().all(|d| d.is_ascii_digit()) {
+            return Err(Num2Err::CannotConvert);
+        }
+        if digits.trim_start_matches('0').len() <= 40 {
+            return lang.to_cardinal(self.num);
+        }
+
+        let scheme = lang.grouping_scheme();
+        lang.compose_from_groups(&lang::split_digits(digits, scheme), scheme)
+    }
+
+    // the amount with the code or symbol of its currency in place of the name
+    // of its units, e.g. "USD five hundred and fifty cents"
+    fn labelled_currency(
+        &self,
+        lang: &dyn Language,
+        amount: BigFloat,
+        label: &str,
+    ) -> Result<String, Num2Err> {
+        let whole = amount.int();
+        // amounts of subunits only are named as usual, e.g. "fifty cents"
+        if amount.is_inf() || whole.is_zero() {
+            return lang.to_currency(amount, self.currency);
+        }
+
+        let ratio = BigFloat::from(self.currency.subunit_ratio());
+        let fraction = (amount.frac().abs() * ratio).int();
+        let cardinal = lang.to_cardinal(whole)?;
+        let words = match self.currency_position {
+            // the sign stays in front, e.g. "minus USD five"
+            CurrencyPosition::Before => {
+                let unsigned = lang.to_cardinal(whole.abs())?;
+                match cardinal.strip_suffix(unsigned.as_str()) {
+                    Some(sign) => format!("{}{} {}", sign, label, unsigned),
+                    None => format!("{} {}", label, cardinal),
+                }
+            }
+            CurrencyPosition::After => format!("{} {}", cardinal, label),
+        };
+        if fraction.is_zero() {
+            Ok(words)
+        } else {
+            Ok(format!(
+                "{}{}{}",
+                words,
+                lang.currency_connector(),
+                lang.to_currency(fraction / ratio, self.currency)?
+            ))
+        }
+    }
+
+    // the language set with `lang_custom` or `rbnf`, or else the one of `lang`
+    fn language(&mut self) -> Result<Box<dyn Language>, Num2Err> {
+        let language = match (self.custom.take(), &self.rbnf) {
+            (Some(language), _) => language,
+            (None, Some(locale)) => match lang::rbnf_language(locale) {
+                Some(language) => Box::new(language),
+                None => return Err(Num2Err::CannotConvert),
+            },
+            (None, None) => lang::to_language(&self.lang, &self.preferences)?,
+        };
+        // e.g. "une" rather than "un" with a feminine gender in French
+        let language = match self.gender.and_then(|gender| language.gendered(gender)) {
+            Some(gendered) => gendered,
+            None => language,
+        };
+        // e.g. "двох" rather than "два" in the Ukrainian genitive
+        let language = match self.case.and_then(|case| language.declined(case)) {
+            Some(declined) => declined,
+            None => language,
+        };
+        // e.g. "thousand million" rather than "billion" in the English long
+        // scale
+        match self.scale.and_then(|scale| language.scaled(scale)) {
+            Some(scaled) => Ok(scaled),
+            None => Ok(language),
+        }
+    }
+
+    fn convert(&self, lang: &dyn Language) -> Result<String, Num2Err> {
+        let ssml = self.preferences.iter().any(|v| v == "ssml");
+
+        match self.output {
+            Output::Cardinal => {
+                if self.preferences.iter().any(|v| v == "color") {
+                    if self.num.is_inf()
+                        || self.num.is_negative()
+                        || !self.num.frac().is_zero()
+                        || self.num > BigFloat::from(0xFFFFFF)
+                    {
+                        return Err(Num2Err::CannotConvert);
+                    }
+                    return lang.to_color(self.num);
+                }
+                if self.preferences.iter().any(|v| v == "vague-plural") {
+                    if let Some(words) =
+                        lang::vague_power(self.num).and_then(|p| lang.vague_plural(p))
+                    {
+                        return Ok(words);
+                    }
+                }
+                if self.preferences.iter().any(|v| v == "collective") {
+                    if let Some(words) = lang.to_collective(self.num) {
+                        return Ok(words);
+                    }
+                }
+                let mut words = match &self.digits {
+                    Some(digits) => self.exact_cardinal(lang, digits)?,
+                    None => lang.to_cardinal(self.num)?,
+                };
+                if let Some(max_words) = self.max_words {
+                    if words.split_whitespace().count() > max_words {
+                        words = lang
+                            .to_approximate(self.num, self.significant_digits)
+                            .unwrap_or(words);
+                    }
+                }
+                if ssml {
+                    Ok(lang.to_ssml(words, None))
+                } else {
+                    Ok(words)
+                }
+            }
+            Output::Currency => {
+                let amount = self.currency_amount();
+                let debit = lang.debit_words().filter(|_| {
+                    amount.is_negative() && self.preferences.iter().any(|v| v == "debit")
+                });
+                let label = match self.currency_label {
+                    CurrencyLabel::Name => None,
+                    CurrencyLabel::Code => self.currency.iso_code(),
+                    CurrencyLabel::Symbol => match self.currency.symbol() {
+                        Some(symbol) => Some(String::from(symbol)),
+                        None => self.currency.iso_code(),
+                    },
+                };
+                let read = |amount: BigFloat| match &label {
+                    Some(label) => self.labelled_currency(lang, amount, label),
+                    None => lang.to_currency(amount, self.currency),
+                };
+                let words = match debit {
+                    // e.g. "five dollars in debit" rather than "minus five dollars"
+                    Some(debit) => format!("{} {}", read(amount.abs())?, debit),
+                    None => read(amount)?,
+                };
+                if ssml {
+                    Ok(lang.to_ssml(words, Some(self.currency)))
+                } else {
+                    Ok(words)
+                }
+            }
+            Output::Multiplicative => lang.to_multiplicative(self.num),
+            Output::Scientific => lang.to_scientific(self.num),
+            Output::Approximate => lang.to_approximate(self.num, self.significant_digits),
+            Output::Percent => lang.to_percent(self.num),
+            Output::Permille => lang.to_permille(self.num),
+            Output::Decade | Output::Century => {
+                let step = match self.output {
+                    Output::Decade => 10,
+                    _ => 100,
+                };
+                if self.num.is_inf()
+                    || self.num.is_negative()
+                    || !(self.num % BigFloat::from(step)).is_zero()
+                {
+                    return Err(Num2Err::CannotConvert);
+                }
+                match self.output {
+                    Output::Decade => lang.to_decade(self.num),
+                    _ => lang.to_century(self.num),
+                }
+            }
+            Output::Compact => lang.to_compact(self.num),
+            Output::Expanded => {
+                if self.num.is_inf() || self.num.is_negative() || !self.num.frac().is_zero() {
+                    return Err(Num2Err::CannotConvert);
+                }
+                lang.to_expanded(self.num)
+            }
+            Output::Fraction => {
+                if self.num.is_inf()
+                    || self.denominator.is_inf()
+                    || self.denominator.is_zero()
+                    || !self.num.frac().is_zero()
+                    || !self.denominator.frac().is_zero()
+                {
+                    return Err(Num2Err::CannotConvert);
+                }
+                // e.g. 6/4 reads as "three halves" and 4/2 as "two"
+                let mut divisor = lang::gcd(self.num, self.denominator);
+                if self.denominator.is_negative() {
+                    divisor = -divisor;
+                }
+                let numerator = self.num / divisor;
+                let denominator = self.denominator / divisor;
+                if denominator == BigFloat::from(1) {
+                    return lang.to_cardinal(numerator);
+                }
+                // e.g. 5/2 reads as "two and a half"
+                let mixed = self.preferences.iter().any(|v| v == "mixed-number");
+                if mixed && numerator.abs() > denominator {
+                    let whole = (numerator / denominator).int();
+                    let rest = (numerator - whole * denominator).abs();
+                    if rest.is_zero() {
+                        return lang.to_cardinal(whole);
+                    }
+                    return lang.to_mixed_fraction(whole, rest, denominator);
+                }
+                lang.to_fraction(numerator, denominator)
+            }
+            Output::Ordinal => {
+                if self.num.is_inf() {
+                    return Err(Num2Err::InfiniteOrdinal);
+                }
+                if !self.num.frac().is_zero() {
+                    return Err(Num2Err::FloatingOrdinal);
+                }
+                if self.num.is_negative() {
+                    if self.preferences.iter().any(|v| v == "from-end") {
+                        return lang.to_ordinal_from_end(-self.num);
+                    }
+                    return Err(Num2Err::NegativeOrdinal);
+                }
+                lang.to_ordinal(self.num)
+            }
+            Output::OrdinalNum => {
+                if self.num.is_inf() {
+                    return Err(Num2Err::InfiniteOrdinal);
+                }
+                if !self.num.frac().is_zero() {
+                    return Err(Num2Err::FloatingOrdinal);
+                }
+                if self.num.is_negative() {
+                    return Err(Num2Err::NegativeOrdinal);
+                }
+                if self.num.is_zero() {
+                    let policy = match self
+                        .preferences
+                        .iter()
+                        .rev()
+                        .find(|v| {
+                            ["zero-numeral", "zero-spelled", "zero-error"].contains(&v.as_str())
+                        })
+                        .map(|v| v.as_str())
+                    {
+                        Some("zero-numeral") => ZeroOrdinal::Numeral,
+                        Some("zero-spelled") => ZeroOrdinal::Spelled,
+                        Some(_) => ZeroOrdinal::Error,
+                        None => lang.zero_ordinal(),
+                    };
+                    match policy {
+                        ZeroOrdinal::Numeral => {}
+                        ZeroOrdinal::Spelled => return lang.to_ordinal(self.num),
+                        ZeroOrdinal::Error => return Err(Num2Err::ZeroOrdinal),
+                    }
+                }
+                lang.to_ordinal_num(self.num)
+            }
+            Output::Year => {
+                if self.num.is_inf() {
+                    return Err(Num2Err::InfiniteYear);
+                }
+                if !self.num.frac().is_zero() {
+                    return Err(Num2Err::FloatingYear);
+                }
+                let era = match self.era {
+                    Some(Era::Custom { before, after }) => Some((before, after)),
+                    Some(era) => lang.era_words(era),
+                    None => None,
+                };
+                match era {
+                    // e.g. "seventy-nine AD"
+                    Some((before, after)) => {
+                        let words = lang.to_year(self.num.abs())?;
+                        let era = if self.num.is_negative() {
+                            before
+                        } else {
+                            after
+                        };
+                        if era.is_empty() {
+                            Ok(words)
+                        } else {
+                            Ok(format!("{} {}", words, era))
+                        }
+                    }
+                    None => lang.to_year(self.num),
+                }
+            }
+            Output::Phone => {
+                let number = self.phone.as_deref().ok_or(Num2Err::CannotConvert)?;
+                let (country_code, groups) =
+                    lang::split_phone(number).ok_or(Num2Err::CannotConvert)?;
+                lang.to_phone(country_code, &groups)
+            }
+            Output::YearRange => {
+                let end = self.year_end.ok_or(Num2Err::CannotConvert)?;
+                for year in [self.num, end] {
+                    if year.is_inf() {
+                        return Err(Num2Err::InfiniteYear);
+                    }
+                    if !year.frac().is_zero() {
+                        return Err(Num2Err::FloatingYear);
+                    }
+                }
+                if end < self.num {
+                    return Err(Num2Err::CannotConvert);
+                }
+                let shared_century = self.preferences.iter().any(|v| v == "shared-century");
+                lang.to_year_range(self.num, end, shared_century)
+            }
+            Output::Time => match self.time {
+                Some((hour, minute)) if hour < 24 && minute < 60 => {
+                    let clock_24 = self.preferences.iter().any(|v| v == "24h");
+                    lang.to_time(hour, minute, clock_24)
+                }
+                _ => Err(Num2Err::CannotConvert),
+            },
+            Output::Unit => lang.to_unit(self.num, self.unit),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_bigfloat::BigFloat;
+
+    #[test]
+    fn test_to_words_detailed() {
+        assert_eq!(
+            Num2Words::new(1234567)
+                .lang(Lang::Dutch)
+                .to_words_detailed(),
+            Ok(Num2WordsDetails {
+                words: String::from(
+                    "één miljoen tweehonderdvierendertigduizend vijfhonderdenzevenenzestig"
+                ),
+                scale: Some(String::from("miljoen")),
+                digits: 7,
+                fraction: false,
+            })
+        );
+        assert_eq!(
+            Num2Words::new(42.5).to_words_detailed(),
+            Ok(Num2WordsDetails {
+                words: String::from("forty-two point five"),
+                scale: None,
+                digits: 2,
+                fraction: true,
+            })
+        );
+    }
 
     #[test]
     fn test_string_not_valid() {
         assert!(Num2Words::parse("not a number").is_none());
         assert!(Num2Words::parse("NAN").is_none());
     }
+
+    #[test]
+    fn test_max_words() {
+        assert_eq!(
+            Num2Words::new(3_141_592).max_words(5).to_words(),
+            Ok(String::from("roughly three million"))
+        );
+        assert_eq!(
+            Num2Words::new(42).max_words(5).to_words(),
+            Ok(String::from("forty-two"))
+        );
+        assert_eq!(
+            Num2Words::new(3_000_000).max_words(5).to_words(),
+            Ok(String::from("three million"))
+        );
+        assert_eq!(
+            Num2Words::new(3_141_592)
+                .lang(Lang::French)
+                .max_words(5)
+                .to_words(),
+            Ok(String::from("environ trois millions"))
+        );
+        assert_eq!(
+            Num2Words::new(3_141_592)
+                .lang(Lang::Italian)
+                .max_words(5)
+                .to_words(),
+            Num2Words::new(3_141_592).lang(Lang::Italian).to_words()
+        );
+    }
+
+    #[test]
+    fn test_parse_grouped() {
+        let dutch = Num2Words::parse_grouped("1.234.567", Lang::Dutch).unwrap();
+        assert_eq!(dutch.grouped(), Some("1.234.567"));
+        assert_eq!(
+            dutch.to_words(),
+            Ok(String::from(
+                "één miljoen tweehonderdvierendertigduizend vijfhonderdenzevenenzestig"
+            ))
+        );
+
+        let english = Num2Words::parse_grouped("1,234,567", Lang::English).unwrap();
+        assert_eq!(english.grouped(), Some("1,234,567"));
+        assert_eq!(
+            english.to_words(),
+            Ok(String::from(
+                "one million two hundred thirty-four thousand five hundred and sixty-seven"
+            ))
+        );
+
+        assert_eq!(
+            Num2Words::parse_grouped("-1.234,5", Lang::Dutch)
+                .unwrap()
+                .to_words(),
+            Ok(String::from(
+                "minus éénduizend tweehonderdenvierendertig komma vijf"
+            ))
+        );
+        assert!(Num2Words::parse_grouped("1234567", Lang::English).is_ok());
+        assert_eq!(
+            Num2Words::parse_grouped("1,234,567", Lang::Dutch).err(),
+            Some(Num2Err::GroupingMismatch)
+        );
+        assert_eq!(
+            Num2Words::parse_grouped("1.234.567", Lang::English).err(),
+            Some(Num2Err::GroupingMismatch)
+        );
+        assert_eq!(
+            Num2Words::parse_grouped("12,34", Lang::English).err(),
+            Some(Num2Err::GroupingMismatch)
+        );
+
+        let hindi = Num2Words::parse_grouped("12,34,567", Lang::Hindi).unwrap();
+        assert_eq!(hindi.num, BigFloat::from(1234567));
+        assert_eq!(
+            Num2Words::parse_grouped("1,234,567", Lang::Hindi).err(),
+            Some(Num2Err::GroupingMismatch)
+        );
+        let japanese = Num2Words::parse_grouped("123,4567", Lang::Japanese).unwrap();
+        assert_eq!(japanese.num, BigFloat::from(1234567));
+        assert_eq!(
+            Num2Words::parse_grouped("1,234,567", Lang::Japanese).err(),
+            Some(Num2Err::GroupingMismatch)
+        );
+    }
+
+    #[test]
+    fn test_spell_small_into() {
+        let mut buf = [0; 64];
+        for n in 0..=999u16 {
+            assert_eq!(
+                spell_small_into(&Lang::English, n, &mut buf).map(String::from),
+                Num2Words::new(n).lang(Lang::English).to_words()
+            );
+            assert_eq!(
+                spell_small_into(&Lang::Dutch, n, &mut buf).map(String::from),
+                Num2Words::new(n).lang(Lang::Dutch).to_words()
+            );
+        }
+        assert_eq!(
+            spell_small_into(&Lang::English, 1000, &mut buf),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            spell_small_into(&Lang::English, 777, &mut [0; 8]),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            spell_small_into(&Lang::French, 42, &mut buf),
+            Err(Num2Err::CannotConvert)
+        );
+    }
+
+    #[test]
+    fn test_self_check() {
+        for lang in supported_languages() {
+            let language = crate::lang::to_language(&lang, &[]).unwrap();
+            assert_eq!(language.self_check(), Ok(()));
+        }
+    }
+
+    struct Shout;
+
+    impl Language for Shout {
+        fn to_cardinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+            Ok(format!("{}!", num.to_i64().ok_or(Num2Err::CannotConvert)?))
+        }
+
+        fn to_ordinal(&self, num: BigFloat) -> Result<String, Num2Err> {
+            Ok(format!("#{}", self.to_cardinal(num)?))
+        }
+
+        fn to_ordinal_num(&self, num: BigFloat) -> Result<String, Num2Err> {
+            self.to_ordinal(num)
+        }
+
+        fn to_year(&self, num: BigFloat) -> Result<String, Num2Err> {
+            self.to_cardinal(num)
+        }
+
+        fn to_currency(&self, num: BigFloat, currency: Currency) -> Result<String, Num2Err> {
+            Ok(format!(
+                "{} {}",
+                self.to_cardinal(num)?,
+                currency.default_string(true)
+            ))
+        }
+
+        fn scale_word(&self, _: usize) -> Option<&'static str> {
+            None
+        }
+
+        fn hundred_word(&self) -> &'static str {
+            "100!"
+        }
+
+        fn thousand_word(&self) -> &'static str {
+            "1000!"
+        }
+    }
+
+    #[test]
+    fn test_parse_words() {
+        let parse = |words: &str| Num2Words::parse_words(words, Lang::English);
+        assert_eq!(parse("Forty Two"), Ok(BigFloat::from(42)));
+        assert_eq!(parse("one thousand and five"), Ok(BigFloat::from(1005)));
+        assert_eq!(parse("four hundred two"), Ok(BigFloat::from(402)));
+        assert_eq!(
+            parse("two million three hundred thousand"),
+            Ok(BigFloat::from(2_300_000))
+        );
+        assert_eq!(
+            parse("minus zero point seven five"),
+            Ok(BigFloat::from(-0.75))
+        );
+        assert_eq!(parse("point five"), Ok(BigFloat::from(0.5)));
+        assert_eq!(parse("two three"), Err(Num2Err::CannotConvert));
+        assert_eq!(parse("thousand million"), Err(Num2Err::CannotConvert));
+        assert_eq!(parse("forty-two apples"), Err(Num2Err::CannotConvert));
+
+        // a feminine "one" is a count of its own, not part of the scale word
+        assert_eq!(
+            Num2Words::parse_words("двадцять одна тисяча триста сорок чотири", Lang::Ukrainian),
+            Ok(BigFloat::from(21_344))
+        );
+
+        // round trips
+        let locales = [
+            "en", "fr", "fr_BE", "fr_CH", "nl", "fy", "pl", "uk", "be", "cs", "sk", "ro", "el",
+            "ar", "it", "pt", "pt_BR", "sv", "no", "da", "fi", "eo", "ps", "hi", "ja", "zh", "ko",
+        ];
+        for locale in locales {
+            let lang = || locale.parse::<Lang>().unwrap();
+            for num in [21, 101, 21_000, 321_012, 2_471_930] {
+                let words = Num2Words::new(num).lang(lang()).to_words().unwrap();
+                assert_eq!(
+                    Num2Words::parse_words(&words, lang()),
+                    Ok(BigFloat::from(num)),
+                    "{}",
+                    words
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_locale_tags() {
+        assert!(matches!("nl-NL".parse::<Lang>(), Ok(Lang::Dutch)));
+        assert!(matches!("fy_NL".parse::<Lang>(), Ok(Lang::Frisian)));
+        assert!(matches!("fr-CH".parse::<Lang>(), Ok(Lang::French_CH)));
+        assert!(matches!("fr-CA".parse::<Lang>(), Ok(Lang::French)));
+        assert!(matches!("en-Latn-GB".parse::<Lang>(), Ok(Lang::English)));
+        assert!(matches!(
+            "pt-br-u-nu-latn".parse::<Lang>(),
+            Ok(Lang::Portuguese_BR)
+        ));
+        assert!(matches!(
+            resolve_locale("fr-CA"),
+            Some((Lang::French, locale)) if locale == "fr"
+        ));
+        assert!("xx-NL".parse::<Lang>().is_err());
+    }
+
+    #[test]
+    fn test_register_language() {
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Custom(String::from("shout")))
+                .to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert!("shout".parse::<Lang>().is_err());
+
+        register_language("shout", || Box::new(Shout));
+        assert!(matches!("shout".parse::<Lang>(), Ok(Lang::Custom(key)) if key == "shout"));
+        assert_eq!(
+            Num2Words::new(42)
+                .lang(Lang::Custom(String::from("shout")))
+                .to_words(),
+            Ok(String::from("42!"))
+        );
+        assert_eq!(
+            Num2Words::new(3)
+                .lang(Lang::Custom(String::from("shout")))
+                .ordinal()
+                .to_words(),
+            Ok(String::from("#3!"))
+        );
+        assert_eq!(
+            Num2Words::new(7)
+                .lang(Lang::Custom(String::from("shout")))
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("7! euros"))
+        );
+    }
+
+    #[test]
+    fn test_lang_custom() {
+        assert_eq!(
+            Num2Words::new(42).lang_custom(Shout).to_words(),
+            Ok(String::from("42!"))
+        );
+        assert_eq!(
+            Num2Words::new(-1).lang_custom(Shout).ordinal().to_words(),
+            Err(Num2Err::NegativeOrdinal)
+        );
+        assert_eq!(
+            Num2Words::new(42)
+                .lang_custom(Shout)
+                .lang(Lang::English)
+                .to_words(),
+            Ok(String::from("forty-two"))
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        assert_eq!(
+            Num2Words::new(2.678).precision(2).to_words(),
+            Ok(String::from("two point six eight"))
+        );
+        assert_eq!(
+            Num2Words::new(2.678)
+                .precision(2)
+                .round(RoundingMode::Truncate)
+                .to_words(),
+            Ok(String::from("two point six seven"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.125)
+                .precision(2)
+                .round(RoundingMode::HalfEven)
+                .to_words(),
+            Ok(String::from("minus point one two"))
+        );
+        assert_eq!(
+            Num2Words::new(-0.125).precision(2).to_words(),
+            Ok(String::from("minus point one three"))
+        );
+        assert_eq!(
+            Num2Words::new(3.5).round(RoundingMode::HalfEven).to_words(),
+            Ok(String::from("four"))
+        );
+        assert_eq!(
+            Num2Words::new(12.3456)
+                .precision(2)
+                .currency(Currency::EUR)
+                .to_words(),
+            Ok(String::from("twelve euros and thirty-five cents"))
+        );
+    }
+
+    #[test]
+    fn test_currency_label() {
+        let labelled = |num: f64, currency, label, position| {
+            Num2Words::new(num)
+                .currency(currency)
+                .currency_label(label)
+                .currency_position(position)
+                .to_words()
+        };
+        assert_eq!(
+            labelled(
+                500.5,
+                Currency::USD,
+                CurrencyLabel::Code,
+                CurrencyPosition::Before
+            ),
+            Ok(String::from("USD five hundred and fifty cents"))
+        );
+        assert_eq!(
+            labelled(
+                500.5,
+                Currency::USD,
+                CurrencyLabel::Code,
+                CurrencyPosition::After
+            ),
+            Ok(String::from("five hundred USD and fifty cents"))
+        );
+        assert_eq!(
+            labelled(
+                1.0,
+                Currency::GBP,
+                CurrencyLabel::Symbol,
+                CurrencyPosition::Before
+            ),
+            Ok(String::from("£ one"))
+        );
+        assert_eq!(
+            labelled(
+                0.5,
+                Currency::USD,
+                CurrencyLabel::Code,
+                CurrencyPosition::Before
+            ),
+            Ok(String::from("fifty cents"))
+        );
+        // symbols fall back to codes, codes to names
+        assert_eq!(
+            labelled(
+                2.0,
+                Currency::CHF,
+                CurrencyLabel::Symbol,
+                CurrencyPosition::After
+            ),
+            Ok(String::from("two CHF"))
+        );
+        assert_eq!(
+            labelled(
+                2.0,
+                Currency::DOLLAR,
+                CurrencyLabel::Code,
+                CurrencyPosition::Before
+            ),
+            Ok(String::from("two dollars"))
+        );
+        assert_eq!(
+            Num2Words::new(-3)
+                .lang(Lang::Ukrainian)
+                .currency(Currency::UAH)
+                .currency_label(CurrencyLabel::Symbol)
+                .to_words(),
+            Ok(String::from("мінус ₴ три"))
+        );
+    }
+
+    #[test]
+    fn test_from_digits() {
+        let digits = "123456789012345678901234567890123456789012345678";
+        assert_eq!(
+            Num2Words::from_digits(digits).to_words(),
+            Ok(String::from(
+                "one hundred twenty-three quattuordecillion four hundred fifty-six \
+                tredecillion seven hundred eighty-nine duodecillion twelve undecillion \
+                three hundred forty-five decillion six hundred seventy-eight nonillion \
+                nine hundred one octillion two hundred thirty-four septillion five \
+                hundred sixty-seven sextillion eight hundred ninety quintillion one \
+                hundred twenty-three quadrillion four hundred fifty-six trillion seven \
+                hundred eighty-nine billion twelve million three hundred forty-five \
+                thousand six hundred and seventy-eight"
+            ))
+        );
+        assert_eq!(
+            Num2Words::from_digits("1000000000000000000000000000000000000000000000007")
+                .lang(Lang::Dutch)
+                .to_words(),
+            Ok(String::from("één octiljoen zeven"))
+        );
+        // scale words past the tables and past a BigFloat, after Conway and
+        // Wechsler
+        let words = Num2Words::from_digits(&"9".repeat(200)).to_words().unwrap();
+        assert!(words.starts_with("ninety-nine quinquasexagintillion nine hundred"));
+        for (zeros, words) in [
+            (303, "one centillion"),
+            (306, "one uncentillion"),
+            (3003, "one millinillion"),
+            (3333, "one millidecicentillion"),
+        ] {
+            assert_eq!(
+                Num2Words::from_digits(&format!("1{}", "0".repeat(zeros))).to_words(),
+                Ok(String::from(words))
+            );
+        }
+        assert_eq!(
+            Num2Words::from_digits(&format!("1{}", "0".repeat(6000)))
+                .lang(Lang::Dutch)
+                .to_words(),
+            Ok(String::from("éénmilliniljoen"))
+        );
+        assert_eq!(
+            Num2Words::from_digits("00042").to_words(),
+            Ok(String::from("forty-two"))
+        );
+        assert_eq!(
+            Num2Words::from_digits("4.2").to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        assert_eq!(
+            Num2Words::from_digits(digits).lang(Lang::French).to_words(),
+            Err(Num2Err::CannotConvert)
+        );
+        // other outputs keep the magnitude of digits past the 40th
+        let digits = "1000000000000000000000000000000000000000000001";
+        assert_eq!(
+            Num2Words::from_digits(digits).ordinal().to_words(),
+            Ok(String::from("one quattuordecillionth"))
+        );
+        assert_eq!(
+            Num2Words::from_digits(digits).approximate().to_words(),
+            Ok(String::from("roughly a quattuordecillion"))
+        );
+        // u128 fits in the 40 digits of a BigFloat
+        assert!(Num2Words::new(u128::MAX)
+            .to_words()
+            .unwrap()
+            .ends_with("two hundred eleven thousand four hundred and fifty-five"));
+    }
 }
@@ -12,6 +12,36 @@ pub enum Output {
     OrdinalNum,
     /// Number in year form, e.g., `nineteen oh-one`
     Year,
+    /// Number as a multiplicative, e.g., `twice`
+    Multiplicative,
+    /// Number in scientific form, e.g., `ten to the third power`
+    Scientific,
+    /// Number rounded and hedged, e.g., `about a hundred`
+    Approximate,
+    /// Number with a fraction of a scale word, e.g., `two and a half million`
+    Compact,
+    /// Number read digit by digit with its place value, e.g.,
+    /// `two hundreds and four ones`
+    Expanded,
+    /// Number as the numerator of a fraction, e.g., `seven thirteenths`
+    Fraction,
+    /// Number as a percentage, e.g., `five percent`
+    Percent,
+    /// Number as a per-mille value, e.g., `twelve per mille`
+    Permille,
+    /// Range of years, e.g., `nineteen fourteen to nineteen eighteen`
+    YearRange,
+    /// Year as its decade, e.g., `the nineteen-nineties`
+    Decade,
+    /// Year as its century, e.g., `the twentieth century`
+    Century,
+    /// Phone number read in its groups of digits, e.g.,
+    /// `plus thirty-one, six, one two three four`
+    Phone,
+    /// Time of day, e.g., `quarter past three`
+    Time,
+    /// Number followed by a unit of measure, e.g., `twenty-one kilometers`
+    Unit,
 }
 
 impl FromStr for Output {
@@ -20,13 +50,22 @@ impl FromStr for Output {
     /// Parses a string to return a value of this type
     ///
     ///
-    /// | &str          | Output               |
-    /// | ------------- | -------------------- |
-    /// | `cardinal`    | `Output::Cardinal`   |
-    /// | `currency`    | `Output::Currency`   |
-    /// | `ordinal`     | `Output::Ordinal`    |
-    /// | `ordinal_num` | `Output::OrdinalNum` |
-    /// | `year`        | `Output::Year`       |
+    /// | &str             | Output                   |
+    /// | ---------------- | ------------------------ |
+    /// | `cardinal`       | `Output::Cardinal`       |
+    /// | `currency`       | `Output::Currency`       |
+    /// | `ordinal`        | `Output::Ordinal`        |
+    /// | `ordinal_num`    | `Output::OrdinalNum`     |
+    /// | `year`           | `Output::Year`           |
+    /// | `multiplicative` | `Output::Multiplicative` |
+    /// | `scientific`     | `Output::Scientific`     |
+    /// | `approximate`    | `Output::Approximate`    |
+    /// | `compact`        | `Output::Compact`        |
+    /// | `expanded`       | `Output::Expanded`       |
+    /// | `percent`        | `Output::Percent`        |
+    /// | `permille`       | `Output::Permille`       |
+    /// | `decade`         | `Output::Decade`         |
+    /// | `century`        | `Output::Century`        |
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         match input {
             "cardinal" => Ok(Output::Cardinal),
@@ -34,6 +73,15 @@ impl FromStr for Output {
             "ordinal" => Ok(Output::Ordinal),
             "ordinal_num" => Ok(Output::OrdinalNum),
             "year" => Ok(Output::Year),
+            "multiplicative" => Ok(Output::Multiplicative),
+            "scientific" => Ok(Output::Scientific),
+            "approximate" => Ok(Output::Approximate),
+            "compact" => Ok(Output::Compact),
+            "expanded" => Ok(Output::Expanded),
+            "percent" => Ok(Output::Percent),
+            "permille" => Ok(Output::Permille),
+            "decade" => Ok(Output::Decade),
+            "century" => Ok(Output::Century),
             _ => Err(()),
         }
     }
@@ -0,0 +1,83 @@
+use std::str::FromStr;
+
+/// Defines units of measure
+///
+/// Languages read the unit's word after the number, in the form agreeing
+/// with it. Units a language has no word for can still be read as a
+/// [`Unit::Custom`], which gives its forms itself.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Unit {
+    /// Millimeter, `mm`
+    Millimeter,
+    /// Centimeter, `cm`
+    Centimeter,
+    /// Meter, `m`
+    Meter,
+    /// Kilometer, `km`
+    Kilometer,
+    /// Mile, `mi`
+    Mile,
+    /// Gram, `g`
+    Gram,
+    /// Kilogram, `kg`
+    Kilogram,
+    /// Liter, `l`
+    Liter,
+    /// Second, `s`
+    Second,
+    /// Minute, `min`
+    Minute,
+    /// Hour, `h`
+    Hour,
+    /// Day, `d`
+    Day,
+    /// Unit given by its forms in the language it is read in: `singular`
+    /// after one, `paucal` after a few in the languages that have such a
+    /// form (e.g. "2 kilometry" in Polish) and `plural` otherwise
+    Custom {
+        singular: &'static str,
+        paucal: &'static str,
+        plural: &'static str,
+    },
+}
+
+impl Unit {
+    /// Forms of a custom unit agreeing with one, a few, many and a fraction,
+    /// as read by [`Language::unit_words`](crate::Language::unit_words)
+    ///
+    /// Fractions take the plural form. Other units return `None`.
+    pub fn custom_words(&self) -> Option<[&'static str; 4]> {
+        match *self {
+            Unit::Custom {
+                singular,
+                paucal,
+                plural,
+            } => Some([singular, paucal, plural, plural]),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Unit {
+    type Err = ();
+
+    /// Parses the symbol of a unit, e.g. `km`
+    fn from_str(unit: &str) -> Result<Self, Self::Err> {
+        match unit {
+            "mm" => Ok(Unit::Millimeter),
+            "cm" => Ok(Unit::Centimeter),
+            "m" => Ok(Unit::Meter),
+            "km" => Ok(Unit::Kilometer),
+            "mi" => Ok(Unit::Mile),
+            "g" => Ok(Unit::Gram),
+            "kg" => Ok(Unit::Kilogram),
+            "l" | "L" => Ok(Unit::Liter),
+            "s" => Ok(Unit::Second),
+            "min" => Ok(Unit::Minute),
+            "h" => Ok(Unit::Hour),
+            "d" => Ok(Unit::Day),
+            _ => Err(()),
+        }
+    }
+}
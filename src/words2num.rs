@@ -0,0 +1,340 @@
+use crate::{num2words::Num2Err, GroupingScheme, Language};
+use num_bigfloat::BigFloat;
+
+// Scale words looked up, up to a decillion with groups of a thousand
+const MAX_SCALES: usize = 11;
+
+// Words are matched without spaces, hyphens or case, so that "forty-two",
+// "Forty Two" and compounds such as "tweeënveertig" tokenize alike
+fn normalize(words: &str) -> String {
+    words
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '‐' | '‑' | ','))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Piece {
+    // a number below the first scale or a part of it, e.g. "four hundred"
+    // and "and two" in "four hundred and two"
+    Group(u64),
+    // a scale word, e.g. "thousand"
+    Scale(usize),
+    // a scale word read along with its count, e.g. the Greek "χίλια" for one
+    // thousand or the Ukrainian "однатисяча"
+    Counted(u64, usize),
+}
+
+// Words of a language, read from its own cardinals
+struct Vocabulary {
+    pieces: Vec<(String, Piece)>,
+    scales: Vec<BigFloat>,
+    digits: Vec<String>,
+    minus: Option<String>,
+    point: Option<String>,
+}
+
+impl Vocabulary {
+    fn new<L: Language + ?Sized>(language: &L) -> Result<Self, Num2Err> {
+        let cardinal = |num: BigFloat| language.to_cardinal(num).map(|w| normalize(&w));
+        let group_size: u64 = match language.grouping_scheme() {
+            GroupingScheme::Myriad => 10000,
+            _ => 1000,
+        };
+
+        let mut pieces = vec![];
+        for n in 0..100 {
+            pieces.push((cardinal(BigFloat::from(n))?, Piece::Group(n)));
+        }
+        let digits = pieces[..10].iter().map(|(w, _)| w.clone()).collect();
+        let one = pieces[1].0.clone();
+
+        let first_scale = cardinal(BigFloat::from(group_size))?;
+
+        // hundreds (and thousands within myriads), also as read before the
+        // rest of the group, e.g. "deux cent" in "deux cent un", on their own
+        // or after a scale
+        let mut place = 100;
+        while place < group_size {
+            for digit in 1..10 {
+                let head = digit * place;
+                pieces.push((cardinal(BigFloat::from(head))?, Piece::Group(head)));
+                let words = cardinal(BigFloat::from(head + 1))?;
+                let after_scale = cardinal(BigFloat::from(group_size + head + 1))?;
+                let after_scale = after_scale.strip_prefix(first_scale.as_str());
+                for words in std::iter::once(words.as_str()).chain(after_scale) {
+                    if let Some(words) = words.strip_suffix(one.as_str()) {
+                        pieces.push((String::from(words), Piece::Group(head)));
+                    }
+                }
+                let after_scale = cardinal(BigFloat::from(group_size + head))?;
+                if let Some(words) = after_scale.strip_prefix(first_scale.as_str()) {
+                    pieces.push((String::from(words), Piece::Group(head)));
+                }
+            }
+            place *= 10;
+        }
+
+        // the rest of a group may be joined differently after a hundred or a
+        // scale, e.g. "and five" in "one thousand and five"
+        for head in [100, group_size] {
+            let head_words = cardinal(BigFloat::from(head))?;
+            for n in 1..100 {
+                let words = cardinal(BigFloat::from(head + n))?;
+                if let Some(tail) = words.strip_prefix(head_words.as_str()) {
+                    pieces.push((String::from(tail), Piece::Group(n)));
+                }
+            }
+        }
+
+        // scale words, stripped of their count, e.g. "million" and "millions",
+        // after the counts up to the first compound tens, the other tens and
+        // the hundreds, e.g. "quatre-vingt mille" and "settantun milioni"
+        let counts: Vec<u64> = (1..30)
+            .chain((3..10).flat_map(|d| [d * 10, d * 10 + 1]))
+            .chain((1..10).flat_map(|d| [d * 100, d * 100 + 1]))
+            .collect();
+        let scale_step = match language.grouping_scheme() {
+            GroupingScheme::Indian => 100,
+            _ => group_size,
+        };
+        let mut scales = vec![];
+        let mut scale = BigFloat::from(1);
+        for power in 0..MAX_SCALES {
+            scale *= BigFloat::from(match power {
+                0 => group_size,
+                _ => scale_step,
+            });
+            let mut readings = vec![];
+            for &count in counts.iter().filter(|&&count| count < scale_step) {
+                match cardinal(scale * BigFloat::from(count)) {
+                    Ok(words) => readings.push((count, words)),
+                    Err(_) => break,
+                }
+            }
+            if readings.is_empty() {
+                break;
+            }
+
+            let mut forms: Vec<String> = vec![];
+            let mut unread = vec![];
+            for (count, words) in readings {
+                let form = pieces
+                    .iter()
+                    .filter(|(_, piece)| matches!(piece, Piece::Group(n) if *n == count))
+                    .filter_map(|(count_words, _)| words.strip_prefix(count_words.as_str()))
+                    .filter(|form| !form.is_empty())
+                    .min_by_key(|form| form.len())
+                    .map(String::from);
+                match form {
+                    Some(form) if !forms.contains(&form) => forms.push(form),
+                    Some(_) => {}
+                    None => unread.push((count, words)),
+                }
+            }
+
+            // counts may be read differently before a scale word, e.g. the
+            // feminine "одна" in "одна тисяча", or along with it
+            for (count, words) in unread {
+                let head = forms
+                    .iter()
+                    .filter_map(|form| words.strip_suffix(form.as_str()))
+                    .find(|head| !head.is_empty())
+                    .map(String::from);
+                match head {
+                    Some(head) => {
+                        // the units of a compound count, e.g. "μία" in "είκοσι μία"
+                        let units = match count {
+                            0..=20 => 0,
+                            21..=99 => count % 10,
+                            _ => count % 100,
+                        };
+                        let units_words = pieces
+                            .iter()
+                            .filter(|(_, piece)| matches!(piece, Piece::Group(n) if *n == count - units))
+                            .find_map(|(words, _)| head.strip_prefix(words.as_str()))
+                            .filter(|words| units > 0 && !words.is_empty())
+                            .map(String::from);
+                        if let Some(units_words) = units_words {
+                            pieces.push((units_words, Piece::Group(units)));
+                        }
+                        pieces.push((head, Piece::Group(count)));
+                    }
+                    None if forms.contains(&words) => {}
+                    None => pieces.push((words, Piece::Counted(count, power))),
+                }
+            }
+            for form in forms {
+                pieces.push((form, Piece::Scale(power)));
+            }
+            scales.push(scale);
+        }
+
+        // "minus" and "point", around the readings of 1 and 0.5
+        let minus = cardinal(BigFloat::from(-1))
+            .ok()
+            .and_then(|w| w.strip_suffix(one.as_str()).map(String::from))
+            .filter(|w| !w.is_empty());
+        let point = cardinal(BigFloat::from(0.5)).ok().and_then(|w| {
+            let w = w.strip_suffix(pieces[5].0.as_str())?;
+            Some(String::from(
+                w.strip_prefix(pieces[0].0.as_str()).unwrap_or(w),
+            ))
+        });
+
+        Ok(Self {
+            pieces,
+            scales,
+            digits,
+            minus,
+            point: point.filter(|w| !w.is_empty()),
+        })
+    }
+
+    // splits the words in as few pieces as possible
+    fn segment(&self, words: &str) -> Option<Vec<Piece>> {
+        let mut best: Vec<Option<(usize, usize, Piece)>> = vec![None; words.len() + 1];
+        let mut reached = vec![false; words.len() + 1];
+        reached[0] = true;
+
+        for start in 0..words.len() {
+            if !reached[start] {
+                continue;
+            }
+            let pieces_so_far = match best[start] {
+                Some((count, _, _)) => count,
+                None => 0,
+            };
+            for (word, piece) in &self.pieces {
+                if word.is_empty() || !words[start..].starts_with(word.as_str()) {
+                    continue;
+                }
+                let end = start + word.len();
+                let better = match best[end] {
+                    Some((count, _, _)) => pieces_so_far + 1 < count,
+                    None => true,
+                };
+                if better {
+                    best[end] = Some((pieces_so_far + 1, start, *piece));
+                    reached[end] = true;
+                }
+            }
+        }
+
+        let mut pieces = vec![];
+        let mut end = words.len();
+        while end > 0 {
+            let (_, start, piece) = best[end]?;
+            pieces.push(piece);
+            end = start;
+        }
+        pieces.reverse();
+        Some(pieces)
+    }
+
+    // "four hundred two" without its "and" adds up as well
+    fn add(group: Option<u64>, n: u64) -> Result<u64, Num2Err> {
+        match group {
+            None => Ok(n),
+            Some(g) if n > 0 && g % 10u64.pow(n.to_string().len() as u32) == 0 => Ok(g + n),
+            Some(_) => Err(Num2Err::CannotConvert),
+        }
+    }
+
+    // e.g. [Group(4), Scale(0), Group(2)] => 4002
+    fn combine(&self, pieces: &[Piece]) -> Result<BigFloat, Num2Err> {
+        let mut total = BigFloat::from(0);
+        let mut group: Option<u64> = None;
+        let mut last_scale: Option<usize> = None;
+
+        for piece in pieces {
+            match *piece {
+                Piece::Group(n) => group = Some(Self::add(group, n)?),
+                Piece::Scale(power) | Piece::Counted(_, power) => {
+                    if let Piece::Counted(n, _) = *piece {
+                        group = Some(Self::add(group, n)?);
+                    }
+                    if last_scale.is_some_and(|last| power >= last) {
+                        return Err(Num2Err::CannotConvert);
+                    }
+                    total += BigFloat::from(group.unwrap_or(1)) * self.scales[power];
+                    group = None;
+                    last_scale = Some(power);
+                }
+            }
+        }
+
+        Ok(total + BigFloat::from(group.unwrap_or(0)))
+    }
+
+    fn integer(&self, words: &str) -> Result<BigFloat, Num2Err> {
+        let pieces = self.segment(words).ok_or(Num2Err::CannotConvert)?;
+        if pieces.is_empty() {
+            return Err(Num2Err::CannotConvert);
+        }
+        self.combine(&pieces)
+    }
+
+    // e.g. "seven five" => 0.75
+    fn fraction(&self, mut words: &str) -> Result<BigFloat, Num2Err> {
+        let mut fraction = BigFloat::from(0);
+        let mut place = BigFloat::from(1);
+
+        while !words.is_empty() {
+            let (digit, rest) = self
+                .digits
+                .iter()
+                .enumerate()
+                .find_map(|(digit, word)| Some((digit, words.strip_prefix(word.as_str())?)))
+                .ok_or(Num2Err::CannotConvert)?;
+            place /= BigFloat::from(10);
+            fraction += BigFloat::from(digit as u64) * place;
+            words = rest;
+        }
+
+        Ok(fraction)
+    }
+
+    fn number(&self, words: &str) -> Result<BigFloat, Num2Err> {
+        if let Some(rest) = self
+            .minus
+            .as_ref()
+            .and_then(|m| words.strip_prefix(m.as_str()))
+        {
+            return Ok(-self.number(rest)?);
+        }
+        if let Ok(num) = self.integer(words) {
+            return Ok(num);
+        }
+
+        // the point may also be found within words, so try each of them
+        let point = self.point.as_ref().ok_or(Num2Err::CannotConvert)?;
+        for (i, _) in words.match_indices(point.as_str()) {
+            let (integral, fraction) = (&words[..i], &words[i + point.len()..]);
+            if fraction.is_empty() {
+                continue;
+            }
+            // some languages leave out the zero, e.g. "point five"
+            let integral = match integral.is_empty() {
+                true => Ok(BigFloat::from(0)),
+                false => self.integer(integral),
+            };
+            if let (Ok(integral), Ok(fraction)) = (integral, self.fraction(fraction)) {
+                return Ok(integral + fraction);
+            }
+        }
+
+        Err(Num2Err::CannotConvert)
+    }
+}
+
+/// Reads a cardinal written in words back into a number, with the words of
+/// the language's own cardinals, see [`Language::parse_words`]
+pub fn parse_words<L: Language + ?Sized>(language: &L, words: &str) -> Result<BigFloat, Num2Err> {
+    let words = normalize(words);
+    if words.is_empty() {
+        return Err(Num2Err::CannotConvert);
+    }
+    Vocabulary::new(language)?.number(&words)
+}
@@ -0,0 +1,87 @@
+use num2words::{Lang, Num2Words};
+
+fn spell(lang: Lang, num: i64) -> String {
+    Num2Words::new(num).lang(lang).to_words().unwrap()
+}
+
+#[test]
+fn test_billion() {
+    assert_eq!(spell(Lang::English, 1_000_000_000), "one billion");
+    assert_eq!(spell(Lang::French, 1_000_000_000), "un milliard");
+    assert_eq!(spell(Lang::French_BE, 1_000_000_000), "un milliard");
+    assert_eq!(spell(Lang::French_CH, 1_000_000_000), "un milliard");
+    assert_eq!(spell(Lang::Ukrainian, 1_000_000_000), "один мільярд");
+    assert_eq!(spell(Lang::Dutch, 1_000_000_000), "één miljard");
+    assert_eq!(spell(Lang::Frisian, 1_000_000_000), "ien miljard");
+    assert_eq!(spell(Lang::Pashto, 1_000_000_000), "یو میلیارد");
+    assert_eq!(spell(Lang::Belarusian, 1_000_000_000), "адзін мільярд");
+    assert_eq!(spell(Lang::Italian, 1_000_000_000), "un miliardo");
+    assert_eq!(spell(Lang::Portuguese, 1_000_000_000), "mil milhões");
+    assert_eq!(spell(Lang::Portuguese_BR, 1_000_000_000), "um bilhão");
+    assert_eq!(spell(Lang::Polish, 1_000_000_000), "miliard");
+    assert_eq!(spell(Lang::Arabic, 1_000_000_000), "مليار");
+    assert_eq!(spell(Lang::Japanese, 1_000_000_000), "十億");
+    assert_eq!(spell(Lang::Chinese, 1_000_000_000), "十亿");
+    assert_eq!(spell(Lang::Korean, 1_000_000_000), "십억");
+    assert_eq!(spell(Lang::Hindi, 1_000_000_000), "एक अरब");
+    assert_eq!(spell(Lang::Swedish, 1_000_000_000), "en miljard");
+    assert_eq!(spell(Lang::Norwegian, 1_000_000_000), "en milliard");
+    assert_eq!(spell(Lang::Danish, 1_000_000_000), "en milliard");
+    assert_eq!(spell(Lang::Romanian, 1_000_000_000), "un miliard");
+    assert_eq!(spell(Lang::Czech, 1_000_000_000), "miliarda");
+    assert_eq!(spell(Lang::Slovak, 1_000_000_000), "miliarda");
+    assert_eq!(spell(Lang::Greek, 1_000_000_000), "ένα δισεκατομμύριο");
+    assert_eq!(spell(Lang::Finnish, 1_000_000_000), "miljardi");
+    assert_eq!(spell(Lang::Esperanto, 1_000_000_000), "unu miliardo");
+}
+
+#[test]
+fn test_trillion() {
+    assert_eq!(spell(Lang::English, 1_000_000_000_000), "one trillion");
+    assert_eq!(spell(Lang::French, 1_000_000_000_000), "un billion");
+    assert_eq!(spell(Lang::French_BE, 1_000_000_000_000), "un billion");
+    assert_eq!(spell(Lang::French_CH, 1_000_000_000_000), "un billion");
+    assert_eq!(spell(Lang::Ukrainian, 1_000_000_000_000), "один трильйон");
+    assert_eq!(spell(Lang::Dutch, 1_000_000_000_000), "één biljoen");
+    assert_eq!(spell(Lang::Frisian, 1_000_000_000_000), "ien biljoen");
+    assert_eq!(spell(Lang::Pashto, 1_000_000_000_000), "یو ټریلیون");
+    assert_eq!(spell(Lang::Belarusian, 1_000_000_000_000), "адзін трыльён");
+    assert_eq!(spell(Lang::Italian, 1_000_000_000_000), "un bilione");
+    assert_eq!(spell(Lang::Portuguese, 1_000_000_000_000), "um bilião");
+    assert_eq!(spell(Lang::Portuguese_BR, 1_000_000_000_000), "um trilhão");
+    assert_eq!(spell(Lang::Polish, 1_000_000_000_000), "bilion");
+    assert_eq!(spell(Lang::Arabic, 1_000_000_000_000), "تريليون");
+    assert_eq!(spell(Lang::Japanese, 1_000_000_000_000), "一兆");
+    assert_eq!(spell(Lang::Chinese, 1_000_000_000_000), "一兆");
+    assert_eq!(spell(Lang::Korean, 1_000_000_000_000), "일조");
+    assert_eq!(spell(Lang::Hindi, 1_000_000_000_000), "दस खरब");
+    assert_eq!(spell(Lang::Swedish, 1_000_000_000_000), "en biljon");
+    assert_eq!(spell(Lang::Norwegian, 1_000_000_000_000), "en billion");
+    assert_eq!(spell(Lang::Danish, 1_000_000_000_000), "en billion");
+    assert_eq!(spell(Lang::Romanian, 1_000_000_000_000), "un bilion");
+    assert_eq!(spell(Lang::Czech, 1_000_000_000_000), "bilion");
+    assert_eq!(spell(Lang::Slovak, 1_000_000_000_000), "bilión");
+    assert_eq!(spell(Lang::Greek, 1_000_000_000_000), "ένα τρισεκατομμύριο");
+    assert_eq!(spell(Lang::Finnish, 1_000_000_000_000), "biljoona");
+    assert_eq!(spell(Lang::Esperanto, 1_000_000_000_000), "unu biliono");
+}
+
+#[test]
+fn test_float_scales() {
+    let langs: [fn() -> Lang; 4] = [
+        || Lang::English,
+        || Lang::French,
+        || Lang::Ukrainian,
+        || Lang::Dutch,
+    ];
+    for lang in langs {
+        assert_eq!(
+            Num2Words::new(1e9).lang(lang()).to_words().unwrap(),
+            spell(lang(), 1_000_000_000)
+        );
+        assert_eq!(
+            Num2Words::new(1e12).lang(lang()).to_words().unwrap(),
+            spell(lang(), 1_000_000_000_000)
+        );
+    }
+}